@@ -11,6 +11,10 @@ pub struct Model {
     pub title: String,
     #[sea_orm(column_type = "Text")]
     pub content: String,
+    #[sea_orm(column_type = "JsonBinary", nullable)]
+    pub blocks: Option<Json>,
+    pub word_count: i32,
+    pub reading_time: i32,
     pub author_id: Uuid,
     pub created_at: DateTime,
     pub cover_url: String,
@@ -19,6 +23,8 @@ pub struct Model {
     pub slug: String,
     pub tag_id: Option<i32>,
     pub tag_value: Option<String>,
+    pub version: i32,
+    pub comments_locked: bool,
 }
 
 #[derive(Copy, Clone, Debug, EnumIter, DeriveRelation)]
@@ -31,8 +37,22 @@ pub enum Relation {
         on_delete = "SetNull"
     )]
     ArticleTag,
+    #[sea_orm(has_many = "super::article_coauthor::Entity")]
+    ArticleCoauthor,
+    #[sea_orm(has_many = "super::article_note::Entity")]
+    ArticleNote,
+    #[sea_orm(has_many = "super::article_daily_views::Entity")]
+    ArticleDailyViews,
+    #[sea_orm(has_one = "super::article_edit_lock::Entity")]
+    ArticleEditLock,
+    #[sea_orm(has_many = "super::article_reaction::Entity")]
+    ArticleReaction,
+    #[sea_orm(has_many = "super::article_view::Entity")]
+    ArticleView,
     #[sea_orm(has_many = "super::comment::Entity")]
     Comment,
+    #[sea_orm(has_many = "super::mention::Entity")]
+    Mention,
     #[sea_orm(
         belongs_to = "super::user::Entity",
         from = "Column::AuthorId",
@@ -49,12 +69,54 @@ impl Related<super::article_tag::Entity> for Entity {
     }
 }
 
+impl Related<super::article_coauthor::Entity> for Entity {
+    fn to() -> RelationDef {
+        Relation::ArticleCoauthor.def()
+    }
+}
+
+impl Related<super::article_note::Entity> for Entity {
+    fn to() -> RelationDef {
+        Relation::ArticleNote.def()
+    }
+}
+
+impl Related<super::article_daily_views::Entity> for Entity {
+    fn to() -> RelationDef {
+        Relation::ArticleDailyViews.def()
+    }
+}
+
+impl Related<super::article_edit_lock::Entity> for Entity {
+    fn to() -> RelationDef {
+        Relation::ArticleEditLock.def()
+    }
+}
+
+impl Related<super::article_reaction::Entity> for Entity {
+    fn to() -> RelationDef {
+        Relation::ArticleReaction.def()
+    }
+}
+
+impl Related<super::article_view::Entity> for Entity {
+    fn to() -> RelationDef {
+        Relation::ArticleView.def()
+    }
+}
+
 impl Related<super::comment::Entity> for Entity {
     fn to() -> RelationDef {
         Relation::Comment.def()
     }
 }
 
+impl Related<super::mention::Entity> for Entity {
+    fn to() -> RelationDef {
+        Relation::Mention.def()
+    }
+}
+
 impl Related<super::user::Entity> for Entity {
     fn to() -> RelationDef {
         Relation::User.def()
@@ -14,6 +14,9 @@ pub struct Model {
     pub created_at: DateTime,
     pub is_active: bool,
     pub article_id: Option<Uuid>,
+    pub parent_id: Option<Uuid>,
+    pub edited_at: Option<DateTime>,
+    pub pinned_at: Option<DateTime>,
 }
 
 #[derive(Copy, Clone, Debug, EnumIter, DeriveRelation)]
@@ -26,8 +29,22 @@ pub enum Relation {
         on_delete = "NoAction"
     )]
     Article,
+    #[sea_orm(
+        belongs_to = "Entity",
+        from = "Column::ParentId",
+        to = "Column::Id",
+        on_update = "NoAction",
+        on_delete = "Cascade"
+    )]
+    SelfRef,
+    #[sea_orm(has_many = "super::comment_like::Entity")]
+    CommentLike,
     #[sea_orm(has_many = "super::comment_report::Entity")]
     CommentReport,
+    #[sea_orm(has_many = "super::comment_revision::Entity")]
+    CommentRevision,
+    #[sea_orm(has_many = "super::mention::Entity")]
+    Mention,
     #[sea_orm(
         belongs_to = "super::user::Entity",
         from = "Column::AuthorId",
@@ -44,12 +61,30 @@ impl Related<super::article::Entity> for Entity {
     }
 }
 
+impl Related<super::comment_like::Entity> for Entity {
+    fn to() -> RelationDef {
+        Relation::CommentLike.def()
+    }
+}
+
 impl Related<super::comment_report::Entity> for Entity {
     fn to() -> RelationDef {
         Relation::CommentReport.def()
     }
 }
 
+impl Related<super::comment_revision::Entity> for Entity {
+    fn to() -> RelationDef {
+        Relation::CommentRevision.def()
+    }
+}
+
+impl Related<super::mention::Entity> for Entity {
+    fn to() -> RelationDef {
+        Relation::Mention.def()
+    }
+}
+
 impl Related<super::user::Entity> for Entity {
     fn to() -> RelationDef {
         Relation::User.def()
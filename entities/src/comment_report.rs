@@ -10,6 +10,7 @@ pub struct Model {
     pub id: i32,
     pub comment_id: Uuid,
     pub user_id: Uuid,
+    pub reason: String,
     #[sea_orm(column_type = "Text")]
     pub message: String,
     pub created_at: DateTime,
@@ -14,6 +14,7 @@ pub struct Model {
     pub link_is_external: bool,
     pub created_at: DateTime,
     pub available_until: Option<DateTime>,
+    pub version: i32,
 }
 
 #[derive(Copy, Clone, Debug, EnumIter, DeriveRelation)]
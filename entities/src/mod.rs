@@ -3,11 +3,21 @@
 pub mod prelude;
 
 pub mod article;
+pub mod article_coauthor;
+pub mod article_daily_views;
+pub mod article_edit_lock;
+pub mod article_note;
+pub mod article_reaction;
 pub mod article_tag;
+pub mod article_view;
 pub mod comment;
+pub mod comment_like;
 pub mod comment_report;
+pub mod comment_revision;
 pub mod free_badge;
+pub mod mention;
 pub mod sea_orm_active_enums;
 pub mod team_role;
 pub mod team_user;
 pub mod user;
+pub mod word_filter;
@@ -1,10 +1,20 @@
 //! `SeaORM` Entity. Generated by sea-orm-codegen 0.12.10
 
 pub use super::article::Entity as Article;
+pub use super::article_coauthor::Entity as ArticleCoauthor;
+pub use super::article_daily_views::Entity as ArticleDailyViews;
+pub use super::article_edit_lock::Entity as ArticleEditLock;
+pub use super::article_note::Entity as ArticleNote;
+pub use super::article_reaction::Entity as ArticleReaction;
 pub use super::article_tag::Entity as ArticleTag;
+pub use super::article_view::Entity as ArticleView;
 pub use super::comment::Entity as Comment;
+pub use super::comment_like::Entity as CommentLike;
 pub use super::comment_report::Entity as CommentReport;
+pub use super::comment_revision::Entity as CommentRevision;
 pub use super::free_badge::Entity as FreeBadge;
+pub use super::mention::Entity as Mention;
 pub use super::team_role::Entity as TeamRole;
 pub use super::team_user::Entity as TeamUser;
 pub use super::user::Entity as User;
+pub use super::word_filter::Entity as WordFilter;
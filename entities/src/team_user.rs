@@ -14,6 +14,7 @@ pub struct Model {
     pub discord: Option<String>,
     pub created_at: DateTime,
     pub team_role_id: Uuid,
+    pub version: i32,
 }
 
 #[derive(Copy, Clone, Debug, EnumIter, DeriveRelation)]
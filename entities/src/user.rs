@@ -15,14 +15,23 @@ pub struct Model {
     pub created_at: DateTime,
     pub last_login: Option<DateTime>,
     pub role: Option<Role>,
+    pub version: i32,
 }
 
 #[derive(Copy, Clone, Debug, EnumIter, DeriveRelation)]
 pub enum Relation {
     #[sea_orm(has_many = "super::article::Entity")]
     Article,
+    #[sea_orm(has_many = "super::article_coauthor::Entity")]
+    ArticleCoauthor,
+    #[sea_orm(has_many = "super::article_edit_lock::Entity")]
+    ArticleEditLock,
+    #[sea_orm(has_many = "super::article_reaction::Entity")]
+    ArticleReaction,
     #[sea_orm(has_many = "super::comment::Entity")]
     Comment,
+    #[sea_orm(has_many = "super::comment_like::Entity")]
+    CommentLike,
 }
 
 impl Related<super::article::Entity> for Entity {
@@ -31,10 +40,34 @@ impl Related<super::article::Entity> for Entity {
     }
 }
 
+impl Related<super::article_coauthor::Entity> for Entity {
+    fn to() -> RelationDef {
+        Relation::ArticleCoauthor.def()
+    }
+}
+
+impl Related<super::article_edit_lock::Entity> for Entity {
+    fn to() -> RelationDef {
+        Relation::ArticleEditLock.def()
+    }
+}
+
+impl Related<super::article_reaction::Entity> for Entity {
+    fn to() -> RelationDef {
+        Relation::ArticleReaction.def()
+    }
+}
+
 impl Related<super::comment::Entity> for Entity {
     fn to() -> RelationDef {
         Relation::Comment.def()
     }
 }
 
+impl Related<super::comment_like::Entity> for Entity {
+    fn to() -> RelationDef {
+        Relation::CommentLike.def()
+    }
+}
+
 impl ActiveModelBehavior for ActiveModel {}
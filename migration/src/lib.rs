@@ -24,6 +24,22 @@ mod m20240719_034959_create_article_tag_table_and_add_tag_to_article;
 mod m20240722_224100_remove_unique_constraint_from_team_user_field;
 mod m20240725_022019_create_free_badges_table;
 mod m20240726_175757_rename_user_role_enum_writter_property_to_writer;
+mod m20261019_120000_create_article_view_tables;
+mod m20261019_130000_create_article_reaction_table;
+mod m20261019_140000_add_blocks_to_article;
+mod m20261019_150000_add_reading_metadata_to_article;
+mod m20261019_160000_create_article_coauthor_table;
+mod m20261019_170000_create_article_note_table;
+mod m20261019_180000_create_article_edit_lock_table;
+mod m20261019_190000_add_version_to_mutable_tables;
+mod m20261019_200000_add_parent_id_to_comment_table;
+mod m20261019_210000_create_comment_revision_table;
+mod m20261019_220000_create_mention_table;
+mod m20261019_230000_create_word_filter_table;
+mod m20261019_231000_add_comments_locked_to_article_table;
+mod m20261019_232000_add_pinned_at_to_comment_table;
+mod m20261019_233000_create_comment_like_table;
+mod m20261019_234000_add_reason_to_comment_report_table;
 
 pub struct Migrator;
 
@@ -55,6 +71,22 @@ impl MigratorTrait for Migrator {
             Box::new(m20240722_224100_remove_unique_constraint_from_team_user_field::Migration),
             Box::new(m20240725_022019_create_free_badges_table::Migration),
             Box::new(m20240726_175757_rename_user_role_enum_writter_property_to_writer::Migration),
+            Box::new(m20261019_120000_create_article_view_tables::Migration),
+            Box::new(m20261019_130000_create_article_reaction_table::Migration),
+            Box::new(m20261019_140000_add_blocks_to_article::Migration),
+            Box::new(m20261019_150000_add_reading_metadata_to_article::Migration),
+            Box::new(m20261019_160000_create_article_coauthor_table::Migration),
+            Box::new(m20261019_170000_create_article_note_table::Migration),
+            Box::new(m20261019_180000_create_article_edit_lock_table::Migration),
+            Box::new(m20261019_190000_add_version_to_mutable_tables::Migration),
+            Box::new(m20261019_200000_add_parent_id_to_comment_table::Migration),
+            Box::new(m20261019_210000_create_comment_revision_table::Migration),
+            Box::new(m20261019_220000_create_mention_table::Migration),
+            Box::new(m20261019_230000_create_word_filter_table::Migration),
+            Box::new(m20261019_231000_add_comments_locked_to_article_table::Migration),
+            Box::new(m20261019_232000_add_pinned_at_to_comment_table::Migration),
+            Box::new(m20261019_233000_create_comment_like_table::Migration),
+            Box::new(m20261019_234000_add_reason_to_comment_report_table::Migration),
         ]
     }
 }
@@ -0,0 +1,89 @@
+use sea_orm_migration::prelude::*;
+
+#[derive(DeriveMigrationName)]
+pub struct Migration;
+
+#[async_trait::async_trait]
+impl MigrationTrait for Migration {
+    async fn up(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .create_table(
+                Table::create()
+                    .table(ArticleView::Table)
+                    .if_not_exists()
+                    .col(ColumnDef::new(ArticleView::ArticleId).uuid().not_null())
+                    .col(ColumnDef::new(ArticleView::ViewerKey).string().not_null())
+                    .col(ColumnDef::new(ArticleView::ViewedAt).date_time().not_null().extra("DEFAULT NOW()"))
+                    .primary_key(Index::create().col(ArticleView::ArticleId).col(ArticleView::ViewerKey))
+
+                    .foreign_key(ForeignKey::create().name("fk-article-view-article-id")
+                        .from(ArticleView::Table, ArticleView::ArticleId)
+                        .to(Article::Table, Article::Id)
+                        .on_delete(ForeignKeyAction::Cascade))
+
+                    .to_owned(),
+            )
+            .await?;
+
+        manager
+            .create_table(
+                Table::create()
+                    .table(ArticleDailyViews::Table)
+                    .if_not_exists()
+                    .col(ColumnDef::new(ArticleDailyViews::ArticleId).uuid().not_null())
+                    .col(ColumnDef::new(ArticleDailyViews::Day).date().not_null())
+                    .col(ColumnDef::new(ArticleDailyViews::Views).big_integer().not_null().default(0))
+                    .primary_key(Index::create().col(ArticleDailyViews::ArticleId).col(ArticleDailyViews::Day))
+
+                    .foreign_key(ForeignKey::create().name("fk-article-daily-views-article-id")
+                        .from(ArticleDailyViews::Table, ArticleDailyViews::ArticleId)
+                        .to(Article::Table, Article::Id)
+                        .on_delete(ForeignKeyAction::Cascade))
+
+                    .to_owned(),
+            )
+            .await?;
+
+        manager
+            .create_index(
+                Index::create()
+                    .name("idx-article-daily-views-day")
+                    .table(ArticleDailyViews::Table)
+                    .col(ArticleDailyViews::Day)
+                    .to_owned()
+            )
+            .await
+    }
+
+    async fn down(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .drop_table(Table::drop().table(ArticleDailyViews::Table).to_owned())
+            .await?;
+
+        manager
+            .drop_table(Table::drop().table(ArticleView::Table).to_owned())
+            .await
+    }
+}
+
+#[derive(DeriveIden)]
+enum ArticleView {
+    Table,
+    ArticleId,
+    ViewerKey,
+    ViewedAt,
+}
+
+#[derive(DeriveIden)]
+enum ArticleDailyViews {
+    Table,
+    ArticleId,
+    Day,
+    Views,
+}
+
+#[derive(DeriveIden)]
+enum Article {
+    Table,
+    Id,
+}
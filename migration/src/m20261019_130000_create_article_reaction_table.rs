@@ -0,0 +1,63 @@
+use sea_orm_migration::prelude::*;
+
+#[derive(DeriveMigrationName)]
+pub struct Migration;
+
+#[async_trait::async_trait]
+impl MigrationTrait for Migration {
+    async fn up(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .create_table(
+                Table::create()
+                    .table(ArticleReaction::Table)
+                    .if_not_exists()
+                    .col(ColumnDef::new(ArticleReaction::ArticleId).uuid().not_null())
+                    .col(ColumnDef::new(ArticleReaction::UserId).uuid().not_null())
+                    .col(ColumnDef::new(ArticleReaction::Reaction).string().not_null())
+                    .col(ColumnDef::new(ArticleReaction::CreatedAt).date_time().not_null().extra("DEFAULT NOW()"))
+                    .col(ColumnDef::new(ArticleReaction::UpdatedAt).date_time())
+                    .primary_key(Index::create().col(ArticleReaction::ArticleId).col(ArticleReaction::UserId))
+
+                    .foreign_key(ForeignKey::create().name("fk-article-reaction-article-id")
+                        .from(ArticleReaction::Table, ArticleReaction::ArticleId)
+                        .to(Article::Table, Article::Id)
+                        .on_delete(ForeignKeyAction::Cascade))
+
+                    .foreign_key(ForeignKey::create().name("fk-article-reaction-user-id")
+                        .from(ArticleReaction::Table, ArticleReaction::UserId)
+                        .to(User::Table, User::Id)
+                        .on_delete(ForeignKeyAction::Cascade))
+
+                    .to_owned(),
+            )
+            .await
+    }
+
+    async fn down(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .drop_table(Table::drop().table(ArticleReaction::Table).to_owned())
+            .await
+    }
+}
+
+#[derive(DeriveIden)]
+enum ArticleReaction {
+    Table,
+    ArticleId,
+    UserId,
+    Reaction,
+    CreatedAt,
+    UpdatedAt,
+}
+
+#[derive(DeriveIden)]
+enum Article {
+    Table,
+    Id,
+}
+
+#[derive(DeriveIden)]
+enum User {
+    Table,
+    Id,
+}
@@ -0,0 +1,51 @@
+use sea_orm_migration::prelude::*;
+
+#[derive(DeriveMigrationName)]
+pub struct Migration;
+
+#[async_trait::async_trait]
+impl MigrationTrait for Migration {
+    async fn up(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .alter_table(
+                Table::alter()
+                    .table(Article::Table)
+                    .add_column(ColumnDef::new(Article::WordCount).integer().not_null().default(0))
+                    .add_column(ColumnDef::new(Article::ReadingTime).integer().not_null().default(0))
+                    .to_owned()
+            )
+            .await?;
+
+        // approximates the metadata of the existing articles from their raw content, until they are saved again
+        let db = manager.get_connection();
+
+        db.execute_unprepared(
+            "UPDATE article SET word_count = COALESCE(array_length(regexp_split_to_array(btrim(content), '\\s+'), 1), 0)"
+        ).await?;
+
+        db.execute_unprepared(
+            "UPDATE article SET reading_time = GREATEST(1, CEIL(word_count / 200.0))"
+        ).await?;
+
+        Ok(())
+    }
+
+    async fn down(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .alter_table(
+                Table::alter()
+                    .table(Article::Table)
+                    .drop_column(Article::WordCount)
+                    .drop_column(Article::ReadingTime)
+                    .to_owned()
+            )
+            .await
+    }
+}
+
+#[derive(DeriveIden)]
+enum Article {
+    Table,
+    WordCount,
+    ReadingTime
+}
@@ -0,0 +1,63 @@
+use sea_orm_migration::prelude::*;
+
+#[derive(DeriveMigrationName)]
+pub struct Migration;
+
+#[async_trait::async_trait]
+impl MigrationTrait for Migration {
+    async fn up(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .create_table(
+                Table::create()
+                    .table(ArticleCoauthor::Table)
+                    .if_not_exists()
+                    .col(ColumnDef::new(ArticleCoauthor::ArticleId).uuid().not_null())
+                    .col(ColumnDef::new(ArticleCoauthor::UserId).uuid().not_null())
+                    .col(ColumnDef::new(ArticleCoauthor::Position).integer().not_null())
+                    .col(ColumnDef::new(ArticleCoauthor::Role).string())
+                    .col(ColumnDef::new(ArticleCoauthor::CreatedAt).date_time().not_null().extra("DEFAULT NOW()"))
+                    .primary_key(Index::create().col(ArticleCoauthor::ArticleId).col(ArticleCoauthor::UserId))
+
+                    .foreign_key(ForeignKey::create().name("fk-article-coauthor-article-id")
+                        .from(ArticleCoauthor::Table, ArticleCoauthor::ArticleId)
+                        .to(Article::Table, Article::Id)
+                        .on_delete(ForeignKeyAction::Cascade))
+
+                    .foreign_key(ForeignKey::create().name("fk-article-coauthor-user-id")
+                        .from(ArticleCoauthor::Table, ArticleCoauthor::UserId)
+                        .to(User::Table, User::Id)
+                        .on_delete(ForeignKeyAction::Cascade))
+
+                    .to_owned(),
+            )
+            .await
+    }
+
+    async fn down(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .drop_table(Table::drop().table(ArticleCoauthor::Table).to_owned())
+            .await
+    }
+}
+
+#[derive(DeriveIden)]
+enum ArticleCoauthor {
+    Table,
+    ArticleId,
+    UserId,
+    Position,
+    Role,
+    CreatedAt,
+}
+
+#[derive(DeriveIden)]
+enum Article {
+    Table,
+    Id,
+}
+
+#[derive(DeriveIden)]
+enum User {
+    Table,
+    Id,
+}
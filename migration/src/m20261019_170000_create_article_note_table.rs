@@ -0,0 +1,88 @@
+use sea_orm_migration::prelude::*;
+
+#[derive(DeriveMigrationName)]
+pub struct Migration;
+
+#[async_trait::async_trait]
+impl MigrationTrait for Migration {
+    async fn up(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .create_table(
+                Table::create()
+                    .table(ArticleNote::Table)
+                    .if_not_exists()
+                    .col(ColumnDef::new(ArticleNote::Id).uuid().not_null().primary_key())
+                    .col(ColumnDef::new(ArticleNote::ArticleId).uuid().not_null())
+                    .col(ColumnDef::new(ArticleNote::AuthorId).uuid().not_null())
+                    .col(ColumnDef::new(ArticleNote::ParentId).uuid())
+                    .col(ColumnDef::new(ArticleNote::Content).text().not_null())
+                    .col(ColumnDef::new(ArticleNote::ResolvedBy).uuid())
+                    .col(ColumnDef::new(ArticleNote::ResolvedAt).date_time())
+                    .col(ColumnDef::new(ArticleNote::CreatedAt).date_time().not_null().extra("DEFAULT NOW()"))
+
+                    .foreign_key(ForeignKey::create().name("fk-article-note-article-id")
+                        .from(ArticleNote::Table, ArticleNote::ArticleId)
+                        .to(Article::Table, Article::Id)
+                        .on_delete(ForeignKeyAction::Cascade))
+
+                    .foreign_key(ForeignKey::create().name("fk-article-note-author-id")
+                        .from(ArticleNote::Table, ArticleNote::AuthorId)
+                        .to(User::Table, User::Id)
+                        .on_delete(ForeignKeyAction::Cascade))
+
+                    .foreign_key(ForeignKey::create().name("fk-article-note-parent-id")
+                        .from(ArticleNote::Table, ArticleNote::ParentId)
+                        .to(ArticleNote::Table, ArticleNote::Id)
+                        .on_delete(ForeignKeyAction::Cascade))
+
+                    .foreign_key(ForeignKey::create().name("fk-article-note-resolved-by")
+                        .from(ArticleNote::Table, ArticleNote::ResolvedBy)
+                        .to(User::Table, User::Id)
+                        .on_delete(ForeignKeyAction::SetNull))
+
+                    .to_owned(),
+            )
+            .await?;
+
+        manager
+            .create_index(
+                Index::create()
+                    .name("idx-article-note-article-id")
+                    .table(ArticleNote::Table)
+                    .col(ArticleNote::ArticleId)
+                    .to_owned(),
+            )
+            .await
+    }
+
+    async fn down(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .drop_table(Table::drop().table(ArticleNote::Table).to_owned())
+            .await
+    }
+}
+
+#[derive(DeriveIden)]
+enum ArticleNote {
+    Table,
+    Id,
+    ArticleId,
+    AuthorId,
+    ParentId,
+    Content,
+    ResolvedBy,
+    ResolvedAt,
+    CreatedAt,
+}
+
+#[derive(DeriveIden)]
+enum Article {
+    Table,
+    Id,
+}
+
+#[derive(DeriveIden)]
+enum User {
+    Table,
+    Id,
+}
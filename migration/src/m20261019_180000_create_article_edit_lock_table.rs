@@ -0,0 +1,60 @@
+use sea_orm_migration::prelude::*;
+
+#[derive(DeriveMigrationName)]
+pub struct Migration;
+
+#[async_trait::async_trait]
+impl MigrationTrait for Migration {
+    async fn up(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .create_table(
+                Table::create()
+                    .table(ArticleEditLock::Table)
+                    .if_not_exists()
+                    .col(ColumnDef::new(ArticleEditLock::ArticleId).uuid().not_null().primary_key())
+                    .col(ColumnDef::new(ArticleEditLock::UserId).uuid().not_null())
+                    .col(ColumnDef::new(ArticleEditLock::AcquiredAt).date_time().not_null())
+                    .col(ColumnDef::new(ArticleEditLock::ExpiresAt).date_time().not_null())
+
+                    .foreign_key(ForeignKey::create().name("fk-article-edit-lock-article-id")
+                        .from(ArticleEditLock::Table, ArticleEditLock::ArticleId)
+                        .to(Article::Table, Article::Id)
+                        .on_delete(ForeignKeyAction::Cascade))
+
+                    .foreign_key(ForeignKey::create().name("fk-article-edit-lock-user-id")
+                        .from(ArticleEditLock::Table, ArticleEditLock::UserId)
+                        .to(User::Table, User::Id)
+                        .on_delete(ForeignKeyAction::Cascade))
+
+                    .to_owned(),
+            )
+            .await
+    }
+
+    async fn down(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .drop_table(Table::drop().table(ArticleEditLock::Table).to_owned())
+            .await
+    }
+}
+
+#[derive(DeriveIden)]
+enum ArticleEditLock {
+    Table,
+    ArticleId,
+    UserId,
+    AcquiredAt,
+    ExpiresAt,
+}
+
+#[derive(DeriveIden)]
+enum Article {
+    Table,
+    Id,
+}
+
+#[derive(DeriveIden)]
+enum User {
+    Table,
+    Id,
+}
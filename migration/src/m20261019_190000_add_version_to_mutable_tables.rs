@@ -0,0 +1,47 @@
+use sea_orm_migration::prelude::*;
+
+#[derive(DeriveMigrationName)]
+pub struct Migration;
+
+#[async_trait::async_trait]
+impl MigrationTrait for Migration {
+    async fn up(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        for table in versioned_tables() {
+            manager
+                .alter_table(
+                    Table::alter()
+                        .table(table)
+                        .add_column(ColumnDef::new(Version).integer().not_null().default(1))
+                        .to_owned()
+                )
+                .await?;
+        }
+
+        Ok(())
+    }
+
+    async fn down(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        for table in versioned_tables() {
+            manager
+                .alter_table(
+                    Table::alter()
+                        .table(table)
+                        .drop_column(Version)
+                        .to_owned()
+                )
+                .await?;
+        }
+
+        Ok(())
+    }
+}
+
+fn versioned_tables() -> Vec<Alias> {
+    ["article", "article_tag", "free_badge", "team_user", "user"]
+        .into_iter()
+        .map(Alias::new)
+        .collect()
+}
+
+#[derive(DeriveIden)]
+struct Version;
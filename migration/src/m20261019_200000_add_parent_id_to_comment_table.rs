@@ -0,0 +1,57 @@
+use sea_orm_migration::prelude::*;
+
+#[derive(DeriveMigrationName)]
+pub struct Migration;
+
+#[async_trait::async_trait]
+impl MigrationTrait for Migration {
+    async fn up(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .alter_table(
+                Table::alter()
+                    .table(Comment::Table)
+                    .add_column(ColumnDef::new(Comment::ParentId).uuid().null())
+                    .add_foreign_key(TableForeignKey::new().name("fk-comment-parent-id")
+                        .from_tbl(Comment::Table)
+                        .from_col(Comment::ParentId)
+                        .to_tbl(Comment::Table)
+                        .to_col(Comment::Id)
+                        .on_delete(ForeignKeyAction::Cascade))
+                    .to_owned(),
+            )
+            .await?;
+
+        manager
+            .create_index(
+                Index::create()
+                    .name("idx-comment-parent-id")
+                    .table(Comment::Table)
+                    .col(Comment::ParentId)
+                    .to_owned(),
+            )
+            .await
+    }
+
+    async fn down(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .drop_index(Index::drop().name("idx-comment-parent-id").table(Comment::Table).to_owned())
+            .await?;
+
+        manager
+            .alter_table(
+                Table::alter()
+                    .table(Comment::Table)
+                    .drop_foreign_key(Alias::new("fk-comment-parent-id"))
+                    .drop_column(Comment::ParentId)
+                    .to_owned(),
+            )
+            .await
+    }
+}
+
+#[derive(DeriveIden)]
+enum Comment {
+    Table,
+    Id,
+    ParentId,
+}
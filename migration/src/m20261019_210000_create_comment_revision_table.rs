@@ -0,0 +1,68 @@
+use sea_orm_migration::prelude::*;
+
+#[derive(DeriveMigrationName)]
+pub struct Migration;
+
+#[async_trait::async_trait]
+impl MigrationTrait for Migration {
+    async fn up(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .alter_table(
+                Table::alter()
+                    .table(Comment::Table)
+                    .add_column(ColumnDef::new(Comment::EditedAt).date_time().null())
+                    .to_owned(),
+            )
+            .await?;
+
+        manager
+            .create_table(
+                Table::create()
+                    .table(CommentRevision::Table)
+                    .if_not_exists()
+                    .col(ColumnDef::new(CommentRevision::Id).uuid().not_null().primary_key())
+                    .col(ColumnDef::new(CommentRevision::CommentId).uuid().not_null())
+                    .col(ColumnDef::new(CommentRevision::Content).text().not_null())
+                    .col(ColumnDef::new(CommentRevision::RevisedAt).date_time().not_null())
+
+                    .foreign_key(ForeignKey::create().name("fk-comment-revision-comment-id")
+                        .from(CommentRevision::Table, CommentRevision::CommentId)
+                        .to(Comment::Table, Comment::Id)
+                        .on_delete(ForeignKeyAction::Cascade))
+
+                    .to_owned(),
+            )
+            .await
+    }
+
+    async fn down(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .drop_table(Table::drop().table(CommentRevision::Table).to_owned())
+            .await?;
+
+        manager
+            .alter_table(
+                Table::alter()
+                    .table(Comment::Table)
+                    .drop_column(Comment::EditedAt)
+                    .to_owned(),
+            )
+            .await
+    }
+}
+
+#[derive(DeriveIden)]
+enum CommentRevision {
+    Table,
+    Id,
+    CommentId,
+    Content,
+    RevisedAt,
+}
+
+#[derive(DeriveIden)]
+enum Comment {
+    Table,
+    Id,
+    EditedAt,
+}
@@ -0,0 +1,90 @@
+use sea_orm_migration::prelude::*;
+
+#[derive(DeriveMigrationName)]
+pub struct Migration;
+
+#[async_trait::async_trait]
+impl MigrationTrait for Migration {
+    async fn up(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .create_table(
+                Table::create()
+                    .table(Mention::Table)
+                    .if_not_exists()
+                    .col(ColumnDef::new(Mention::Id).uuid().not_null().primary_key())
+                    .col(ColumnDef::new(Mention::UserId).uuid().not_null())
+                    .col(ColumnDef::new(Mention::MentionedBy).uuid().not_null())
+                    .col(ColumnDef::new(Mention::ArticleId).uuid().null())
+                    .col(ColumnDef::new(Mention::CommentId).uuid().null())
+                    .col(ColumnDef::new(Mention::CreatedAt).date_time().not_null())
+
+                    .foreign_key(ForeignKey::create().name("fk-mention-user-id")
+                        .from(Mention::Table, Mention::UserId)
+                        .to(User::Table, User::Id)
+                        .on_delete(ForeignKeyAction::Cascade))
+
+                    .foreign_key(ForeignKey::create().name("fk-mention-mentioned-by")
+                        .from(Mention::Table, Mention::MentionedBy)
+                        .to(User::Table, User::Id)
+                        .on_delete(ForeignKeyAction::Cascade))
+
+                    .foreign_key(ForeignKey::create().name("fk-mention-article-id")
+                        .from(Mention::Table, Mention::ArticleId)
+                        .to(Article::Table, Article::Id)
+                        .on_delete(ForeignKeyAction::Cascade))
+
+                    .foreign_key(ForeignKey::create().name("fk-mention-comment-id")
+                        .from(Mention::Table, Mention::CommentId)
+                        .to(Comment::Table, Comment::Id)
+                        .on_delete(ForeignKeyAction::Cascade))
+
+                    .to_owned(),
+            )
+            .await?;
+
+        manager
+            .create_index(
+                Index::create()
+                    .name("idx-mention-user-id")
+                    .table(Mention::Table)
+                    .col(Mention::UserId)
+                    .to_owned(),
+            )
+            .await
+    }
+
+    async fn down(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .drop_table(Table::drop().table(Mention::Table).to_owned())
+            .await
+    }
+}
+
+#[derive(DeriveIden)]
+enum Mention {
+    Table,
+    Id,
+    UserId,
+    MentionedBy,
+    ArticleId,
+    CommentId,
+    CreatedAt,
+}
+
+#[derive(DeriveIden)]
+enum User {
+    Table,
+    Id,
+}
+
+#[derive(DeriveIden)]
+enum Article {
+    Table,
+    Id,
+}
+
+#[derive(DeriveIden)]
+enum Comment {
+    Table,
+    Id,
+}
@@ -0,0 +1,43 @@
+use sea_orm_migration::prelude::*;
+
+#[derive(DeriveMigrationName)]
+pub struct Migration;
+
+#[async_trait::async_trait]
+impl MigrationTrait for Migration {
+    async fn up(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .create_table(
+                Table::create()
+                    .table(WordFilter::Table)
+                    .if_not_exists()
+                    .col(ColumnDef::new(WordFilter::Id).uuid().not_null().primary_key())
+                    .col(ColumnDef::new(WordFilter::Pattern).string().not_null())
+                    .col(ColumnDef::new(WordFilter::IsRegex).boolean().not_null().default(false))
+                    .col(ColumnDef::new(WordFilter::Action).string().not_null())
+                    .col(ColumnDef::new(WordFilter::CreatedAt).date_time().not_null().extra("DEFAULT NOW()"))
+                    .col(ColumnDef::new(WordFilter::UpdatedAt).date_time())
+                    .col(ColumnDef::new(WordFilter::Version).integer().not_null().default(1))
+                    .to_owned(),
+            )
+            .await
+    }
+
+    async fn down(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .drop_table(Table::drop().table(WordFilter::Table).to_owned())
+            .await
+    }
+}
+
+#[derive(DeriveIden)]
+enum WordFilter {
+    Table,
+    Id,
+    Pattern,
+    IsRegex,
+    Action,
+    CreatedAt,
+    UpdatedAt,
+    Version,
+}
@@ -0,0 +1,70 @@
+use sea_orm_migration::prelude::*;
+
+#[derive(DeriveMigrationName)]
+pub struct Migration;
+
+#[async_trait::async_trait]
+impl MigrationTrait for Migration {
+    async fn up(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .create_table(
+                Table::create()
+                    .table(CommentLike::Table)
+                    .if_not_exists()
+                    .col(ColumnDef::new(CommentLike::CommentId).uuid().not_null())
+                    .col(ColumnDef::new(CommentLike::UserId).uuid().not_null())
+                    .col(ColumnDef::new(CommentLike::CreatedAt).date_time().not_null().extra("DEFAULT NOW()"))
+                    .primary_key(Index::create().col(CommentLike::CommentId).col(CommentLike::UserId))
+
+                    .foreign_key(ForeignKey::create().name("fk-comment-like-comment-id")
+                        .from(CommentLike::Table, CommentLike::CommentId)
+                        .to(Comment::Table, Comment::Id)
+                        .on_delete(ForeignKeyAction::Cascade))
+
+                    .foreign_key(ForeignKey::create().name("fk-comment-like-user-id")
+                        .from(CommentLike::Table, CommentLike::UserId)
+                        .to(User::Table, User::Id)
+                        .on_delete(ForeignKeyAction::Cascade))
+
+                    .to_owned(),
+            )
+            .await?;
+
+        // the weekly ranking only looks at the latest likes
+        manager
+            .create_index(
+                Index::create()
+                    .name("idx-comment-like-created-at")
+                    .table(CommentLike::Table)
+                    .col(CommentLike::CreatedAt)
+                    .to_owned(),
+            )
+            .await
+    }
+
+    async fn down(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .drop_table(Table::drop().table(CommentLike::Table).to_owned())
+            .await
+    }
+}
+
+#[derive(DeriveIden)]
+enum CommentLike {
+    Table,
+    CommentId,
+    UserId,
+    CreatedAt,
+}
+
+#[derive(DeriveIden)]
+enum Comment {
+    Table,
+    Id,
+}
+
+#[derive(DeriveIden)]
+enum User {
+    Table,
+    Id,
+}
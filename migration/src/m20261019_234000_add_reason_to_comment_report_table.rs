@@ -0,0 +1,67 @@
+use sea_orm_migration::prelude::*;
+
+#[derive(DeriveMigrationName)]
+pub struct Migration;
+
+#[async_trait::async_trait]
+impl MigrationTrait for Migration {
+    async fn up(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        // the existing reports only have a free text message
+        manager
+            .alter_table(
+                Table::alter()
+                    .table(CommentReport::Table)
+                    .add_column(ColumnDef::new(CommentReport::Reason).string().not_null().default("other"))
+                    .to_owned(),
+            )
+            .await?;
+
+        // keeps only the first report of each user on each comment, so the constraint can be created
+        let db = manager.get_connection();
+
+        db.execute_unprepared(
+            "DELETE FROM comment_report AS duplicate USING comment_report AS first \
+            WHERE duplicate.comment_id = first.comment_id AND duplicate.user_id = first.user_id AND duplicate.id > first.id"
+        ).await?;
+
+        manager
+            .create_index(
+                Index::create()
+                    .name("idx-comment-report-comment-id-user-id")
+                    .table(CommentReport::Table)
+                    .col(CommentReport::CommentId)
+                    .col(CommentReport::UserId)
+                    .unique()
+                    .to_owned(),
+            )
+            .await
+    }
+
+    async fn down(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .drop_index(
+                Index::drop()
+                    .name("idx-comment-report-comment-id-user-id")
+                    .table(CommentReport::Table)
+                    .to_owned(),
+            )
+            .await?;
+
+        manager
+            .alter_table(
+                Table::alter()
+                    .table(CommentReport::Table)
+                    .drop_column(CommentReport::Reason)
+                    .to_owned(),
+            )
+            .await
+    }
+}
+
+#[derive(DeriveIden)]
+enum CommentReport {
+    Table,
+    CommentId,
+    UserId,
+    Reason,
+}
@@ -1,3 +1,9 @@
+use base64::{engine::general_purpose::URL_SAFE_NO_PAD, Engine};
+use chrono::{DateTime as ChronoDateTime, NaiveDateTime as DateTime};
+use std::fmt::Display;
+use std::str::FromStr;
+use uuid::Uuid;
+
 #[derive(Debug, PartialEq, Eq)]
 pub struct PaginationResponse {
     pub current_page: u32,
@@ -12,4 +18,170 @@ pub struct PaginationParameters<QT> {
     pub query: Option<QT>,
 }
 
-pub const DEFAULT_PER_PAGE: u8 = 9;
\ No newline at end of file
+pub const DEFAULT_PER_PAGE: u8 = 9;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum CursorDirection {
+    /// Items older than the cursor (next page on a newest-first listing).
+    After,
+    /// Items newer than the cursor (previous page on a newest-first listing).
+    Before,
+}
+
+/**
+ # Pagination Cursor
+ Keyset position on a listing ordered by `created_at` and `id`, both descending, or by a `rank`
+ before them on listings sorted by something else, such as the most replied comments. The `id`
+ is a `Uuid` on most listings, and a serial number on those of tables keyed by one.
+
+ It is sent to the clients as an opaque url-safe string that also carries the direction
+ the listing must walk from this position, so a `nextCursor` or `prevCursor` can be sent
+ back as-is.
+ */
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct PaginationCursor<Id = Uuid> {
+    pub created_at: DateTime,
+    pub id: Id,
+    pub direction: CursorDirection,
+    pub rank: Option<i64>,
+}
+
+impl<Id: Display + FromStr> PaginationCursor<Id> {
+    pub fn new(created_at: DateTime, id: Id, direction: CursorDirection) -> Self {
+        PaginationCursor { created_at, id, direction, rank: None }
+    }
+
+    pub fn with_rank(mut self, rank: Option<i64>) -> Self {
+        self.rank = rank;
+        self
+    }
+
+    pub fn encode(&self) -> String {
+        let direction = match self.direction {
+            CursorDirection::After => "a",
+            CursorDirection::Before => "b",
+        };
+
+        let created_at = self.created_at.and_utc();
+        let mut raw = format!("{}:{}.{}:{}", direction, created_at.timestamp(), created_at.timestamp_subsec_nanos(), self.id);
+
+        if let Some(rank) = self.rank {
+            raw.push_str(&format!(":{rank}"));
+        }
+
+        URL_SAFE_NO_PAD.encode(raw)
+    }
+
+    pub fn decode(value: &str) -> Option<Self> {
+        let raw = URL_SAFE_NO_PAD.decode(value).ok()?;
+        let raw = String::from_utf8(raw).ok()?;
+
+        let mut parts = raw.splitn(4, ':');
+
+        let direction = match parts.next()? {
+            "a" => CursorDirection::After,
+            "b" => CursorDirection::Before,
+            _ => return None,
+        };
+
+        let (secs, nanos) = parts.next()?.split_once('.')?;
+        let created_at = ChronoDateTime::from_timestamp(secs.parse().ok()?, nanos.parse().ok()?)?.naive_utc();
+        let id = parts.next()?.parse().ok()?;
+
+        let rank = match parts.next() {
+            None => None,
+            Some(rank) => Some(rank.parse().ok()?),
+        };
+
+        Some(PaginationCursor { created_at, id, direction, rank })
+    }
+}
+
+#[derive(Clone)]
+pub struct CursorPaginationParameters<QT, Id = Uuid> {
+    pub cursor: Option<PaginationCursor<Id>>,
+    pub items_per_page: u32,
+    pub query: Option<QT>,
+}
+
+#[derive(Debug, PartialEq, Eq)]
+pub struct CursorPaginationResponse {
+    pub next_cursor: Option<String>,
+    pub prev_cursor: Option<String>,
+}
+
+impl CursorPaginationResponse {
+    /**
+     Builds the neighbour cursors of a page.
+
+     `first` and `last` are the (`created_at`, `id`) keys of the page's edge items, and `has_more`
+     tells whether the repository found items beyond the page in the direction it walked.
+     */
+    pub fn from_page_edges<Id: Display + FromStr>(
+        cursor: Option<&PaginationCursor<Id>>,
+        first: Option<(DateTime, Id)>,
+        last: Option<(DateTime, Id)>,
+        has_more: bool,
+    ) -> Self {
+        Self::from_ranked_page_edges(
+            cursor,
+            first.map(|(created_at, id)| (None, created_at, id)),
+            last.map(|(created_at, id)| (None, created_at, id)),
+            has_more,
+        )
+    }
+
+    /// Same as `from_page_edges`, for listings ordered by a rank before `created_at` and `id`.
+    pub fn from_ranked_page_edges<Id: Display + FromStr>(
+        cursor: Option<&PaginationCursor<Id>>,
+        first: Option<(Option<i64>, DateTime, Id)>,
+        last: Option<(Option<i64>, DateTime, Id)>,
+        has_more: bool,
+    ) -> Self {
+        let (has_next, has_prev) = match cursor {
+            None => (has_more, false),
+            Some(cursor) => match cursor.direction {
+                CursorDirection::After => (has_more, true),
+                CursorDirection::Before => (true, has_more),
+            }
+        };
+
+        let next_cursor = match (has_next, last) {
+            (true, Some((rank, created_at, id))) => Some(PaginationCursor::new(created_at, id, CursorDirection::After).with_rank(rank).encode()),
+            _ => None,
+        };
+
+        let prev_cursor = match (has_prev, first) {
+            (true, Some((rank, created_at, id))) => Some(PaginationCursor::new(created_at, id, CursorDirection::Before).with_rank(rank).encode()),
+            _ => None,
+        };
+
+        CursorPaginationResponse { next_cursor, prev_cursor }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use chrono::NaiveDate;
+
+    #[test]
+    fn test_if_cursor_can_be_encoded_and_decoded() {
+        let created_at = NaiveDate::from_ymd_opt(2024, 7, 26).unwrap().and_hms_micro_opt(17, 57, 57, 123456).unwrap();
+        let cursor = PaginationCursor::new(created_at, Uuid::new_v4(), CursorDirection::Before);
+
+        let decoded = PaginationCursor::decode(&cursor.encode()).unwrap();
+
+        assert_eq!(cursor, decoded);
+
+        let ranked_cursor = cursor.clone().with_rank(Some(12));
+
+        assert_eq!(Some(12), PaginationCursor::<Uuid>::decode(&ranked_cursor.encode()).unwrap().rank);
+        assert!(PaginationCursor::<Uuid>::decode("not-a-cursor").is_none());
+
+        let serial_cursor = PaginationCursor::new(created_at, 42, CursorDirection::After);
+
+        assert_eq!(serial_cursor, PaginationCursor::<i32>::decode(&serial_cursor.encode()).unwrap());
+        assert!(PaginationCursor::<i32>::decode(&cursor.encode()).is_none(), "A uuid cursor can't be used on a serial listing.");
+    }
+}
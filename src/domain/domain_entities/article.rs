@@ -3,6 +3,7 @@ use uuid::Uuid;
 
 use crate::libs::time::TimeHelper;
 
+use super::article_block::ArticleBlock;
 use super::slug::Slug;
 
 #[derive(Clone, Debug, PartialEq, Eq, PartialOrd, Ord)]
@@ -12,12 +13,17 @@ pub struct Article {
     cover_url: String,
     title: String,
     content: String,
+    blocks: Option<Vec<ArticleBlock>>,
+    word_count: u32,
+    reading_time: u32,
     approved: bool,
+    comments_locked: bool,
     tag_id: Option<i32>,
     tag_value: Option<String>,
     created_at: DateTime,
     updated_at: Option<DateTime>,
     slug: Slug,
+    version: i32,
 }
 
 impl Article {
@@ -27,6 +33,7 @@ impl Article {
         author_id: Uuid,
         title: String,
         content: String,
+        blocks: Option<Vec<ArticleBlock>>,
         cover_url: String,
         tag_id: i32,
         tag_value: String
@@ -44,12 +51,17 @@ impl Article {
             cover_url,
             title,
             content,
+            blocks,
+            word_count: 0,
+            reading_time: 0,
             tag_id: Some(tag_id),
             tag_value: Some(tag_value),
             approved: false,
+            comments_locked: false,
             created_at,
             updated_at,
-            slug
+            slug,
+            version: 1
         }
     }
 
@@ -59,12 +71,17 @@ impl Article {
         cover_url: String,
         title: String,
         content: String,
+        blocks: Option<Vec<ArticleBlock>>,
+        word_count: u32,
+        reading_time: u32,
         approved: bool,
+        comments_locked: bool,
         created_at: DateTime,
         updated_at: Option<DateTime>,
         tag_id: Option<i32>,
         tag_value: Option<String>,
         slug: Slug,
+        version: i32,
     ) -> Self {
         Article {
             id,
@@ -72,12 +89,17 @@ impl Article {
             cover_url,
             title,
             content,
+            blocks,
+            word_count,
+            reading_time,
             approved,
+            comments_locked,
             tag_value,
             tag_id,
             created_at,
             updated_at,
-            slug
+            slug,
+            version
         }
     }
 
@@ -93,6 +115,11 @@ impl Article {
         self.id
     }
 
+    /// Incremented on every save, so that concurrent changes to the same row are detected.
+    pub fn version(&self) -> i32 {
+        self.version
+    }
+
     pub fn author_id(&self) -> Uuid {
         self.author_id
     }
@@ -109,10 +136,29 @@ impl Article {
         self.content.as_ref()
     }
 
+    /// The blocks the article was written with, when it was written with the block editor instead of Markdown.
+    pub fn blocks(&self) -> Option<&[ArticleBlock]> {
+        self.blocks.as_deref()
+    }
+
+    pub fn word_count(&self) -> u32 {
+        self.word_count
+    }
+
+    /// Estimated reading time, in minutes.
+    pub fn reading_time(&self) -> u32 {
+        self.reading_time
+    }
+
     pub fn approved(&self) -> bool {
         self.approved
     }
 
+    /// Locked articles are still readable with their comments, but take no new ones.
+    pub fn comments_locked(&self) -> bool {
+        self.comments_locked
+    }
+
     pub fn created_at(&self) -> DateTime {
         self.created_at
     }
@@ -157,10 +203,25 @@ impl Article {
         self.touch();
     }
 
+    pub fn set_blocks(&mut self, blocks: Option<Vec<ArticleBlock>>) {
+        self.blocks = blocks;
+        self.touch();
+    }
+
+    /// Derived from the content, so it does not count as an update by itself.
+    pub fn set_reading_metadata(&mut self, word_count: u32, reading_time: u32) {
+        self.word_count = word_count;
+        self.reading_time = reading_time;
+    }
+
     pub fn set_approved(&mut self, approved: bool) {
         self.approved = approved;
     }
 
+    pub fn set_comments_locked(&mut self, comments_locked: bool) {
+        self.comments_locked = comments_locked;
+    }
+
     pub fn set_tag_id(&mut self, tag_id: i32) { self.tag_id = Some(tag_id) }
 
     pub fn set_tag_value(&mut self, tag_value: String) { self.tag_value = Some(tag_value) }
@@ -0,0 +1,232 @@
+use once_cell::sync::Lazy;
+use regex::Regex;
+use serde::{Deserialize, Serialize};
+
+use super::article_content::ArticleContent;
+
+pub const MAX_BLOCKS_PER_ARTICLE: usize = 200;
+const MAX_TEXT_LENGTH: usize = 10_000;
+const MAX_SHORT_TEXT_LENGTH: usize = 200;
+const MAX_BADGES_PER_GRID: usize = 60;
+
+static BADGE_CODE_REGX: Lazy<Regex> = Lazy::new(|| Regex::new(r"^[A-Za-z0-9_]{1,32}$").unwrap());
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum CalloutTone {
+    Info,
+    Success,
+    Warning,
+    Danger,
+}
+
+impl CalloutTone {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            CalloutTone::Info => "info",
+            CalloutTone::Success => "success",
+            CalloutTone::Warning => "warning",
+            CalloutTone::Danger => "danger",
+        }
+    }
+}
+
+#[derive(Clone, Debug, PartialEq, Eq, PartialOrd, Ord, Serialize, Deserialize)]
+pub struct BadgeGridItem {
+    pub code: String,
+    pub name: Option<String>,
+}
+
+impl BadgeGridItem {
+    pub fn image_url(&self) -> String {
+        format!("https://images.habbo.com/c_images/album1584/{}.gif", self.code)
+    }
+}
+
+/**
+ # Article Block
+ A piece of an article written with the block editor instead of Markdown. The text of the blocks is
+ plain text: it is escaped when rendered, so markup inside of it shows up as typed.
+
+ Serialized as `{"type": "badge-grid", ...}`, with the fields in camelCase.
+ */
+#[derive(Clone, Debug, PartialEq, Eq, PartialOrd, Ord, Serialize, Deserialize)]
+#[serde(tag = "type", rename_all = "kebab-case", rename_all_fields = "camelCase")]
+pub enum ArticleBlock {
+    Paragraph {
+        text: String,
+    },
+    Heading {
+        level: u8,
+        text: String,
+    },
+    Image {
+        url: String,
+        alt: Option<String>,
+        caption: Option<String>,
+    },
+    BadgeGrid {
+        title: Option<String>,
+        badges: Vec<BadgeGridItem>,
+    },
+    FurniCard {
+        name: String,
+        image_url: String,
+        description: Option<String>,
+    },
+    Embed {
+        url: String,
+        title: Option<String>,
+    },
+    Callout {
+        tone: CalloutTone,
+        title: Option<String>,
+        text: String,
+    },
+}
+
+impl ArticleBlock {
+    /// Checks the block can be rendered as it is, returning what is wrong with it otherwise.
+    pub fn validate(&self) -> Result<(), String> {
+        match self {
+            ArticleBlock::Paragraph { text } => validate_text("text", text, MAX_TEXT_LENGTH),
+            ArticleBlock::Heading { level, text } => {
+                if !(1..=6).contains(level) {
+                    return Err("Heading level must be between 1 and 6.".into());
+                }
+
+                validate_text("text", text, MAX_SHORT_TEXT_LENGTH)
+            },
+            ArticleBlock::Image { url, alt, caption } => {
+                if !ArticleContent::image_url_is_allowed(url) {
+                    return Err("Image url must be an https url from Habbo.".into());
+                }
+
+                validate_optional_text("alt", alt, MAX_SHORT_TEXT_LENGTH)?;
+                validate_optional_text("caption", caption, MAX_SHORT_TEXT_LENGTH)
+            },
+            ArticleBlock::BadgeGrid { title, badges } => {
+                if badges.is_empty() || badges.len() > MAX_BADGES_PER_GRID {
+                    return Err(format!("Badge grids must have between 1 and {} badges.", MAX_BADGES_PER_GRID));
+                }
+
+                if let Some(badge) = badges.iter().find(|badge| !BADGE_CODE_REGX.is_match(&badge.code)) {
+                    return Err(format!("'{}' is not a valid badge code.", badge.code));
+                }
+
+                for badge in badges {
+                    validate_optional_text("badge name", &badge.name, MAX_SHORT_TEXT_LENGTH)?;
+                }
+
+                validate_optional_text("title", title, MAX_SHORT_TEXT_LENGTH)
+            },
+            ArticleBlock::FurniCard { name, image_url, description } => {
+                if !ArticleContent::image_url_is_allowed(image_url) {
+                    return Err("Furni image url must be an https url from Habbo.".into());
+                }
+
+                validate_text("name", name, MAX_SHORT_TEXT_LENGTH)?;
+                validate_optional_text("description", description, MAX_TEXT_LENGTH)
+            },
+            ArticleBlock::Embed { url, title } => {
+                if !ArticleContent::embed_url_is_allowed(url) {
+                    return Err("Embed url must be a YouTube embed url.".into());
+                }
+
+                validate_optional_text("title", title, MAX_SHORT_TEXT_LENGTH)
+            },
+            ArticleBlock::Callout { title, text, .. } => {
+                validate_optional_text("title", title, MAX_SHORT_TEXT_LENGTH)?;
+                validate_text("text", text, MAX_TEXT_LENGTH)
+            },
+        }
+    }
+
+    /// The text readers get from the block, used for excerpts and the plain text version of the article.
+    pub fn plain_text(&self) -> String {
+        let texts: Vec<&str> = match self {
+            ArticleBlock::Paragraph { text } | ArticleBlock::Heading { text, .. } => vec![text],
+            ArticleBlock::Image { caption, .. } => caption.iter().map(|caption| caption.as_str()).collect(),
+            ArticleBlock::BadgeGrid { title, badges } => title
+                .iter()
+                .map(|title| title.as_str())
+                .chain(badges.iter().map(|badge| badge.name.as_deref().unwrap_or(&badge.code)))
+                .collect(),
+            ArticleBlock::FurniCard { name, description, .. } => std::iter::once(name.as_str())
+                .chain(description.iter().map(|description| description.as_str()))
+                .collect(),
+            ArticleBlock::Embed { .. } => vec![],
+            ArticleBlock::Callout { title, text, .. } => title
+                .iter()
+                .map(|title| title.as_str())
+                .chain(std::iter::once(text.as_str()))
+                .collect(),
+        };
+
+        texts.iter().map(|text| text.trim()).filter(|text| !text.is_empty()).collect::<Vec<&str>>().join("\n")
+    }
+}
+
+fn validate_text(field: &str, text: &str, max_length: usize) -> Result<(), String> {
+    if text.trim().is_empty() {
+        return Err(format!("Block {} must not be empty.", field));
+    }
+
+    validate_optional_text(field, &Some(text.to_owned()), max_length)
+}
+
+fn validate_optional_text(field: &str, text: &Option<String>, max_length: usize) -> Result<(), String> {
+    match text {
+        Some(text) if text.chars().count() > max_length => {
+            Err(format!("Block {} must have at most {} characters.", field, max_length))
+        },
+        _ => Ok(())
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_if_blocks_are_deserialized_from_the_editor_format() {
+        let blocks: Vec<ArticleBlock> = serde_json::from_str(r#"[
+            {"type": "heading", "level": 2, "text": "Prêmios"},
+            {"type": "badge-grid", "badges": [{"code": "BR001", "name": "Emblema"}]},
+            {"type": "furni-card", "name": "Trono", "imageUrl": "https://images.habbo.com/dcr/hof_furni/throne.png"},
+            {"type": "callout", "tone": "warning", "text": "Corre!"}
+        ]"#).unwrap();
+
+        assert_eq!(ArticleBlock::Heading { level: 2, text: "Prêmios".into() }, blocks[0]);
+        assert!(blocks.iter().all(|block| block.validate().is_ok()));
+        assert_eq!("Emblema", blocks[1].plain_text());
+    }
+
+    #[test]
+    fn test_if_invalid_blocks_are_rejected() {
+        let foreign_image = ArticleBlock::Image { url: "https://evil.com/a.png".into(), alt: None, caption: None };
+        let bad_badge = ArticleBlock::BadgeGrid { title: None, badges: vec![BadgeGridItem { code: "<script>".into(), name: None }] };
+        let bad_embed = ArticleBlock::Embed { url: "https://www.youtube.com/watch?v=dQw4w9WgXcQ".into(), title: None };
+        let empty_paragraph = ArticleBlock::Paragraph { text: "   ".into() };
+
+        assert!(foreign_image.validate().is_err());
+        assert!(bad_badge.validate().is_err());
+        assert!(bad_embed.validate().is_err());
+        assert!(empty_paragraph.validate().is_err());
+    }
+
+    #[test]
+    fn test_if_urls_pointing_elsewhere_than_their_allowed_looking_host_are_rejected() {
+        let image = ArticleBlock::Image { url: "https://evil.com\\.habbo.com/a.png".into(), alt: None, caption: None };
+        let furni = ArticleBlock::FurniCard {
+            name: "Trono".into(),
+            image_url: "https://images.habbo.com@evil.com/throne.png".into(),
+            description: None,
+        };
+        let embed = ArticleBlock::Embed { url: "https://evil.com\\.youtube.com/embed/x".into(), title: None };
+
+        assert!(image.validate().is_err());
+        assert!(furni.validate().is_err());
+        assert!(embed.validate().is_err());
+    }
+}
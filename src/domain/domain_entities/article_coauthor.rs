@@ -0,0 +1,68 @@
+use chrono::NaiveDateTime as DateTime;
+use uuid::Uuid;
+
+use crate::libs::time::TimeHelper;
+
+/**
+ # Article Coauthor
+ A staff member credited on an article besides its author. Co-authors are listed in the order of their
+ `position` and may say what they did on the article, e.g. "texto", "imagens" or "revisão".
+ */
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ArticleCoauthor {
+    article_id: Uuid,
+    user_id: Uuid,
+    position: u32,
+    role: Option<String>,
+    created_at: DateTime,
+}
+
+impl ArticleCoauthor {
+    // CONSTRUCTORS
+    pub fn new(article_id: Uuid, user_id: Uuid, position: u32, role: Option<String>) -> Self {
+        ArticleCoauthor {
+            article_id,
+            user_id,
+            position,
+            role,
+            created_at: TimeHelper::now(),
+        }
+    }
+
+    pub fn new_from_existing(
+        article_id: Uuid,
+        user_id: Uuid,
+        position: u32,
+        role: Option<String>,
+        created_at: DateTime,
+    ) -> Self {
+        ArticleCoauthor {
+            article_id,
+            user_id,
+            position,
+            role,
+            created_at,
+        }
+    }
+
+    // GETTERS
+    pub fn article_id(&self) -> Uuid {
+        self.article_id
+    }
+
+    pub fn user_id(&self) -> Uuid {
+        self.user_id
+    }
+
+    pub fn position(&self) -> u32 {
+        self.position
+    }
+
+    pub fn role(&self) -> Option<&str> {
+        self.role.as_deref()
+    }
+
+    pub fn created_at(&self) -> DateTime {
+        self.created_at
+    }
+}
@@ -0,0 +1,495 @@
+use std::collections::HashSet;
+
+use ammonia::{Url, UrlRelative};
+use once_cell::sync::Lazy;
+use pulldown_cmark::{html, Event, HeadingLevel, Options, Parser, Tag, TagEnd};
+use regex::Regex;
+use unicode_normalization::UnicodeNormalization;
+
+use super::article::Article;
+use super::article_block::ArticleBlock;
+
+/// Hosts, and their subdomains, that images can be loaded from: the hotels and Habbo's imaging services.
+const ALLOWED_IMAGE_HOSTS: [&str; 10] = [
+    "habbo.com",
+    "habbo.com.br",
+    "habbo.com.tr",
+    "habbo.de",
+    "habbo.es",
+    "habbo.fi",
+    "habbo.fr",
+    "habbo.it",
+    "habbo.nl",
+    "habbogroup.com",
+];
+
+/// Embeds are only allowed from YouTube's player, and only on these hosts.
+const ALLOWED_EMBED_HOSTS: [&str; 3] = ["www.youtube.com", "youtube.com", "www.youtube-nocookie.com"];
+
+static HTML_TAG_REGX: Lazy<Regex> = Lazy::new(|| Regex::new(r"<[^>]*>").unwrap());
+
+static SANITIZER: Lazy<ammonia::Builder<'static>> = Lazy::new(|| {
+    let mut sanitizer = ammonia::Builder::default();
+
+    sanitizer
+        .add_tags(["iframe"])
+        .add_tag_attributes("iframe", ["src", "width", "height", "title", "allowfullscreen"])
+        .add_tag_attributes("h1", ["id"])
+        .add_tag_attributes("h2", ["id"])
+        .add_tag_attributes("h3", ["id"])
+        .add_tag_attributes("h4", ["id"])
+        .add_tag_attributes("h5", ["id"])
+        .add_tag_attributes("h6", ["id"])
+        .add_allowed_classes("figure", ["article-image"])
+        .add_allowed_classes("div", ["badge-grid", "furni-card", "furni-card-body", "embed", "callout", "callout-info", "callout-success", "callout-warning", "callout-danger"])
+        .add_allowed_classes("p", ["badge-grid-title", "callout-title"])
+        .add_allowed_classes("li", ["badge"])
+        .url_schemes(HashSet::from(["https", "mailto"]))
+        .url_relative(UrlRelative::Deny)
+        .attribute_filter(|element, attribute, value| {
+            match (element, attribute) {
+                ("img", "src") if !url_host_is_allowed(value, &ALLOWED_IMAGE_HOSTS, None) => None,
+                ("iframe", "src") if !url_host_is_allowed(value, &ALLOWED_EMBED_HOSTS, Some("/embed/")) => None,
+                _ => Some(value.into()),
+            }
+        });
+
+    sanitizer
+});
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct TableOfContentsEntry {
+    level: u8,
+    title: String,
+    anchor: String,
+}
+
+impl TableOfContentsEntry {
+    pub fn level(&self) -> u8 {
+        self.level
+    }
+
+    pub fn title(&self) -> &str {
+        &self.title
+    }
+
+    /// The `id` of the heading in the rendered HTML.
+    pub fn anchor(&self) -> &str {
+        &self.anchor
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct RenderedArticleContent {
+    html: String,
+    text: String,
+    table_of_contents: Vec<TableOfContentsEntry>,
+}
+
+impl RenderedArticleContent {
+    pub fn html(&self) -> &str {
+        &self.html
+    }
+
+    /// The content without any markup, with its blocks separated by blank lines.
+    pub fn text(&self) -> &str {
+        &self.text
+    }
+
+    pub fn table_of_contents(&self) -> &[TableOfContentsEntry] {
+        &self.table_of_contents
+    }
+}
+
+enum ArticleContentSource<'c> {
+    Markdown(&'c str),
+    Blocks(&'c [ArticleBlock]),
+}
+
+/**
+ # Article Content
+ Articles' content is written either in Markdown or, with the block editor, as a list of `ArticleBlock`s.
+ This is the pipeline that turns it into what readers get:
+ - sanitized HTML, in which images can only come from Habbo and embeds can only be YouTube videos;
+ - a table of contents, built from the headings, which get anchors in the HTML;
+ - its plain text, and an excerpt of it.
+
+ Raw HTML inside the Markdown is allowed, but goes through the same sanitization.
+ */
+pub struct ArticleContent<'c> {
+    source: ArticleContentSource<'c>,
+}
+
+impl<'c> ArticleContent<'c> {
+    pub fn new(markdown: &'c str) -> Self {
+        ArticleContent { source: ArticleContentSource::Markdown(markdown) }
+    }
+
+    pub fn from_blocks(blocks: &'c [ArticleBlock]) -> Self {
+        ArticleContent { source: ArticleContentSource::Blocks(blocks) }
+    }
+
+    /// The article's blocks, if it was written with the block editor, its Markdown otherwise.
+    pub fn from_article(article: &'c Article) -> Self {
+        match article.blocks() {
+            Some(blocks) => Self::from_blocks(blocks),
+            None => Self::new(article.content()),
+        }
+    }
+
+    pub fn image_url_is_allowed(url: &str) -> bool {
+        url_host_is_allowed(url, &ALLOWED_IMAGE_HOSTS, None)
+    }
+
+    pub fn embed_url_is_allowed(url: &str) -> bool {
+        url_host_is_allowed(url, &ALLOWED_EMBED_HOSTS, Some("/embed/"))
+    }
+
+    fn parse(markdown: &str) -> Vec<Event<'_>> {
+        let options = Options::ENABLE_TABLES | Options::ENABLE_STRIKETHROUGH;
+        Parser::new_ext(markdown, options).collect()
+    }
+
+    pub fn render(&self) -> RenderedArticleContent {
+        let (unsafe_html, table_of_contents) = match self.source {
+            ArticleContentSource::Markdown(markdown) => Self::render_markdown(markdown),
+            ArticleContentSource::Blocks(blocks) => Self::render_blocks(blocks),
+        };
+
+        RenderedArticleContent {
+            html: SANITIZER.clean(&unsafe_html).to_string(),
+            text: self.plain_text(),
+            table_of_contents,
+        }
+    }
+
+    fn render_markdown(markdown: &str) -> (String, Vec<TableOfContentsEntry>) {
+        let mut events = Self::parse(markdown);
+        let mut table_of_contents = vec![];
+        let mut used_anchors: HashSet<String> = HashSet::new();
+
+        for index in 0..events.len() {
+            let level = match &events[index] {
+                Event::Start(Tag::Heading { level, .. }) => *level,
+                _ => continue,
+            };
+
+            let title = events[index + 1..]
+                .iter()
+                .take_while(|event| !matches!(event, Event::End(TagEnd::Heading(_))))
+                .filter_map(|event| match event {
+                    Event::Text(text) | Event::Code(text) => Some(text.as_ref()),
+                    _ => None,
+                })
+                .collect::<String>();
+
+            let anchor = Self::generate_anchor(&title, &mut used_anchors);
+
+            events[index] = Event::Start(Tag::Heading {
+                level,
+                id: Some(anchor.clone().into()),
+                classes: vec![],
+                attrs: vec![],
+            });
+
+            table_of_contents.push(TableOfContentsEntry {
+                level: Self::heading_level_number(level),
+                title: title.trim().to_owned(),
+                anchor,
+            });
+        }
+
+        let mut unsafe_html = String::new();
+        html::push_html(&mut unsafe_html, events.into_iter());
+
+        (unsafe_html, table_of_contents)
+    }
+
+    fn render_blocks(blocks: &[ArticleBlock]) -> (String, Vec<TableOfContentsEntry>) {
+        let mut unsafe_html = String::new();
+        let mut table_of_contents = vec![];
+        let mut used_anchors: HashSet<String> = HashSet::new();
+
+        for block in blocks {
+            let html = match block {
+                ArticleBlock::Paragraph { text } => format!("<p>{}</p>", escape_html_lines(text)),
+                ArticleBlock::Heading { level, text } => {
+                    let anchor = Self::generate_anchor(text, &mut used_anchors);
+                    let html = format!("<h{level} id=\"{}\">{}</h{level}>", anchor, escape_html(text.trim()), level = level);
+
+                    table_of_contents.push(TableOfContentsEntry { level: *level, title: text.trim().to_owned(), anchor });
+
+                    html
+                },
+                ArticleBlock::Image { url, alt, caption } => format!(
+                    "<figure class=\"article-image\"><img src=\"{}\" alt=\"{}\">{}</figure>",
+                    escape_html(url),
+                    escape_html(alt.as_deref().unwrap_or_default()),
+                    caption.as_ref().map(|caption| format!("<figcaption>{}</figcaption>", escape_html(caption))).unwrap_or_default()
+                ),
+                ArticleBlock::BadgeGrid { title, badges } => {
+                    let items = badges.iter().map(|badge| {
+                        let name = badge.name.as_deref().unwrap_or(&badge.code);
+
+                        format!(
+                            "<li class=\"badge\"><img src=\"{}\" alt=\"{}\" title=\"{}\"><span>{}</span></li>",
+                            escape_html(&badge.image_url()),
+                            escape_html(&badge.code),
+                            escape_html(name),
+                            escape_html(name)
+                        )
+                    }).collect::<String>();
+
+                    format!(
+                        "<div class=\"badge-grid\">{}<ul>{}</ul></div>",
+                        title.as_ref().map(|title| format!("<p class=\"badge-grid-title\">{}</p>", escape_html(title))).unwrap_or_default(),
+                        items
+                    )
+                },
+                ArticleBlock::FurniCard { name, image_url, description } => format!(
+                    "<div class=\"furni-card\"><img src=\"{}\" alt=\"{}\"><div class=\"furni-card-body\"><strong>{}</strong>{}</div></div>",
+                    escape_html(image_url),
+                    escape_html(name),
+                    escape_html(name),
+                    description.as_ref().map(|description| format!("<p>{}</p>", escape_html_lines(description))).unwrap_or_default()
+                ),
+                ArticleBlock::Embed { url, title } => format!(
+                    "<div class=\"embed\"><iframe src=\"{}\" title=\"{}\" width=\"560\" height=\"315\" allowfullscreen></iframe></div>",
+                    escape_html(url),
+                    escape_html(title.as_deref().unwrap_or("YouTube video"))
+                ),
+                ArticleBlock::Callout { tone, title, text } => format!(
+                    "<div class=\"callout callout-{}\">{}<p>{}</p></div>",
+                    tone.as_str(),
+                    title.as_ref().map(|title| format!("<p class=\"callout-title\"><strong>{}</strong></p>", escape_html(title))).unwrap_or_default(),
+                    escape_html_lines(text)
+                ),
+            };
+
+            unsafe_html.push_str(&html);
+            unsafe_html.push('\n');
+        }
+
+        (unsafe_html, table_of_contents)
+    }
+
+    /// The content without any markup.
+    pub fn plain_text(&self) -> String {
+        match self.source {
+            ArticleContentSource::Markdown(markdown) => {
+                let mut text = String::new();
+
+                for event in Self::parse(markdown) {
+                    match event {
+                        Event::Text(content) | Event::Code(content) => text.push_str(&content),
+                        Event::Html(content) | Event::InlineHtml(content) => text.push_str(&HTML_TAG_REGX.replace_all(&content, " ")),
+                        Event::End(TagEnd::Paragraph | TagEnd::Heading(_) | TagEnd::Item | TagEnd::CodeBlock) => text.push_str("\n\n"),
+                        Event::SoftBreak | Event::HardBreak | Event::End(_) => text.push(' '),
+                        _ => (),
+                    }
+                }
+
+                text.trim().to_owned()
+            },
+            ArticleContentSource::Blocks(blocks) => blocks
+                .iter()
+                .map(|block| block.plain_text())
+                .filter(|text| !text.is_empty())
+                .collect::<Vec<String>>()
+                .join("\n\n"),
+        }
+    }
+
+    /// Plain text of the content, in a single line, cut at the last whole word that fits in `max_chars`.
+    pub fn excerpt(&self, max_chars: usize) -> String {
+        let text = self.plain_text().split_whitespace().collect::<Vec<&str>>().join(" ");
+
+        if text.chars().count() <= max_chars {
+            return text;
+        }
+
+        let cut = text.char_indices().nth(max_chars).map(|(index, _)| index).unwrap_or(text.len());
+        let truncated = &text[..cut];
+        let truncated = match truncated.rfind(' ') {
+            Some(last_space) => &truncated[..last_space],
+            None => truncated,
+        };
+
+        format!("{}…", truncated.trim_end_matches(|c: char| c.is_ascii_punctuation()))
+    }
+
+    fn generate_anchor(title: &str, used_anchors: &mut HashSet<String>) -> String {
+        let mut anchor = String::new();
+
+        for c in title.trim().to_lowercase().nfkd() {
+            if c.is_whitespace() || c == '-' {
+                if !anchor.is_empty() && !anchor.ends_with('-') {
+                    anchor.push('-');
+                }
+            } else if c.is_alphanumeric() {
+                anchor.push(c);
+            }
+        }
+
+        let anchor = match anchor.trim_end_matches('-') {
+            "" => "section".to_owned(),
+            anchor => anchor.to_owned(),
+        };
+
+        let mut unique_anchor = anchor.clone();
+        let mut repetitions = 1;
+
+        while used_anchors.contains(&unique_anchor) {
+            repetitions += 1;
+            unique_anchor = format!("{}-{}", anchor, repetitions);
+        }
+
+        used_anchors.insert(unique_anchor.clone());
+        unique_anchor
+    }
+
+    fn heading_level_number(level: HeadingLevel) -> u8 {
+        match level {
+            HeadingLevel::H1 => 1,
+            HeadingLevel::H2 => 2,
+            HeadingLevel::H3 => 3,
+            HeadingLevel::H4 => 4,
+            HeadingLevel::H5 => 5,
+            HeadingLevel::H6 => 6,
+        }
+    }
+}
+
+fn escape_html(value: &str) -> String {
+    let mut escaped = String::with_capacity(value.len());
+
+    for c in value.chars() {
+        match c {
+            '&' => escaped.push_str("&amp;"),
+            '<' => escaped.push_str("&lt;"),
+            '>' => escaped.push_str("&gt;"),
+            '"' => escaped.push_str("&quot;"),
+            '\'' => escaped.push_str("&#39;"),
+            _ => escaped.push(c),
+        }
+    }
+
+    escaped
+}
+
+/// Escapes the text, keeping its line breaks.
+fn escape_html_lines(value: &str) -> String {
+    value.trim().lines().map(escape_html).collect::<Vec<String>>().join("<br>")
+}
+
+/// The URL is parsed the way browsers do, so that tricks like `https://evil.com\.habbo.com` are read as the host they load from.
+fn url_host_is_allowed(url: &str, allowed_hosts: &[&str], required_path_prefix: Option<&str>) -> bool {
+    let url = match Url::parse(url) {
+        Ok(url) => url,
+        Err(_) => return false,
+    };
+
+    if url.scheme() != "https" || !url.username().is_empty() || url.password().is_some() {
+        return false;
+    }
+
+    let host = match url.host_str() {
+        Some(host) => host,
+        None => return false,
+    };
+
+    let host_is_allowed = allowed_hosts.iter().any(|allowed_host| {
+        host == *allowed_host || host.ends_with(&format!(".{}", allowed_host))
+    });
+
+    host_is_allowed && required_path_prefix.is_none_or(|prefix| url.path().starts_with(prefix))
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::domain::domain_entities::article_block::BadgeGridItem;
+
+    #[test]
+    fn test_if_scripts_and_foreign_sources_are_removed() {
+        let content = ArticleContent::new(concat!(
+            "Olá <script>alert('xss')</script>**mundo**\n\n",
+            "<img src=\"https://www.habbo.com.br/habbo-imaging/badge/ADM.gif\" onerror=\"alert(1)\">\n",
+            "<img src=\"https://evil.com/tracker.gif\">\n\n",
+            "<iframe src=\"https://www.youtube.com/embed/dQw4w9WgXcQ\"></iframe>\n",
+            "<iframe src=\"https://evil.com/embed/page\"></iframe>\n\n",
+            "[link](javascript:alert(1))"
+        )).render();
+
+        let html = content.html();
+
+        assert!(!html.contains("<script"));
+        assert!(!html.contains("onerror"));
+        assert!(!html.contains("javascript:"));
+        assert!(!html.contains("evil.com"));
+        assert!(html.contains("<strong>mundo</strong>"));
+        assert!(html.contains("src=\"https://www.habbo.com.br/habbo-imaging/badge/ADM.gif\""));
+        assert!(html.contains("src=\"https://www.youtube.com/embed/dQw4w9WgXcQ\""));
+    }
+
+    #[test]
+    fn test_if_hosts_are_read_the_way_browsers_read_them() {
+        assert!(!ArticleContent::image_url_is_allowed("https://evil.com\\.habbo.com/x.gif"));
+        assert!(!ArticleContent::image_url_is_allowed("https://habbo.com@evil.com/x.gif"));
+        assert!(!ArticleContent::image_url_is_allowed("https://user@www.habbo.com/x.gif"));
+        assert!(!ArticleContent::image_url_is_allowed("http://www.habbo.com/x.gif"));
+        assert!(!ArticleContent::image_url_is_allowed("https://"));
+        assert!(!ArticleContent::embed_url_is_allowed("https://evil.com\\.youtube.com/embed/x"));
+        assert!(!ArticleContent::embed_url_is_allowed("https://www.youtube.com/watch?v=x"));
+        assert!(!ArticleContent::image_url_is_allowed("https://EVIL.com/habbo.com/x.gif"));
+
+        assert!(ArticleContent::image_url_is_allowed("https://WWW.Habbo.com.BR/habbo-imaging/badge/ADM.gif"));
+        assert!(ArticleContent::embed_url_is_allowed("https://www.YouTube.com/embed/dQw4w9WgXcQ"));
+
+        let html = ArticleContent::new("<img src=\"https://evil.com\\.habbo.com/x.gif\">").render();
+
+        assert!(!html.html().contains("evil.com"));
+    }
+
+    #[test]
+    fn test_if_table_of_contents_is_generated() {
+        let content = ArticleContent::new("# Evento de Verão\n\n## Prêmios\n\ntexto\n\n## Prêmios\n").render();
+
+        let anchors = content.table_of_contents().iter().map(|entry| entry.anchor()).collect::<Vec<&str>>();
+
+        assert_eq!(vec!["evento-de-verao", "premios", "premios-2"], anchors);
+        assert_eq!(2, content.table_of_contents()[1].level());
+        assert!(content.html().contains("<h2 id=\"premios-2\">"));
+    }
+
+    #[test]
+    fn test_if_excerpt_strips_markup_and_cuts_at_whole_words() {
+        assert_eq!("Olá, mundo", ArticleContent::new("# Olá,\n\n<b>mundo</b>").excerpt(280));
+
+        let excerpt = ArticleContent::new(&"palavra ".repeat(100)).excerpt(280);
+
+        assert!(excerpt.ends_with("palavra…"));
+        assert!(excerpt.chars().count() <= 281);
+    }
+
+    #[test]
+    fn test_if_blocks_are_rendered_escaped_and_sanitized() {
+        let blocks = vec![
+            ArticleBlock::Heading { level: 2, text: "Emblemas <novos>".into() },
+            ArticleBlock::Paragraph { text: "<script>alert('xss')</script>Olá".into() },
+            ArticleBlock::BadgeGrid { title: None, badges: vec![BadgeGridItem { code: "BR001".into(), name: None }] },
+            ArticleBlock::Embed { url: "https://evil.com/embed/page".into(), title: None },
+        ];
+
+        let content = ArticleContent::from_blocks(&blocks).render();
+        let html = content.html();
+
+        assert!(!html.contains("<script"));
+        assert!(!html.contains("evil.com"));
+        assert!(html.contains("<h2 id=\"emblemas-novos\">Emblemas &lt;novos&gt;</h2>"));
+        assert!(html.contains("<div class=\"badge-grid\">"));
+        assert!(html.contains("src=\"https://images.habbo.com/c_images/album1584/BR001.gif\""));
+        assert_eq!("emblemas-novos", content.table_of_contents()[0].anchor());
+        assert_eq!("Emblemas <novos>\n\n<script>alert('xss')</script>Olá\n\nBR001", content.text());
+    }
+}
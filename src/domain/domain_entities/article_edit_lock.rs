@@ -0,0 +1,89 @@
+use chrono::NaiveDateTime as DateTime;
+use uuid::Uuid;
+
+use super::role::Role;
+use crate::libs::time::TimeHelper;
+
+/**
+ # Article Edit Lock
+ Advisory lock taken by a staff member while editing an article, so that others know the article
+ is being edited and don't overwrite each other's changes. The lock lasts until `expires_at`, and
+ must be kept alive by its holder while the editor is open.
+
+ The holder's nickname and role are read along with the lock, and are not persisted with it.
+ */
+#[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord)]
+pub struct ArticleEditLock {
+    article_id: Uuid,
+    user_id: Uuid,
+    holder_nickname: Option<String>,
+    holder_role: Option<Role>,
+    acquired_at: DateTime,
+    expires_at: DateTime,
+}
+
+impl ArticleEditLock {
+    // CONSTRUCTORS
+    pub fn new(article_id: Uuid, user_id: Uuid, expires_at: DateTime) -> Self {
+        ArticleEditLock {
+            article_id,
+            user_id,
+            holder_nickname: None,
+            holder_role: None,
+            acquired_at: TimeHelper::now(),
+            expires_at,
+        }
+    }
+
+    pub fn new_from_existing(
+        article_id: Uuid,
+        user_id: Uuid,
+        holder_nickname: Option<String>,
+        holder_role: Option<Role>,
+        acquired_at: DateTime,
+        expires_at: DateTime,
+    ) -> Self {
+        ArticleEditLock {
+            article_id,
+            user_id,
+            holder_nickname,
+            holder_role,
+            acquired_at,
+            expires_at,
+        }
+    }
+
+    // GETTERS
+    pub fn article_id(&self) -> Uuid {
+        self.article_id
+    }
+
+    pub fn user_id(&self) -> Uuid {
+        self.user_id
+    }
+
+    pub fn holder_nickname(&self) -> Option<&str> {
+        self.holder_nickname.as_deref()
+    }
+
+    pub fn holder_role(&self) -> Option<Role> {
+        self.holder_role.clone()
+    }
+
+    pub fn acquired_at(&self) -> DateTime {
+        self.acquired_at
+    }
+
+    pub fn expires_at(&self) -> DateTime {
+        self.expires_at
+    }
+
+    pub fn is_expired(&self, now: DateTime) -> bool {
+        self.expires_at <= now
+    }
+
+    // SETTERS
+    pub fn set_expires_at(&mut self, expires_at: DateTime) {
+        self.expires_at = expires_at;
+    }
+}
@@ -0,0 +1,105 @@
+use chrono::NaiveDateTime as DateTime;
+use uuid::Uuid;
+
+use crate::libs::time::TimeHelper;
+
+/**
+ # Article Note
+ Internal feedback left by the staff on an article, e.g. while reviewing it before approval. Notes are
+ threaded: a note with a `parent_id` is a reply to the note that starts the thread, and only those can
+ be resolved.
+ */
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct ArticleNote {
+    id: Uuid,
+    article_id: Uuid,
+    author_id: Uuid,
+    parent_id: Option<Uuid>,
+    content: String,
+    resolved_by: Option<Uuid>,
+    resolved_at: Option<DateTime>,
+    created_at: DateTime,
+}
+
+impl ArticleNote {
+    // CONSTRUCTORS
+    pub fn new(article_id: Uuid, author_id: Uuid, parent_id: Option<Uuid>, content: String) -> Self {
+        ArticleNote {
+            id: Uuid::new_v4(),
+            article_id,
+            author_id,
+            parent_id,
+            content,
+            resolved_by: None,
+            resolved_at: None,
+            created_at: TimeHelper::now(),
+        }
+    }
+
+    #[allow(clippy::too_many_arguments)]
+    pub fn new_from_existing(
+        id: Uuid,
+        article_id: Uuid,
+        author_id: Uuid,
+        parent_id: Option<Uuid>,
+        content: String,
+        resolved_by: Option<Uuid>,
+        resolved_at: Option<DateTime>,
+        created_at: DateTime,
+    ) -> Self {
+        ArticleNote {
+            id,
+            article_id,
+            author_id,
+            parent_id,
+            content,
+            resolved_by,
+            resolved_at,
+            created_at,
+        }
+    }
+
+    // GETTERS
+    pub fn id(&self) -> Uuid {
+        self.id
+    }
+
+    pub fn article_id(&self) -> Uuid {
+        self.article_id
+    }
+
+    pub fn author_id(&self) -> Uuid {
+        self.author_id
+    }
+
+    pub fn parent_id(&self) -> Option<Uuid> {
+        self.parent_id
+    }
+
+    pub fn content(&self) -> &str {
+        &self.content
+    }
+
+    pub fn resolved_by(&self) -> Option<Uuid> {
+        self.resolved_by
+    }
+
+    pub fn resolved_at(&self) -> Option<DateTime> {
+        self.resolved_at
+    }
+
+    pub fn created_at(&self) -> DateTime {
+        self.created_at
+    }
+
+    pub fn is_resolved(&self) -> bool {
+        self.resolved_at.is_some()
+    }
+
+    // SETTERS
+    /// `None` reopens the thread.
+    pub fn set_resolved_by(&mut self, user_id: Option<Uuid>) {
+        self.resolved_at = user_id.map(|_| TimeHelper::now());
+        self.resolved_by = user_id;
+    }
+}
@@ -0,0 +1,188 @@
+use std::collections::BTreeMap;
+use std::str::FromStr;
+
+use chrono::NaiveDateTime as DateTime;
+use uuid::Uuid;
+
+use crate::errors::enum_coercion_error::EnumCoercionError;
+use crate::libs::time::TimeHelper;
+
+/**
+ # Reaction Kind
+ Reactions a reader can leave on an article. The last ones are Habbo-themed and are rendered by
+ the clients with the hotel's own sprites.
+ */
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum ReactionKind {
+    Heart,
+    Laugh,
+    Wow,
+    Sad,
+    Duck,
+    Frank,
+    Credit,
+}
+
+impl ReactionKind {
+    pub const ALL: [ReactionKind; 7] = [
+        ReactionKind::Heart,
+        ReactionKind::Laugh,
+        ReactionKind::Wow,
+        ReactionKind::Sad,
+        ReactionKind::Duck,
+        ReactionKind::Frank,
+        ReactionKind::Credit,
+    ];
+
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            ReactionKind::Heart => "heart",
+            ReactionKind::Laugh => "laugh",
+            ReactionKind::Wow => "wow",
+            ReactionKind::Sad => "sad",
+            ReactionKind::Duck => "duck",
+            ReactionKind::Frank => "frank",
+            ReactionKind::Credit => "credit",
+        }
+    }
+}
+
+impl FromStr for ReactionKind {
+    type Err = EnumCoercionError;
+
+    fn from_str(s: &str) -> Result<Self, EnumCoercionError> {
+        let s = s.to_lowercase();
+
+        ReactionKind::ALL
+            .into_iter()
+            .find(|kind| kind.as_str() == s)
+            .ok_or_else(|| EnumCoercionError::new("Reaction"))
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ArticleReaction {
+    article_id: Uuid,
+    user_id: Uuid,
+    kind: ReactionKind,
+    created_at: DateTime,
+    updated_at: Option<DateTime>,
+}
+
+impl ArticleReaction {
+    // CONSTRUCTORS
+    pub fn new(article_id: Uuid, user_id: Uuid, kind: ReactionKind) -> Self {
+        ArticleReaction {
+            article_id,
+            user_id,
+            kind,
+            created_at: TimeHelper::now(),
+            updated_at: None,
+        }
+    }
+
+    pub fn new_from_existing(
+        article_id: Uuid,
+        user_id: Uuid,
+        kind: ReactionKind,
+        created_at: DateTime,
+        updated_at: Option<DateTime>,
+    ) -> Self {
+        ArticleReaction {
+            article_id,
+            user_id,
+            kind,
+            created_at,
+            updated_at,
+        }
+    }
+
+    fn touch(&mut self) {
+        self.updated_at = Some(TimeHelper::now());
+    }
+
+    // GETTERS
+    pub fn article_id(&self) -> Uuid {
+        self.article_id
+    }
+
+    pub fn user_id(&self) -> Uuid {
+        self.user_id
+    }
+
+    pub fn kind(&self) -> ReactionKind {
+        self.kind
+    }
+
+    pub fn created_at(&self) -> DateTime {
+        self.created_at
+    }
+
+    pub fn updated_at(&self) -> Option<DateTime> {
+        self.updated_at
+    }
+
+    // SETTERS
+    pub fn set_kind(&mut self, kind: ReactionKind) {
+        self.kind = kind;
+        self.touch();
+    }
+}
+
+/**
+ # Article Reactions Summary
+ How many readers left each kind of reaction on an article, and which one the current user left,
+ if any. Every kind is present on the counts, even the ones nobody reacted with.
+ */
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ArticleReactionsSummary {
+    counts: BTreeMap<ReactionKind, u64>,
+    user_reaction: Option<ReactionKind>,
+}
+
+impl ArticleReactionsSummary {
+    pub fn new(counts: impl IntoIterator<Item = (ReactionKind, u64)>, user_reaction: Option<ReactionKind>) -> Self {
+        let mut summary_counts: BTreeMap<ReactionKind, u64> = ReactionKind::ALL.into_iter().map(|kind| (kind, 0)).collect();
+
+        for (kind, count) in counts {
+            summary_counts.insert(kind, count);
+        }
+
+        ArticleReactionsSummary {
+            counts: summary_counts,
+            user_reaction,
+        }
+    }
+
+    pub fn counts(&self) -> &BTreeMap<ReactionKind, u64> {
+        &self.counts
+    }
+
+    pub fn user_reaction(&self) -> Option<ReactionKind> {
+        self.user_reaction
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_if_reaction_kind_can_be_parsed() {
+        for kind in ReactionKind::ALL {
+            assert_eq!(kind, ReactionKind::from_str(kind.as_str()).unwrap());
+        }
+
+        assert_eq!(ReactionKind::Duck, ReactionKind::from_str("DUCK").unwrap());
+        assert!(ReactionKind::from_str("thumbs_down").is_err());
+    }
+
+    #[test]
+    fn test_if_summary_has_every_reaction_kind() {
+        let summary = ArticleReactionsSummary::new(vec![(ReactionKind::Heart, 3)], Some(ReactionKind::Heart));
+
+        assert_eq!(ReactionKind::ALL.len(), summary.counts().len());
+        assert_eq!(Some(&3), summary.counts().get(&ReactionKind::Heart));
+        assert_eq!(Some(&0), summary.counts().get(&ReactionKind::Credit));
+    }
+}
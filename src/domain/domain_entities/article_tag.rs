@@ -17,14 +17,16 @@ impl DraftArticleTag {
 #[derive(Clone, Debug, PartialEq, Eq, PartialOrd, Ord)]
 pub struct ArticleTag {
     id: i32,
-    value: String
+    value: String,
+    version: i32
 }
 
 impl ArticleTag {
-    pub fn new_from_existing(id: i32, value: String) -> Self {
+    pub fn new_from_existing(id: i32, value: String, version: i32) -> Self {
         Self {
             id,
             value,
+            version,
         }
     }
 
@@ -32,6 +34,11 @@ impl ArticleTag {
         self.id
     }
 
+    /// Incremented on every save, so that concurrent changes to the same row are detected.
+    pub fn version(&self) -> i32 {
+        self.version
+    }
+
     pub fn value(&self) -> &String {
         &self.value
     }
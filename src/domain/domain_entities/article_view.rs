@@ -0,0 +1,123 @@
+use chrono::{NaiveDate, NaiveDateTime as DateTime};
+use hmac::{Hmac, Mac};
+use sha2::Sha256;
+use uuid::Uuid;
+
+use crate::libs::time::TimeHelper;
+
+/**
+ # Article Viewer
+ Who is reading an article. Anonymous readers are identified by their IP address and user agent,
+ which are never persisted as they are: only a hash of them is used as the viewer key. The hash is
+ keyed by a server-side secret, otherwise the few billion IPv4 addresses could be hashed until the
+ stored keys were matched back to them.
+ */
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum ArticleViewer {
+    User(Uuid),
+    Anonymous { ip: String, user_agent: String },
+}
+
+impl ArticleViewer {
+    pub fn key(&self, secret: &str) -> String {
+        match self {
+            ArticleViewer::User(user_id) => format!("user:{}", user_id),
+            ArticleViewer::Anonymous { ip, user_agent } => {
+                let mut hasher = Hmac::<Sha256>::new_from_slice(secret.as_bytes()).expect("HMAC accepts keys of any length");
+                hasher.update(ip.as_bytes());
+                hasher.update(b"|");
+                hasher.update(user_agent.as_bytes());
+
+                let hash = hasher.finalize().into_bytes().iter().map(|byte| format!("{:02x}", byte)).collect::<String>();
+
+                format!("anon:{}", hash)
+            }
+        }
+    }
+}
+
+#[derive(Clone, Debug, PartialEq, Eq, PartialOrd, Ord)]
+pub struct ArticleView {
+    article_id: Uuid,
+    viewer_key: String,
+    viewed_at: DateTime,
+}
+
+impl ArticleView {
+    // CONSTRUCTORS
+    pub fn new(article_id: Uuid, viewer: &ArticleViewer, viewer_key_secret: &str) -> Self {
+        ArticleView {
+            article_id,
+            viewer_key: viewer.key(viewer_key_secret),
+            viewed_at: TimeHelper::now(),
+        }
+    }
+
+    pub fn new_from_existing(article_id: Uuid, viewer_key: String, viewed_at: DateTime) -> Self {
+        ArticleView {
+            article_id,
+            viewer_key,
+            viewed_at,
+        }
+    }
+
+    // GETTERS
+
+    pub fn article_id(&self) -> Uuid {
+        self.article_id
+    }
+
+    pub fn viewer_key(&self) -> &str {
+        self.viewer_key.as_ref()
+    }
+
+    pub fn viewed_at(&self) -> DateTime {
+        self.viewed_at
+    }
+}
+
+#[derive(Clone, Debug, PartialEq, Eq, PartialOrd, Ord)]
+pub struct ArticleDailyViews {
+    article_id: Uuid,
+    day: NaiveDate,
+    views: u64,
+}
+
+impl ArticleDailyViews {
+    pub fn new_from_existing(article_id: Uuid, day: NaiveDate, views: u64) -> Self {
+        ArticleDailyViews {
+            article_id,
+            day,
+            views,
+        }
+    }
+
+    pub fn article_id(&self) -> Uuid {
+        self.article_id
+    }
+
+    pub fn day(&self) -> NaiveDate {
+        self.day
+    }
+
+    pub fn views(&self) -> u64 {
+        self.views
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_if_anonymous_viewer_key_does_not_expose_ip() {
+        let viewer = ArticleViewer::Anonymous { ip: "127.0.0.1".into(), user_agent: "Mozilla/5.0".into() };
+        let same_viewer = ArticleViewer::Anonymous { ip: "127.0.0.1".into(), user_agent: "Mozilla/5.0".into() };
+        let other_viewer = ArticleViewer::Anonymous { ip: "127.0.0.1".into(), user_agent: "curl/8.0".into() };
+
+        assert_eq!(viewer.key("secret"), same_viewer.key("secret"));
+        assert_ne!(viewer.key("secret"), other_viewer.key("secret"));
+        assert_ne!(viewer.key("secret"), viewer.key("another secret"), "Expected the key to depend on the server's secret.");
+        assert!(!viewer.key("secret").contains("127.0.0.1"));
+    }
+}
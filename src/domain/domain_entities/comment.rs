@@ -11,6 +11,10 @@ pub struct Comment {
     content: String,
     is_active: bool,
     created_at: DateTime,
+    parent_id: Option<Uuid>,
+    edited_at: Option<DateTime>,
+    /// When the comment was pinned to the top of the article's comments, `None` if it isn't.
+    pinned_at: Option<DateTime>,
 }
 
 impl Comment {
@@ -20,6 +24,7 @@ impl Comment {
         author_id: Uuid,
         article_id: Option<Uuid>,
         content: String,
+        parent_id: Option<Uuid>,
     ) -> Self {
         let id = Uuid::new_v4();
         let created_at  = TimeHelper::now();
@@ -31,10 +36,14 @@ impl Comment {
             author_id,
             content,
             is_active,
-            created_at
+            created_at,
+            parent_id,
+            edited_at: None,
+            pinned_at: None,
         }
     }
 
+    #[allow(clippy::too_many_arguments)]
     pub fn new_from_existing(
         id: Uuid,
         article_id: Option<Uuid>,
@@ -42,6 +51,9 @@ impl Comment {
         content: String,
         is_active: bool,
         created_at: DateTime,
+        parent_id: Option<Uuid>,
+        edited_at: Option<DateTime>,
+        pinned_at: Option<DateTime>,
     ) -> Self {
         Comment {
             id,
@@ -50,6 +62,9 @@ impl Comment {
             content,
             is_active,
             created_at,
+            parent_id,
+            edited_at,
+            pinned_at,
         }
     }
 
@@ -79,6 +94,19 @@ impl Comment {
         self.created_at
     }
 
+    /// The comment this one replies to, `None` for top-level comments.
+    pub fn parent_id(&self) -> Option<Uuid> {
+        self.parent_id
+    }
+
+    pub fn edited_at(&self) -> Option<DateTime> {
+        self.edited_at
+    }
+
+    pub fn pinned_at(&self) -> Option<DateTime> {
+        self.pinned_at
+    }
+
     // SETTERS
 
     pub fn set_content(&mut self, content: String) {
@@ -88,4 +116,17 @@ impl Comment {
     pub fn set_is_active(&mut self, is_active: bool) {
         self.is_active = is_active
     }
+
+    pub fn set_edited_at(&mut self, edited_at: Option<DateTime>) {
+        self.edited_at = edited_at
+    }
+
+    pub fn set_pinned_at(&mut self, pinned_at: Option<DateTime>) {
+        self.pinned_at = pinned_at
+    }
+
+    /// Hides the comment the same way moderators do, until one of them reviews it.
+    pub fn hold_for_review(&mut self) {
+        self.is_active = false
+    }
 }
\ No newline at end of file
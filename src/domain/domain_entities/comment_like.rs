@@ -0,0 +1,70 @@
+use chrono::NaiveDateTime as DateTime;
+use uuid::Uuid;
+
+use crate::libs::time::TimeHelper;
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct CommentLike {
+    comment_id: Uuid,
+    user_id: Uuid,
+    created_at: DateTime,
+}
+
+impl CommentLike {
+    // CONSTRUCTORS
+    pub fn new(comment_id: Uuid, user_id: Uuid) -> Self {
+        CommentLike {
+            comment_id,
+            user_id,
+            created_at: TimeHelper::now(),
+        }
+    }
+
+    pub fn new_from_existing(comment_id: Uuid, user_id: Uuid, created_at: DateTime) -> Self {
+        CommentLike {
+            comment_id,
+            user_id,
+            created_at,
+        }
+    }
+
+    // GETTERS
+    pub fn comment_id(&self) -> Uuid {
+        self.comment_id
+    }
+
+    pub fn user_id(&self) -> Uuid {
+        self.user_id
+    }
+
+    pub fn created_at(&self) -> DateTime {
+        self.created_at
+    }
+}
+
+/**
+ # Comment Likes Summary
+ How many users liked a comment, and whether the current user is one of them.
+ */
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct CommentLikesSummary {
+    like_count: u64,
+    liked_by_me: bool,
+}
+
+impl CommentLikesSummary {
+    pub fn new(like_count: u64, liked_by_me: bool) -> Self {
+        CommentLikesSummary {
+            like_count,
+            liked_by_me,
+        }
+    }
+
+    pub fn like_count(&self) -> u64 {
+        self.like_count
+    }
+
+    pub fn liked_by_me(&self) -> bool {
+        self.liked_by_me
+    }
+}
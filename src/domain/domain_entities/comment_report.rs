@@ -1,12 +1,63 @@
+use std::str::FromStr;
+
 use chrono::NaiveDateTime as DateTime;
 use uuid::Uuid;
 
+use crate::errors::enum_coercion_error::EnumCoercionError;
 use crate::libs::time::TimeHelper;
 
+/**
+ # Report Reason
+ Why a user reported a comment. The report's message details the reason, and is only required
+ when it is `Other`.
+ */
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum ReportReason {
+    Spam,
+    Offensive,
+    ScamPhishing,
+    OffTopic,
+    Other,
+}
+
+impl ReportReason {
+    pub const ALL: [ReportReason; 5] = [
+        ReportReason::Spam,
+        ReportReason::Offensive,
+        ReportReason::ScamPhishing,
+        ReportReason::OffTopic,
+        ReportReason::Other,
+    ];
+
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            ReportReason::Spam => "spam",
+            ReportReason::Offensive => "offensive",
+            ReportReason::ScamPhishing => "scam_phishing",
+            ReportReason::OffTopic => "off_topic",
+            ReportReason::Other => "other",
+        }
+    }
+}
+
+impl FromStr for ReportReason {
+    type Err = EnumCoercionError;
+
+    fn from_str(s: &str) -> Result<Self, EnumCoercionError> {
+        let s = s.to_lowercase();
+
+        ReportReason::ALL
+            .into_iter()
+            .find(|reason| reason.as_str() == s)
+            .ok_or_else(|| EnumCoercionError::new("Report Reason"))
+    }
+}
+
 #[derive(Clone, Debug, PartialEq, Eq, PartialOrd, Ord)]
 pub struct DraftCommentReport {
     comment_id: Uuid,
     user_id: Uuid,
+    reason: ReportReason,
     message: String,
     solved_by: Option<Uuid>,
     created_at: DateTime
@@ -17,6 +68,7 @@ impl DraftCommentReport {
     pub fn new(
         comment_id: Uuid,
         user_id: Uuid,
+        reason: ReportReason,
         message: String,
     ) -> Self {
         let solved_by = None;
@@ -25,6 +77,7 @@ impl DraftCommentReport {
         DraftCommentReport {
             comment_id,
             user_id,
+            reason,
             message,
             solved_by,
             created_at
@@ -37,6 +90,7 @@ impl DraftCommentReport {
             id,
             user_id: self.user_id,
             comment_id: self.comment_id,
+            reason: self.reason,
             message: self.message,
             solved_by: self.solved_by,
             created_at: self.created_at,
@@ -49,6 +103,7 @@ pub struct CommentReport {
     id: i32,
     comment_id: Uuid,
     user_id: Uuid,
+    reason: ReportReason,
     message: String,
     solved_by: Option<Uuid>,
     created_at: DateTime
@@ -65,6 +120,7 @@ impl CommentReport {
         id: i32,
         comment_id: Uuid,
         user_id: Uuid,
+        reason: ReportReason,
         message: String,
         solved_by: Option<Uuid>,
         created_at: DateTime
@@ -73,6 +129,7 @@ impl CommentReport {
             id,
             user_id,
             comment_id,
+            reason,
             message,
             solved_by,
             created_at,
@@ -92,6 +149,8 @@ impl CommentReportTrait for comment_report {
 
     fn user_id(&self) -> Uuid { self.user_id }
 
+    fn reason(&self) -> ReportReason { self.reason }
+
     fn message(&self) -> String { self.message.clone() }
 
     fn solved_by(&self) -> Option<Uuid> { self.solved_by }
@@ -102,6 +161,7 @@ impl CommentReportTrait for comment_report {
 pub trait CommentReportTrait {
     fn comment_id(&self) -> Uuid;
     fn user_id(&self) -> Uuid;
+    fn reason(&self) -> ReportReason;
     fn message(&self) -> String;
     fn solved_by(&self) -> Option<Uuid>;
     fn created_at(&self) -> DateTime;
@@ -109,4 +169,19 @@ pub trait CommentReportTrait {
 
 pub trait CommentReportIdTrait {
     fn id(&self) -> i32;
-}
\ No newline at end of file
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_if_report_reason_can_be_parsed() {
+        for reason in ReportReason::ALL {
+            assert_eq!(reason, ReportReason::from_str(reason.as_str()).unwrap());
+        }
+
+        assert_eq!(ReportReason::ScamPhishing, ReportReason::from_str("SCAM_PHISHING").unwrap());
+        assert!(ReportReason::from_str("boring").is_err());
+    }
+}
@@ -0,0 +1,56 @@
+use chrono::NaiveDateTime as DateTime;
+use uuid::Uuid;
+
+use crate::libs::time::TimeHelper;
+
+/**
+ # Comment Revision
+ The content a comment had before one of its author's edits, kept so moderators can see what was
+ changed after other users already read or replied to it.
+ */
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct CommentRevision {
+    id: Uuid,
+    comment_id: Uuid,
+    content: String,
+    revised_at: DateTime,
+}
+
+impl CommentRevision {
+    // CONSTRUCTORS
+    pub fn new(comment_id: Uuid, content: String) -> Self {
+        CommentRevision {
+            id: Uuid::new_v4(),
+            comment_id,
+            content,
+            revised_at: TimeHelper::now(),
+        }
+    }
+
+    pub fn new_from_existing(id: Uuid, comment_id: Uuid, content: String, revised_at: DateTime) -> Self {
+        CommentRevision {
+            id,
+            comment_id,
+            content,
+            revised_at,
+        }
+    }
+
+    // GETTERS
+    pub fn id(&self) -> Uuid {
+        self.id
+    }
+
+    pub fn comment_id(&self) -> Uuid {
+        self.comment_id
+    }
+
+    pub fn content(&self) -> &str {
+        self.content.as_ref()
+    }
+
+    /// When this content was replaced by the edit.
+    pub fn revised_at(&self) -> DateTime {
+        self.revised_at
+    }
+}
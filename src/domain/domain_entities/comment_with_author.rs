@@ -3,6 +3,7 @@ use uuid::Uuid;
 
 use crate::libs::time::TimeHelper;
 
+use super::mention::Mention;
 use super::user::User;
 
 #[derive(Clone, Debug, PartialEq, Eq, PartialOrd, Ord)]
@@ -12,7 +13,17 @@ pub struct CommentWithAuthor {
     content: String,
     is_active: bool,
     created_at: DateTime,
-    author: User
+    author: User,
+    parent_id: Option<Uuid>,
+    reply_count: u64,
+    edited_at: Option<DateTime>,
+    /// When the comment was pinned to the top of the article's comments, `None` if it isn't.
+    pinned_at: Option<DateTime>,
+    /// Users mentioned in the content, only loaded when listing comments.
+    mentions: Vec<Mention>,
+    like_count: u64,
+    /// Whether the current user liked the comment, `None` when nobody is logged in.
+    liked_by_me: Option<bool>,
 }
 
 impl CommentWithAuthor{
@@ -21,10 +32,13 @@ impl CommentWithAuthor{
         article_id: Option<Uuid>,
         content: String,
         author: User,
+        parent_id: Option<Uuid>,
     ) -> Self {
         let id = Uuid::new_v4();
         let created_at  = TimeHelper::now();
         let is_active = true;
+        let reply_count = 0;
+        let edited_at = None;
 
         CommentWithAuthor {
             id,
@@ -32,17 +46,29 @@ impl CommentWithAuthor{
             content,
             is_active,
             created_at,
-            author
+            author,
+            parent_id,
+            reply_count,
+            edited_at,
+            pinned_at: None,
+            mentions: vec![],
+            like_count: 0,
+            liked_by_me: None,
         }
     }
 
+    #[allow(clippy::too_many_arguments)]
     pub fn new_from_existing(
         id: Uuid,
         article_id: Option<Uuid>,
         content: String,
         is_active: bool,
         created_at: DateTime,
-        author: User
+        author: User,
+        parent_id: Option<Uuid>,
+        reply_count: u64,
+        edited_at: Option<DateTime>,
+        pinned_at: Option<DateTime>,
     ) -> Self {
         CommentWithAuthor {
             id,
@@ -50,7 +76,14 @@ impl CommentWithAuthor{
             content,
             is_active,
             created_at,
-            author
+            author,
+            parent_id,
+            reply_count,
+            edited_at,
+            pinned_at,
+            mentions: vec![],
+            like_count: 0,
+            liked_by_me: None,
         }
     }
 
@@ -78,4 +111,46 @@ impl CommentWithAuthor{
     pub fn author(&self) -> &User {
         &self.author
     }
+
+    pub fn parent_id(&self) -> Option<Uuid> {
+        self.parent_id
+    }
+
+    /// Number of direct replies, not counting deeper ones.
+    pub fn reply_count(&self) -> u64 {
+        self.reply_count
+    }
+
+    pub fn edited_at(&self) -> Option<DateTime> {
+        self.edited_at
+    }
+
+    pub fn pinned_at(&self) -> Option<DateTime> {
+        self.pinned_at
+    }
+
+    pub fn mentions(&self) -> &[Mention] {
+        &self.mentions
+    }
+
+    pub fn like_count(&self) -> u64 {
+        self.like_count
+    }
+
+    pub fn liked_by_me(&self) -> Option<bool> {
+        self.liked_by_me
+    }
+
+    // SETTERS
+    pub fn set_mentions(&mut self, mentions: Vec<Mention>) {
+        self.mentions = mentions;
+    }
+
+    pub fn set_like_count(&mut self, like_count: u64) {
+        self.like_count = like_count;
+    }
+
+    pub fn set_liked_by_me(&mut self, liked_by_me: Option<bool>) {
+        self.liked_by_me = liked_by_me;
+    }
 }
@@ -11,7 +11,8 @@ pub struct FreeBadge {
     link: String,
     link_is_external: bool,
     created_at: NaiveDateTime,
-    available_until: Option<NaiveDateTime>
+    available_until: Option<NaiveDateTime>,
+    version: i32,
 }
 
 impl FreeBadge {
@@ -32,7 +33,8 @@ impl FreeBadge {
             link,
             link_is_external,
             available_until,
-            created_at
+            created_at,
+            version: 1
         }
     }
 
@@ -43,7 +45,8 @@ impl FreeBadge {
         link: String,
         link_is_external: bool,
         created_at: NaiveDateTime,
-        available_until: Option<NaiveDateTime>
+        available_until: Option<NaiveDateTime>,
+        version: i32
     ) -> FreeBadge {
         FreeBadge {
             id,
@@ -52,7 +55,8 @@ impl FreeBadge {
             link,
             link_is_external,
             available_until,
-            created_at
+            created_at,
+            version
         }
     }
 
@@ -61,6 +65,11 @@ impl FreeBadge {
         self.id
     }
 
+    /// Incremented on every save, so that concurrent changes to the same row are detected.
+    pub fn version(&self) -> i32 {
+        self.version
+    }
+
     pub fn code(&self) -> &String {
         &self.code
     }
@@ -0,0 +1,63 @@
+use super::article::Article;
+use super::article_edit_lock::ArticleEditLock;
+use super::role::Role;
+
+/**
+ # Listed Article
+ An article as shown on listings, along with what the listing needs from its author, its comments
+ and its edit lock, so it can be fetched at once instead of with a query per article.
+ */
+#[derive(Clone, Debug, PartialEq, Eq, PartialOrd, Ord)]
+pub struct ListedArticle {
+    article: Article,
+    author_nickname: String,
+    author_role: Option<Role>,
+    comment_count: u64,
+    edit_lock: Option<ArticleEditLock>,
+}
+
+impl ListedArticle {
+    // CONSTRUCTORS
+    pub fn new(
+        article: Article,
+        author_nickname: String,
+        author_role: Option<Role>,
+        comment_count: u64,
+        edit_lock: Option<ArticleEditLock>,
+    ) -> Self {
+        ListedArticle {
+            article,
+            author_nickname,
+            author_role,
+            comment_count,
+            edit_lock
+        }
+    }
+
+    // GETTERS
+    pub fn article(&self) -> &Article {
+        &self.article
+    }
+
+    pub fn author_nickname(&self) -> &str {
+        &self.author_nickname
+    }
+
+    pub fn author_role(&self) -> Option<Role> {
+        self.author_role.clone()
+    }
+
+    /// Counts only the active comments.
+    pub fn comment_count(&self) -> u64 {
+        self.comment_count
+    }
+
+    /// Only a lock that hasn't expired yet.
+    pub fn edit_lock(&self) -> Option<&ArticleEditLock> {
+        self.edit_lock.as_ref()
+    }
+
+    pub fn into_article(self) -> Article {
+        self.article
+    }
+}
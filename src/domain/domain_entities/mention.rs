@@ -0,0 +1,236 @@
+use std::collections::HashMap;
+
+use chrono::NaiveDateTime as DateTime;
+use uuid::Uuid;
+
+use crate::core::NICKNAME_REGX;
+use crate::libs::time::TimeHelper;
+
+/// Only the first mentions of a content are looked up, so a comment full of `@` can't flood the database.
+pub const MAX_MENTIONS_PER_CONTENT: usize = 10;
+
+/// Characters a nickname may end with that are more likely to be the sentence's punctuation.
+const TRAILING_PUNCTUATION: [char; 6] = ['.', ',', '!', '?', ':', ';'];
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum MentionSource {
+    /// Mentions written in the article's own content.
+    Article(Uuid),
+    /// The article is kept along with the comment, so the mentioned user can be taken to it.
+    Comment { comment_id: Uuid, article_id: Option<Uuid> },
+}
+
+/**
+ # Mention
+ A user `@nickname`d on an article or comment. There is one mention per user and source, no
+ matter how many times the nickname appears on it.
+ */
+#[derive(Clone, Debug, PartialEq, Eq, PartialOrd, Ord)]
+pub struct Mention {
+    id: Uuid,
+    user_id: Uuid,
+    mentioned_by: Uuid,
+    article_id: Option<Uuid>,
+    comment_id: Option<Uuid>,
+    created_at: DateTime,
+    /// The mentioned user's nickname, only present when read from the database.
+    nickname: Option<String>,
+}
+
+impl Mention {
+    // CONSTRUCTORS
+    pub fn new(user_id: Uuid, mentioned_by: Uuid, source: MentionSource) -> Self {
+        let (article_id, comment_id) = match source {
+            MentionSource::Article(article_id) => (Some(article_id), None),
+            MentionSource::Comment { comment_id, article_id } => (article_id, Some(comment_id)),
+        };
+
+        Mention {
+            id: Uuid::new_v4(),
+            user_id,
+            mentioned_by,
+            article_id,
+            comment_id,
+            created_at: TimeHelper::now(),
+            nickname: None,
+        }
+    }
+
+    pub fn new_from_existing(
+        id: Uuid,
+        user_id: Uuid,
+        mentioned_by: Uuid,
+        article_id: Option<Uuid>,
+        comment_id: Option<Uuid>,
+        created_at: DateTime,
+        nickname: Option<String>,
+    ) -> Self {
+        Mention {
+            id,
+            user_id,
+            mentioned_by,
+            article_id,
+            comment_id,
+            created_at,
+            nickname,
+        }
+    }
+
+    // GETTERS
+    pub fn id(&self) -> Uuid {
+        self.id
+    }
+
+    pub fn user_id(&self) -> Uuid {
+        self.user_id
+    }
+
+    pub fn mentioned_by(&self) -> Uuid {
+        self.mentioned_by
+    }
+
+    pub fn article_id(&self) -> Option<Uuid> {
+        self.article_id
+    }
+
+    pub fn comment_id(&self) -> Option<Uuid> {
+        self.comment_id
+    }
+
+    pub fn created_at(&self) -> DateTime {
+        self.created_at
+    }
+
+    pub fn nickname(&self) -> Option<&str> {
+        self.nickname.as_deref()
+    }
+}
+
+/// An `@nickname` found in a text, before it's known whether the nickname belongs to someone.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct MentionCandidate {
+    /// Offset of the `@`, in characters.
+    start: usize,
+    token: String,
+}
+
+impl MentionCandidate {
+    /// Finds every `@` followed by characters allowed in nicknames, skipping the ones glued to a
+    /// word, such as e-mail addresses.
+    pub fn parse(content: &str) -> Vec<MentionCandidate> {
+        let chars: Vec<char> = content.chars().collect();
+        let mut candidates = vec![];
+        let mut index = 0;
+
+        while index < chars.len() {
+            let starts_mention = chars[index] == '@'
+                && (index == 0 || !chars[index - 1].is_alphanumeric());
+
+            if !starts_mention {
+                index += 1;
+                continue;
+            }
+
+            let token: String = chars[index + 1..].iter()
+                .take_while(|c| NICKNAME_REGX.is_match(c.encode_utf8(&mut [0; 4])))
+                .collect();
+
+            let token_length = token.chars().count();
+
+            if token_length > 0 {
+                candidates.push(MentionCandidate { start: index, token });
+            }
+
+            index += token_length + 1;
+        }
+
+        candidates
+    }
+
+    pub fn start(&self) -> usize {
+        self.start
+    }
+
+    /// The nicknames this mention may refer to, longest first: nicknames may end with punctuation,
+    /// so `@Salem,` could be either `Salem,` or `Salem`.
+    pub fn nicknames(&self) -> Vec<&str> {
+        let mut nicknames = vec![self.token.as_str()];
+        let mut nickname = self.token.as_str();
+
+        while let Some(trimmed) = nickname.strip_suffix(TRAILING_PUNCTUATION) {
+            if trimmed.is_empty() { break; }
+
+            nicknames.push(trimmed);
+            nickname = trimmed;
+        }
+
+        nicknames
+    }
+}
+
+/// Where a resolved mention is in a text, in character offsets, `start` being the `@`'s.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct MentionSpan {
+    pub start: usize,
+    pub end: usize,
+    pub user_id: Uuid,
+    pub nickname: String,
+}
+
+impl MentionSpan {
+    /// Locates the stored mentions in the content they were parsed from. Nicknames that don't
+    /// belong to any of the mentions are left out, staying plain text.
+    pub fn find_in(content: &str, mentions: &[Mention]) -> Vec<MentionSpan> {
+        let users: HashMap<String, (Uuid, &str)> = mentions.iter()
+            .filter_map(|mention| mention.nickname().map(|nickname| (nickname.to_lowercase(), (mention.user_id(), nickname))))
+            .collect();
+
+        if users.is_empty() { return vec![]; }
+
+        MentionCandidate::parse(content).into_iter()
+            .filter_map(|candidate| {
+                candidate.nicknames().into_iter()
+                    .find_map(|nickname| users.get(&nickname.to_lowercase()))
+                    .map(|(user_id, nickname)| MentionSpan {
+                        start: candidate.start(),
+                        end: candidate.start() + 1 + nickname.chars().count(),
+                        user_id: *user_id,
+                        nickname: nickname.to_string(),
+                    })
+            })
+            .collect()
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_mentions_are_parsed_and_located() {
+        let candidates = MentionCandidate::parse("Olá @Salem, viu isso? Manda para contato@habbo.com e @Elffi.");
+
+        assert_eq!(2, candidates.len());
+        assert_eq!(vec!["Salem,", "Salem"], candidates[0].nicknames());
+        assert_eq!(vec!["Elffi.", "Elffi"], candidates[1].nicknames());
+
+        let salem_id = Uuid::new_v4();
+        let mentions = vec![Mention::new_from_existing(
+            Uuid::new_v4(),
+            salem_id,
+            Uuid::new_v4(),
+            Some(Uuid::new_v4()),
+            None,
+            TimeHelper::now(),
+            Some("Salem".into()),
+        )];
+
+        let spans = MentionSpan::find_in("Olá @salem, viu isso? E você, @Desconhecido?", &mentions);
+
+        assert_eq!(
+            vec![MentionSpan { start: 4, end: 10, user_id: salem_id, nickname: "Salem".into() }],
+            spans,
+            "Only known nicknames should be linked, the others staying plain text."
+        );
+    }
+}
@@ -1,11 +1,23 @@
 pub mod user;
 pub mod role;
 pub mod article;
+pub mod article_view;
+pub mod article_reaction;
+pub mod article_coauthor;
+pub mod article_edit_lock;
+pub mod article_note;
+pub mod article_content;
+pub mod article_block;
+pub mod listed_article;
 pub mod slug;
 pub mod comment;
 pub mod comment_report;
 pub mod comment_with_author;
+pub mod comment_like;
+pub mod comment_revision;
+pub mod mention;
 pub mod team_user;
 pub mod team_role;
 pub mod article_tag;
-pub mod free_badge;
\ No newline at end of file
+pub mod free_badge;
+pub mod word_filter;
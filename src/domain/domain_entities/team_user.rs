@@ -10,7 +10,8 @@ pub struct TeamUser {
     user_function: String,
     twitter: Option<String>,
     discord: Option<String>,
-    created_at: DateTime
+    created_at: DateTime,
+    version: i32,
 }
 
 impl TeamUser {
@@ -33,6 +34,7 @@ impl TeamUser {
             twitter,
             discord,
             created_at,
+            version: 1,
         }
     }
 
@@ -43,7 +45,8 @@ impl TeamUser {
         user_function: String,
         twitter: Option<String>,
         discord: Option<String>,
-        created_at: DateTime
+        created_at: DateTime,
+        version: i32,
     ) -> Self {
         TeamUser {
             id,
@@ -53,6 +56,7 @@ impl TeamUser {
             twitter,
             discord,
             created_at,
+            version,
         }
     }
 
@@ -61,6 +65,11 @@ impl TeamUser {
         self.id
     }
 
+    /// Incremented on every save, so that concurrent changes to the same row are detected.
+    pub fn version(&self) -> i32 {
+        self.version
+    }
+
     pub fn team_role_id(&self) -> Uuid {
         self.team_role_id
     }
@@ -12,6 +12,7 @@ pub struct User {
     created_at: DateTime,
     last_login: Option<DateTime>,
     role: Option<Role>,
+    version: i32,
 }
 
 impl User {
@@ -33,7 +34,8 @@ impl User {
             password,
             created_at,
             last_login,
-            role
+            role,
+            version: 1
         }
     }
 
@@ -44,6 +46,7 @@ impl User {
         created_at: DateTime,
         last_login: Option<DateTime>,
         role: Option<Role>,
+        version: i32,
     ) -> Self {
         User {
             id,
@@ -51,7 +54,8 @@ impl User {
             password,
             created_at,
             last_login,
-            role
+            role,
+            version
         }
     }
     
@@ -61,6 +65,11 @@ impl User {
         self.id
     }
 
+    /// Incremented on every save, so that concurrent changes to the same row are detected.
+    pub fn version(&self) -> i32 {
+        self.version
+    }
+
     pub fn nickname(&self) -> &str {
         self.nickname.as_ref()
     }
@@ -0,0 +1,209 @@
+use std::str::FromStr;
+
+use chrono::NaiveDateTime as DateTime;
+use regex::{Regex, RegexBuilder};
+use unicode_normalization::char::is_combining_mark;
+use unicode_normalization::UnicodeNormalization;
+use uuid::Uuid;
+
+use crate::errors::enum_coercion_error::EnumCoercionError;
+use crate::libs::time::TimeHelper;
+
+/// Keeps moderators' patterns from compiling into huge automatons.
+const MAX_COMPILED_PATTERN_SIZE: usize = 1 << 20;
+
+/**
+ # Word Filter Action
+ What happens to a content matching a word filter. Not every content can be deactivated or
+ masked, see `WordFilterPolitics`.
+ */
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum WordFilterAction {
+    /// Replaces every matched character with an asterisk.
+    Mask,
+    /// Keeps the content as written, but hides it until a moderator reviews it.
+    Deactivate,
+    Reject,
+}
+
+impl WordFilterAction {
+    pub const ALL: [WordFilterAction; 3] = [
+        WordFilterAction::Mask,
+        WordFilterAction::Deactivate,
+        WordFilterAction::Reject,
+    ];
+
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            WordFilterAction::Mask => "mask",
+            WordFilterAction::Deactivate => "deactivate",
+            WordFilterAction::Reject => "reject",
+        }
+    }
+}
+
+impl FromStr for WordFilterAction {
+    type Err = EnumCoercionError;
+
+    fn from_str(s: &str) -> Result<Self, EnumCoercionError> {
+        let s = s.to_lowercase();
+
+        WordFilterAction::ALL
+            .into_iter()
+            .find(|action| action.as_str() == s)
+            .ok_or_else(|| EnumCoercionError::new("Word Filter Action"))
+    }
+}
+
+/**
+ # Word Filter
+ A banned word or regular expression kept by the moderators. Matching is case and accent
+ insensitive: both the pattern and the text are stripped of their accents before being compared,
+ so `pao` matches `Pão`. Words only match whole, regular expressions match anywhere.
+ */
+#[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord)]
+pub struct WordFilter {
+    id: Uuid,
+    pattern: String,
+    is_regex: bool,
+    action: WordFilterAction,
+    created_at: DateTime,
+    updated_at: Option<DateTime>,
+    version: i32,
+}
+
+impl WordFilter {
+    // CONSTRUCTORS
+    pub fn new(pattern: String, is_regex: bool, action: WordFilterAction) -> Self {
+        WordFilter {
+            id: Uuid::new_v4(),
+            pattern,
+            is_regex,
+            action,
+            created_at: TimeHelper::now(),
+            updated_at: None,
+            version: 1,
+        }
+    }
+
+    pub fn new_from_existing(
+        id: Uuid,
+        pattern: String,
+        is_regex: bool,
+        action: WordFilterAction,
+        created_at: DateTime,
+        updated_at: Option<DateTime>,
+        version: i32,
+    ) -> Self {
+        WordFilter {
+            id,
+            pattern,
+            is_regex,
+            action,
+            created_at,
+            updated_at,
+            version,
+        }
+    }
+
+    // GETTERS
+    pub fn id(&self) -> Uuid {
+        self.id
+    }
+
+    pub fn pattern(&self) -> &str {
+        &self.pattern
+    }
+
+    pub fn is_regex(&self) -> bool {
+        self.is_regex
+    }
+
+    pub fn action(&self) -> WordFilterAction {
+        self.action
+    }
+
+    pub fn created_at(&self) -> DateTime {
+        self.created_at
+    }
+
+    pub fn updated_at(&self) -> Option<DateTime> {
+        self.updated_at
+    }
+
+    /// Incremented on every save, so that concurrent changes to the same row are detected.
+    pub fn version(&self) -> i32 {
+        self.version
+    }
+
+    // SETTERS
+    pub fn set_pattern(&mut self, pattern: String) {
+        self.pattern = pattern;
+    }
+
+    pub fn set_is_regex(&mut self, is_regex: bool) {
+        self.is_regex = is_regex;
+    }
+
+    pub fn set_action(&mut self, action: WordFilterAction) {
+        self.action = action;
+    }
+
+    pub fn set_updated_at(&mut self, updated_at: Option<DateTime>) {
+        self.updated_at = updated_at;
+    }
+
+    // METHODS
+
+    /// Strips the accents of every character, one character for another, so that offsets in the
+    /// folded text are the same as in the original one.
+    pub fn fold(text: &str) -> String {
+        text.chars()
+            .map(|c| c.nfd().find(|c| !is_combining_mark(*c)).unwrap_or(c))
+            .collect()
+    }
+
+    /// Compiles the pattern to run against folded texts. Fails on invalid regular expressions.
+    pub fn compile(&self) -> Result<Regex, regex::Error> {
+        let pattern = Self::fold(self.pattern.trim());
+
+        let pattern = if self.is_regex {
+            pattern
+        } else {
+            let starts_with_word = pattern.chars().next().is_some_and(char::is_alphanumeric);
+            let ends_with_word = pattern.chars().last().is_some_and(char::is_alphanumeric);
+
+            format!(
+                "{}{}{}",
+                if starts_with_word { r"\b" } else { "" },
+                regex::escape(&pattern),
+                if ends_with_word { r"\b" } else { "" },
+            )
+        };
+
+        RegexBuilder::new(&pattern)
+            .case_insensitive(true)
+            .size_limit(MAX_COMPILED_PATTERN_SIZE)
+            .build()
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_words_match_whole_and_ignoring_accents() {
+        let word = WordFilter::new("pao".into(), false, WordFilterAction::Mask).compile().unwrap();
+
+        assert!(word.is_match(&WordFilter::fold("Quero PÃO!")));
+        assert!(!word.is_match(&WordFilter::fold("Sapão")), "Words should only match whole.");
+
+        let regex = WordFilter::new(r"c[4a]ss[i1]no".into(), true, WordFilterAction::Reject).compile().unwrap();
+
+        assert!(regex.is_match(&WordFilter::fold("Melhor CASSÍNO do hotel")));
+        assert!(regex.is_match(&WordFilter::fold("c4ss1noonline")));
+
+        assert!(WordFilter::new("(".into(), true, WordFilterAction::Mask).compile().is_err());
+    }
+}
@@ -0,0 +1,35 @@
+use crate::domain::services::acquire_article_edit_lock_service::AcquireArticleEditLockService;
+use actix_web::HttpResponse;
+use either::Either::{self, *};
+use crate::errors::internal_error::InternalError;
+use crate::infra::sea::repositories::sea_article_coauthor_repository::SeaArticleCoauthorRepository;
+use crate::infra::sea::repositories::sea_article_edit_lock_repository::SeaArticleEditLockRepository;
+use crate::infra::sea::repositories::sea_article_repository::SeaArticleRepository;
+use crate::infra::sea::sea_service::SeaService;
+
+pub async fn exec() -> Either<AcquireArticleEditLockService<SeaArticleRepository, SeaArticleCoauthorRepository, SeaArticleEditLockRepository>, HttpResponse> {
+    let sea_service = SeaService::new().await;
+
+    if sea_service.is_err() {
+        return Right(crate::util::generate_error_response(Box::new(InternalError::new())))
+    }
+
+    let sea_service = sea_service.unwrap();
+
+    let article_repository: Box<SeaArticleRepository> =
+    Box::new(SeaArticleRepository::new(sea_service.clone()).await);
+
+    let article_coauthor_repository: Box<SeaArticleCoauthorRepository> =
+    Box::new(SeaArticleCoauthorRepository::new(sea_service.clone()).await);
+
+    let article_edit_lock_repository: Box<SeaArticleEditLockRepository> =
+    Box::new(SeaArticleEditLockRepository::new(sea_service).await);
+
+    let acquire_article_edit_lock_service = AcquireArticleEditLockService::new(
+        article_repository,
+        article_coauthor_repository,
+        article_edit_lock_repository
+    );
+
+    Left(acquire_article_edit_lock_service)
+}
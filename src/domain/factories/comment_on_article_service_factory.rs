@@ -1,12 +1,17 @@
+use crate::domain::politics::comment_spam_politics::{CommentSpamFilter, CommentSpamSettings};
 use crate::domain::services::comment_on_article_service::CommentOnArticleService;
 use actix_web::HttpResponse;
 use either::Either::{self, *};
 use crate::errors::internal_error::InternalError;
 use crate::infra::sea::repositories::sea_article_repository::SeaArticleRepository;
 use crate::infra::sea::repositories::sea_comment_repository::SeaCommentRepository;
+use crate::infra::sea::repositories::sea_mention_repository::SeaMentionRepository;
+use crate::infra::sea::repositories::sea_user_repository::SeaUserRepository;
+use crate::infra::sea::repositories::sea_word_filter_repository::SeaWordFilterRepository;
 use crate::infra::sea::sea_service::SeaService;
+use crate::ENV_VARS;
 
-pub async fn exec() -> Either<CommentOnArticleService<SeaCommentRepository, SeaArticleRepository>, HttpResponse> {
+pub async fn exec() -> Either<CommentOnArticleService<SeaCommentRepository, SeaArticleRepository, SeaUserRepository, SeaMentionRepository, SeaWordFilterRepository>, HttpResponse> {
     let sea_service = SeaService::new().await;
 
     if sea_service.is_err() {
@@ -16,9 +21,24 @@ pub async fn exec() -> Either<CommentOnArticleService<SeaCommentRepository, SeaA
     let sea_service = sea_service.unwrap();
 
     let comment_repository: Box<SeaCommentRepository> = Box::new(SeaCommentRepository::new(sea_service.clone()).await);
-    let article_repository: Box<SeaArticleRepository> = Box::new(SeaArticleRepository::new(sea_service).await);
+    let article_repository: Box<SeaArticleRepository> = Box::new(SeaArticleRepository::new(sea_service.clone()).await);
+    let user_repository: Box<SeaUserRepository> = Box::new(SeaUserRepository::new(sea_service.clone()).await);
+    let mention_repository: Box<SeaMentionRepository> = Box::new(SeaMentionRepository::new(sea_service.clone()).await);
+    let word_filter_repository: Box<SeaWordFilterRepository> = Box::new(SeaWordFilterRepository::new(sea_service).await);
     
-    let comment_on_article_service = CommentOnArticleService::new(comment_repository, article_repository);
+    let spam_settings = CommentSpamSettings::with_blocklists(
+        ENV_VARS.comment_blocked_words.as_deref(),
+        ENV_VARS.comment_blocked_domains.as_deref(),
+    );
+
+    let comment_on_article_service = CommentOnArticleService::new(
+        comment_repository,
+        article_repository,
+        user_repository,
+        mention_repository,
+        word_filter_repository,
+        CommentSpamFilter::new(&spam_settings),
+    );
 
     Left(comment_on_article_service)
 }
\ No newline at end of file
@@ -0,0 +1,22 @@
+use crate::domain::services::count_articles_views_service::CountArticlesViewsService;
+use actix_web::HttpResponse;
+use either::Either::{self, *};
+use crate::errors::internal_error::InternalError;
+use crate::infra::sea::repositories::sea_article_view_repository::SeaArticleViewRepository;
+use crate::infra::sea::sea_service::SeaService;
+
+pub async fn exec() -> Either<CountArticlesViewsService<SeaArticleViewRepository>, HttpResponse> {
+    let sea_service = SeaService::new().await;
+
+    if sea_service.is_err() {
+        return Right(crate::util::generate_error_response(Box::new(InternalError::new())))
+    }
+
+    let sea_service = sea_service.unwrap();
+
+    let article_view_repository: Box<SeaArticleViewRepository> = Box::new(SeaArticleViewRepository::new(sea_service).await);
+
+    let count_articles_views_service = CountArticlesViewsService::new(article_view_repository);
+
+    Left(count_articles_views_service)
+}
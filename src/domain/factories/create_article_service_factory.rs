@@ -4,10 +4,11 @@ use either::Either::{self, *};
 use crate::errors::internal_error::InternalError;
 use crate::infra::sea::repositories::sea_article_repository::SeaArticleRepository;
 use crate::infra::sea::repositories::sea_article_tag_repository::SeaArticleTagRepository;
+use crate::infra::sea::repositories::sea_mention_repository::SeaMentionRepository;
 use crate::infra::sea::repositories::sea_user_repository::SeaUserRepository;
 use crate::infra::sea::sea_service::SeaService;
 
-pub async fn exec() -> Either<CreateArticleService<SeaArticleRepository, SeaArticleTagRepository, SeaUserRepository>, HttpResponse> {
+pub async fn exec() -> Either<CreateArticleService<SeaArticleRepository, SeaArticleTagRepository, SeaUserRepository, SeaMentionRepository>, HttpResponse> {
     let sea_service = SeaService::new().await;
 
     if sea_service.is_err() {
@@ -18,12 +19,14 @@ pub async fn exec() -> Either<CreateArticleService<SeaArticleRepository, SeaArti
 
     let sea_article_repository: Box<SeaArticleRepository> = Box::new(SeaArticleRepository::new(sea_service.clone()).await);
     let sea_article_tag_repository: Box<SeaArticleTagRepository> = Box::new(SeaArticleTagRepository::new(sea_service.clone()).await);
-    let sea_user_repository: Box<SeaUserRepository> = Box::new(SeaUserRepository::new(sea_service).await);
+    let sea_user_repository: Box<SeaUserRepository> = Box::new(SeaUserRepository::new(sea_service.clone()).await);
+    let sea_mention_repository: Box<SeaMentionRepository> = Box::new(SeaMentionRepository::new(sea_service).await);
 
     let create_article_service = CreateArticleService::new(
         sea_article_repository,
         sea_article_tag_repository,
-        sea_user_repository
+        sea_user_repository,
+        sea_mention_repository
     );
 
     Left(create_article_service)
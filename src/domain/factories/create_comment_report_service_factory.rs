@@ -4,9 +4,10 @@ use either::Either::{self, *};
 use crate::errors::internal_error::InternalError;
 use crate::infra::sea::repositories::sea_comment_report_repository::SeaCommentReportRepository;
 use crate::infra::sea::repositories::sea_comment_repository::SeaCommentRepository;
+use crate::infra::sea::repositories::sea_word_filter_repository::SeaWordFilterRepository;
 use crate::infra::sea::sea_service::SeaService;
 
-pub async fn exec() -> Either<CreateCommentReportService<SeaCommentRepository, SeaCommentReportRepository>, HttpResponse> {
+pub async fn exec() -> Either<CreateCommentReportService<SeaCommentRepository, SeaCommentReportRepository, SeaWordFilterRepository>, HttpResponse> {
     let sea_service = SeaService::new().await;
 
     if sea_service.is_err() {
@@ -16,11 +17,13 @@ pub async fn exec() -> Either<CreateCommentReportService<SeaCommentRepository, S
     let sea_service = sea_service.unwrap();
     
     let comment_repository = Box::new(SeaCommentRepository::new(sea_service.clone()).await);
-    let comment_report_repository = Box::new(SeaCommentReportRepository::new(sea_service).await);
+    let comment_report_repository = Box::new(SeaCommentReportRepository::new(sea_service.clone()).await);
+    let word_filter_repository = Box::new(SeaWordFilterRepository::new(sea_service).await);
     
     let create_comment_report_service = CreateCommentReportService::new(
         comment_repository,
-        comment_report_repository
+        comment_report_repository,
+        word_filter_repository
     );
 
     Left(create_comment_report_service)
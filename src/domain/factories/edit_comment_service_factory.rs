@@ -0,0 +1,46 @@
+use actix_web::HttpResponse;
+use either::Either::{self, *};
+use crate::domain::politics::comment_politics::CommentPolitics;
+use crate::domain::politics::comment_spam_politics::{CommentSpamFilter, CommentSpamSettings};
+use crate::domain::services::edit_comment_service::EditCommentService;
+use crate::errors::internal_error::InternalError;
+use crate::infra::sea::repositories::sea_comment_repository::SeaCommentRepository;
+use crate::infra::sea::repositories::sea_mention_repository::SeaMentionRepository;
+use crate::infra::sea::repositories::sea_user_repository::SeaUserRepository;
+use crate::infra::sea::repositories::sea_word_filter_repository::SeaWordFilterRepository;
+use crate::infra::sea::sea_service::SeaService;
+use crate::ENV_VARS;
+
+pub async fn exec() -> Either<EditCommentService<SeaCommentRepository, SeaUserRepository, SeaMentionRepository, SeaWordFilterRepository>, HttpResponse> {
+    let sea_service = SeaService::new().await;
+
+    if sea_service.is_err() {
+        return Right(crate::util::generate_error_response(Box::new(InternalError::new())))
+    }
+
+    let sea_service = sea_service.unwrap();
+
+    let comment_repository: Box<SeaCommentRepository> = Box::new(SeaCommentRepository::new(sea_service.clone()).await);
+    let user_repository: Box<SeaUserRepository> = Box::new(SeaUserRepository::new(sea_service.clone()).await);
+    let mention_repository: Box<SeaMentionRepository> = Box::new(SeaMentionRepository::new(sea_service.clone()).await);
+    let word_filter_repository: Box<SeaWordFilterRepository> = Box::new(SeaWordFilterRepository::new(sea_service).await);
+
+    let edit_window_minutes = ENV_VARS.comment_edit_window_minutes
+        .unwrap_or_else(CommentPolitics::default_edit_window_minutes);
+
+    let spam_settings = CommentSpamSettings::with_blocklists(
+        ENV_VARS.comment_blocked_words.as_deref(),
+        ENV_VARS.comment_blocked_domains.as_deref(),
+    );
+
+    let edit_comment_service = EditCommentService::new(
+        comment_repository,
+        user_repository,
+        mention_repository,
+        word_filter_repository,
+        edit_window_minutes,
+        CommentSpamFilter::content_checks(&spam_settings),
+    );
+
+    Left(edit_comment_service)
+}
@@ -0,0 +1,35 @@
+use crate::domain::services::fetch_article_notes_service::FetchArticleNotesService;
+use actix_web::HttpResponse;
+use either::Either::{self, *};
+use crate::errors::internal_error::InternalError;
+use crate::infra::sea::repositories::sea_article_coauthor_repository::SeaArticleCoauthorRepository;
+use crate::infra::sea::repositories::sea_article_note_repository::SeaArticleNoteRepository;
+use crate::infra::sea::repositories::sea_article_repository::SeaArticleRepository;
+use crate::infra::sea::sea_service::SeaService;
+
+pub async fn exec() -> Either<FetchArticleNotesService<SeaArticleRepository, SeaArticleCoauthorRepository, SeaArticleNoteRepository>, HttpResponse> {
+    let sea_service = SeaService::new().await;
+
+    if sea_service.is_err() {
+        return Right(crate::util::generate_error_response(Box::new(InternalError::new())))
+    }
+
+    let sea_service = sea_service.unwrap();
+
+    let article_repository: Box<SeaArticleRepository> =
+    Box::new(SeaArticleRepository::new(sea_service.clone()).await);
+
+    let article_coauthor_repository: Box<SeaArticleCoauthorRepository> =
+    Box::new(SeaArticleCoauthorRepository::new(sea_service.clone()).await);
+
+    let article_note_repository: Box<SeaArticleNoteRepository> =
+    Box::new(SeaArticleNoteRepository::new(sea_service).await);
+
+    let fetch_article_notes_service = FetchArticleNotesService::new(
+        article_repository,
+        article_coauthor_repository,
+        article_note_repository
+    );
+
+    Left(fetch_article_notes_service)
+}
@@ -0,0 +1,30 @@
+use actix_web::HttpResponse;
+use either::Either::{self, *};
+use crate::domain::services::fetch_comment_replies_service::FetchCommentRepliesService;
+use crate::errors::internal_error::InternalError;
+use crate::infra::sea::repositories::sea_comment_like_repository::SeaCommentLikeRepository;
+use crate::infra::sea::repositories::sea_comment_repository::SeaCommentRepository;
+use crate::infra::sea::repositories::sea_comment_user_article_repository::SeaCommentUserArticleRepository;
+use crate::infra::sea::sea_service::SeaService;
+
+pub async fn exec() -> Either<FetchCommentRepliesService<SeaCommentRepository, SeaCommentUserArticleRepository, SeaCommentLikeRepository>, HttpResponse> {
+    let sea_service = SeaService::new().await;
+
+    if sea_service.is_err() {
+        return Right(crate::util::generate_error_response(Box::new(InternalError::new())))
+    }
+
+    let sea_service = sea_service.unwrap();
+
+    let comment_repository: Box<SeaCommentRepository> = Box::new(SeaCommentRepository::new(sea_service.clone()).await);
+    let comment_user_article_repository: Box<SeaCommentUserArticleRepository> = Box::new(SeaCommentUserArticleRepository::new(sea_service.clone()).await);
+    let comment_like_repository: Box<SeaCommentLikeRepository> = Box::new(SeaCommentLikeRepository::new(sea_service).await);
+
+    let fetch_comment_replies_service = FetchCommentRepliesService::new(
+        comment_repository,
+        comment_user_article_repository,
+        comment_like_repository,
+    );
+
+    Left(fetch_comment_replies_service)
+}
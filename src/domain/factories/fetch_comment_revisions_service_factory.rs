@@ -0,0 +1,22 @@
+use actix_web::HttpResponse;
+use either::Either::{self, *};
+use crate::domain::services::fetch_comment_revisions_service::FetchCommentRevisionsService;
+use crate::errors::internal_error::InternalError;
+use crate::infra::sea::repositories::sea_comment_repository::SeaCommentRepository;
+use crate::infra::sea::repositories::sea_comment_revision_repository::SeaCommentRevisionRepository;
+use crate::infra::sea::sea_service::SeaService;
+
+pub async fn exec() -> Either<FetchCommentRevisionsService<SeaCommentRepository, SeaCommentRevisionRepository>, HttpResponse> {
+    let sea_service = SeaService::new().await;
+
+    if sea_service.is_err() {
+        return Right(crate::util::generate_error_response(Box::new(InternalError::new())))
+    }
+
+    let sea_service = sea_service.unwrap();
+
+    let comment_repository: Box<SeaCommentRepository> = Box::new(SeaCommentRepository::new(sea_service.clone()).await);
+    let comment_revision_repository: Box<SeaCommentRevisionRepository> = Box::new(SeaCommentRevisionRepository::new(sea_service).await);
+
+    Left(FetchCommentRevisionsService::new(comment_repository, comment_revision_repository))
+}
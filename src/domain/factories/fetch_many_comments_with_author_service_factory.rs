@@ -2,10 +2,12 @@ use actix_web::HttpResponse;
 use either::Either::{self, *};
 use crate::domain::services::fetch_many_comments_with_author_service::FetchManyArticleCommentsWithAuthorService;
 use crate::errors::internal_error::InternalError;
+use crate::infra::sea::repositories::sea_article_repository::SeaArticleRepository;
+use crate::infra::sea::repositories::sea_comment_like_repository::SeaCommentLikeRepository;
 use crate::infra::sea::repositories::sea_comment_user_article_repository::SeaCommentUserArticleRepository;
 use crate::infra::sea::sea_service::SeaService;
 
-pub async fn exec() -> Either<FetchManyArticleCommentsWithAuthorService<SeaCommentUserArticleRepository>, HttpResponse> {
+pub async fn exec() -> Either<FetchManyArticleCommentsWithAuthorService<SeaCommentUserArticleRepository, SeaArticleRepository, SeaCommentLikeRepository>, HttpResponse> {
     let sea_service = SeaService::new().await;
 
     if sea_service.is_err() {
@@ -14,10 +16,14 @@ pub async fn exec() -> Either<FetchManyArticleCommentsWithAuthorService<SeaComme
 
     let sea_service = sea_service.unwrap();
 
-    let comment_user_article_repository: Box<SeaCommentUserArticleRepository> = Box::new(SeaCommentUserArticleRepository::new(sea_service).await);
+    let comment_user_article_repository: Box<SeaCommentUserArticleRepository> = Box::new(SeaCommentUserArticleRepository::new(sea_service.clone()).await);
+    let article_repository: Box<SeaArticleRepository> = Box::new(SeaArticleRepository::new(sea_service.clone()).await);
+    let comment_like_repository: Box<SeaCommentLikeRepository> = Box::new(SeaCommentLikeRepository::new(sea_service).await);
 
     let fetch_many_article_comments_service = FetchManyArticleCommentsWithAuthorService::new(
         comment_user_article_repository,
+        article_repository,
+        comment_like_repository,
     );
 
     Left(fetch_many_article_comments_service)
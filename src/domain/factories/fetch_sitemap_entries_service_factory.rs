@@ -0,0 +1,30 @@
+use crate::domain::services::fetch_sitemap_entries_service::FetchSitemapEntriesService;
+use actix_web::HttpResponse;
+use either::Either::{self, *};
+use crate::errors::internal_error::InternalError;
+use crate::infra::sea::repositories::sea_article_repository::SeaArticleRepository;
+use crate::infra::sea::repositories::sea_article_tag_repository::SeaArticleTagRepository;
+use crate::infra::sea::sea_service::SeaService;
+
+pub async fn exec() -> Either<FetchSitemapEntriesService<SeaArticleRepository, SeaArticleTagRepository>, HttpResponse> {
+    let sea_service = SeaService::new().await;
+
+    if sea_service.is_err() {
+        return Right(crate::util::generate_error_response(Box::new(InternalError::new())))
+    }
+
+    let sea_service = sea_service.unwrap();
+
+    let article_repository: Box<SeaArticleRepository> =
+    Box::new(SeaArticleRepository::new(sea_service.clone()).await);
+
+    let article_tag_repository: Box<SeaArticleTagRepository> =
+    Box::new(SeaArticleTagRepository::new(sea_service).await);
+
+    let fetch_sitemap_entries_service = FetchSitemapEntriesService::new(
+        article_repository,
+        article_tag_repository
+    );
+
+    Left(fetch_sitemap_entries_service)
+}
@@ -0,0 +1,22 @@
+use crate::domain::services::fetch_trending_articles_service::FetchTrendingArticlesService;
+use actix_web::HttpResponse;
+use either::Either::{self, *};
+use crate::errors::internal_error::InternalError;
+use crate::infra::sea::repositories::sea_article_view_repository::SeaArticleViewRepository;
+use crate::infra::sea::sea_service::SeaService;
+
+pub async fn exec() -> Either<FetchTrendingArticlesService<SeaArticleViewRepository>, HttpResponse> {
+    let sea_service = SeaService::new().await;
+
+    if sea_service.is_err() {
+        return Right(crate::util::generate_error_response(Box::new(InternalError::new())))
+    }
+
+    let sea_service = sea_service.unwrap();
+
+    let article_view_repository: Box<SeaArticleViewRepository> = Box::new(SeaArticleViewRepository::new(sea_service).await);
+
+    let fetch_trending_articles_service = FetchTrendingArticlesService::new(article_view_repository);
+
+    Left(fetch_trending_articles_service)
+}
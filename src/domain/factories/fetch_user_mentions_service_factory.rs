@@ -0,0 +1,20 @@
+use actix_web::HttpResponse;
+use either::Either::{self, *};
+use crate::domain::services::fetch_user_mentions_service::FetchUserMentionsService;
+use crate::errors::internal_error::InternalError;
+use crate::infra::sea::repositories::sea_mention_repository::SeaMentionRepository;
+use crate::infra::sea::sea_service::SeaService;
+
+pub async fn exec() -> Either<FetchUserMentionsService<SeaMentionRepository>, HttpResponse> {
+    let sea_service = SeaService::new().await;
+
+    if sea_service.is_err() {
+        return Right(crate::util::generate_error_response(Box::new(InternalError::new())))
+    }
+
+    let sea_service = sea_service.unwrap();
+
+    let mention_repository: Box<SeaMentionRepository> = Box::new(SeaMentionRepository::new(sea_service).await);
+
+    Left(FetchUserMentionsService::new(mention_repository))
+}
@@ -0,0 +1,27 @@
+use crate::domain::services::fetch_weekly_top_comment_service::FetchWeeklyTopCommentService;
+use actix_web::HttpResponse;
+use either::Either::{self, *};
+use crate::errors::internal_error::InternalError;
+use crate::infra::sea::repositories::sea_comment_like_repository::SeaCommentLikeRepository;
+use crate::infra::sea::repositories::sea_comment_user_article_repository::SeaCommentUserArticleRepository;
+use crate::infra::sea::sea_service::SeaService;
+
+pub async fn exec() -> Either<FetchWeeklyTopCommentService<SeaCommentUserArticleRepository, SeaCommentLikeRepository>, HttpResponse> {
+    let sea_service = SeaService::new().await;
+
+    if sea_service.is_err() {
+        return Right(crate::util::generate_error_response(Box::new(InternalError::new())))
+    }
+
+    let sea_service = sea_service.unwrap();
+
+    let comment_user_article_repository: Box<SeaCommentUserArticleRepository> =
+    Box::new(SeaCommentUserArticleRepository::new(sea_service.clone()).await);
+
+    let comment_like_repository: Box<SeaCommentLikeRepository> =
+    Box::new(SeaCommentLikeRepository::new(sea_service).await);
+
+    let fetch_weekly_top_comment_service = FetchWeeklyTopCommentService::new(comment_user_article_repository, comment_like_repository);
+
+    Left(fetch_weekly_top_comment_service)
+}
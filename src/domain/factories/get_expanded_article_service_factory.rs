@@ -3,11 +3,15 @@ use actix_web::HttpResponse;
 use either::Either::{self, *};
 use crate::errors::internal_error::InternalError;
 use crate::infra::sea::repositories::sea_article_repository::SeaArticleRepository;
+use crate::infra::sea::repositories::sea_article_reaction_repository::SeaArticleReactionRepository;
+use crate::infra::sea::repositories::sea_article_coauthor_repository::SeaArticleCoauthorRepository;
 use crate::infra::sea::repositories::sea_comment_user_article_repository::SeaCommentUserArticleRepository;
+use crate::infra::sea::repositories::sea_comment_like_repository::SeaCommentLikeRepository;
+use crate::infra::sea::repositories::sea_mention_repository::SeaMentionRepository;
 use crate::infra::sea::sea_service::SeaService;
 use crate::infra::sea::repositories::sea_user_repository::SeaUserRepository;
 
-pub async fn exec() -> Either<GetExpandedArticleService<SeaUserRepository, SeaArticleRepository, SeaCommentUserArticleRepository>, HttpResponse> {
+pub async fn exec() -> Either<GetExpandedArticleService<SeaUserRepository, SeaArticleRepository, SeaCommentUserArticleRepository, SeaArticleReactionRepository, SeaArticleCoauthorRepository, SeaMentionRepository, SeaCommentLikeRepository>, HttpResponse> {
     let sea_service = SeaService::new().await;
 
     if sea_service.is_err() {
@@ -23,12 +27,28 @@ pub async fn exec() -> Either<GetExpandedArticleService<SeaUserRepository, SeaAr
     Box::new(SeaArticleRepository::new(sea_service.clone()).await);
     
     let comment_user_article_repository: Box<SeaCommentUserArticleRepository> =
-    Box::new(SeaCommentUserArticleRepository::new(sea_service).await);
+    Box::new(SeaCommentUserArticleRepository::new(sea_service.clone()).await);
+
+    let article_reaction_repository: Box<SeaArticleReactionRepository> =
+    Box::new(SeaArticleReactionRepository::new(sea_service.clone()).await);
+
+    let article_coauthor_repository: Box<SeaArticleCoauthorRepository> =
+    Box::new(SeaArticleCoauthorRepository::new(sea_service.clone()).await);
+
+    let mention_repository: Box<SeaMentionRepository> =
+    Box::new(SeaMentionRepository::new(sea_service.clone()).await);
+
+    let comment_like_repository: Box<SeaCommentLikeRepository> =
+    Box::new(SeaCommentLikeRepository::new(sea_service).await);
     
     let get_expanded_article_service = GetExpandedArticleService::new(
         user_repository,
         article_repository,
-        comment_user_article_repository
+        comment_user_article_repository,
+        article_reaction_repository,
+        article_coauthor_repository,
+        mention_repository,
+        comment_like_repository
     );
 
     Left(get_expanded_article_service)
@@ -11,6 +11,19 @@ pub mod delete_article_service_factory;
 pub mod fetch_many_articles_service_factory;
 pub mod fetch_home_page_articles_service_factory;
 pub mod get_expanded_article_service_factory;
+pub mod register_article_view_service_factory;
+pub mod fetch_trending_articles_service_factory;
+pub mod count_articles_views_service_factory;
+pub mod react_to_article_service_factory;
+pub mod fetch_sitemap_entries_service_factory;
+pub mod fetch_articles_archive_service_factory;
+pub mod set_article_coauthors_service_factory;
+pub mod create_article_note_service_factory;
+pub mod fetch_article_notes_service_factory;
+pub mod resolve_article_note_service_factory;
+pub mod acquire_article_edit_lock_service_factory;
+pub mod refresh_article_edit_lock_service_factory;
+pub mod release_article_edit_lock_service_factory;
 
 pub mod comment_on_article_service_factory;
 pub mod delete_comment_service_factory;
@@ -32,6 +45,10 @@ pub mod update_team_user_service_factory;
 pub mod delete_team_user_service_factory;
 pub mod fetch_many_team_users_service_factory;
 pub mod fetch_many_comments_with_author_service_factory;
+pub mod fetch_comment_replies_service_factory;
+pub mod edit_comment_service_factory;
+pub mod fetch_comment_revisions_service_factory;
+pub mod fetch_user_mentions_service_factory;
 
 pub mod create_article_tag_service_factory;
 pub mod update_article_tag_service_factory;
@@ -42,3 +59,11 @@ pub mod create_free_badge_service_factory;
 pub mod update_free_badge_service_factory;
 pub mod fetch_many_free_badges_service_factory;
 pub mod delete_free_badge_service_factory;
+pub mod create_word_filter_service_factory;
+pub mod update_word_filter_service_factory;
+pub mod fetch_many_word_filters_service_factory;
+pub mod delete_word_filter_service_factory;
+pub mod lock_article_comments_service_factory;
+pub mod pin_comment_service_factory;
+pub mod like_comment_service_factory;
+pub mod fetch_weekly_top_comment_service_factory;
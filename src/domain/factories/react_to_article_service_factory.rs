@@ -0,0 +1,30 @@
+use crate::domain::services::react_to_article_service::ReactToArticleService;
+use actix_web::HttpResponse;
+use either::Either::{self, *};
+use crate::errors::internal_error::InternalError;
+use crate::infra::sea::repositories::sea_article_reaction_repository::SeaArticleReactionRepository;
+use crate::infra::sea::repositories::sea_article_repository::SeaArticleRepository;
+use crate::infra::sea::sea_service::SeaService;
+
+pub async fn exec() -> Either<ReactToArticleService<SeaArticleRepository, SeaArticleReactionRepository>, HttpResponse> {
+    let sea_service = SeaService::new().await;
+
+    if sea_service.is_err() {
+        return Right(crate::util::generate_error_response(Box::new(InternalError::new())))
+    }
+
+    let sea_service = sea_service.unwrap();
+
+    let article_repository: Box<SeaArticleRepository> =
+    Box::new(SeaArticleRepository::new(sea_service.clone()).await);
+
+    let article_reaction_repository: Box<SeaArticleReactionRepository> =
+    Box::new(SeaArticleReactionRepository::new(sea_service).await);
+
+    let react_to_article_service = ReactToArticleService::new(
+        article_repository,
+        article_reaction_repository
+    );
+
+    Left(react_to_article_service)
+}
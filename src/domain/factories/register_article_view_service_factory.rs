@@ -0,0 +1,27 @@
+use crate::domain::services::register_article_view_service::RegisterArticleViewService;
+use actix_web::HttpResponse;
+use either::Either::{self, *};
+use crate::errors::internal_error::InternalError;
+use crate::infra::sea::repositories::sea_article_view_repository::SeaArticleViewRepository;
+use crate::infra::sea::sea_service::SeaService;
+use crate::ENV_VARS;
+
+pub async fn exec() -> Either<RegisterArticleViewService<SeaArticleViewRepository>, HttpResponse> {
+    let sea_service = SeaService::new().await;
+
+    if sea_service.is_err() {
+        return Right(crate::util::generate_error_response(Box::new(InternalError::new())))
+    }
+
+    let sea_service = sea_service.unwrap();
+
+    let article_view_repository: Box<SeaArticleViewRepository> = Box::new(SeaArticleViewRepository::new(sea_service).await);
+
+    let viewer_key_secret = ENV_VARS.article_view_secret.clone()
+        .filter(|secret| !secret.is_empty())
+        .unwrap_or_else(|| ENV_VARS.jwt_secret.clone());
+
+    let register_article_view_service = RegisterArticleViewService::new(article_view_repository, viewer_key_secret);
+
+    Left(register_article_view_service)
+}
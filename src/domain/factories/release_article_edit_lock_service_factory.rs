@@ -0,0 +1,21 @@
+use crate::domain::services::release_article_edit_lock_service::ReleaseArticleEditLockService;
+use actix_web::HttpResponse;
+use either::Either::{self, *};
+use crate::errors::internal_error::InternalError;
+use crate::infra::sea::repositories::sea_article_edit_lock_repository::SeaArticleEditLockRepository;
+use crate::infra::sea::sea_service::SeaService;
+
+pub async fn exec() -> Either<ReleaseArticleEditLockService<SeaArticleEditLockRepository>, HttpResponse> {
+    let sea_service = SeaService::new().await;
+
+    if sea_service.is_err() {
+        return Right(crate::util::generate_error_response(Box::new(InternalError::new())))
+    }
+
+    let article_edit_lock_repository: Box<SeaArticleEditLockRepository> =
+    Box::new(SeaArticleEditLockRepository::new(sea_service.unwrap()).await);
+
+    let release_article_edit_lock_service = ReleaseArticleEditLockService::new(article_edit_lock_repository);
+
+    Left(release_article_edit_lock_service)
+}
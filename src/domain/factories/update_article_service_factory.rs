@@ -4,9 +4,14 @@ use either::Either::{self, *};
 use crate::errors::internal_error::InternalError;
 use crate::infra::sea::repositories::sea_article_repository::SeaArticleRepository;
 use crate::infra::sea::repositories::sea_article_tag_repository::SeaArticleTagRepository;
+use crate::infra::sea::repositories::sea_article_coauthor_repository::SeaArticleCoauthorRepository;
+use crate::infra::sea::repositories::sea_article_note_repository::SeaArticleNoteRepository;
+use crate::infra::sea::repositories::sea_article_edit_lock_repository::SeaArticleEditLockRepository;
+use crate::infra::sea::repositories::sea_mention_repository::SeaMentionRepository;
+use crate::infra::sea::repositories::sea_user_repository::SeaUserRepository;
 use crate::infra::sea::sea_service::SeaService;
 
-pub async fn exec() -> Either<UpdateArticleService<SeaArticleRepository, SeaArticleTagRepository>, HttpResponse> {
+pub async fn exec() -> Either<UpdateArticleService<SeaArticleRepository, SeaArticleTagRepository, SeaArticleCoauthorRepository, SeaArticleNoteRepository, SeaArticleEditLockRepository, SeaUserRepository, SeaMentionRepository>, HttpResponse> {
     let sea_service = SeaService::new().await;
 
     if sea_service.is_err() {
@@ -16,11 +21,21 @@ pub async fn exec() -> Either<UpdateArticleService<SeaArticleRepository, SeaArti
     let sea_service = sea_service.unwrap();
     
     let article_repository = Box::new(SeaArticleRepository::new(sea_service.clone()).await);
-    let article_tag_repository = Box::new(SeaArticleTagRepository::new(sea_service).await);
+    let article_tag_repository = Box::new(SeaArticleTagRepository::new(sea_service.clone()).await);
+    let article_coauthor_repository = Box::new(SeaArticleCoauthorRepository::new(sea_service.clone()).await);
+    let article_note_repository = Box::new(SeaArticleNoteRepository::new(sea_service.clone()).await);
+    let article_edit_lock_repository = Box::new(SeaArticleEditLockRepository::new(sea_service.clone()).await);
+    let user_repository = Box::new(SeaUserRepository::new(sea_service.clone()).await);
+    let mention_repository = Box::new(SeaMentionRepository::new(sea_service).await);
     
     let update_article_service = UpdateArticleService::new(
         article_repository,
-        article_tag_repository
+        article_tag_repository,
+        article_coauthor_repository,
+        article_note_repository,
+        article_edit_lock_repository,
+        user_repository,
+        mention_repository
     );
 
     Left(update_article_service)
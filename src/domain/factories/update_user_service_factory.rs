@@ -4,9 +4,10 @@ use crate::errors::internal_error::InternalError;
 use crate::infra::cryptography::PasswordAuthHasherAndVerifier;
 use crate::infra::sea::sea_service::SeaService;
 use crate::infra::sea::repositories::sea_user_repository::SeaUserRepository;
+use crate::infra::sea::repositories::sea_word_filter_repository::SeaWordFilterRepository;
 use crate::domain::services::update_user_service::UpdateUserService;
 
-pub async fn exec() -> Either<UpdateUserService<SeaUserRepository>, HttpResponse> {
+pub async fn exec() -> Either<UpdateUserService<SeaUserRepository, SeaWordFilterRepository>, HttpResponse> {
     let sea_service = SeaService::new().await;
 
     if sea_service.is_err() {
@@ -15,11 +16,12 @@ pub async fn exec() -> Either<UpdateUserService<SeaUserRepository>, HttpResponse
 
     let sea_service = sea_service.unwrap();
 
-    let user_repository: Box<SeaUserRepository> = Box::new(SeaUserRepository::new(sea_service).await);
+    let user_repository: Box<SeaUserRepository> = Box::new(SeaUserRepository::new(sea_service.clone()).await);
+    let word_filter_repository: Box<SeaWordFilterRepository> = Box::new(SeaWordFilterRepository::new(sea_service).await);
 
     let hasher = Box::new(PasswordAuthHasherAndVerifier {});
     
-    let update_user_service = UpdateUserService::new(user_repository, hasher);
+    let update_user_service = UpdateUserService::new(user_repository, word_filter_repository, hasher);
 
     Left(update_user_service)
 }
\ No newline at end of file
@@ -0,0 +1,22 @@
+use actix_web::HttpResponse;
+use either::Either::{self, *};
+use crate::domain::services::update_word_filter_service::UpdateWordFilterService;
+use crate::errors::internal_error::InternalError;
+use crate::infra::sea::repositories::sea_word_filter_repository::SeaWordFilterRepository;
+use crate::infra::sea::sea_service::SeaService;
+
+pub async fn exec() -> Either<UpdateWordFilterService<SeaWordFilterRepository>, HttpResponse> {
+    let sea_service = SeaService::new().await;
+
+    if sea_service.is_err() {
+        return Right(crate::util::generate_error_response(Box::new(InternalError::new())))
+    }
+
+    let sea_service = sea_service.unwrap();
+
+    let word_filter_repository: Box<SeaWordFilterRepository> = Box::new(SeaWordFilterRepository::new(sea_service).await);
+
+    let update_word_filter_service = UpdateWordFilterService::new(word_filter_repository);
+
+    Left(update_word_filter_service)
+}
@@ -1,15 +1,119 @@
-use chrono::NaiveDateTime;
+use chrono::{NaiveDate, NaiveDateTime};
 use crate::domain::domain_entities::article::Article;
+use crate::domain::domain_entities::article_content::ArticleContent;
+use crate::domain::domain_entities::article_edit_lock::ArticleEditLock;
+use crate::domain::domain_entities::article_view::{ArticleDailyViews, ArticleView};
+use crate::domain::domain_entities::role::Role;
+use crate::util::{verify_role_has_permission, verify_role_hierarchy_matches, RolePermissions};
+use uuid::Uuid;
 
 pub struct ArticlePolitics;
 
 impl ArticlePolitics {
     pub fn article_is_recent(article: Article, now: NaiveDateTime) -> bool {
-        let hours_til_no_longer_recent = 48;
+        let hours_til_no_longer_recent = Self::recent_window_hours();
 
         let difference_between_created_datetime_and_no = now.signed_duration_since(article.created_at());
         let hours_difference = difference_between_created_datetime_and_no.num_hours();
 
         return hours_difference <= hours_til_no_longer_recent;
     } 
-}
\ No newline at end of file
+
+    pub fn recent_window_hours() -> i64 {
+        48
+    }
+
+    /// A viewer reading the same article again within this window is not counted twice.
+    pub fn view_is_repeated(last_view: &ArticleView, now: NaiveDateTime) -> bool {
+        let minutes_til_view_counts_again = 30;
+
+        let minutes_since_last_view = now.signed_duration_since(last_view.viewed_at()).num_minutes();
+
+        return minutes_since_last_view < minutes_til_view_counts_again;
+    }
+
+    pub fn trending_window_days() -> i64 {
+        7
+    }
+
+    /**
+     Sums the article's daily views, halving the weight of each day's views every
+     `half_life_in_days`, so that recent readings rank an article higher than older ones.
+     */
+    pub fn trending_score(daily_views: &[ArticleDailyViews], today: NaiveDate) -> f64 {
+        let half_life_in_days = 1.5;
+
+        daily_views.iter().fold(0.0, |score, bucket| {
+            let age_in_days = today.signed_duration_since(bucket.day()).num_days().max(0) as f64;
+            let decay = 0.5_f64.powf(age_in_days / half_life_in_days);
+
+            score + bucket.views() as f64 * decay
+        })
+    }
+
+    /// Plain text summary of the article's content, as shown on listings and feeds.
+    pub fn excerpt(article: &Article) -> String {
+        let max_chars_in_excerpt = 280;
+
+        ArticleContent::from_article(article).excerpt(max_chars_in_excerpt)
+    }
+
+    pub fn word_count(article: &Article) -> u32 {
+        ArticleContent::from_article(article).plain_text().split_whitespace().count() as u32
+    }
+
+    /// Minutes an average reader takes to read that many words, never less than a minute.
+    pub fn reading_time(word_count: u32) -> u32 {
+        let words_per_minute = 200;
+
+        word_count.div_ceil(words_per_minute).max(1)
+    }
+
+    /// Computes the article's reading metadata from its current content. Must be called before persisting it.
+    pub fn refresh_reading_metadata(article: &mut Article) {
+        let word_count = Self::word_count(article);
+
+        article.set_reading_metadata(word_count, Self::reading_time(word_count));
+    }
+
+    pub fn max_coauthors_per_article() -> usize {
+        5
+    }
+
+    /// Editorial notes are internal: only the article's authors and those reviewing unapproved articles may see them.
+    pub fn user_can_access_article_notes(article: &Article, user_id: Uuid, user_role: &Role, user_is_coauthor: bool) -> bool {
+        if *user_role == Role::User { return false; }
+
+        article.author_id() == user_id
+        || user_is_coauthor
+        || verify_role_has_permission(user_role, RolePermissions::SeeUnapprovedArticle)
+    }
+
+    /// Staff members with the permission edit any article, and authors and co-authors their own ones while still in the team.
+    pub fn user_can_edit_article(article: &Article, user_id: Uuid, user_role: &Role, user_is_coauthor: bool) -> bool {
+        if *user_role == Role::User { return false; }
+
+        article.author_id() == user_id
+        || user_is_coauthor
+        || verify_role_has_permission(user_role, RolePermissions::UpdateArticle)
+    }
+
+    /// Edit locks not kept alive by their holder expire after this window.
+    pub fn edit_lock_expiration(now: NaiveDateTime) -> NaiveDateTime {
+        let minutes_til_lock_expires = 5;
+
+        now + chrono::Duration::try_minutes(minutes_til_lock_expires).unwrap()
+    }
+
+    /// A lock only stops others than its holder from editing, and only while not expired.
+    pub fn edit_lock_blocks_user(lock: &ArticleEditLock, user_id: Uuid, now: NaiveDateTime) -> bool {
+        !lock.is_expired(now) && lock.user_id() != user_id
+    }
+
+    /// Someone else's lock can only be taken over by those above its holder on the role hierarchy.
+    pub fn user_can_force_edit_lock(lock: &ArticleEditLock, user_role: &Role) -> bool {
+        let holder_role = lock.holder_role().unwrap_or(Role::User);
+
+        verify_role_hierarchy_matches(&holder_role, user_role)
+    }
+}
@@ -0,0 +1,33 @@
+use chrono::NaiveDateTime;
+
+use crate::domain::domain_entities::comment::Comment;
+
+pub struct CommentPolitics;
+
+impl CommentPolitics {
+    /// How deep a reply chain may go, top-level comments being at depth 0.
+    pub fn max_reply_depth() -> u8 {
+        3
+    }
+
+    /// How many comments can be pinned to the top of an article at once.
+    pub fn max_pinned_comments() -> usize {
+        3
+    }
+
+    /// How far back the likes count for the home page's most liked comment.
+    pub fn top_comment_window_days() -> i64 {
+        7
+    }
+
+    /// Used when `COMMENT_EDIT_WINDOW_MINUTES` isn't set.
+    pub fn default_edit_window_minutes() -> i64 {
+        15
+    }
+
+    /// Authors can only edit a comment shortly after posting it, so that it can't be changed
+    /// after others already read or replied to it.
+    pub fn edit_window_is_over(comment: &Comment, now: NaiveDateTime, edit_window_minutes: i64) -> bool {
+        now.signed_duration_since(comment.created_at()).num_minutes() >= edit_window_minutes
+    }
+}
@@ -0,0 +1,309 @@
+use std::collections::HashSet;
+
+use chrono::{Duration, NaiveDateTime};
+use once_cell::sync::Lazy;
+use regex::Regex;
+
+use crate::domain::domain_entities::comment::Comment;
+use crate::domain::domain_entities::user::User;
+use crate::errors::bad_request_error::BadRequestError;
+use crate::errors::error::DomainErrorTrait;
+use crate::errors::too_many_requests_error::TooManyRequestsError;
+
+/// Captures the host of `http(s)://` and `www.` links, including the ones inside Markdown links.
+static LINK_REGX: Lazy<Regex> = Lazy::new(|| {
+    Regex::new(r"(?i)(?:https?://|\bwww\.)([a-z0-9\-\.]+)").unwrap()
+});
+
+/// Limits of the default spam check chain, see `CommentSpamFilter::new`.
+#[derive(Clone, Debug)]
+pub struct CommentSpamSettings {
+    /// How many comments an author may post within `rate_limit_window_seconds`.
+    pub rate_limit_count: usize,
+    pub rate_limit_window_seconds: i64,
+    /// How far back the author's comments are compared with the new one.
+    pub duplicate_window_minutes: i64,
+    pub max_links: usize,
+    /// Accounts younger than this can't comment at all.
+    pub new_account_cooldown_minutes: i64,
+    /// Comments with links from accounts younger than this wait for a moderator.
+    pub new_account_review_hours: i64,
+    /// Lowercase, comments containing them wait for a moderator.
+    pub blocked_words: Vec<String>,
+    /// Lowercase and without `www.`, comments linking to them or their subdomains are rejected.
+    pub blocked_domains: Vec<String>,
+}
+
+impl Default for CommentSpamSettings {
+    fn default() -> Self {
+        CommentSpamSettings {
+            rate_limit_count: 5,
+            rate_limit_window_seconds: 60,
+            duplicate_window_minutes: 60,
+            max_links: 2,
+            new_account_cooldown_minutes: 10,
+            new_account_review_hours: 24,
+            blocked_words: vec![],
+            blocked_domains: vec![],
+        }
+    }
+}
+
+impl CommentSpamSettings {
+    /// Default limits with the blocklists given as comma separated lists, as in `COMMENT_BLOCKED_WORDS`
+    /// and `COMMENT_BLOCKED_DOMAINS`.
+    pub fn with_blocklists(blocked_words: Option<&str>, blocked_domains: Option<&str>) -> Self {
+        let split = |list: Option<&str>| -> Vec<String> {
+            list.unwrap_or_default()
+                .split(',')
+                .map(|item| item.trim().trim_start_matches("www.").to_lowercase())
+                .filter(|item| !item.is_empty())
+                .collect()
+        };
+
+        CommentSpamSettings {
+            blocked_words: split(blocked_words),
+            blocked_domains: split(blocked_domains),
+            ..Default::default()
+        }
+    }
+}
+
+/// What the checks know about the comment being posted.
+pub struct CommentSpamContext<'a> {
+    pub author: &'a User,
+    pub content: &'a str,
+    /// The author's comments posted within `CommentSpamFilter::history_window`, active or not.
+    pub recent_comments: &'a [Comment],
+    pub now: NaiveDateTime,
+}
+
+pub enum SpamVerdict {
+    Pass,
+    /// The comment is created inactive, waiting for a moderator. Holds the reason.
+    Review(String),
+    Reject(Box<dyn DomainErrorTrait>),
+}
+
+pub trait CommentSpamCheckTrait: Send + Sync {
+    fn check(&self, context: &CommentSpamContext) -> SpamVerdict;
+}
+
+pub struct RateLimitCheck {
+    max_comments: usize,
+    window: Duration,
+}
+
+impl CommentSpamCheckTrait for RateLimitCheck {
+    fn check(&self, context: &CommentSpamContext) -> SpamVerdict {
+        let since = context.now - self.window;
+        let count = context.recent_comments.iter().filter(|comment| comment.created_at() > since).count();
+
+        if count >= self.max_comments {
+            return SpamVerdict::Reject(Box::new(TooManyRequestsError::new_with_message(format!(
+                "You can't post more than {} comments every {} seconds, try again in a moment.",
+                self.max_comments,
+                self.window.num_seconds(),
+            ))));
+        }
+
+        SpamVerdict::Pass
+    }
+}
+
+pub struct NewAccountCooldownCheck {
+    cooldown: Duration,
+}
+
+impl CommentSpamCheckTrait for NewAccountCooldownCheck {
+    fn check(&self, context: &CommentSpamContext) -> SpamVerdict {
+        if context.now - context.author.created_at() < self.cooldown {
+            return SpamVerdict::Reject(Box::new(BadRequestError::new_with_message(format!(
+                "New accounts have to wait {} minutes before commenting.",
+                self.cooldown.num_minutes(),
+            ))));
+        }
+
+        SpamVerdict::Pass
+    }
+}
+
+pub struct DuplicateContentCheck {
+    window: Duration,
+}
+
+impl DuplicateContentCheck {
+    /// Case and whitespace differences don't make a comment different.
+    fn normalize(content: &str) -> String {
+        content.split_whitespace().collect::<Vec<&str>>().join(" ").to_lowercase()
+    }
+}
+
+impl CommentSpamCheckTrait for DuplicateContentCheck {
+    fn check(&self, context: &CommentSpamContext) -> SpamVerdict {
+        let since = context.now - self.window;
+        let content = Self::normalize(context.content);
+
+        let is_duplicate = context.recent_comments.iter()
+            .any(|comment| comment.created_at() > since && Self::normalize(comment.content()) == content);
+
+        if is_duplicate {
+            return SpamVerdict::Reject(Box::new(BadRequestError::new_with_message(
+                "You have already posted this comment recently.".into()
+            )));
+        }
+
+        SpamVerdict::Pass
+    }
+}
+
+pub struct LinkLimitCheck {
+    max_links: usize,
+    new_account_review: Duration,
+}
+
+impl CommentSpamCheckTrait for LinkLimitCheck {
+    fn check(&self, context: &CommentSpamContext) -> SpamVerdict {
+        let links = LINK_REGX.find_iter(context.content).count();
+
+        if links > self.max_links {
+            return SpamVerdict::Reject(Box::new(BadRequestError::new_with_message(format!(
+                "Comments can't have more than {} links.",
+                self.max_links,
+            ))));
+        }
+
+        if links > 0 && context.now - context.author.created_at() < self.new_account_review {
+            return SpamVerdict::Review("Links posted by a recently created account.".into());
+        }
+
+        SpamVerdict::Pass
+    }
+}
+
+/// Domains are matched along with their subdomains. Words are matched whole and, since they
+/// may still be used innocently, only send the comment to review.
+pub struct BlocklistCheck {
+    words: HashSet<String>,
+    domains: Vec<String>,
+}
+
+impl CommentSpamCheckTrait for BlocklistCheck {
+    fn check(&self, context: &CommentSpamContext) -> SpamVerdict {
+        let blocked_domain = LINK_REGX.captures_iter(context.content)
+            .filter_map(|captures| captures.get(1))
+            .map(|host| host.as_str().trim_end_matches('.').to_lowercase())
+            .find(|host| self.domains.iter().any(|domain| {
+                host == domain || host.ends_with(&format!(".{domain}"))
+            }));
+
+        if let Some(host) = blocked_domain {
+            return SpamVerdict::Reject(Box::new(BadRequestError::new_with_message(format!(
+                "Links to '{host}' aren't allowed."
+            ))));
+        }
+
+        let blocked_word = context.content
+            .split(|c: char| !c.is_alphanumeric())
+            .map(|word| word.to_lowercase())
+            .find(|word| self.words.contains(word));
+
+        if let Some(word) = blocked_word {
+            return SpamVerdict::Review(format!("Contains the blocked word '{word}'."));
+        }
+
+        SpamVerdict::Pass
+    }
+}
+
+/// # Comment Spam Filter
+/// A chain of checks run before a comment is created or edited. Checks run in order: the first rejection
+/// stops the chain, while review reasons are all collected.
+pub struct CommentSpamFilter {
+    checks: Vec<Box<dyn CommentSpamCheckTrait>>,
+    history_window: Duration,
+}
+
+impl CommentSpamFilter {
+    /// The default chain: rate limit, new account cooldown, duplicates, links and blocklists.
+    pub fn new(settings: &CommentSpamSettings) -> Self {
+        let rate_limit_window = Duration::seconds(settings.rate_limit_window_seconds);
+        let duplicate_window = Duration::minutes(settings.duplicate_window_minutes);
+
+        let mut checks: Vec<Box<dyn CommentSpamCheckTrait>> = vec![
+            Box::new(RateLimitCheck {
+                max_comments: settings.rate_limit_count,
+                window: rate_limit_window,
+            }),
+            Box::new(NewAccountCooldownCheck {
+                cooldown: Duration::minutes(settings.new_account_cooldown_minutes),
+            }),
+            Box::new(DuplicateContentCheck {
+                window: duplicate_window,
+            }),
+        ];
+
+        checks.extend(Self::content_checks(settings).checks);
+
+        CommentSpamFilter {
+            checks,
+            history_window: rate_limit_window.max(duplicate_window),
+        }
+    }
+
+    /// Only the checks on the content itself, links and blocklists, for comments being edited: the
+    /// posting limits were enforced when they were created, and they need none of the author's history.
+    pub fn content_checks(settings: &CommentSpamSettings) -> Self {
+        CommentSpamFilter {
+            checks: vec![
+                Box::new(LinkLimitCheck {
+                    max_links: settings.max_links,
+                    new_account_review: Duration::hours(settings.new_account_review_hours),
+                }),
+                Box::new(BlocklistCheck {
+                    words: settings.blocked_words.iter().cloned().collect(),
+                    domains: settings.blocked_domains.clone(),
+                }),
+            ],
+            history_window: Duration::zero(),
+        }
+    }
+
+    pub fn without_checks() -> Self {
+        CommentSpamFilter {
+            checks: vec![],
+            history_window: Duration::zero(),
+        }
+    }
+
+    /// Appends a check to the chain. `history_window` is widened so the check gets the comments it needs.
+    pub fn with_check(mut self, check: Box<dyn CommentSpamCheckTrait>, history_window: Duration) -> Self {
+        self.checks.push(check);
+        self.history_window = self.history_window.max(history_window);
+        self
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.checks.is_empty()
+    }
+
+    /// How far back the author's comments have to be loaded for the checks.
+    pub fn history_window(&self) -> Duration {
+        self.history_window
+    }
+
+    /// Returns the reasons for the comment to be reviewed, empty if it can be published right away.
+    pub fn run(&self, context: &CommentSpamContext) -> Result<Vec<String>, Box<dyn DomainErrorTrait>> {
+        let mut review_reasons = vec![];
+
+        for check in &self.checks {
+            match check.check(context) {
+                SpamVerdict::Pass => (),
+                SpamVerdict::Review(reason) => review_reasons.push(reason),
+                SpamVerdict::Reject(error) => return Err(error),
+            }
+        }
+
+        Ok(review_reasons)
+    }
+}
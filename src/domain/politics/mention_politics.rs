@@ -0,0 +1,70 @@
+use std::collections::{HashMap, HashSet};
+
+use log::error;
+use uuid::Uuid;
+
+use crate::domain::domain_entities::mention::{Mention, MentionCandidate, MentionSource, MAX_MENTIONS_PER_CONTENT};
+use crate::domain::repositories::mention_repository::MentionRepositoryTrait;
+use crate::domain::repositories::user_repository::UserRepositoryTrait;
+use crate::{LOG_SEP, R_EOL};
+
+pub struct MentionPolitics;
+
+impl MentionPolitics {
+    /**
+     Resolves the `@nickname`s of a freshly written or edited content and replaces the mentions stored
+     for it. Nicknames nobody has are ignored, as are users mentioning themselves.
+
+     Mentions are secondary to the content being saved, so failures are only logged.
+     */
+    pub async fn sync_mentions<UserRepository, MentionRepository>(
+        user_repository: &UserRepository,
+        mention_repository: &MentionRepository,
+        source: MentionSource,
+        mentioned_by: Uuid,
+        content: &str,
+    )
+    where
+        UserRepository: UserRepositoryTrait,
+        MentionRepository: MentionRepositoryTrait,
+    {
+        let mut resolved_nicknames: HashMap<String, Option<Uuid>> = HashMap::new();
+        let mut mentioned_users: HashSet<Uuid> = HashSet::new();
+        let mut mentions: Vec<Mention> = vec![];
+
+        for candidate in MentionCandidate::parse(content).into_iter().take(MAX_MENTIONS_PER_CONTENT) {
+            for nickname in candidate.nicknames() {
+                let key = nickname.to_lowercase();
+
+                if !resolved_nicknames.contains_key(&key) {
+                    let user = match user_repository.find_by_nickname(&nickname.to_string()).await {
+                        Ok(user) => user,
+                        Err(err) => {
+                            error!("{R_EOL}{LOG_SEP}{R_EOL}Error occurred while resolving the mentioned nickname '{nickname}':{R_EOL}{:#?}{R_EOL}{LOG_SEP}{R_EOL}", err);
+                            return;
+                        }
+                    };
+
+                    // the lookup is a case-insensitive LIKE, so the nickname is compared again
+                    let user_id = user
+                        .filter(|user| user.nickname().to_lowercase() == key)
+                        .map(|user| user.id());
+
+                    resolved_nicknames.insert(key.clone(), user_id);
+                }
+
+                if let Some(Some(user_id)) = resolved_nicknames.get(&key) {
+                    if *user_id != mentioned_by && mentioned_users.insert(*user_id) {
+                        mentions.push(Mention::new(*user_id, mentioned_by, source));
+                    }
+
+                    break;
+                }
+            }
+        }
+
+        if let Err(err) = mention_repository.replace_many(source, mentions).await {
+            error!("{R_EOL}{LOG_SEP}{R_EOL}Error occurred while saving the mentions of {:?}:{R_EOL}{:#?}{R_EOL}{LOG_SEP}{R_EOL}", source, err);
+        }
+    }
+}
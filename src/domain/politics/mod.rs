@@ -1 +1,5 @@
-pub mod article_politics;
\ No newline at end of file
+pub mod article_politics;
+pub mod comment_politics;
+pub mod comment_spam_politics;
+pub mod mention_politics;
+pub mod word_filter_politics;
\ No newline at end of file
@@ -0,0 +1,87 @@
+use log::error;
+
+use crate::domain::domain_entities::word_filter::{WordFilter, WordFilterAction};
+use crate::errors::bad_request_error::BadRequestError;
+use crate::{LOG_SEP, R_EOL};
+
+/// A content after the word filters ran on it.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct FilteredContent {
+    pub content: String,
+    /// Whether a `Deactivate` filter matched.
+    pub deactivate: bool,
+}
+
+pub struct WordFilterPolitics;
+
+impl WordFilterPolitics {
+    /**
+     Runs the filters on a comment or report message. Any `Reject` match refuses the whole content,
+     `Mask` matches are replaced with asterisks and `Deactivate` matches are left for the caller,
+     since only comments can be deactivated.
+
+     Filters whose pattern doesn't compile anymore are skipped.
+     */
+    pub fn apply(filters: &[WordFilter], content: &str) -> Result<FilteredContent, BadRequestError> {
+        let folded = WordFilter::fold(content);
+        let mut masked: Vec<bool> = vec![false; content.chars().count()];
+        let mut deactivate = false;
+
+        for filter in filters {
+            let Some(regex) = Self::compile(filter) else { continue; };
+
+            let mut matches = regex.find_iter(&folded).filter(|found| !found.is_empty()).peekable();
+
+            if matches.peek().is_none() { continue; }
+
+            match filter.action() {
+                WordFilterAction::Reject => return Err(Self::rejection()),
+                WordFilterAction::Deactivate => deactivate = true,
+                WordFilterAction::Mask => {
+                    for found in matches {
+                        let start = folded[..found.start()].chars().count();
+                        let length = found.as_str().chars().count();
+
+                        masked[start..start + length].iter_mut().for_each(|char_is_masked| *char_is_masked = true);
+                    }
+                }
+            }
+        }
+
+        let content = content.chars()
+            .zip(masked)
+            .map(|(c, is_masked)| if is_masked && !c.is_whitespace() { '*' } else { c })
+            .collect();
+
+        Ok(FilteredContent { content, deactivate })
+    }
+
+    /// Nicknames can't be masked nor hidden, so they are refused on any match.
+    pub fn verify_nickname(filters: &[WordFilter], nickname: &str) -> Result<(), BadRequestError> {
+        let folded = WordFilter::fold(nickname);
+
+        let matches = filters.iter()
+            .filter_map(Self::compile)
+            .any(|regex| regex.find_iter(&folded).any(|found| !found.is_empty()));
+
+        if matches {
+            return Err(BadRequestError::new_with_message("This nickname isn't allowed.".into()));
+        }
+
+        Ok(())
+    }
+
+    fn compile(filter: &WordFilter) -> Option<regex::Regex> {
+        match filter.compile() {
+            Ok(regex) => Some(regex),
+            Err(err) => {
+                error!("{R_EOL}{LOG_SEP}{R_EOL}Word filter '{}' has an invalid pattern and was skipped:{R_EOL}{:#?}{R_EOL}{LOG_SEP}{R_EOL}", filter.id(), err);
+                None
+            }
+        }
+    }
+
+    fn rejection() -> BadRequestError {
+        BadRequestError::new_with_message("The content has words that aren't allowed.".into())
+    }
+}
@@ -0,0 +1,18 @@
+use async_trait::async_trait;
+use uuid::Uuid;
+use std::error::Error;
+
+use crate::domain::domain_entities::article_coauthor::ArticleCoauthor;
+
+#[cfg(test)]
+use mockall::automock;
+
+#[cfg_attr(test, automock)]
+#[async_trait]
+pub trait ArticleCoauthorRepositoryTrait {
+    /// Ordered by position.
+    async fn find_by_article(&self, article_id: Uuid) -> Result<Vec<ArticleCoauthor>, Box<dyn Error>>;
+    async fn find_by_user(&self, article_id: Uuid, user_id: Uuid) -> Result<Option<ArticleCoauthor>, Box<dyn Error>>;
+    /// Replaces all the co-authors of the article at once.
+    async fn replace(&self, article_id: Uuid, coauthors: Vec<ArticleCoauthor>) -> Result<Vec<ArticleCoauthor>, Box<dyn Error>>;
+}
@@ -0,0 +1,18 @@
+use async_trait::async_trait;
+use uuid::Uuid;
+use std::error::Error;
+
+use crate::domain::domain_entities::article_edit_lock::ArticleEditLock;
+
+#[cfg(test)]
+use mockall::automock;
+
+#[cfg_attr(test, automock)]
+#[async_trait]
+pub trait ArticleEditLockRepositoryTrait {
+    /// Finds the article's lock along with its holder's nickname and role, even if it is expired.
+    async fn find_by_article(&self, article_id: Uuid) -> Result<Option<ArticleEditLock>, Box<dyn Error>>;
+    /// Creates the article's lock, or replaces the existing one.
+    async fn save(&self, lock: ArticleEditLock) -> Result<ArticleEditLock, Box<dyn Error>>;
+    async fn delete(&self, article_id: Uuid) -> Result<(), Box<dyn Error>>;
+}
@@ -0,0 +1,18 @@
+use async_trait::async_trait;
+use uuid::Uuid;
+use std::error::Error;
+
+use crate::domain::domain_entities::article_note::ArticleNote;
+
+#[cfg(test)]
+use mockall::automock;
+
+#[cfg_attr(test, automock)]
+#[async_trait]
+pub trait ArticleNoteRepositoryTrait {
+    async fn create(&self, note: ArticleNote) -> Result<ArticleNote, Box<dyn Error>>;
+    async fn save(&self, note: ArticleNote) -> Result<ArticleNote, Box<dyn Error>>;
+    async fn find_by_id(&self, id: Uuid) -> Result<Option<ArticleNote>, Box<dyn Error>>;
+    /// Every note on the article, replies included, from the oldest to the newest.
+    async fn find_many_by_article(&self, article_id: Uuid) -> Result<Vec<ArticleNote>, Box<dyn Error>>;
+}
@@ -0,0 +1,20 @@
+use async_trait::async_trait;
+use uuid::Uuid;
+use std::collections::HashMap;
+use std::error::Error;
+
+use crate::domain::domain_entities::article_reaction::{ArticleReaction, ReactionKind};
+
+#[cfg(test)]
+use mockall::automock;
+
+#[cfg_attr(test, automock)]
+#[async_trait]
+pub trait ArticleReactionRepositoryTrait {
+    /// Replaces the user's reaction if another one was created in the meantime.
+    async fn create(&self, reaction: ArticleReaction) -> Result<ArticleReaction, Box<dyn Error>>;
+    async fn save(&self, reaction: ArticleReaction) -> Result<ArticleReaction, Box<dyn Error>>;
+    async fn delete(&self, reaction: ArticleReaction) -> Result<(), Box<dyn Error>>;
+    async fn find_by_user(&self, article_id: Uuid, user_id: Uuid) -> Result<Option<ArticleReaction>, Box<dyn Error>>;
+    async fn count_by_article(&self, article_id: Uuid) -> Result<HashMap<ReactionKind, u64>, Box<dyn Error>>;
+}
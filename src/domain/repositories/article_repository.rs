@@ -3,7 +3,9 @@ use uuid::Uuid;
 use std::error::Error;
 
 use crate::domain::domain_entities::article::Article;
-use crate::core::pagination::PaginationParameters;
+use crate::domain::domain_entities::listed_article::ListedArticle;
+use crate::core::pagination::{CursorPaginationParameters, PaginationParameters};
+use chrono::NaiveDateTime as DateTime;
 use crate::domain::domain_entities::slug::Slug;
 
 #[cfg(test)]
@@ -11,15 +13,46 @@ use mockall::automock;
 
 #[derive(Debug)]
 pub struct FindManyArticlesResponse (
-    pub Vec<Article>,
+    pub Vec<ListedArticle>,
     pub u64,
 );
 
+#[derive(Debug)]
+pub struct FindManyArticlesByCursorResponse (
+    pub Vec<ListedArticle>, // data, always ordered from the newest to the oldest
+    pub bool, // whether there are more items past the page, in the cursor direction
+);
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ApprovedArticleSlug (
+    pub Slug,
+    pub DateTime, // last modification, i.e., the updated_at or, if the article has never been updated, the created_at
+);
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ArticleArchiveMonth (
+    pub i32, // year
+    pub u32, // month, from 1 to 12
+    pub u64, // approved articles created in the month
+);
+
 #[derive(PartialEq, Eq, Debug, Clone)]
 pub enum ArticleQueryType {
     Title(String),
     Tag(i32),
     Author(Uuid),
+    ReadingTime(Option<u32>, Option<u32>), // minimum and maximum minutes, both inclusive
+    CreatedAfter(DateTime),
+    CreatedBetween(DateTime, DateTime), // start inclusive, end exclusive
+}
+
+#[derive(PartialEq, Eq, Debug, Clone, Copy, Default)]
+pub enum ArticleSortOrder {
+    #[default]
+    Newest,
+    Oldest,
+    ShortestReadingTime,
+    LongestReadingTime,
 }
 
 #[cfg_attr(test, automock)]
@@ -31,9 +64,20 @@ pub trait ArticleRepositoryTrait {
 
     async fn find_by_slug(&self, slug: &Slug) -> Result<Option<Article>, Box<dyn Error>>;
 
-    async fn find_many(&self, params: PaginationParameters<ArticleQueryType>, show_only_approved_state: Option<bool>) -> Result<FindManyArticlesResponse, Box<dyn Error>>;
+    /// Articles matching every one of the filters of the query.
+    async fn find_many(&self, params: PaginationParameters<Vec<ArticleQueryType>>, show_only_approved_state: Option<bool>, sort: ArticleSortOrder) -> Result<FindManyArticlesResponse, Box<dyn Error>>;
+
+    async fn find_many_by_cursor(&self, params: CursorPaginationParameters<Vec<ArticleQueryType>>, show_only_approved_state: Option<bool>) -> Result<FindManyArticlesByCursorResponse, Box<dyn Error>>;
+
+    /// Articles matching every one of the filters, from the oldest to the newest, without pagination.
+    async fn find_all_by_filters(&self, filters: Vec<ArticleQueryType>, show_only_approved_state: Option<bool>, limit: u64) -> Result<Vec<ListedArticle>, Box<dyn Error>>;
 
     async fn get_home_articles(&self) -> Result<Vec<Article>, Box<dyn Error>>;
 
+    async fn find_approved_slugs(&self) -> Result<Vec<ApprovedArticleSlug>, Box<dyn Error>>;
+
+    /// Months that have approved articles, from the newest to the oldest.
+    async fn count_approved_by_month(&self) -> Result<Vec<ArticleArchiveMonth>, Box<dyn Error>>;
+
     async fn save(&self, article: Article) -> Result<Article, Box<dyn Error>>;
 }
\ No newline at end of file
@@ -29,6 +29,8 @@ pub trait ArticleTagRepositoryTrait {
 
     async fn find_many(&self, params: PaginationParameters<ArticleTagQueryType>) -> Result<FindManyArticleTagsResponse, Box<dyn Error>>;
 
+    async fn find_all(&self) -> Result<Vec<ArticleTag>, Box<dyn Error>>;
+
     async fn save(&self, article_tag: ArticleTag) -> Result<ArticleTag, Box<dyn Error>>;
 
     async fn delete(&self, article_tag: ArticleTag) -> Result<(), Box<dyn Error>>;
@@ -0,0 +1,24 @@
+use async_trait::async_trait;
+use chrono::NaiveDate;
+use uuid::Uuid;
+use std::collections::HashMap;
+use std::error::Error;
+
+use crate::domain::domain_entities::article::Article;
+use crate::domain::domain_entities::article_view::{ArticleDailyViews, ArticleView};
+
+#[cfg(test)]
+use mockall::automock;
+
+#[cfg_attr(test, automock)]
+#[async_trait]
+pub trait ArticleViewRepositoryTrait {
+    async fn find_by_viewer(&self, article_id: Uuid, viewer_key: String) -> Result<Option<ArticleView>, Box<dyn Error>>;
+
+    /// Persists the view as the viewer's latest one and adds it to the article's bucket of the view's day.
+    async fn register(&self, view: ArticleView) -> Result<ArticleView, Box<dyn Error>>;
+
+    async fn count_views(&self, article_ids: Vec<Uuid>) -> Result<HashMap<Uuid, u64>, Box<dyn Error>>;
+
+    async fn find_approved_daily_views_since(&self, since: NaiveDate) -> Result<Vec<(ArticleDailyViews, Article)>, Box<dyn Error>>;
+}
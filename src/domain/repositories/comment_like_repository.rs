@@ -0,0 +1,19 @@
+use async_trait::async_trait;
+use uuid::Uuid;
+use std::error::Error;
+
+use crate::domain::domain_entities::comment_like::CommentLike;
+
+#[cfg(test)]
+use mockall::automock;
+
+#[cfg_attr(test, automock)]
+#[async_trait]
+pub trait CommentLikeRepositoryTrait {
+    async fn create(&self, like: CommentLike) -> Result<CommentLike, Box<dyn Error>>;
+    async fn delete(&self, like: CommentLike) -> Result<(), Box<dyn Error>>;
+    async fn find_by_user(&self, comment_id: Uuid, user_id: Uuid) -> Result<Option<CommentLike>, Box<dyn Error>>;
+    async fn count_by_comment(&self, comment_id: Uuid) -> Result<u64, Box<dyn Error>>;
+    /// Which of the given comments the user liked.
+    async fn find_liked_comment_ids(&self, user_id: Uuid, comment_ids: Vec<Uuid>) -> Result<Vec<Uuid>, Box<dyn Error>>;
+}
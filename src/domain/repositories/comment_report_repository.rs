@@ -2,8 +2,8 @@ use async_trait::async_trait;
 use uuid::Uuid;
 use std::error::Error;
 
-use crate::domain::domain_entities::comment_report::{DraftCommentReport, CommentReport};
-use crate::core::pagination::PaginationParameters;
+use crate::domain::domain_entities::comment_report::{DraftCommentReport, CommentReport, ReportReason};
+use crate::core::pagination::{CursorPaginationParameters, PaginationParameters};
 
 #[cfg(test)]
 use mockall::automock;
@@ -14,11 +14,18 @@ pub struct FindManyCommentReportsResponse (
     pub u64,
 );
 
+#[derive(Debug)]
+pub struct FindManyCommentReportsByCursorResponse (
+    pub Vec<CommentReport>, // data, always ordered from the newest to the oldest
+    pub bool, // whether there are more items past the page, in the cursor direction
+);
+
 #[derive(PartialEq, Eq, Debug, Clone)]
 pub enum CommentReportQueryType {
     SolvedBy(Uuid),
     Solved(bool),
     Content(String),
+    Reason(ReportReason),
 }
 
 #[cfg_attr(test, automock)]
@@ -28,8 +35,13 @@ pub trait CommentReportRepositoryTrait {
 
     async fn find_by_id(&self, comm_report_id: i32) -> Result<Option<CommentReport>, Box<dyn Error>>;
 
+    /// Finds the report a user has already made on a comment, if any.
+    async fn find_by_reporter(&self, comment_id: Uuid, user_id: Uuid) -> Result<Option<CommentReport>, Box<dyn Error>>;
+
     async fn find_many(&self, params: PaginationParameters<CommentReportQueryType>) -> Result<FindManyCommentReportsResponse, Box<dyn Error>>;
 
+    async fn find_many_by_cursor(&self, params: CursorPaginationParameters<CommentReportQueryType, i32>) -> Result<FindManyCommentReportsByCursorResponse, Box<dyn Error>>;
+
     async fn save(&self, comment_report: CommentReport) -> Result<CommentReport, Box<dyn Error>>;
 
     async fn delete(&self, comment_report: CommentReport) -> Result<(), Box<dyn Error>>;
@@ -1,8 +1,10 @@
 use async_trait::async_trait;
+use chrono::NaiveDateTime;
 use uuid::Uuid;
 use std::error::Error;
 
 use crate::domain::domain_entities::comment::Comment;
+use crate::domain::domain_entities::comment_revision::CommentRevision;
 
 #[cfg(test)]
 use mockall::automock;
@@ -14,7 +16,16 @@ pub trait CommentRepositoryTrait {
 
     async fn find_by_id(&self, id: Uuid) -> Result<Option<Comment>, Box<dyn Error>>;
 
+    /// The author's comments posted since the given time, active or not, newest first.
+    async fn find_many_by_author_since(&self, author_id: Uuid, since: NaiveDateTime) -> Result<Vec<Comment>, Box<dyn Error>>;
+
+    /// The article's pinned comments, in the order they were pinned.
+    async fn find_many_pinned_by_article(&self, article_id: Uuid) -> Result<Vec<Comment>, Box<dyn Error>>;
+
     async fn delete(&self, comment: Comment) -> Result<(), Box<dyn Error>>;
 
     async fn save(&self, comment: Comment) -> Result<Comment, Box<dyn Error>>;
+
+    /// Saves an edited comment along with the revision holding its previous content.
+    async fn save_with_revision(&self, comment: Comment, revision: CommentRevision) -> Result<Comment, Box<dyn Error>>;
 }
\ No newline at end of file
@@ -0,0 +1,15 @@
+use async_trait::async_trait;
+use uuid::Uuid;
+use std::error::Error;
+
+use crate::domain::domain_entities::comment_revision::CommentRevision;
+
+#[cfg(test)]
+use mockall::automock;
+
+#[cfg_attr(test, automock)]
+#[async_trait]
+pub trait CommentRevisionRepositoryTrait {
+    /// Every previous content of the comment, from the newest to the oldest.
+    async fn find_many_by_comment(&self, comment_id: Uuid) -> Result<Vec<CommentRevision>, Box<dyn Error>>;
+}
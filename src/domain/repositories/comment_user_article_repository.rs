@@ -1,7 +1,8 @@
 use async_trait::async_trait;
+use chrono::NaiveDateTime as DateTime;
 use uuid::Uuid;
 use std::error::Error;
-use crate::core::pagination::PaginationParameters;
+use crate::core::pagination::{CursorPaginationParameters, PaginationParameters};
 
 use crate::domain::domain_entities::comment_with_author::CommentWithAuthor;
  
@@ -14,6 +15,40 @@ pub struct FindManyCommentsWithAuthorResponse (
     pub u64, // count
 );
 
+#[derive(Debug)]
+pub struct FindManyCommentsWithAuthorByCursorResponse (
+    pub Vec<CommentWithAuthor>, // data
+    pub bool, // has more
+);
+
+/// Ties are always broken by the newest comment, so every sort is stable for cursor pagination.
+#[derive(PartialEq, Eq, Debug, Clone, Copy, Default)]
+pub enum CommentSortOrder {
+    #[default]
+    Newest,
+    Oldest,
+    /// The most replied comments first.
+    MostReplied,
+    /// The most liked comments first.
+    Top,
+}
+
+impl CommentSortOrder {
+    /// Whether the comments are ranked by something other than their creation date.
+    pub fn is_ranked(&self) -> bool {
+        matches!(self, CommentSortOrder::MostReplied | CommentSortOrder::Top)
+    }
+
+    /// The key the comments are ordered by before `created_at` and `id`, if any.
+    pub fn rank(&self, comment: &CommentWithAuthor) -> Option<i64> {
+        match self {
+            CommentSortOrder::Newest | CommentSortOrder::Oldest => None,
+            CommentSortOrder::MostReplied => Some(comment.reply_count() as i64),
+            CommentSortOrder::Top => Some(comment.like_count() as i64),
+        }
+    }
+}
+
 #[derive(PartialEq, Eq, Debug, Clone)]
 pub enum CommentWithAuthorQueryType {
     Author(Uuid),
@@ -23,10 +58,34 @@ pub enum CommentWithAuthorQueryType {
 #[cfg_attr(test, automock)]
 #[async_trait]
 pub trait CommentUserArticleRepositoryTrait {
+    /// Lists the article's top-level comments, each with its number of direct replies.
+    /// Pinned comments come first, in the order they were pinned.
     async fn find_many_comments(
         &self,
         article_id: Uuid,
         include_inactive: bool,
+        sort: CommentSortOrder,
         params: PaginationParameters<CommentWithAuthorQueryType>
     ) -> Result<FindManyCommentsWithAuthorResponse, Box<dyn Error>>;
+
+    /// Same as `find_many_comments`, walking from the cursor instead of skipping pages.
+    /// Pinned comments are not part of the walk, they are only put on top of the first page.
+    async fn find_many_comments_by_cursor(
+        &self,
+        article_id: Uuid,
+        include_inactive: bool,
+        sort: CommentSortOrder,
+        params: CursorPaginationParameters<CommentWithAuthorQueryType>
+    ) -> Result<FindManyCommentsWithAuthorByCursorResponse, Box<dyn Error>>;
+
+    /// Lists the direct replies of a comment, oldest first.
+    async fn find_many_replies(
+        &self,
+        parent_id: Uuid,
+        include_inactive: bool,
+        params: PaginationParameters<CommentWithAuthorQueryType>
+    ) -> Result<FindManyCommentsWithAuthorResponse, Box<dyn Error>>;
+
+    /// The visible comment of an approved article that got the most likes since `since`, the newest one on ties.
+    async fn find_most_liked_since(&self, since: DateTime) -> Result<Option<CommentWithAuthor>, Box<dyn Error>>;
 }
@@ -0,0 +1,39 @@
+use async_trait::async_trait;
+use uuid::Uuid;
+use std::error::Error;
+
+use crate::core::pagination::{CursorPaginationParameters, PaginationParameters};
+use crate::domain::domain_entities::mention::{Mention, MentionSource};
+
+#[cfg(test)]
+use mockall::automock;
+
+#[derive(Debug)]
+pub struct FindManyMentionsResponse (
+    pub Vec<Mention>, // data
+    pub u64, // count
+);
+
+#[derive(Debug)]
+pub struct FindManyMentionsByCursorResponse (
+    pub Vec<Mention>, // data, always ordered from the newest to the oldest
+    pub bool, // whether there are more items past the page, in the cursor direction
+);
+
+#[cfg_attr(test, automock)]
+#[async_trait]
+pub trait MentionRepositoryTrait {
+    /// Replaces the mentions stored for the source, e.g. after its content was edited.
+    async fn replace_many(&self, source: MentionSource, mentions: Vec<Mention>) -> Result<(), Box<dyn Error>>;
+
+    /// Mentions on the article's content, comments left out, with the mentioned users' nicknames.
+    async fn find_many_by_article(&self, article_id: Uuid) -> Result<Vec<Mention>, Box<dyn Error>>;
+
+    /// Mentions on any of the comments, with the mentioned users' nicknames.
+    async fn find_many_by_comments(&self, comment_ids: Vec<Uuid>) -> Result<Vec<Mention>, Box<dyn Error>>;
+
+    /// Where the user was mentioned, from the newest to the oldest.
+    async fn find_many_by_user(&self, user_id: Uuid, params: PaginationParameters<()>) -> Result<FindManyMentionsResponse, Box<dyn Error>>;
+
+    async fn find_many_by_user_by_cursor(&self, user_id: Uuid, params: CursorPaginationParameters<()>) -> Result<FindManyMentionsByCursorResponse, Box<dyn Error>>;
+}
@@ -1,10 +1,19 @@
 pub mod user_repository;
 pub mod article_repository;
+pub mod article_view_repository;
+pub mod article_reaction_repository;
+pub mod comment_like_repository;
+pub mod article_coauthor_repository;
+pub mod article_edit_lock_repository;
+pub mod article_note_repository;
 pub mod comment_repository;
 pub mod article_comment_repository;
 pub mod comment_report_repository;
 pub mod comment_user_article_repository;
+pub mod comment_revision_repository;
+pub mod mention_repository;
 pub mod team_role_repository;
 pub mod team_user_repository;
 pub mod article_tag_repository;
 pub mod free_badge_repository;
+pub mod word_filter_repository;
@@ -2,7 +2,7 @@ use std::error::Error;
 use async_trait::async_trait;
 use uuid::Uuid;
 
-use crate::{core::pagination::PaginationParameters, domain::domain_entities::{role::Role, user::User}};
+use crate::{core::pagination::{CursorPaginationParameters, PaginationParameters}, domain::domain_entities::{role::Role, user::User}};
 
 #[cfg(test)]
 use mockall::automock;
@@ -13,6 +13,12 @@ pub struct FindManyUsersResponse (
     pub u64,
 );
 
+#[derive(Debug)]
+pub struct FindManyUsersByCursorResponse (
+    pub Vec<User>, // data, always ordered from the newest to the oldest
+    pub bool, // whether there are more items past the page, in the cursor direction
+);
+
 #[derive(PartialEq, Eq, Debug, Clone)]
 pub enum UserQueryType {
     Role(Role),
@@ -32,4 +38,6 @@ pub trait UserRepositoryTrait {
     async fn save(&self, user: User) -> Result<User, Box<dyn Error>>;
 
     async fn find_many(&self, params: PaginationParameters<UserQueryType>) -> Result<FindManyUsersResponse, Box<dyn Error>>;
+
+    async fn find_many_by_cursor(&self, params: CursorPaginationParameters<UserQueryType>) -> Result<FindManyUsersByCursorResponse, Box<dyn Error>>;
 }
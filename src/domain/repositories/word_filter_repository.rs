@@ -0,0 +1,27 @@
+use async_trait::async_trait;
+use std::error::Error;
+use uuid::Uuid;
+
+use crate::core::pagination::PaginationParameters;
+use crate::domain::domain_entities::word_filter::WordFilter;
+
+#[cfg(test)]
+use mockall::automock;
+
+#[derive(Debug)]
+pub struct FindManyWordFiltersResponse (
+    pub Vec<WordFilter>,
+    pub u64,
+);
+
+#[cfg_attr(test, automock)]
+#[async_trait]
+pub trait WordFilterRepositoryTrait {
+    async fn create(&self, word_filter: WordFilter) -> Result<WordFilter, Box<dyn Error>>;
+    async fn save(&self, word_filter: WordFilter) -> Result<WordFilter, Box<dyn Error>>;
+    async fn delete(&self, word_filter: WordFilter) -> Result<(), Box<dyn Error>>;
+    async fn find_by_id(&self, id: Uuid) -> Result<Option<WordFilter>, Box<dyn Error>>;
+    async fn find_many(&self, params: PaginationParameters<()>) -> Result<FindManyWordFiltersResponse, Box<dyn Error>>;
+    /// Every filter, to be run on a content.
+    async fn find_all(&self) -> Result<Vec<WordFilter>, Box<dyn Error>>;
+}
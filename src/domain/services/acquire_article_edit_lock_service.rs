@@ -0,0 +1,209 @@
+use uuid::Uuid;
+
+use crate::domain::domain_entities::article_edit_lock::ArticleEditLock;
+use crate::domain::domain_entities::role::Role;
+use crate::domain::politics::article_politics::ArticlePolitics;
+use crate::domain::repositories::article_coauthor_repository::ArticleCoauthorRepositoryTrait;
+use crate::domain::repositories::article_edit_lock_repository::ArticleEditLockRepositoryTrait;
+use crate::domain::repositories::article_repository::ArticleRepositoryTrait;
+use crate::errors::conflict_error::ConflictError;
+use crate::errors::error::DomainErrorTrait;
+use crate::errors::resource_not_found::ResourceNotFoundError;
+use crate::errors::unauthorized_error::UnauthorizedError;
+use crate::libs::time::TimeHelper;
+use crate::util::generate_service_internal_error;
+
+pub struct AcquireArticleEditLockParams {
+    pub user_id: Uuid,
+    pub user_role: Role,
+    pub article_id: Uuid,
+    /// Takes the lock over from its current holder, if the user is above them on the role hierarchy.
+    pub force: bool,
+}
+
+pub struct AcquireArticleEditLockService<AR, ACR, ALR>
+where   AR: ArticleRepositoryTrait,
+        ACR: ArticleCoauthorRepositoryTrait,
+        ALR: ArticleEditLockRepositoryTrait
+        {
+    article_repository: Box<AR>,
+    article_coauthor_repository: Box<ACR>,
+    article_edit_lock_repository: Box<ALR>,
+}
+
+impl<
+    AR: ArticleRepositoryTrait,
+    ACR: ArticleCoauthorRepositoryTrait,
+    ALR: ArticleEditLockRepositoryTrait
+> AcquireArticleEditLockService<AR, ACR, ALR> {
+    pub fn new(article_repository: Box<AR>, article_coauthor_repository: Box<ACR>, article_edit_lock_repository: Box<ALR>) -> Self {
+        AcquireArticleEditLockService {
+            article_repository,
+            article_coauthor_repository,
+            article_edit_lock_repository
+        }
+    }
+
+    pub async fn exec(&self, params: AcquireArticleEditLockParams) -> Result<ArticleEditLock, Box<dyn DomainErrorTrait>> {
+        let article = self.article_repository.find_by_id(params.article_id).await;
+
+        if article.is_err() {
+            return Err(generate_service_internal_error(
+                "Error occurred on Acquire Article Edit Lock Service, while finding the article by id".into(),
+                &article.unwrap_err()
+            ));
+        }
+
+        let article = match article.unwrap() {
+            Some(article) => article,
+            None => return Err(Box::new(ResourceNotFoundError::new()))
+        };
+
+        let coauthor = self.article_coauthor_repository.find_by_user(article.id(), params.user_id).await;
+
+        if coauthor.is_err() {
+            return Err(generate_service_internal_error(
+                "Error occurred on Acquire Article Edit Lock Service, while finding the article's co-author".into(),
+                &coauthor.unwrap_err()
+            ));
+        }
+
+        let user_is_coauthor = coauthor.unwrap().is_some();
+
+        if !ArticlePolitics::user_can_edit_article(&article, params.user_id, &params.user_role, user_is_coauthor) {
+            return Err(Box::new(UnauthorizedError::new()));
+        }
+
+        verify_article_edit_lock(
+            self.article_edit_lock_repository.as_ref(),
+            article.id(),
+            params.user_id,
+            &params.user_role,
+            params.force
+        ).await?;
+
+        let lock = ArticleEditLock::new(
+            article.id(),
+            params.user_id,
+            ArticlePolitics::edit_lock_expiration(TimeHelper::now())
+        );
+
+        let response = self.article_edit_lock_repository.save(lock).await;
+
+        if response.is_err() {
+            return Err(generate_service_internal_error(
+                "Error occurred on Acquire Article Edit Lock Service, while saving the lock".into(),
+                &response.unwrap_err()
+            ));
+        }
+
+        Ok(response.unwrap())
+    }
+}
+
+/**
+ Verifies the article's edit lock doesn't stop the user from writing the article.
+
+ Returns the lock being taken over when the user forces someone else's lock, so the caller can hand it to the user.
+ */
+pub async fn verify_article_edit_lock<ALR>(
+    article_edit_lock_repository: &ALR,
+    article_id: Uuid,
+    user_id: Uuid,
+    user_role: &Role,
+    force: bool
+) -> Result<Option<ArticleEditLock>, Box<dyn DomainErrorTrait>>
+where   ALR: ArticleEditLockRepositoryTrait
+{
+    let lock = article_edit_lock_repository.find_by_article(article_id).await;
+
+    if lock.is_err() {
+        return Err(generate_service_internal_error(
+            "Error occurred while finding the article's edit lock".into(),
+            &lock.unwrap_err()
+        ));
+    }
+
+    let lock = match lock.unwrap() {
+        Some(lock) => lock,
+        None => return Ok(None)
+    };
+
+    if !ArticlePolitics::edit_lock_blocks_user(&lock, user_id, TimeHelper::now()) { return Ok(None); }
+
+    if !force {
+        return Err(Box::new(ConflictError::new_with_message(format!(
+            "Article is being edited by {} until {}.",
+            lock.holder_nickname().unwrap_or("another user"),
+            lock.expires_at()
+        ))));
+    }
+
+    if !ArticlePolitics::user_can_force_edit_lock(&lock, user_role) {
+        return Err(Box::new(UnauthorizedError::new()));
+    }
+
+    Ok(Some(lock))
+}
+
+#[cfg(test)]
+mod test {
+    use http::StatusCode;
+
+    use super::*;
+    use crate::domain::domain_entities::article::Article;
+    use crate::tests::repositories::article_coauthor_repository::get_article_coauthor_repository;
+    use crate::tests::repositories::article_edit_lock_repository::get_article_edit_lock_repository;
+    use crate::tests::repositories::article_repository::get_article_repository;
+
+    #[tokio::test]
+    async fn test() {
+        let (article_db, article_repository) = get_article_repository();
+        let (_, article_coauthor_repository) = get_article_coauthor_repository();
+        let (lock_db, article_edit_lock_repository) = get_article_edit_lock_repository();
+
+        let article = Article::new(Uuid::new_v4(), "Rascunho".into(), "Conteúdo".into(), None, "cover.com".into(), 1, "Foo".into());
+        article_db.lock().unwrap().push(article.clone());
+
+        let service = AcquireArticleEditLockService::new(
+            Box::new(article_repository),
+            Box::new(article_coauthor_repository),
+            Box::new(article_edit_lock_repository)
+        );
+
+        let params = |user_id: Uuid, user_role: Role, force: bool| AcquireArticleEditLockParams {
+            user_id,
+            user_role,
+            article_id: article.id(),
+            force
+        };
+
+        let result = service.exec(params(Uuid::new_v4(), Role::Writer, false)).await;
+        assert_eq!(result.unwrap_err().code(), &StatusCode::UNAUTHORIZED); // not the article's author
+
+        let lock = service.exec(params(article.author_id(), Role::Writer, false)).await.unwrap();
+        assert_eq!(lock.user_id(), article.author_id());
+
+        // the lock as read from the database, along with its holder's role
+        lock_db.lock().unwrap()[0] = ArticleEditLock::new_from_existing(
+            article.id(),
+            article.author_id(),
+            Some("Autor".into()),
+            Some(Role::Writer),
+            lock.acquired_at(),
+            lock.expires_at()
+        );
+
+        let editor_id = Uuid::new_v4();
+
+        let result = service.exec(params(editor_id, Role::Editor, false)).await;
+        assert_eq!(result.unwrap_err().code(), &StatusCode::CONFLICT);
+
+        let result = service.exec(params(Uuid::new_v4(), Role::Writer, true)).await;
+        assert_eq!(result.unwrap_err().code(), &StatusCode::UNAUTHORIZED); // can't edit it, let alone force the lock
+
+        let lock = service.exec(params(editor_id, Role::Editor, true)).await.unwrap();
+        assert_eq!(lock.user_id(), editor_id);
+        assert_eq!(lock_db.lock().unwrap().len(), 1);
+    }
+}
@@ -1,43 +1,70 @@
-use log::error;
+use log::{error, info};
 use uuid::Uuid;
 
 use crate::domain::domain_entities::comment::Comment;
+use crate::domain::domain_entities::mention::MentionSource;
+use crate::domain::politics::comment_politics::CommentPolitics;
+use crate::domain::politics::comment_spam_politics::{CommentSpamContext, CommentSpamFilter};
+use crate::domain::politics::word_filter_politics::WordFilterPolitics;
 use crate::domain::repositories::{
     article_repository::ArticleRepositoryTrait,
     comment_repository::CommentRepositoryTrait,
+    mention_repository::MentionRepositoryTrait,
+    user_repository::UserRepositoryTrait,
+    word_filter_repository::WordFilterRepositoryTrait,
 };
+use crate::domain::politics::mention_politics::MentionPolitics;
 use crate::errors::error::DomainErrorTrait;
 use crate::errors::{
     bad_request_error::BadRequestError,
     internal_error::InternalError,
+    unauthorized_error::UnauthorizedError,
 };
+use crate::libs::time::TimeHelper;
 use crate::{R_EOL, LOG_SEP};
 
 pub struct CommentOnArticleParams {
     pub author_id: Uuid,
     pub article_id: Uuid,
-    pub content: String
+    pub content: String,
+    /// The comment being replied to, which must belong to the same article.
+    pub parent_id: Option<Uuid>,
 }
 
-pub struct CommentOnArticleService<CR, AR>
-where CR: CommentRepositoryTrait, AR: ArticleRepositoryTrait
+pub struct CommentOnArticleService<CR, AR, UR, MR, WFR>
+where CR: CommentRepositoryTrait, AR: ArticleRepositoryTrait, UR: UserRepositoryTrait, MR: MentionRepositoryTrait, WFR: WordFilterRepositoryTrait
 {
     comment_repository: Box<CR>,
     article_repository: Box<AR>,
+    user_repository: Box<UR>,
+    mention_repository: Box<MR>,
+    word_filter_repository: Box<WFR>,
+    spam_filter: CommentSpamFilter,
 }
 
 impl<
 CR: CommentRepositoryTrait,
 AR: ArticleRepositoryTrait,
+UR: UserRepositoryTrait,
+MR: MentionRepositoryTrait,
+WFR: WordFilterRepositoryTrait,
 >
-CommentOnArticleService<CR, AR> {
+CommentOnArticleService<CR, AR, UR, MR, WFR> {
     pub fn new(
         comment_repository: Box<CR>,
         article_repository: Box<AR>,
+        user_repository: Box<UR>,
+        mention_repository: Box<MR>,
+        word_filter_repository: Box<WFR>,
+        spam_filter: CommentSpamFilter,
     ) -> Self {
         CommentOnArticleService {
             comment_repository,
             article_repository,
+            user_repository,
+            mention_repository,
+            word_filter_repository,
+            spam_filter,
         }
     }
 
@@ -49,14 +76,47 @@ CommentOnArticleService<CR, AR> {
             return Err( Box::new( InternalError::new() ) );
         }
 
-        if article_on_db.unwrap().is_none() { return Err( Box::new( BadRequestError::new() ) ); }
+        let article = match article_on_db.unwrap() {
+            Some(article) => article,
+            None => return Err( Box::new( BadRequestError::new() ) ),
+        };
 
-        let comment = Comment::new(
+        if article.comments_locked() {
+            return Err(Box::new(BadRequestError::new_with_message("Comments are closed on this article.".into())));
+        }
+
+        if let Some(parent_id) = params.parent_id {
+            self.verify_parent(parent_id, params.article_id).await?;
+        }
+
+        let word_filters = self.word_filter_repository.find_all().await;
+
+        if word_filters.is_err() {
+            error!("{R_EOL}{LOG_SEP}{R_EOL}Error occurred on comment_on_article_service.rs, while fetching the word filters from db:{R_EOL}{:#?}{R_EOL}{LOG_SEP}{R_EOL}", word_filters.unwrap_err());
+            return Err(Box::new(InternalError::new()));
+        }
+
+        let filtered = WordFilterPolitics::apply(&word_filters.unwrap(), &params.content)
+            .map_err(|err| Box::new(err) as Box<dyn DomainErrorTrait>)?;
+
+        let mut review_reasons = self.check_spam(params.author_id, &filtered.content).await?;
+
+        if filtered.deactivate {
+            review_reasons.push("Matched a word filter.".into());
+        }
+
+        let mut comment = Comment::new(
             params.author_id,
             Some(params.article_id),
-            params.content,
+            filtered.content,
+            params.parent_id,
         );
 
+        if !review_reasons.is_empty() {
+            info!("Comment '{}' was created inactive for moderator review: {}", comment.id(), review_reasons.join(" "));
+            comment.hold_for_review();
+        }
+
         let response = self.comment_repository.create(comment).await;
 
         if response.is_err() {
@@ -64,7 +124,95 @@ CommentOnArticleService<CR, AR> {
             return Err(Box::new(InternalError::new()));
         }
 
-        Ok(response.unwrap())
+        let comment = response.unwrap();
+
+        // nobody is notified about comments waiting for review
+        if comment.is_active() {
+            MentionPolitics::sync_mentions(
+                self.user_repository.as_ref(),
+                self.mention_repository.as_ref(),
+                MentionSource::Comment { comment_id: comment.id(), article_id: comment.article_id() },
+                comment.author_id(),
+                comment.content(),
+            ).await;
+        }
+
+        Ok(comment)
+    }
+
+    /// Runs the spam filter, returning why the comment has to be reviewed, if it has to.
+    async fn check_spam(&self, author_id: Uuid, content: &str) -> Result<Vec<String>, Box<dyn DomainErrorTrait>> {
+        if self.spam_filter.is_empty() { return Ok(vec![]); }
+
+        let author = self.user_repository.find_by_id(&author_id).await;
+
+        if author.is_err() {
+            error!("{R_EOL}{LOG_SEP}{R_EOL}Error occurred on comment_on_article_service.rs, while fetching the author from db:{R_EOL}{:#?}{R_EOL}{LOG_SEP}{R_EOL}", author.unwrap_err());
+            return Err(Box::new(InternalError::new()));
+        }
+
+        let author = match author.unwrap() {
+            Some(author) => author,
+            None => return Err(Box::new(UnauthorizedError::new())),
+        };
+
+        let now = TimeHelper::now();
+
+        let recent_comments = self.comment_repository.find_many_by_author_since(
+            author_id,
+            now - self.spam_filter.history_window(),
+        ).await;
+
+        if recent_comments.is_err() {
+            error!("{R_EOL}{LOG_SEP}{R_EOL}Error occurred on comment_on_article_service.rs, while fetching the author's recent comments from db:{R_EOL}{:#?}{R_EOL}{LOG_SEP}{R_EOL}", recent_comments.unwrap_err());
+            return Err(Box::new(InternalError::new()));
+        }
+
+        self.spam_filter.run(&CommentSpamContext {
+            author: &author,
+            content,
+            recent_comments: &recent_comments.unwrap(),
+            now,
+        })
+    }
+
+    /// Checks the parent is a visible comment on the same article and that replying to it
+    /// doesn't go past `CommentPolitics::max_reply_depth`.
+    async fn verify_parent(&self, parent_id: Uuid, article_id: Uuid) -> Result<(), Box<dyn DomainErrorTrait>> {
+        let parent = self.find_comment(parent_id).await?;
+
+        let parent = match parent {
+            Some(parent) if parent.is_active() && parent.article_id() == Some(article_id) => parent,
+            _ => return Err(Box::new(BadRequestError::new_with_message(
+                "The comment being replied to doesn't exist on this article.".into()
+            ))),
+        };
+
+        let mut depth: u8 = 1;
+        let mut ancestor_id = parent.parent_id();
+
+        while let Some(id) = ancestor_id {
+            depth += 1;
+
+            if depth > CommentPolitics::max_reply_depth() { break; }
+
+            ancestor_id = self.find_comment(id).await?.and_then(|ancestor| ancestor.parent_id());
+        }
+
+        if depth > CommentPolitics::max_reply_depth() {
+            return Err(Box::new(BadRequestError::new_with_message(
+                format!("Replies can't be nested more than {} levels deep.", CommentPolitics::max_reply_depth())
+            )));
+        }
+
+        Ok(())
+    }
+
+    async fn find_comment(&self, id: Uuid) -> Result<Option<Comment>, Box<dyn DomainErrorTrait>> {
+        self.comment_repository.find_by_id(id).await.map_err(|err| {
+            error!("{R_EOL}{LOG_SEP}{R_EOL}Error occurred on comment_on_article_service.rs, while fetching the parent comment from db:{R_EOL}{:#?}{R_EOL}{LOG_SEP}{R_EOL}", err);
+            Box::new(InternalError::new()) as Box<dyn DomainErrorTrait>
+        })
     }
 }
 
@@ -74,11 +222,16 @@ mod test {
     use std::sync::Mutex;
     use std::sync::Arc;
     
-    use crate::domain::domain_entities::article::Article;
-    use crate::domain::domain_entities::slug::Slug;
+    use crate::domain::domain_entities::role::Role;
+    use crate::domain::domain_entities::user::User;
+    use crate::domain::domain_entities::word_filter::{WordFilter, WordFilterAction};
+    use crate::domain::politics::comment_spam_politics::CommentSpamSettings;
     use crate::domain::repositories::comment_repository::MockCommentRepositoryTrait;
     use crate::libs::time::TimeHelper;
-    use crate::tests::repositories::article_repository::get_article_repository;
+    use crate::tests::repositories::article_repository::{get_article, get_article_repository};
+    use crate::tests::repositories::mention_repository::get_mention_repository;
+    use crate::tests::repositories::user_repository::get_user_repository;
+    use crate::tests::repositories::word_filter_repository::get_word_filter_repository;
 
     #[allow(dead_code)]
     #[derive(Clone, Copy)]
@@ -96,21 +249,7 @@ mod test {
         let user_id = Uuid::new_v4();
         let article_id = Uuid::new_v4();
 
-        article_db.lock().unwrap().push(
-            Article::new_from_existing(
-                article_id.clone(),
-                user_id.clone(),
-                "cover_url".into(),
-                "title".into(),
-                "content".into(),
-                false,
-                TimeHelper::now(),
-                None,
-                Some(1),
-                Some("Foo".to_string()),
-                Slug::new(article_id, "title".into())
-            )
-        );
+        article_db.lock().unwrap().push(get_article(article_id, user_id));
 
         let comment_article_db: Arc<Mutex<Vec<CommentArticle>>> = Arc::new(Mutex::new(vec![]));
         let comment_db: Arc<Mutex<Vec<Comment>>> = Arc::new(Mutex::new(vec![]));
@@ -132,15 +271,24 @@ mod test {
             Ok(comment)
         });
 
+        let (user_db, mocked_user_repo) = get_user_repository();
+        let (mention_db, mocked_mention_repo) = get_mention_repository();
+        let (_word_filter_db, mocked_word_filter_repo) = get_word_filter_repository();
+
         let sut = CommentOnArticleService::new(
             Box::new(mocked_comment_repo),
             Box::new(mocked_article_repo),
+            Box::new(mocked_user_repo),
+            Box::new(mocked_mention_repo),
+            Box::new(mocked_word_filter_repo),
+            CommentSpamFilter::without_checks(),
         );
 
         let res = sut.exec(CommentOnArticleParams {
             article_id: article_id.clone(),
             author_id: user_id.clone(),
-            content: "This article is awesome!".into()
+            content: "This article is awesome!".into(),
+            parent_id: None,
         }).await;
 
         assert!(res.is_ok());
@@ -160,5 +308,268 @@ mod test {
             (relation_1.article_id, relation_1.comment_id),
             (article_id, res.id())
         );
+
+        let salem = User::new("Salem".into(), "123".into(), Some(Role::User));
+        user_db.lock().unwrap().push(salem.clone());
+
+        let mentioning = sut.exec(CommentOnArticleParams {
+            article_id,
+            author_id: user_id,
+            content: "Olha isso @salem, e você @Desconhecido!".into(),
+            parent_id: None,
+        }).await.unwrap();
+
+        let mentions = mention_db.lock().unwrap();
+
+        assert_eq!(1, mentions.len(), "Only nicknames that belong to someone should be mentioned.");
+        assert_eq!(
+            (salem.id(), user_id, Some(mentioning.id()), Some(article_id)),
+            (mentions[0].user_id(), mentions[0].mentioned_by(), mentions[0].comment_id(), mentions[0].article_id())
+        );
+
+        drop(mentions);
+
+        article_db.lock().unwrap()[0].set_comments_locked(true);
+
+        let on_locked_article = sut.exec(CommentOnArticleParams {
+            article_id,
+            author_id: user_id,
+            content: "Ainda dá pra comentar?".into(),
+            parent_id: None,
+        }).await;
+
+        assert_eq!(&400, on_locked_article.unwrap_err().code());
+        assert_eq!(2, comment_db.lock().unwrap().len(), "No comment should be created on a locked article.");
+    }
+
+    #[tokio::test]
+    async fn test_reply_must_share_the_article_and_respect_max_depth() {
+        let (article_db, mocked_article_repo) = get_article_repository();
+        let mut mocked_comment_repo = MockCommentRepositoryTrait::new();
+
+        let user_id = Uuid::new_v4();
+        let article_id = Uuid::new_v4();
+
+        article_db.lock().unwrap().push(get_article(article_id, user_id));
+
+        let mut parent_id = None;
+        let mut chain: Vec<Comment> = vec![];
+
+        for _ in 0..=CommentPolitics::max_reply_depth() {
+            let comment = Comment::new(user_id, Some(article_id), "Reply".into(), parent_id);
+            parent_id = Some(comment.id());
+            chain.push(comment);
+        }
+
+        let other_article_comment = Comment::new(user_id, Some(Uuid::new_v4()), "Elsewhere".into(), None);
+        chain.push(other_article_comment.clone());
+
+        let comment_db: Arc<Mutex<Vec<Comment>>> = Arc::new(Mutex::new(chain.clone()));
+
+        let comment_db_move_clone = Arc::clone(&comment_db);
+        mocked_comment_repo
+        .expect_find_by_id()
+        .returning(move |id| {
+            Ok(comment_db_move_clone.lock().unwrap().iter().find(|comment| comment.id() == id).cloned())
+        });
+
+        mocked_comment_repo
+        .expect_create()
+        .returning(|comment| Ok(comment));
+
+        let (_user_db, mocked_user_repo) = get_user_repository();
+        let (_mention_db, mocked_mention_repo) = get_mention_repository();
+        let (_word_filter_db, mocked_word_filter_repo) = get_word_filter_repository();
+
+        let sut = CommentOnArticleService::new(
+            Box::new(mocked_comment_repo),
+            Box::new(mocked_article_repo),
+            Box::new(mocked_user_repo),
+            Box::new(mocked_mention_repo),
+            Box::new(mocked_word_filter_repo),
+            CommentSpamFilter::without_checks(),
+        );
+
+        let reply = sut.exec(CommentOnArticleParams {
+            article_id,
+            author_id: user_id,
+            content: "Replying to the first comment".into(),
+            parent_id: Some(chain[0].id()),
+        }).await;
+
+        assert_eq!(Some(chain[0].id()), reply.unwrap().parent_id());
+
+        let too_deep = sut.exec(CommentOnArticleParams {
+            article_id,
+            author_id: user_id,
+            content: "Replying to the deepest reply".into(),
+            parent_id: parent_id,
+        }).await;
+
+        assert!(too_deep.is_err(), "Replies shouldn't be nested past the max depth.");
+
+        let wrong_article = sut.exec(CommentOnArticleParams {
+            article_id,
+            author_id: user_id,
+            content: "Replying across articles".into(),
+            parent_id: Some(other_article_comment.id()),
+        }).await;
+
+        assert!(wrong_article.is_err(), "The parent comment should belong to the same article.");
     }
-}
\ No newline at end of file
+
+    #[tokio::test]
+    async fn test_spam_checks_reject_or_hold_comments_for_review() {
+        let (article_db, mocked_article_repo) = get_article_repository();
+        let mut mocked_comment_repo = MockCommentRepositoryTrait::new();
+
+        let article_id = Uuid::new_v4();
+
+        let veteran = User::new_from_existing(
+            Uuid::new_v4(),
+            "Veterano".into(),
+            "123".into(),
+            TimeHelper::now() - chrono::Duration::days(30),
+            None,
+            Some(Role::User),
+            1,
+        );
+        let newcomer = User::new_from_existing(
+            Uuid::new_v4(),
+            "Novato".into(),
+            "123".into(),
+            TimeHelper::now() - chrono::Duration::hours(2),
+            None,
+            Some(Role::User),
+            1,
+        );
+        let just_registered = User::new("Recem".into(), "123".into(), Some(Role::User));
+
+        article_db.lock().unwrap().push(get_article(article_id, veteran.id()));
+
+        let comment_db: Arc<Mutex<Vec<Comment>>> = Arc::new(Mutex::new(vec![]));
+
+        let comment_db_move_clone = Arc::clone(&comment_db);
+        mocked_comment_repo
+        .expect_find_many_by_author_since()
+        .returning(move |author_id, since| {
+            Ok(comment_db_move_clone.lock().unwrap().iter()
+                .filter(|comment| comment.author_id() == author_id && comment.created_at() >= since)
+                .cloned()
+                .collect())
+        });
+
+        let comment_db_move_clone = Arc::clone(&comment_db);
+        mocked_comment_repo
+        .expect_create()
+        .returning(move |comment| {
+            comment_db_move_clone.lock().unwrap().push(comment.clone());
+            Ok(comment)
+        });
+
+        let (user_db, mocked_user_repo) = get_user_repository();
+        let (_mention_db, mocked_mention_repo) = get_mention_repository();
+        let (_word_filter_db, mocked_word_filter_repo) = get_word_filter_repository();
+
+        user_db.lock().unwrap().extend([veteran.clone(), newcomer.clone(), just_registered.clone()]);
+
+        let sut = CommentOnArticleService::new(
+            Box::new(mocked_comment_repo),
+            Box::new(mocked_article_repo),
+            Box::new(mocked_user_repo),
+            Box::new(mocked_mention_repo),
+            Box::new(mocked_word_filter_repo),
+            CommentSpamFilter::new(&CommentSpamSettings::with_blocklists(Some("cassino, Golpe"), Some("www.spam.com"))),
+        );
+
+        let comment = |author_id: Uuid, content: &str| CommentOnArticleParams {
+            article_id,
+            author_id,
+            content: content.into(),
+            parent_id: None,
+        };
+
+        let published = sut.exec(comment(veteran.id(), "Muito bom o artigo!")).await.unwrap();
+        assert!(published.is_active());
+
+        let duplicate = sut.exec(comment(veteran.id(), "muito  bom o   artigo!")).await;
+        assert_eq!(&400, duplicate.unwrap_err().code(), "Repeated comments should be rejected.");
+
+        let too_many_links = sut.exec(comment(veteran.id(), "https://a.com https://b.com [c](http://c.com)")).await;
+        assert_eq!(&400, too_many_links.unwrap_err().code(), "Comments over the link limit should be rejected.");
+
+        let blocked_domain = sut.exec(comment(veteran.id(), "Veja em https://promo.spam.com/oferta")).await;
+        assert_eq!(&400, blocked_domain.unwrap_err().code(), "Links to blocked domains should be rejected.");
+
+        let blocked_word = sut.exec(comment(veteran.id(), "Esse golpe é antigo.")).await.unwrap();
+        assert!(!blocked_word.is_active(), "Comments with blocked words should wait for review.");
+
+        let cooldown = sut.exec(comment(just_registered.id(), "Cheguei agora!")).await;
+        assert_eq!(&400, cooldown.unwrap_err().code(), "Accounts on cooldown shouldn't be able to comment.");
+
+        let newcomer_link = sut.exec(comment(newcomer.id(), "Olha isso: www.habbo.com.br")).await.unwrap();
+        assert!(!newcomer_link.is_active(), "Links from new accounts should wait for review.");
+
+        for index in 0..3 {
+            sut.exec(comment(veteran.id(), &format!("Comentário número {index}"))).await.unwrap();
+        }
+
+        let rate_limited = sut.exec(comment(veteran.id(), "Mais um comentário")).await;
+        assert_eq!(&429, rate_limited.unwrap_err().code(), "Authors should be rate limited.");
+    }
+
+    #[tokio::test]
+    async fn test_word_filters_mask_hide_or_reject_comments() {
+        let (article_db, mocked_article_repo) = get_article_repository();
+        let mut mocked_comment_repo = MockCommentRepositoryTrait::new();
+
+        let user_id = Uuid::new_v4();
+        let article_id = Uuid::new_v4();
+
+        article_db.lock().unwrap().push(get_article(article_id, user_id));
+
+        mocked_comment_repo
+        .expect_create()
+        .returning(|comment| Ok(comment));
+
+        let (_user_db, mocked_user_repo) = get_user_repository();
+        let (_mention_db, mocked_mention_repo) = get_mention_repository();
+        let (word_filter_db, mocked_word_filter_repo) = get_word_filter_repository();
+
+        word_filter_db.lock().unwrap().extend([
+            WordFilter::new("bobba".into(), false, WordFilterAction::Mask),
+            WordFilter::new("otario".into(), false, WordFilterAction::Deactivate),
+            WordFilter::new(r"c[4a]ss[i1]no".into(), true, WordFilterAction::Reject),
+        ]);
+
+        let sut = CommentOnArticleService::new(
+            Box::new(mocked_comment_repo),
+            Box::new(mocked_article_repo),
+            Box::new(mocked_user_repo),
+            Box::new(mocked_mention_repo),
+            Box::new(mocked_word_filter_repo),
+            CommentSpamFilter::without_checks(),
+        );
+
+        let comment = |content: &str| CommentOnArticleParams {
+            article_id,
+            author_id: user_id,
+            content: content.into(),
+            parent_id: None,
+        };
+
+        let masked = sut.exec(comment("Que BOBBA, bobbagem não!")).await.unwrap();
+
+        assert_eq!("Que *****, bobbagem não!", masked.content());
+        assert!(masked.is_active());
+
+        let hidden = sut.exec(comment("Seu otário")).await.unwrap();
+
+        assert_eq!("Seu otário", hidden.content(), "Accents shouldn't get around the filters.");
+        assert!(!hidden.is_active());
+
+        let rejected = sut.exec(comment("Melhor C4SSÍNO do hotel")).await;
+
+        assert_eq!(&400, rejected.unwrap_err().code());
+    }
+}
@@ -0,0 +1,91 @@
+use std::collections::HashMap;
+
+use uuid::Uuid;
+
+use crate::domain::domain_entities::role::Role;
+use crate::domain::repositories::article_view_repository::ArticleViewRepositoryTrait;
+use crate::errors::error::DomainErrorTrait;
+use crate::util::{generate_service_internal_error, verify_role_has_permission, RolePermissions};
+
+pub struct CountArticlesViewsParams<'exec> {
+    pub user_role: Option<&'exec Role>,
+    pub article_ids: Vec<Uuid>,
+}
+
+pub struct CountArticlesViewsService<ArticleViewRepository: ArticleViewRepositoryTrait> {
+    article_view_repository: Box<ArticleViewRepository>,
+}
+
+impl<ArticleViewRepository: ArticleViewRepositoryTrait> CountArticlesViewsService<ArticleViewRepository> {
+    pub fn new(article_view_repository: Box<ArticleViewRepository>) -> Self {
+        CountArticlesViewsService {
+            article_view_repository
+        }
+    }
+
+    /// Returns `None` if the user is not allowed to see the articles' statistics.
+    pub async fn exec<'exec>(&self, params: CountArticlesViewsParams<'exec>) -> Result<Option<HashMap<Uuid, u64>>, Box<dyn DomainErrorTrait>> {
+        let user_can_see_views = match params.user_role {
+            None => false,
+            Some(role) => verify_role_has_permission(role, RolePermissions::SeeArticleViews)
+        };
+
+        if !user_can_see_views {
+            return Ok(None);
+        }
+
+        let article_ids = params.article_ids;
+
+        let counts = self.article_view_repository.count_views(article_ids.clone()).await;
+
+        if counts.is_err() {
+            return Err(generate_service_internal_error(
+                "Error occurred on Count Articles Views Service, while counting the articles views",
+                &counts.unwrap_err()
+            ));
+        }
+
+        let mut counts = counts.unwrap();
+
+        for article_id in article_ids {
+            counts.entry(article_id).or_insert(0);
+        }
+
+        Ok(Some(counts))
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::domain::repositories::article_view_repository::MockArticleViewRepositoryTrait;
+
+    #[tokio::test]
+    async fn test() {
+        let mut article_view_repository = MockArticleViewRepositoryTrait::new();
+
+        let viewed_article_id = Uuid::new_v4();
+        let unseen_article_id = Uuid::new_v4();
+
+        article_view_repository
+            .expect_count_views()
+            .returning(move |_ids| Ok(HashMap::from([(viewed_article_id, 42)])));
+
+        let sut = CountArticlesViewsService::new(Box::new(article_view_repository));
+
+        let staff_result = sut.exec(CountArticlesViewsParams {
+            user_role: Some(&Role::Writer),
+            article_ids: vec![viewed_article_id, unseen_article_id],
+        }).await.unwrap().unwrap();
+
+        assert_eq!(Some(&42), staff_result.get(&viewed_article_id));
+        assert_eq!(Some(&0), staff_result.get(&unseen_article_id));
+
+        let user_result = sut.exec(CountArticlesViewsParams {
+            user_role: Some(&Role::User),
+            article_ids: vec![viewed_article_id],
+        }).await.unwrap();
+
+        assert!(user_result.is_none(), "Expected common users not to see the articles views.");
+    }
+}
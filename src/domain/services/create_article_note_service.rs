@@ -0,0 +1,203 @@
+use uuid::Uuid;
+
+use crate::domain::domain_entities::article::Article;
+use crate::domain::domain_entities::article_note::ArticleNote;
+use crate::domain::domain_entities::role::Role;
+use crate::domain::politics::article_politics::ArticlePolitics;
+use crate::domain::repositories::article_coauthor_repository::ArticleCoauthorRepositoryTrait;
+use crate::domain::repositories::article_note_repository::ArticleNoteRepositoryTrait;
+use crate::domain::repositories::article_repository::ArticleRepositoryTrait;
+use crate::errors::bad_request_error::BadRequestError;
+use crate::errors::error::DomainErrorTrait;
+use crate::errors::resource_not_found::ResourceNotFoundError;
+use crate::errors::unauthorized_error::UnauthorizedError;
+use crate::util::generate_service_internal_error;
+
+pub struct CreateArticleNoteParams {
+    pub user_id: Uuid,
+    pub user_role: Role,
+    pub article_id: Uuid,
+    /// The note starting the thread being replied to.
+    pub parent_id: Option<Uuid>,
+    pub content: String,
+}
+
+/**
+ # Create Article Note Service
+ Leaves an internal note on an article, or replies to one. Replies always go to the note starting
+ the thread, so threads are a single level deep.
+ */
+pub struct CreateArticleNoteService<AR, ACR, ANR>
+where   AR: ArticleRepositoryTrait,
+        ACR: ArticleCoauthorRepositoryTrait,
+        ANR: ArticleNoteRepositoryTrait
+        {
+    article_repository: Box<AR>,
+    article_coauthor_repository: Box<ACR>,
+    article_note_repository: Box<ANR>,
+}
+
+impl<
+    AR: ArticleRepositoryTrait,
+    ACR: ArticleCoauthorRepositoryTrait,
+    ANR: ArticleNoteRepositoryTrait
+> CreateArticleNoteService<AR, ACR, ANR> {
+    pub fn new(article_repository: Box<AR>, article_coauthor_repository: Box<ACR>, article_note_repository: Box<ANR>) -> Self {
+        CreateArticleNoteService {
+            article_repository,
+            article_coauthor_repository,
+            article_note_repository
+        }
+    }
+
+    pub async fn exec(&self, params: CreateArticleNoteParams) -> Result<ArticleNote, Box<dyn DomainErrorTrait>> {
+        let article = find_article_with_notes_access(
+            self.article_repository.as_ref(),
+            self.article_coauthor_repository.as_ref(),
+            params.article_id,
+            params.user_id,
+            &params.user_role
+        ).await?;
+
+        if let Some(parent_id) = params.parent_id {
+            let parent = self.article_note_repository.find_by_id(parent_id).await;
+
+            if parent.is_err() {
+                return Err(generate_service_internal_error(
+                    "Error occurred on Create Article Note Service, while finding the parent note".into(),
+                    &parent.unwrap_err()
+                ));
+            }
+
+            match parent.unwrap() {
+                Some(parent) if parent.article_id() == article.id() && parent.parent_id().is_none() => (),
+                _ => return Err(Box::new(BadRequestError::new_with_message("Notes can only reply to threads on the same article.".into())))
+            }
+        }
+
+        let note = ArticleNote::new(article.id(), params.user_id, params.parent_id, params.content.trim().to_owned());
+
+        let response = self.article_note_repository.create(note).await;
+
+        if response.is_err() {
+            return Err(generate_service_internal_error(
+                "Error occurred on Create Article Note Service, while creating the note".into(),
+                &response.unwrap_err()
+            ));
+        }
+
+        Ok(response.unwrap())
+    }
+}
+
+/// Finds the article, making sure the user is allowed to see and write its editorial notes.
+pub async fn find_article_with_notes_access<AR, ACR>(
+    article_repository: &AR,
+    article_coauthor_repository: &ACR,
+    article_id: Uuid,
+    user_id: Uuid,
+    user_role: &Role
+) -> Result<Article, Box<dyn DomainErrorTrait>>
+where   AR: ArticleRepositoryTrait,
+        ACR: ArticleCoauthorRepositoryTrait
+{
+    let article = article_repository.find_by_id(article_id).await;
+
+    if article.is_err() {
+        return Err(generate_service_internal_error(
+            "Error occurred while finding the article of the notes".into(),
+            &article.unwrap_err()
+        ));
+    }
+
+    let article = match article.unwrap() {
+        Some(article) => article,
+        None => return Err(Box::new(ResourceNotFoundError::new()))
+    };
+
+    let coauthor = article_coauthor_repository.find_by_user(article.id(), user_id).await;
+
+    if coauthor.is_err() {
+        return Err(generate_service_internal_error(
+            "Error occurred while finding the article's co-author".into(),
+            &coauthor.unwrap_err()
+        ));
+    }
+
+    let user_is_coauthor = coauthor.unwrap().is_some();
+
+    if !ArticlePolitics::user_can_access_article_notes(&article, user_id, user_role, user_is_coauthor) {
+        return Err(Box::new(UnauthorizedError::new()));
+    }
+
+    Ok(article)
+}
+
+#[cfg(test)]
+mod test {
+    use http::StatusCode;
+
+    use super::*;
+    use crate::tests::repositories::article_coauthor_repository::get_article_coauthor_repository;
+    use crate::tests::repositories::article_note_repository::get_article_note_repository;
+    use crate::tests::repositories::article_repository::get_article_repository;
+
+    #[tokio::test]
+    async fn test() {
+        let (article_db, article_repository) = get_article_repository();
+        let (_, article_coauthor_repository) = get_article_coauthor_repository();
+        let (note_db, article_note_repository) = get_article_note_repository();
+
+        let article = Article::new(Uuid::new_v4(), "Rascunho".into(), "Conteúdo".into(), None, "cover.com".into(), 1, "Foo".into());
+        article_db.lock().unwrap().push(article.clone());
+
+        let service = CreateArticleNoteService::new(
+            Box::new(article_repository),
+            Box::new(article_coauthor_repository),
+            Box::new(article_note_repository)
+        );
+
+        let editor_id = Uuid::new_v4();
+
+        let thread = service.exec(CreateArticleNoteParams {
+            user_id: editor_id,
+            user_role: Role::Editor,
+            article_id: article.id(),
+            parent_id: None,
+            content: " Faltou a fonte da imagem. ".into()
+        }).await.unwrap();
+
+        assert_eq!("Faltou a fonte da imagem.", thread.content());
+
+        let reply = service.exec(CreateArticleNoteParams {
+            user_id: article.author_id(),
+            user_role: Role::Writer,
+            article_id: article.id(),
+            parent_id: Some(thread.id()),
+            content: "Adicionei!".into()
+        }).await.unwrap();
+
+        assert_eq!(Some(thread.id()), reply.parent_id());
+
+        let nested_reply = service.exec(CreateArticleNoteParams {
+            user_id: editor_id,
+            user_role: Role::Editor,
+            article_id: article.id(),
+            parent_id: Some(reply.id()),
+            content: "Valeu".into()
+        }).await;
+
+        assert_eq!(&StatusCode::BAD_REQUEST, nested_reply.unwrap_err().code());
+
+        let other_writer = service.exec(CreateArticleNoteParams {
+            user_id: Uuid::new_v4(),
+            user_role: Role::Writer,
+            article_id: article.id(),
+            parent_id: None,
+            content: "Oi".into()
+        }).await;
+
+        assert_eq!(&StatusCode::UNAUTHORIZED, other_writer.unwrap_err().code());
+        assert_eq!(2, note_db.lock().unwrap().len());
+    }
+}
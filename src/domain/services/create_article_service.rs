@@ -1,9 +1,15 @@
 use uuid::Uuid;
 
 use crate::domain::domain_entities::article::Article;
+use crate::domain::domain_entities::article_block::ArticleBlock;
+use crate::domain::domain_entities::article_content::ArticleContent;
+use crate::domain::domain_entities::mention::MentionSource;
+use crate::domain::politics::article_politics::ArticlePolitics;
 use crate::domain::repositories::article_repository::ArticleRepositoryTrait;
 use crate::domain::repositories::article_tag_repository::ArticleTagRepositoryTrait;
+use crate::domain::repositories::mention_repository::MentionRepositoryTrait;
 use crate::domain::repositories::user_repository::UserRepositoryTrait;
+use crate::domain::politics::mention_politics::MentionPolitics;
 use crate::errors::bad_request_error::BadRequestError;
 use crate::errors::error::DomainErrorTrait;
 use crate::errors::unauthorized_error::UnauthorizedError;
@@ -16,33 +22,39 @@ pub struct CreateArticleParams {
     pub cover_url: String,
     pub title: String,
     pub content: String,
+    pub blocks: Option<Vec<ArticleBlock>>,
     pub tag_id: i32,
 }
 pub struct CreateArticleService<
     ArticleRepository: ArticleRepositoryTrait,
     ArticleTagRepository: ArticleTagRepositoryTrait,
-    UserRepository: UserRepositoryTrait
+    UserRepository: UserRepositoryTrait,
+    MentionRepository: MentionRepositoryTrait
 > {
     article_repository: Box<ArticleRepository>,
     article_tag_repository: Box<ArticleTagRepository>,
-    user_repository: Box<UserRepository>
+    user_repository: Box<UserRepository>,
+    mention_repository: Box<MentionRepository>
 }
 
 impl<
     ArticleRepository: ArticleRepositoryTrait,
     ArticleTagRepository: ArticleTagRepositoryTrait,
     UserRepository: UserRepositoryTrait,
-> CreateArticleService<ArticleRepository, ArticleTagRepository, UserRepository>
+    MentionRepository: MentionRepositoryTrait,
+> CreateArticleService<ArticleRepository, ArticleTagRepository, UserRepository, MentionRepository>
 {
     pub fn new(
         article_repository: Box<ArticleRepository>,
         article_tag_repository: Box<ArticleTagRepository>,
-        user_repository: Box<UserRepository>
+        user_repository: Box<UserRepository>,
+        mention_repository: Box<MentionRepository>
     ) -> Self {
         CreateArticleService {
             article_repository,
             article_tag_repository,
             user_repository,
+            mention_repository,
         }
     }
 
@@ -69,6 +81,12 @@ impl<
             }
         };
 
+        let blocks = params.blocks.filter(|blocks| !blocks.is_empty());
+
+        if params.content.trim().is_empty() && blocks.is_none() {
+            return Err(Box::new(BadRequestError::new_with_message("Article must have either content or blocks.".into())));
+        }
+
         let tag = self.article_tag_repository.find_by_id(params.tag_id).await;
 
         if tag.is_err() {
@@ -85,15 +103,18 @@ impl<
 
         let tag = tag.unwrap();
 
-        let article = Article::new(
+        let mut article = Article::new(
             author_id,
             params.title,
             params.content,
+            blocks,
             params.cover_url,
             tag.id(),
             tag.value().to_owned()
         );
 
+        ArticlePolitics::refresh_reading_metadata(&mut article);
+
         let response = self.article_repository.create(article).await;
 
         if response.is_err() {
@@ -103,8 +124,18 @@ impl<
                 &err
             ))
         }
-        
-        return Ok(response.unwrap());
+
+        let article = response.unwrap();
+
+        MentionPolitics::sync_mentions(
+            self.user_repository.as_ref(),
+            self.mention_repository.as_ref(),
+            MentionSource::Article(article.id()),
+            article.author_id(),
+            &ArticleContent::from_article(&article).plain_text(),
+        ).await;
+
+        return Ok(article);
     }
 }
 
@@ -114,8 +145,10 @@ mod test {
     use std::sync::{Arc, Mutex};
     use crate::domain::{domain_entities::{user::User, role::Role}, repositories::user_repository::MockUserRepositoryTrait};
     use crate::domain::domain_entities::article_tag::ArticleTag;
+    use crate::domain::domain_entities::article_block::ArticleBlock;
     use crate::domain::repositories::article_tag_repository::MockArticleTagRepositoryTrait;
     use crate::tests::repositories::article_repository::get_article_repository;
+    use crate::tests::repositories::mention_repository::get_mention_repository;
     use super::CreateArticleParams;
 
     #[tokio::test]
@@ -123,14 +156,16 @@ mod test {
         let (_article_db, mocked_article_repo) = get_article_repository();
         let mut mocked_tag_repo: MockArticleTagRepositoryTrait = MockArticleTagRepositoryTrait::new();
         let mut mocked_user_repo: MockUserRepositoryTrait = MockUserRepositoryTrait::new();
+        let (mention_db, mocked_mention_repo) = get_mention_repository();
 
         let tag_db: Arc<Mutex<Vec<ArticleTag>>> = Arc::new(Mutex::new(vec![]));
         let user_db: Arc<Mutex<Vec<User>>> = Arc::new(Mutex::new(vec![]));
 
         let user = User::new("Kaio".into(), "123".into(), Some(Role::Writer));
-        let tag = ArticleTag::new_from_existing(1, "News".into());
+        let mentioned_user = User::new("Vamp".into(), "123".into(), Some(Role::User));
+        let tag = ArticleTag::new_from_existing(1, "News".into(), 1);
 
-        user_db.lock().unwrap().push(user.clone());
+        user_db.lock().unwrap().extend([user.clone(), mentioned_user.clone()]);
         tag_db.lock().unwrap().push(tag.clone());
 
         let db = Arc::clone(&tag_db);
@@ -161,21 +196,61 @@ mod test {
             return Ok(None);
         });
 
+        let db_clone = Arc::clone(&user_db);
+        mocked_user_repo
+        .expect_find_by_nickname()
+        .returning(move |nickname| {
+            Ok(db_clone.lock().unwrap().iter().find(|user| user.nickname().eq_ignore_ascii_case(nickname)).cloned())
+        });
+
         let service = super::CreateArticleService {
             article_repository: Box::new(mocked_article_repo),
             article_tag_repository: Box::new(mocked_tag_repo),
-            user_repository: Box::new(mocked_user_repo)
+            user_repository: Box::new(mocked_user_repo),
+            mention_repository: Box::new(mocked_mention_repo)
         };
 
         let result = service.exec(CreateArticleParams {
             custom_author_id: None,
             staff_id: user.id(),
             content: "Conteúdo do artigo aqui".to_string(),
+            blocks: None,
+            cover_url: "https://i.imgur.com/fodase".to_string(),
+            title: "Fake title".to_string(),
+            tag_id: tag.id(),
+        }).await;
+
+        let article = result.unwrap();
+
+        assert_eq!("Conteúdo do artigo aqui", article.content());
+        assert_eq!(4, article.word_count());
+        assert_eq!(1, article.reading_time());
+
+        let empty_result = service.exec(CreateArticleParams {
+            custom_author_id: None,
+            staff_id: user.id(),
+            content: "".to_string(),
+            blocks: Some(vec![]),
+            cover_url: "https://i.imgur.com/fodase".to_string(),
+            title: "Fake title".to_string(),
+            tag_id: tag.id(),
+        }).await;
+
+        assert!(empty_result.is_err());
+
+        let blocks = vec![ArticleBlock::Paragraph { text: "Conteúdo em blocos com @Vamp".into() }];
+
+        let blocks_result = service.exec(CreateArticleParams {
+            custom_author_id: None,
+            staff_id: user.id(),
+            content: "".to_string(),
+            blocks: Some(blocks.clone()),
             cover_url: "https://i.imgur.com/fodase".to_string(),
             title: "Fake title".to_string(),
             tag_id: tag.id(),
         }).await;
 
-        assert_eq!("Conteúdo do artigo aqui", result.unwrap().content());
+        assert_eq!(Some(blocks.as_slice()), blocks_result.unwrap().blocks());
+        assert_eq!(vec![mentioned_user.id()], mention_db.lock().unwrap().iter().map(|mention| mention.user_id()).collect::<Vec<_>>(), "Mentions in blocks should be recorded too.");
     }
 }
@@ -3,39 +3,51 @@ use uuid::Uuid;
 
 use crate::domain::domain_entities::comment_report::DraftCommentReport;
 use crate::domain::domain_entities::comment_report::CommentReport;
+use crate::domain::domain_entities::comment_report::ReportReason;
+use crate::domain::politics::word_filter_politics::WordFilterPolitics;
 use crate::domain::repositories::comment_repository::CommentRepositoryTrait;
 use crate::domain::repositories::comment_report_repository::CommentReportRepositoryTrait;
+use crate::domain::repositories::word_filter_repository::WordFilterRepositoryTrait;
 use crate::errors::bad_request_error::BadRequestError;
+use crate::errors::conflict_error::ConflictError;
 use crate::errors::error::DomainErrorTrait;
 use crate::errors::internal_error::InternalError;
+use crate::util::generate_service_internal_error;
 
 use crate::{LOG_SEP, R_EOL};
 
 pub struct CreateCommentReportParams {
     pub user_id: Uuid,
     pub comment_id: Uuid,
-    pub content: String,
+    pub reason: ReportReason,
+    /// Free text detailing the report, required when the reason is `Other`.
+    pub content: Option<String>,
 }
 pub struct CreateCommentReportService<
 CR: CommentRepositoryTrait,
 CRR: CommentReportRepositoryTrait,
+WFR: WordFilterRepositoryTrait,
 > {
     comment_repository: Box<CR>,
-    comment_report_repository: Box<CRR>
+    comment_report_repository: Box<CRR>,
+    word_filter_repository: Box<WFR>
 }
 
 impl<
 CR: CommentRepositoryTrait,
-CRR: CommentReportRepositoryTrait
+CRR: CommentReportRepositoryTrait,
+WFR: WordFilterRepositoryTrait
 >
-CreateCommentReportService<CR, CRR> {
+CreateCommentReportService<CR, CRR, WFR> {
     pub fn new(
         comment_repository: Box<CR>,
-        comment_report_repository: Box<CRR>
+        comment_report_repository: Box<CRR>,
+        word_filter_repository: Box<WFR>
     ) -> Self {
         CreateCommentReportService {
             comment_repository,
-            comment_report_repository
+            comment_report_repository,
+            word_filter_repository
         }
     }
 
@@ -64,20 +76,59 @@ CreateCommentReportService<CR, CRR> {
         let comment_on_db = comment_on_db.unwrap();
         let comment_id = comment_on_db.id();
 
+        let content = params.content.unwrap_or_default();
+
+        if params.reason == ReportReason::Other && content.trim().is_empty() {
+            return Err(Box::new(BadRequestError::new_with_message(
+                "Reports with the \"other\" reason must describe the issue.".into()
+            )));
+        }
+
+        let existing_report = self.comment_report_repository.find_by_reporter(comment_id, params.user_id).await;
+
+        if existing_report.is_err() {
+            error!(
+                "{R_EOL}{LOG_SEP}{R_EOL}Error occurred on Create Comment Report Service, while checking for the user's previous report:{R_EOL}{}{R_EOL}{LOG_SEP}{R_EOL}",
+                existing_report.as_ref().unwrap_err()
+            );
+            return Err(Box::new(InternalError::new()));
+        }
+
+        if existing_report.unwrap().is_some() {
+            return Err(Box::new(ConflictError::new_with_message(
+                "You already reported this comment.".into()
+            )));
+        }
+
+        let word_filters = self.word_filter_repository.find_all().await;
+
+        if word_filters.is_err() {
+            error!(
+                "{R_EOL}{LOG_SEP}{R_EOL}Error occurred on Create Comment Report Service, while fetching the word filters:{R_EOL}{}{R_EOL}{LOG_SEP}{R_EOL}",
+                word_filters.as_ref().unwrap_err()
+            );
+            return Err(Box::new(InternalError::new()));
+        }
+
+        // reports aren't public, so there is nothing to deactivate: only masking and rejecting apply
+        let filtered = WordFilterPolitics::apply(&word_filters.unwrap(), &content)
+            .map_err(|err| Box::new(err) as Box<dyn DomainErrorTrait>)?;
+
         let comment_report = DraftCommentReport::new(
             comment_id,
             params.user_id,
-            params.content,
+            params.reason,
+            filtered.content,
         );
 
+        // the previous report may have been created in the meantime, which the repository reports as a conflict
         let response = self.comment_report_repository.create(comment_report).await;
 
-        if response.is_err() {
-            error!(
-                "{R_EOL}{LOG_SEP}{R_EOL}Error occurred on Create Comment Report Service, while creating the comment report:{R_EOL}{}{R_EOL}{LOG_SEP}{R_EOL}",
-                response.as_ref().unwrap_err()
-            );
-            return Err(Box::new(InternalError::new()));
+        if let Err(err) = response {
+            return Err(generate_service_internal_error(
+                "Error occurred on Create Comment Report Service, while creating the comment report",
+                &err,
+            ));
         }
         
         return Ok(response.unwrap());
@@ -95,10 +146,14 @@ mod test {
     use crate::domain::domain_entities::comment::Comment;
     use crate::domain::domain_entities::comment_report::CommentReportTrait;
     use crate::domain::domain_entities::comment_report::DraftCommentReport;
+    use crate::domain::domain_entities::comment_report::ReportReason;
     use crate::domain::repositories::comment_repository::MockCommentRepositoryTrait;
     use crate::domain::repositories::comment_report_repository::MockCommentReportRepositoryTrait;
+    use crate::domain::domain_entities::word_filter::{WordFilter, WordFilterAction};
+    use crate::errors::conflict_error::ConflictError;
     use crate::libs::time::TimeHelper;
-    use super::{CommentReport, CreateCommentReportParams};
+    use crate::tests::repositories::word_filter_repository::get_word_filter_repository;
+    use super::{CommentReport, CreateCommentReportParams, DomainErrorTrait};
 
     #[tokio::test]
     async fn test() {
@@ -118,12 +173,24 @@ mod test {
                 Uuid::new_v4(),
                 "notíca de um autor de merda fodido".into(),
                 true,
-                TimeHelper::now()
+                TimeHelper::now(),
+                None,
+                None,
+                None,
             );
 
             Ok(Some(fake_comm))
         });
 
+        let db_clone = Arc::clone(&db);
+        mocked_comment_report_repo
+        .expect_find_by_reporter()
+        .returning(move |comment_id, user_id| {
+            Ok(db_clone.lock().unwrap().iter().find(|report| {
+                report.comment_id() == comment_id && report.user_id() == user_id
+            }).cloned())
+        });
+
         let db_clone = Arc::clone(&db);
         mocked_comment_report_repo
         .expect_create()
@@ -135,21 +202,106 @@ mod test {
         })
         .times(1);
 
+        let (word_filter_db, mocked_word_filter_repo) = get_word_filter_repository();
+
+        word_filter_db.lock().unwrap().extend([
+            WordFilter::new("merda".into(), false, WordFilterAction::Mask),
+            WordFilter::new("fodido".into(), false, WordFilterAction::Deactivate),
+            WordFilter::new("golpe".into(), false, WordFilterAction::Reject),
+        ]);
+
         let service = super::CreateCommentReportService {
             comment_repository: Box::new(mocked_comment_repo),
-            comment_report_repository: Box::new(mocked_comment_report_repo)
+            comment_report_repository: Box::new(mocked_comment_report_repo),
+            word_filter_repository: Box::new(mocked_word_filter_repo)
         };
 
+        let comment_id = Uuid::new_v4();
+        let user_id = Uuid::new_v4();
+
         let result = service.exec(CreateCommentReportParams {
-            comment_id: Uuid::new_v4(),
-            user_id: Uuid::new_v4(),
-            content: "Esse comentário é tóxico e ofensivo.".into()
+            comment_id,
+            user_id,
+            reason: ReportReason::Offensive,
+            content: Some("Esse comentário é tóxico e ofensivo, chama o autor de merda fodido.".into())
         }).await;
 
         let result = result.unwrap();
 
-        assert_eq!("Esse comentário é tóxico e ofensivo.", result.message());
+        assert_eq!("Esse comentário é tóxico e ofensivo, chama o autor de ***** fodido.", result.message());
 
+        assert_eq!(ReportReason::Offensive, result.reason());
         assert_eq!(result, db.lock().unwrap()[0]);
+
+        let duplicated = service.exec(CreateCommentReportParams {
+            comment_id,
+            user_id,
+            reason: ReportReason::Spam,
+            content: None
+        }).await;
+
+        assert_eq!(&409, duplicated.unwrap_err().code());
+
+        let undescribed = service.exec(CreateCommentReportParams {
+            comment_id: Uuid::new_v4(),
+            user_id: Uuid::new_v4(),
+            reason: ReportReason::Other,
+            content: None
+        }).await;
+
+        assert_eq!(&400, undescribed.unwrap_err().code());
+
+        let rejected = service.exec(CreateCommentReportParams {
+            comment_id: Uuid::new_v4(),
+            user_id: Uuid::new_v4(),
+            reason: ReportReason::ScamPhishing,
+            content: Some("Caiu no golpe".into())
+        }).await;
+
+        assert!(rejected.is_err());
+    }
+
+    #[tokio::test]
+    async fn test_if_a_report_created_in_the_meantime_is_a_conflict() {
+        let mut mocked_comment_repo: MockCommentRepositoryTrait = MockCommentRepositoryTrait::new();
+        let mut mocked_comment_report_repo: MockCommentReportRepositoryTrait = MockCommentReportRepositoryTrait::new();
+
+        mocked_comment_repo
+        .expect_find_by_id()
+        .returning(|id| Ok(Some(Comment::new_from_existing(
+            id,
+            Some(Uuid::new_v4()),
+            Uuid::new_v4(),
+            "Spam".into(),
+            true,
+            TimeHelper::now(),
+            None,
+            None,
+            None,
+        ))));
+
+        mocked_comment_report_repo
+        .expect_find_by_reporter()
+        .returning(|_, _| Ok(None));
+
+        // the unique constraint caught a report created after the check
+        mocked_comment_report_repo
+        .expect_create()
+        .returning(|_| Err(Box::new(ConflictError::new_with_message("You already reported this comment.".into()))));
+
+        let service = super::CreateCommentReportService {
+            comment_repository: Box::new(mocked_comment_repo),
+            comment_report_repository: Box::new(mocked_comment_report_repo),
+            word_filter_repository: Box::new(get_word_filter_repository().1)
+        };
+
+        let result = service.exec(CreateCommentReportParams {
+            comment_id: Uuid::new_v4(),
+            user_id: Uuid::new_v4(),
+            reason: ReportReason::Spam,
+            content: None
+        }).await;
+
+        assert_eq!(&409, result.unwrap_err().code());
     }
 }
@@ -3,10 +3,12 @@ use log::error;
 use crate::domain::cryptography::hasher::HasherTrait;
 use crate::domain::domain_entities::role::Role;
 use crate::domain::domain_entities::user::User;
+use crate::domain::politics::word_filter_politics::WordFilterPolitics;
 use crate::errors::error::DomainErrorTrait;
 use crate::errors::internal_error::InternalError;
 use crate::errors::user_already_exists_error::UserAlreadyExistsError;
 use crate::domain::repositories::user_repository::UserRepositoryTrait;
+use crate::domain::repositories::word_filter_repository::WordFilterRepositoryTrait;
 
 use crate::{LOG_SEP, R_EOL};
 
@@ -14,15 +16,22 @@ pub struct CreateUserParams {
     pub nickname: String,
     pub password: String,
 }
-pub struct CreateUserService<UserRepository: UserRepositoryTrait> {
+pub struct CreateUserService<UserRepository: UserRepositoryTrait, WordFilterRepository: WordFilterRepositoryTrait> {
     user_repository: Box<UserRepository>,
+    word_filter_repository: Box<WordFilterRepository>,
     hasher: Box<dyn HasherTrait>
 }
 
-impl<UserRepositoryType : UserRepositoryTrait> CreateUserService<UserRepositoryType> {
-    pub fn new(user_repository: Box<UserRepositoryType>, hasher: Box<dyn HasherTrait>) -> Self {
+impl<UserRepositoryType : UserRepositoryTrait, WordFilterRepositoryType: WordFilterRepositoryTrait>
+CreateUserService<UserRepositoryType, WordFilterRepositoryType> {
+    pub fn new(
+        user_repository: Box<UserRepositoryType>,
+        word_filter_repository: Box<WordFilterRepositoryType>,
+        hasher: Box<dyn HasherTrait>
+    ) -> Self {
         CreateUserService {
             user_repository,
+            word_filter_repository,
             hasher,
         }
     }
@@ -33,6 +42,21 @@ impl<UserRepositoryType : UserRepositoryTrait> CreateUserService<UserRepositoryT
 
     #[inline]
     async fn create(&self, params: CreateUserParams, role: Role) -> Result<User, Box<dyn DomainErrorTrait>> {
+        let word_filters = self.word_filter_repository.find_all().await;
+
+        if word_filters.is_err() {
+            error!(
+                "{R_EOL}{LOG_SEP}{R_EOL}Error occurred on Create User Service, while fetching the word filters:{R_EOL}{}{R_EOL}{LOG_SEP}{R_EOL}",
+                word_filters.as_ref().unwrap_err()
+            );
+
+            return Err( Box::new( InternalError::new() ) );
+        }
+
+        if let Err(err) = WordFilterPolitics::verify_nickname(&word_filters.unwrap(), &params.nickname) {
+            return Err( Box::new(err) );
+        }
+
         let user_on_db = self.user_repository.find_by_nickname(&params.nickname).await;
 
         if user_on_db.is_err() {
@@ -72,6 +96,8 @@ impl<UserRepositoryType : UserRepositoryTrait> CreateUserService<UserRepositoryT
 #[cfg(test)]
 mod test {
     use crate::domain::{repositories::user_repository::MockUserRepositoryTrait, cryptography::hasher::MockHasherTrait};
+    use crate::domain::domain_entities::word_filter::{WordFilter, WordFilterAction};
+    use crate::tests::repositories::word_filter_repository::get_word_filter_repository;
     use super::{User, CreateUserParams};
 
     #[tokio::test]
@@ -97,8 +123,13 @@ mod test {
             format!("{}--hashed", param_password)
         });
 
+        let (word_filter_db, mocked_word_filter_repo) = get_word_filter_repository();
+
+        word_filter_db.lock().unwrap().push(WordFilter::new("staff".into(), true, WordFilterAction::Mask));
+
         let service = super::CreateUserService {
             user_repository: Box::new(mocked_repo),
+            word_filter_repository: Box::new(mocked_word_filter_repo),
             hasher: Box::new(mocked_hasher)
         };
 
@@ -108,5 +139,12 @@ mod test {
         }).await;
 
         assert_eq!("Madalena", result.unwrap().nickname());
+
+        let filtered_nickname = service.exec(CreateUserParams {
+            nickname: "Falso-STAFF".to_string(),
+            password: "madalena123".to_string()
+        }).await;
+
+        assert!(filtered_nickname.is_err(), "Nicknames matching any word filter should be refused.");
     }
 }
\ No newline at end of file
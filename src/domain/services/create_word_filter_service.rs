@@ -0,0 +1,92 @@
+use crate::domain::domain_entities::role::Role;
+use crate::domain::domain_entities::word_filter::{WordFilter, WordFilterAction};
+use crate::domain::repositories::word_filter_repository::WordFilterRepositoryTrait;
+use crate::errors::bad_request_error::BadRequestError;
+use crate::errors::error::DomainErrorTrait;
+use crate::errors::unauthorized_error::UnauthorizedError;
+use crate::util::{generate_service_internal_error, RolePermissions, verify_role_has_permission};
+
+pub struct CreateWordFilterParams {
+    pub user_role: Role,
+    pub pattern: String,
+    pub is_regex: bool,
+    pub action: WordFilterAction,
+}
+
+pub struct CreateWordFilterService<WordFilterRepository: WordFilterRepositoryTrait> {
+    word_filter_repository: Box<WordFilterRepository>
+}
+
+impl<WordFilterRepository: WordFilterRepositoryTrait> CreateWordFilterService<WordFilterRepository> {
+    pub fn new(word_filter_repository: Box<WordFilterRepository>) -> Self {
+        CreateWordFilterService {
+            word_filter_repository
+        }
+    }
+
+    pub async fn exec(&self, params: CreateWordFilterParams) -> Result<WordFilter, Box<dyn DomainErrorTrait>> {
+        let user_can_manage_word_filters = verify_role_has_permission(&params.user_role, RolePermissions::ManageWordFilters);
+
+        if !user_can_manage_word_filters {
+            return Err(Box::new(UnauthorizedError::new()));
+        }
+
+        let word_filter = WordFilter::new(params.pattern, params.is_regex, params.action);
+
+        if let Err(err) = word_filter.compile() {
+            return Err(Box::new(BadRequestError::new_with_message(format!("Invalid pattern: {err}"))));
+        }
+
+        let word_filter = self.word_filter_repository.create(word_filter).await;
+
+        if word_filter.is_err() {
+            return Err(generate_service_internal_error(
+                "Error occurred in Create Word Filter Service, on creating the word filter in the database".into(),
+                &word_filter.unwrap_err()
+            ));
+        }
+
+        Ok(word_filter.unwrap())
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::tests::repositories::word_filter_repository::get_word_filter_repository;
+
+    #[tokio::test]
+    async fn test_if_moderators_can_create_valid_word_filters() {
+        let (word_filters_db, word_filter_repository) = get_word_filter_repository();
+        let sut = CreateWordFilterService::new(Box::new(word_filter_repository));
+
+        let result = sut.exec(CreateWordFilterParams {
+            user_role: Role::Coord,
+            pattern: r"c[4a]ss[i1]no".into(),
+            is_regex: true,
+            action: WordFilterAction::Reject,
+        }).await;
+
+        assert!(result.is_ok());
+        assert_eq!(word_filters_db.lock().unwrap()[0], result.unwrap());
+
+        let invalid_regex = sut.exec(CreateWordFilterParams {
+            user_role: Role::Coord,
+            pattern: "(unclosed".into(),
+            is_regex: true,
+            action: WordFilterAction::Mask,
+        }).await;
+
+        assert_eq!(&400, invalid_regex.unwrap_err().code());
+
+        let unauthorized = sut.exec(CreateWordFilterParams {
+            user_role: Role::Editor,
+            pattern: "bobba".into(),
+            is_regex: false,
+            action: WordFilterAction::Mask,
+        }).await;
+
+        assert!(unauthorized.is_err());
+        assert_eq!(1, word_filters_db.lock().unwrap().len());
+    }
+}
@@ -126,6 +126,7 @@ mod test {
             Uuid::new_v4(),
             "Título inicial".to_string(),
             "Conteúdo inicial".to_string(),
+            None,
             "coverurl.inicial".to_string(),
             1,
             "Foo".into(),
@@ -158,7 +159,8 @@ mod test {
                     "password".to_string(),
                     TimeHelper::now(),
                     None,
-                    Some(Role::Principal)
+                    Some(Role::Principal),
+                    1
                 );
 
                 Ok(Some(fake_user))
@@ -67,7 +67,7 @@ mod test {
         let (tag_db, tag_repository) = get_article_tag_repository();
         let sut = super::DeleteArticleTagService::new(tag_repository);
 
-        tag_db.lock().unwrap().push(ArticleTag::new_from_existing(1, "Foo".into()));
+        tag_db.lock().unwrap().push(ArticleTag::new_from_existing(1, "Foo".into(), 1));
 
         let response = sut.exec(DeleteArticleTagParams {
             tag_id: 1,
@@ -83,7 +83,7 @@ mod test {
         let (tag_db, tag_repository) = get_article_tag_repository();
         let sut = super::DeleteArticleTagService::new(tag_repository);
 
-        tag_db.lock().unwrap().push(ArticleTag::new_from_existing(1, "Foo".into()));
+        tag_db.lock().unwrap().push(ArticleTag::new_from_existing(1, "Foo".into(), 1));
 
         let response = sut.exec(DeleteArticleTagParams {
             tag_id: 1,
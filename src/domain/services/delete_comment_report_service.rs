@@ -73,7 +73,7 @@ mod test {
     use super::*;
     use std::sync::{Arc, Mutex};
 
-    use crate::domain::domain_entities::comment_report::{CommentReport, CommentReportIdTrait};
+    use crate::domain::domain_entities::comment_report::{CommentReport, ReportReason, CommentReportIdTrait};
     use crate::domain::repositories::comment_report_repository::MockCommentReportRepositoryTrait;
     use crate::libs::time::TimeHelper;
 
@@ -92,6 +92,7 @@ mod test {
             1,
             Uuid::new_v4(),
             Uuid::new_v4(),
+            ReportReason::Offensive,
             "Esse comentário é tóxico.".into(),
             None,
             TimeHelper::now()
@@ -103,6 +104,7 @@ mod test {
             2,
             Uuid::new_v4(),
             Uuid::new_v4(),
+            ReportReason::Offensive,
             "Estão me ofendendo neste comentário!".into(),
             None,
             TimeHelper::now()
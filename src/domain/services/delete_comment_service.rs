@@ -90,6 +90,7 @@ mod test {
             Uuid::new_v4(),
             Some(Uuid::new_v4()),
             "Conteúdo inicial".to_string(),
+            None,
         );
 
         let comment_db: Arc<Mutex<Vec<Comment>>> = Arc::new(Mutex::new(vec![
@@ -0,0 +1,89 @@
+use uuid::Uuid;
+use crate::domain::domain_entities::role::Role;
+use crate::domain::repositories::word_filter_repository::WordFilterRepositoryTrait;
+use crate::errors::error::DomainErrorTrait;
+use crate::errors::resource_not_found::ResourceNotFoundError;
+use crate::errors::unauthorized_error::UnauthorizedError;
+use crate::util::{generate_service_internal_error, RolePermissions, verify_role_has_permission};
+
+pub struct DeleteWordFilterParams {
+    pub user_role: Role,
+    pub word_filter_id: Uuid
+}
+
+pub struct DeleteWordFilterService<WordFilterRepository: WordFilterRepositoryTrait> {
+    word_filter_repository: Box<WordFilterRepository>
+}
+
+impl<WordFilterRepository: WordFilterRepositoryTrait> DeleteWordFilterService<WordFilterRepository> {
+    pub fn new(word_filter_repository: Box<WordFilterRepository>) -> Self {
+        DeleteWordFilterService {
+            word_filter_repository
+        }
+    }
+
+    pub async fn exec(&self, params: DeleteWordFilterParams) -> Result<(), Box<dyn DomainErrorTrait>> {
+        let user_can_manage_word_filters = verify_role_has_permission(&params.user_role, RolePermissions::ManageWordFilters);
+
+        if !user_can_manage_word_filters {
+            return Err(Box::new(UnauthorizedError::new()));
+        }
+
+        let word_filter = self.word_filter_repository.find_by_id(params.word_filter_id).await;
+
+        if word_filter.is_err() {
+            return Err(generate_service_internal_error(
+                "Error occurred in Delete Word Filter Service, on finding the word filter by id".into(),
+                &word_filter.unwrap_err()
+            ));
+        }
+
+        let word_filter = match word_filter.unwrap() {
+            Some(word_filter) => word_filter,
+            None => return Err(Box::new(ResourceNotFoundError::new())),
+        };
+
+        let deleted = self.word_filter_repository.delete(word_filter).await;
+
+        if deleted.is_err() {
+            return Err(generate_service_internal_error(
+                "Error occurred in Delete Word Filter Service, on deleting the word filter from database".into(),
+                &deleted.unwrap_err()
+            ));
+        }
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::domain::domain_entities::word_filter::{WordFilter, WordFilterAction};
+    use crate::tests::repositories::word_filter_repository::get_word_filter_repository;
+
+    #[tokio::test]
+    async fn test_if_only_moderators_can_delete_word_filters() {
+        let (word_filters_db, word_filter_repository) = get_word_filter_repository();
+        let sut = DeleteWordFilterService::new(Box::new(word_filter_repository));
+
+        let word_filter = WordFilter::new("bobba".into(), false, WordFilterAction::Mask);
+        word_filters_db.lock().unwrap().push(word_filter.clone());
+
+        let unauthorized = sut.exec(DeleteWordFilterParams {
+            user_role: Role::Writer,
+            word_filter_id: word_filter.id(),
+        }).await;
+
+        assert!(unauthorized.is_err());
+        assert_eq!(1, word_filters_db.lock().unwrap().len());
+
+        let result = sut.exec(DeleteWordFilterParams {
+            user_role: Role::Admin,
+            word_filter_id: word_filter.id(),
+        }).await;
+
+        assert!(result.is_ok());
+        assert!(word_filters_db.lock().unwrap().is_empty());
+    }
+}
@@ -0,0 +1,341 @@
+use chrono::NaiveDateTime;
+use log::info;
+use uuid::Uuid;
+
+use crate::domain::domain_entities::comment::Comment;
+use crate::domain::domain_entities::comment_revision::CommentRevision;
+use crate::domain::domain_entities::mention::MentionSource;
+use crate::domain::politics::comment_politics::CommentPolitics;
+use crate::domain::politics::comment_spam_politics::{CommentSpamContext, CommentSpamFilter};
+use crate::domain::politics::word_filter_politics::WordFilterPolitics;
+use crate::domain::repositories::comment_repository::CommentRepositoryTrait;
+use crate::domain::repositories::mention_repository::MentionRepositoryTrait;
+use crate::domain::repositories::user_repository::UserRepositoryTrait;
+use crate::domain::repositories::word_filter_repository::WordFilterRepositoryTrait;
+use crate::domain::politics::mention_politics::MentionPolitics;
+use crate::errors::bad_request_error::BadRequestError;
+use crate::errors::error::DomainErrorTrait;
+use crate::errors::resource_not_found::ResourceNotFoundError;
+use crate::errors::unauthorized_error::UnauthorizedError;
+use crate::libs::time::TimeHelper;
+use crate::util::generate_service_internal_error;
+
+pub struct EditCommentParams {
+    pub user_id: Uuid,
+    pub comment_id: Uuid,
+    pub content: String,
+}
+
+pub struct EditCommentService<CommentRepository, UserRepository, MentionRepository, WordFilterRepository>
+where
+    CommentRepository: CommentRepositoryTrait,
+    UserRepository: UserRepositoryTrait,
+    MentionRepository: MentionRepositoryTrait,
+    WordFilterRepository: WordFilterRepositoryTrait,
+{
+    comment_repository: Box<CommentRepository>,
+    user_repository: Box<UserRepository>,
+    mention_repository: Box<MentionRepository>,
+    word_filter_repository: Box<WordFilterRepository>,
+    edit_window_minutes: i64,
+    /// See `CommentSpamFilter::content_checks`, the author's history isn't loaded for edits.
+    spam_filter: CommentSpamFilter,
+}
+
+impl<CommentRepository, UserRepository, MentionRepository, WordFilterRepository>
+EditCommentService<CommentRepository, UserRepository, MentionRepository, WordFilterRepository>
+where
+    CommentRepository: CommentRepositoryTrait,
+    UserRepository: UserRepositoryTrait,
+    MentionRepository: MentionRepositoryTrait,
+    WordFilterRepository: WordFilterRepositoryTrait,
+{
+    pub fn new(
+        comment_repository: Box<CommentRepository>,
+        user_repository: Box<UserRepository>,
+        mention_repository: Box<MentionRepository>,
+        word_filter_repository: Box<WordFilterRepository>,
+        edit_window_minutes: i64,
+        spam_filter: CommentSpamFilter,
+    ) -> Self {
+        EditCommentService {
+            comment_repository,
+            user_repository,
+            mention_repository,
+            word_filter_repository,
+            edit_window_minutes,
+            spam_filter,
+        }
+    }
+
+    pub async fn exec(&self, params: EditCommentParams) -> Result<Comment, Box<dyn DomainErrorTrait>> {
+        let comment = self.comment_repository.find_by_id(params.comment_id).await;
+
+        if let Err(err) = comment {
+            return Err(generate_service_internal_error(
+                "Error occurred on Edit Comment Service, while fetching the comment".into(),
+                &err,
+            ));
+        }
+
+        let mut comment = match comment.unwrap() {
+            Some(comment) if comment.is_active() => comment,
+            _ => return Err(Box::new(ResourceNotFoundError::new())),
+        };
+
+        if comment.author_id() != params.user_id {
+            return Err(Box::new(UnauthorizedError::new()));
+        }
+
+        let now = TimeHelper::now();
+
+        if CommentPolitics::edit_window_is_over(&comment, now, self.edit_window_minutes) {
+            return Err(Box::new(BadRequestError::new_with_message(
+                format!("Comments can only be edited up to {} minutes after being posted.", self.edit_window_minutes)
+            )));
+        }
+
+        let word_filters = self.word_filter_repository.find_all().await;
+
+        if let Err(err) = word_filters {
+            return Err(generate_service_internal_error(
+                "Error occurred on Edit Comment Service, while fetching the word filters".into(),
+                &err,
+            ));
+        }
+
+        let filtered = WordFilterPolitics::apply(&word_filters.unwrap(), &params.content)
+            .map_err(|err| Box::new(err) as Box<dyn DomainErrorTrait>)?;
+
+        if comment.content() == filtered.content { return Ok(comment); }
+
+        let mut review_reasons = self.check_spam(comment.author_id(), &filtered.content, now).await?;
+
+        if filtered.deactivate {
+            review_reasons.push("Matched a word filter.".into());
+        }
+
+        let revision = CommentRevision::new(comment.id(), comment.content().to_owned());
+
+        comment.set_content(filtered.content);
+        comment.set_edited_at(Some(now));
+
+        if !review_reasons.is_empty() {
+            info!("Comment '{}' was hidden after an edit for moderator review: {}", comment.id(), review_reasons.join(" "));
+            comment.hold_for_review();
+        }
+
+        let result = self.comment_repository.save_with_revision(comment, revision).await;
+
+        if let Err(err) = result {
+            return Err(generate_service_internal_error(
+                "Error occurred on Edit Comment Service, while saving the comment".into(),
+                &err,
+            ));
+        }
+
+        let comment = result.unwrap();
+
+        // a hidden comment mentions nobody
+        MentionPolitics::sync_mentions(
+            self.user_repository.as_ref(),
+            self.mention_repository.as_ref(),
+            MentionSource::Comment { comment_id: comment.id(), article_id: comment.article_id() },
+            comment.author_id(),
+            if comment.is_active() { comment.content() } else { "" },
+        ).await;
+
+        Ok(comment)
+    }
+
+    /// Runs the spam filter on the new content, returning why the comment has to be reviewed, if it has to.
+    async fn check_spam(&self, author_id: Uuid, content: &str, now: NaiveDateTime) -> Result<Vec<String>, Box<dyn DomainErrorTrait>> {
+        if self.spam_filter.is_empty() { return Ok(vec![]); }
+
+        let author = self.user_repository.find_by_id(&author_id).await;
+
+        if let Err(err) = author {
+            return Err(generate_service_internal_error(
+                "Error occurred on Edit Comment Service, while fetching the author".into(),
+                &err,
+            ));
+        }
+
+        let author = match author.unwrap() {
+            Some(author) => author,
+            None => return Err(Box::new(UnauthorizedError::new())),
+        };
+
+        self.spam_filter.run(&CommentSpamContext {
+            author: &author,
+            content,
+            recent_comments: &[],
+            now,
+        })
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use std::sync::{Arc, Mutex};
+
+    use super::*;
+    use crate::domain::domain_entities::role::Role;
+    use crate::domain::domain_entities::user::User;
+    use crate::domain::politics::comment_spam_politics::CommentSpamSettings;
+    use crate::domain::repositories::comment_repository::MockCommentRepositoryTrait;
+    use crate::tests::repositories::mention_repository::get_mention_repository;
+    use crate::tests::repositories::user_repository::get_user_repository;
+    use crate::tests::repositories::word_filter_repository::get_word_filter_repository;
+
+    fn get_comment_repository(db: &Arc<Mutex<Vec<Comment>>>, revisions: &Arc<Mutex<Vec<CommentRevision>>>) -> MockCommentRepositoryTrait {
+        let mut repository = MockCommentRepositoryTrait::new();
+
+        let db_clone = Arc::clone(db);
+        repository
+            .expect_find_by_id()
+            .returning(move |id| Ok(db_clone.lock().unwrap().iter().find(|comment| comment.id() == id).cloned()));
+
+        let db_clone = Arc::clone(db);
+        let revisions_clone = Arc::clone(revisions);
+        repository
+            .expect_save_with_revision()
+            .returning(move |comment, revision| {
+                let mut db = db_clone.lock().unwrap();
+                let index = db.iter().position(|item| item.id() == comment.id()).unwrap();
+                db[index] = comment.clone();
+                revisions_clone.lock().unwrap().push(revision);
+
+                Ok(comment)
+            });
+
+        repository
+    }
+
+    #[tokio::test]
+    async fn test_author_can_edit_comment_within_the_window() {
+        let db = Arc::new(Mutex::new(vec![]));
+        let revisions = Arc::new(Mutex::new(vec![]));
+
+        let author_id = Uuid::new_v4();
+        let comment = Comment::new(author_id, Some(Uuid::new_v4()), "Typo hree".into(), None);
+        db.lock().unwrap().push(comment.clone());
+
+        let sut = EditCommentService::new(
+            Box::new(get_comment_repository(&db, &revisions)),
+            Box::new(get_user_repository().1),
+            Box::new(get_mention_repository().1),
+            Box::new(get_word_filter_repository().1),
+            15,
+            CommentSpamFilter::without_checks(),
+        );
+
+        let result = sut.exec(EditCommentParams {
+            user_id: author_id,
+            comment_id: comment.id(),
+            content: "Typo here".into(),
+        }).await.unwrap();
+
+        assert_eq!("Typo here", result.content());
+        assert!(result.edited_at().is_some());
+        assert_eq!("Typo hree", revisions.lock().unwrap()[0].content());
+
+        let not_author = sut.exec(EditCommentParams {
+            user_id: Uuid::new_v4(),
+            comment_id: comment.id(),
+            content: "Hijacked".into(),
+        }).await;
+
+        assert!(not_author.is_err(), "Only the author should be able to edit the comment.");
+    }
+
+    #[tokio::test]
+    async fn test_comment_cannot_be_edited_after_the_window() {
+        let db = Arc::new(Mutex::new(vec![]));
+        let revisions = Arc::new(Mutex::new(vec![]));
+
+        let author_id = Uuid::new_v4();
+        let comment = Comment::new_from_existing(
+            Uuid::new_v4(),
+            Some(Uuid::new_v4()),
+            author_id,
+            "Old comment".into(),
+            true,
+            TimeHelper::now() - chrono::Duration::try_minutes(16).unwrap(),
+            None,
+            None,
+            None,
+        );
+        db.lock().unwrap().push(comment.clone());
+
+        let sut = EditCommentService::new(
+            Box::new(get_comment_repository(&db, &revisions)),
+            Box::new(get_user_repository().1),
+            Box::new(get_mention_repository().1),
+            Box::new(get_word_filter_repository().1),
+            15,
+            CommentSpamFilter::without_checks(),
+        );
+
+        let result = sut.exec(EditCommentParams {
+            user_id: author_id,
+            comment_id: comment.id(),
+            content: "Rewritten after the replies".into(),
+        }).await;
+
+        assert!(result.is_err());
+        assert_eq!("Old comment", db.lock().unwrap()[0].content());
+        assert!(revisions.lock().unwrap().is_empty());
+    }
+
+    #[tokio::test]
+    async fn test_edits_go_through_the_spam_content_checks() {
+        let db = Arc::new(Mutex::new(vec![]));
+        let revisions = Arc::new(Mutex::new(vec![]));
+
+        let author = User::new_from_existing(
+            Uuid::new_v4(),
+            "Veterano".into(),
+            "123".into(),
+            TimeHelper::now() - chrono::Duration::days(30),
+            None,
+            Some(Role::User),
+            1,
+        );
+
+        let comment = Comment::new(author.id(), Some(Uuid::new_v4()), "Bom artigo".into(), None);
+        db.lock().unwrap().push(comment.clone());
+
+        let (user_db, mocked_user_repo) = get_user_repository();
+        user_db.lock().unwrap().push(author.clone());
+
+        let sut = EditCommentService::new(
+            Box::new(get_comment_repository(&db, &revisions)),
+            Box::new(mocked_user_repo),
+            Box::new(get_mention_repository().1),
+            Box::new(get_word_filter_repository().1),
+            15,
+            CommentSpamFilter::content_checks(&CommentSpamSettings::with_blocklists(Some("cassino"), Some("spam.com"))),
+        );
+
+        let edit = |content: &str| EditCommentParams {
+            user_id: author.id(),
+            comment_id: comment.id(),
+            content: content.into(),
+        };
+
+        let too_many_links = sut.exec(edit("https://a.com https://b.com https://c.com")).await;
+
+        assert_eq!(&400, too_many_links.unwrap_err().code());
+
+        let blocked_domain = sut.exec(edit("Veja em https://promo.spam.com")).await;
+
+        assert_eq!(&400, blocked_domain.unwrap_err().code());
+        assert_eq!("Bom artigo", db.lock().unwrap()[0].content());
+        assert!(revisions.lock().unwrap().is_empty());
+
+        let blocked_word = sut.exec(edit("Bom artigo sobre cassino")).await.unwrap();
+
+        assert!(!blocked_word.is_active(), "Edits with blocked words should wait for a moderator.");
+    }
+}
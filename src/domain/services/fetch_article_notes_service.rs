@@ -0,0 +1,63 @@
+use uuid::Uuid;
+
+use crate::domain::domain_entities::article_note::ArticleNote;
+use crate::domain::domain_entities::role::Role;
+use crate::domain::repositories::article_coauthor_repository::ArticleCoauthorRepositoryTrait;
+use crate::domain::repositories::article_note_repository::ArticleNoteRepositoryTrait;
+use crate::domain::repositories::article_repository::ArticleRepositoryTrait;
+use crate::errors::error::DomainErrorTrait;
+use crate::util::generate_service_internal_error;
+
+use super::create_article_note_service::find_article_with_notes_access;
+
+pub struct FetchArticleNotesParams {
+    pub user_id: Uuid,
+    pub user_role: Role,
+    pub article_id: Uuid,
+}
+
+/// Every editorial note on the article, replies included, from the oldest to the newest.
+pub struct FetchArticleNotesService<AR, ACR, ANR>
+where   AR: ArticleRepositoryTrait,
+        ACR: ArticleCoauthorRepositoryTrait,
+        ANR: ArticleNoteRepositoryTrait
+        {
+    article_repository: Box<AR>,
+    article_coauthor_repository: Box<ACR>,
+    article_note_repository: Box<ANR>,
+}
+
+impl<
+    AR: ArticleRepositoryTrait,
+    ACR: ArticleCoauthorRepositoryTrait,
+    ANR: ArticleNoteRepositoryTrait
+> FetchArticleNotesService<AR, ACR, ANR> {
+    pub fn new(article_repository: Box<AR>, article_coauthor_repository: Box<ACR>, article_note_repository: Box<ANR>) -> Self {
+        FetchArticleNotesService {
+            article_repository,
+            article_coauthor_repository,
+            article_note_repository
+        }
+    }
+
+    pub async fn exec(&self, params: FetchArticleNotesParams) -> Result<Vec<ArticleNote>, Box<dyn DomainErrorTrait>> {
+        let article = find_article_with_notes_access(
+            self.article_repository.as_ref(),
+            self.article_coauthor_repository.as_ref(),
+            params.article_id,
+            params.user_id,
+            &params.user_role
+        ).await?;
+
+        let notes = self.article_note_repository.find_many_by_article(article.id()).await;
+
+        if notes.is_err() {
+            return Err(generate_service_internal_error(
+                "Error occurred on Fetch Article Notes Service, while finding the article's notes".into(),
+                &notes.unwrap_err()
+            ));
+        }
+
+        Ok(notes.unwrap())
+    }
+}
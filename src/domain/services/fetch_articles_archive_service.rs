@@ -0,0 +1,88 @@
+use crate::domain::repositories::article_repository::{ArticleArchiveMonth, ArticleRepositoryTrait};
+use crate::errors::error::DomainErrorTrait;
+use crate::util::generate_service_internal_error;
+
+/**
+ # Fetch Articles Archive Service
+ The months in which approved articles were published, with how many of them, from the newest to the oldest.
+ Each month's articles are then listed by `FetchManyArticlesService`, with `ServiceArticleQueryType::Month`.
+ */
+pub struct FetchArticlesArchiveService<AR: ArticleRepositoryTrait> {
+    article_repository: Box<AR>,
+}
+
+impl<AR: ArticleRepositoryTrait> FetchArticlesArchiveService<AR> {
+    pub fn new(article_repository: Box<AR>) -> Self {
+        FetchArticlesArchiveService {
+            article_repository
+        }
+    }
+
+    pub async fn exec(&self) -> Result<Vec<ArticleArchiveMonth>, Box<dyn DomainErrorTrait>> {
+        let months = self.article_repository.count_approved_by_month().await;
+
+        if months.is_err() {
+            return Err(generate_service_internal_error(
+                "Error occurred on Fetch Articles Archive Service, while counting the approved articles by month",
+                &months.unwrap_err()
+            ));
+        }
+
+        Ok(months.unwrap())
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use chrono::NaiveDate;
+    use uuid::Uuid;
+
+    use crate::domain::domain_entities::article::Article;
+    use crate::domain::domain_entities::slug::Slug;
+    use crate::tests::repositories::article_repository::get_article_repository;
+
+    fn article_created_at(year: i32, month: u32, approved: bool) -> Article {
+        let id = Uuid::new_v4();
+
+        Article::new_from_existing(
+            id,
+            Uuid::new_v4(),
+            "url".into(),
+            "Notícia".into(),
+            "Conteúdo".into(),
+            None,
+            1,
+            1,
+            approved,
+            false,
+            NaiveDate::from_ymd_opt(year, month, 10).unwrap().and_hms_opt(12, 0, 0).unwrap(),
+            None,
+            Some(1),
+            Some("Tag".into()),
+            Slug::new(id, "Notícia".into()),
+            1
+        )
+    }
+
+    #[tokio::test]
+    async fn test() {
+        let (article_db, article_repository) = get_article_repository();
+
+        article_db.lock().unwrap().push(article_created_at(2024, 7, true));
+        article_db.lock().unwrap().push(article_created_at(2024, 7, true));
+        article_db.lock().unwrap().push(article_created_at(2024, 7, false));
+        article_db.lock().unwrap().push(article_created_at(2023, 12, true));
+        article_db.lock().unwrap().push(article_created_at(2024, 8, true));
+
+        let sut = FetchArticlesArchiveService::new(Box::new(article_repository));
+
+        let months = sut.exec().await.unwrap();
+
+        assert_eq!(vec![
+            ArticleArchiveMonth (2024, 8, 1),
+            ArticleArchiveMonth (2024, 7, 2),
+            ArticleArchiveMonth (2023, 12, 1),
+        ], months);
+    }
+}
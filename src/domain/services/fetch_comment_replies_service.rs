@@ -0,0 +1,190 @@
+use uuid::Uuid;
+
+use crate::core::pagination::{DEFAULT_PER_PAGE, PaginationParameters, PaginationResponse};
+use crate::domain::domain_entities::comment_with_author::CommentWithAuthor;
+use crate::domain::repositories::comment_like_repository::CommentLikeRepositoryTrait;
+use crate::domain::repositories::comment_repository::CommentRepositoryTrait;
+use crate::domain::repositories::comment_user_article_repository::{CommentUserArticleRepositoryTrait, FindManyCommentsWithAuthorResponse};
+use crate::domain::services::fetch_many_comments_with_author_service::FetchManyCommentsWithAuthorResponse;
+use crate::errors::error::DomainErrorTrait;
+use crate::errors::resource_not_found::ResourceNotFoundError;
+use crate::util::generate_service_internal_error;
+
+pub struct FetchCommentRepliesParams {
+    pub comment_id: Uuid,
+    pub page: Option<u32>,
+    pub per_page: Option<u32>,
+    /// The logged-in user, to tell which replies they liked.
+    pub user_id: Option<Uuid>,
+}
+
+pub struct FetchCommentRepliesService<CommentRepository, CommentUserArticleRepository, CommentLikeRepository>
+where
+    CommentRepository: CommentRepositoryTrait,
+    CommentUserArticleRepository: CommentUserArticleRepositoryTrait,
+    CommentLikeRepository: CommentLikeRepositoryTrait,
+{
+    comment_repository: Box<CommentRepository>,
+    comment_user_article_repository: Box<CommentUserArticleRepository>,
+    comment_like_repository: Box<CommentLikeRepository>,
+}
+
+impl<CommentRepository, CommentUserArticleRepository, CommentLikeRepository> FetchCommentRepliesService<CommentRepository, CommentUserArticleRepository, CommentLikeRepository>
+where
+    CommentRepository: CommentRepositoryTrait,
+    CommentUserArticleRepository: CommentUserArticleRepositoryTrait,
+    CommentLikeRepository: CommentLikeRepositoryTrait,
+{
+    pub fn new(
+        comment_repository: Box<CommentRepository>,
+        comment_user_article_repository: Box<CommentUserArticleRepository>,
+        comment_like_repository: Box<CommentLikeRepository>,
+    ) -> Self {
+        FetchCommentRepliesService {
+            comment_repository,
+            comment_user_article_repository,
+            comment_like_repository,
+        }
+    }
+
+    pub async fn exec(&self, params: FetchCommentRepliesParams) -> Result<FetchManyCommentsWithAuthorResponse, Box<dyn DomainErrorTrait>> {
+        let parent = self.comment_repository.find_by_id(params.comment_id).await;
+
+        if let Err(err) = parent {
+            return Err(generate_service_internal_error(
+                "Error occurred on Fetch Comment Replies Service, while fetching the comment".into(),
+                &err,
+            ));
+        }
+
+        match parent.unwrap() {
+            Some(parent) if parent.is_active() => (),
+            _ => return Err(Box::new(ResourceNotFoundError::new())),
+        }
+
+        let items_per_page = params.per_page.unwrap_or(DEFAULT_PER_PAGE as u32);
+        let page = params.page.filter(|page| *page > 0).unwrap_or(1);
+
+        let response = self.comment_user_article_repository.find_many_replies(params.comment_id, false, PaginationParameters {
+            items_per_page,
+            page,
+            query: None,
+        }).await;
+
+        if let Err(err) = response {
+            return Err(generate_service_internal_error(
+                "Error occurred on Fetch Comment Replies Service, while fetching the replies".into(),
+                &err,
+            ));
+        }
+
+        let FindManyCommentsWithAuthorResponse (mut replies, total_items) = response.unwrap();
+
+        if let Some(user_id) = params.user_id {
+            self.mark_liked_replies(user_id, &mut replies).await?;
+        }
+
+        Ok(FetchManyCommentsWithAuthorResponse {
+            data: replies,
+            pagination: PaginationResponse {
+                current_page: page,
+                total_items,
+                total_pages: (total_items as f64 / items_per_page as f64).ceil() as u32
+            }
+        })
+    }
+
+    async fn mark_liked_replies(&self, user_id: Uuid, replies: &mut [CommentWithAuthor]) -> Result<(), Box<dyn DomainErrorTrait>> {
+        let liked_reply_ids = self.comment_like_repository
+            .find_liked_comment_ids(user_id, replies.iter().map(|reply| reply.id()).collect())
+            .await;
+
+        if let Err(err) = liked_reply_ids {
+            return Err(generate_service_internal_error(
+                "Error occurred on Fetch Comment Replies Service, while finding the replies liked by the user".into(),
+                &err,
+            ));
+        }
+
+        let liked_reply_ids = liked_reply_ids.unwrap();
+
+        for reply in replies.iter_mut() {
+            reply.set_liked_by_me(Some(liked_reply_ids.contains(&reply.id())));
+        }
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use tokio;
+
+    use crate::domain::domain_entities::comment::Comment;
+    use crate::domain::domain_entities::comment_like::CommentLike;
+    use crate::domain::domain_entities::role::Role;
+    use crate::domain::domain_entities::user::User;
+    use crate::domain::repositories::comment_repository::MockCommentRepositoryTrait;
+    use crate::domain::repositories::comment_user_article_repository::MockCommentUserArticleRepositoryTrait;
+    use crate::tests::repositories::comment_like_repository::get_comment_like_repository;
+
+    #[tokio::test]
+    async fn test() {
+        let mut mocked_comment_repo = MockCommentRepositoryTrait::new();
+        let mut mocked_comment_user_article_repo = MockCommentUserArticleRepositoryTrait::new();
+
+        let user = User::new("Floricultor".to_string(), "password".to_string(), Some(Role::User));
+        let article_id = Uuid::new_v4();
+        let parent = Comment::new(user.id(), Some(article_id), "Parent".into(), None);
+        let parent_id = parent.id();
+
+        mocked_comment_repo
+            .expect_find_by_id()
+            .returning(move |id| Ok(if id == parent.id() { Some(parent.clone()) } else { None }));
+
+        let replies = vec![
+            CommentWithAuthor::new(Some(article_id), "Reply 1".into(), user.clone(), Some(parent_id)),
+            CommentWithAuthor::new(Some(article_id), "Reply 2".into(), user.clone(), Some(parent_id)),
+        ];
+        let liked_reply_id = replies[1].id();
+
+        mocked_comment_user_article_repo
+            .expect_find_many_replies()
+            .returning(move |id, _include_inactive, _params| {
+                let data: Vec<CommentWithAuthor> = replies.iter().filter(|reply| reply.parent_id() == Some(id)).cloned().collect();
+                let count = data.len() as u64;
+                Ok(FindManyCommentsWithAuthorResponse (data, count))
+            });
+
+        let (like_db, mocked_like_repo) = get_comment_like_repository();
+        like_db.lock().unwrap().push(CommentLike::new(liked_reply_id, user.id()));
+
+        let sut = FetchCommentRepliesService::new(
+            Box::new(mocked_comment_repo),
+            Box::new(mocked_comment_user_article_repo),
+            Box::new(mocked_like_repo),
+        );
+
+        let response = sut.exec(FetchCommentRepliesParams {
+            comment_id: parent_id,
+            page: None,
+            per_page: None,
+            user_id: Some(user.id()),
+        }).await.unwrap();
+
+        assert_eq!(2, response.data.len());
+        assert_eq!(response.pagination, PaginationResponse { current_page: 1, total_pages: 1, total_items: 2 });
+        assert_eq!("Reply 1", response.data[0].content());
+        assert_eq!(vec![Some(false), Some(true)], response.data.iter().map(|reply| reply.liked_by_me()).collect::<Vec<_>>());
+
+        let missing = sut.exec(FetchCommentRepliesParams {
+            comment_id: Uuid::new_v4(),
+            page: None,
+            per_page: None,
+            user_id: None,
+        }).await;
+
+        assert_eq!(&404, missing.unwrap_err().code());
+    }
+}
@@ -0,0 +1,81 @@
+use uuid::Uuid;
+
+use crate::domain::domain_entities::comment::Comment;
+use crate::domain::domain_entities::comment_revision::CommentRevision;
+use crate::domain::domain_entities::role::Role;
+use crate::domain::repositories::comment_repository::CommentRepositoryTrait;
+use crate::domain::repositories::comment_revision_repository::CommentRevisionRepositoryTrait;
+use crate::errors::error::DomainErrorTrait;
+use crate::errors::resource_not_found::ResourceNotFoundError;
+use crate::errors::unauthorized_error::UnauthorizedError;
+use crate::util::{generate_service_internal_error, verify_role_has_permission, RolePermissions};
+
+pub struct FetchCommentRevisionsParams {
+    pub user_role: Role,
+    pub comment_id: Uuid,
+}
+
+#[derive(Debug)]
+pub struct FetchCommentRevisionsResponse {
+    pub comment: Comment,
+    pub revisions: Vec<CommentRevision>,
+}
+
+pub struct FetchCommentRevisionsService<CommentRepository, CommentRevisionRepository>
+where
+    CommentRepository: CommentRepositoryTrait,
+    CommentRevisionRepository: CommentRevisionRepositoryTrait,
+{
+    comment_repository: Box<CommentRepository>,
+    comment_revision_repository: Box<CommentRevisionRepository>,
+}
+
+impl<CommentRepository, CommentRevisionRepository> FetchCommentRevisionsService<CommentRepository, CommentRevisionRepository>
+where
+    CommentRepository: CommentRepositoryTrait,
+    CommentRevisionRepository: CommentRevisionRepositoryTrait,
+{
+    pub fn new(
+        comment_repository: Box<CommentRepository>,
+        comment_revision_repository: Box<CommentRevisionRepository>,
+    ) -> Self {
+        FetchCommentRevisionsService {
+            comment_repository,
+            comment_revision_repository,
+        }
+    }
+
+    pub async fn exec(&self, params: FetchCommentRevisionsParams) -> Result<FetchCommentRevisionsResponse, Box<dyn DomainErrorTrait>> {
+        if !verify_role_has_permission(&params.user_role, RolePermissions::InactivateComment) {
+            return Err(Box::new(UnauthorizedError::new()));
+        }
+
+        let comment = self.comment_repository.find_by_id(params.comment_id).await;
+
+        if let Err(err) = comment {
+            return Err(generate_service_internal_error(
+                "Error occurred on Fetch Comment Revisions Service, while fetching the comment".into(),
+                &err,
+            ));
+        }
+
+        let comment = match comment.unwrap() {
+            Some(comment) => comment,
+            None => return Err(Box::new(ResourceNotFoundError::new())),
+        };
+
+        let revisions = self.comment_revision_repository.find_many_by_comment(comment.id()).await;
+
+        if let Err(err) = revisions {
+            return Err(generate_service_internal_error(
+                "Error occurred on Fetch Comment Revisions Service, while fetching the revisions".into(),
+                &err,
+            ));
+        }
+
+        Ok(FetchCommentRevisionsResponse {
+            comment,
+            revisions: revisions.unwrap(),
+        })
+    }
+}
@@ -40,9 +40,9 @@ mod test {
     async fn test() {
         let (article_db, article_repository) = get_article_repository();
 
-        article_db.lock().unwrap().push(Article::new(Uuid::new_v4(), "Título da notícia 1".to_string(), "Conteúdo da primeira notícia".to_string(), "url".to_string(), 1, "Foo".into()));
-        article_db.lock().unwrap().push(Article::new(Uuid::new_v4(), "Título da notícia 2".to_string(), "Conteúdo da segunda notícia".to_string(), "url".to_string(), 1, "Foo".into()));
-        article_db.lock().unwrap().push(Article::new(Uuid::new_v4(), "Título da notícia 3".to_string(), "Conteúdo da terceira notícia".to_string(), "url".to_string(), 1, "Foo".into()));
+        article_db.lock().unwrap().push(Article::new(Uuid::new_v4(), "Título da notícia 1".to_string(), "Conteúdo da primeira notícia".to_string(), None, "url".to_string(), 1, "Foo".into()));
+        article_db.lock().unwrap().push(Article::new(Uuid::new_v4(), "Título da notícia 2".to_string(), "Conteúdo da segunda notícia".to_string(), None, "url".to_string(), 1, "Foo".into()));
+        article_db.lock().unwrap().push(Article::new(Uuid::new_v4(), "Título da notícia 3".to_string(), "Conteúdo da terceira notícia".to_string(), None, "url".to_string(), 1, "Foo".into()));
 
         let service = FetchHomePageArticlesService::new(Box::new(article_repository));
 
@@ -75,8 +75,8 @@ mod test {
     async fn it_can_fetch_many_article_tags() {
         let (tag_db, tag_repository) = get_article_tag_repository();
 
-        tag_db.lock().unwrap().push(ArticleTag::new_from_existing(1, "Bar".into()));
-        tag_db.lock().unwrap().push(ArticleTag::new_from_existing(2, "Foo".into()));
+        tag_db.lock().unwrap().push(ArticleTag::new_from_existing(1, "Bar".into(), 1));
+        tag_db.lock().unwrap().push(ArticleTag::new_from_existing(2, "Foo".into(), 1));
 
         let sut = super::FetchManyArticleTagsService::new(tag_repository);
 
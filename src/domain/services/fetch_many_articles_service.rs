@@ -1,12 +1,19 @@
+use chrono::{Duration, Months, NaiveDate};
 use log::error;
 
-use crate::core::pagination::{PaginationParameters, PaginationResponse};
-use crate::domain::domain_entities::article::Article;
-use crate::domain::repositories::article_repository::{ArticleQueryType, ArticleRepositoryTrait, FindManyArticlesResponse};
+use crate::core::pagination::{CursorPaginationParameters, CursorPaginationResponse, DEFAULT_PER_PAGE, PaginationCursor, PaginationParameters, PaginationResponse};
+use crate::domain::domain_entities::listed_article::ListedArticle;
+use crate::domain::domain_entities::role::Role;
+use crate::domain::repositories::article_repository::{ArticleQueryType, ArticleRepositoryTrait, ArticleSortOrder, FindManyArticlesByCursorResponse, FindManyArticlesResponse};
 use crate::domain::repositories::user_repository::UserRepositoryTrait;
+use crate::errors::bad_request_error::BadRequestError;
 use crate::errors::error::DomainErrorTrait;
 use crate::errors::internal_error::InternalError;
 use crate::errors::resource_not_found::ResourceNotFoundError;
+use crate::errors::unauthorized_error::UnauthorizedError;
+use crate::domain::politics::article_politics::ArticlePolitics;
+use crate::libs::time::TimeHelper;
+use crate::util::{verify_role_has_permission, RolePermissions};
 
 use crate::{LOG_SEP, R_EOL};
 
@@ -16,15 +23,53 @@ type Error = Box<dyn DomainErrorTrait>;
 pub enum ServiceArticleQueryType {
     Title(String),
     Author(String),
+    Tag(i32),
+    ReadingTime(Option<u32>, Option<u32>),
+    /// Articles still recent enough to be shown as new.
+    New,
+    /// Articles created in the month, given by its year and its number, from 1 to 12.
+    Month(i32, u32),
+    /// Articles created from the first day to the last one, both inclusive.
+    CreatedBetween(NaiveDate, NaiveDate),
 }
 
+const MAX_CALENDAR_DAYS: i64 = 62;
+const MAX_CALENDAR_ARTICLES: u64 = 500;
+
 pub struct FetchManyArticlesParams {
     pub page: Option<u32>,
     pub per_page: Option<u32>,
-    pub query: Option<ServiceArticleQueryType>,
+    /// Every one of them must match.
+    pub queries: Vec<ServiceArticleQueryType>,
+    pub approved_state: Option<bool>,
+    pub sort: ArticleSortOrder
+}
+
+pub struct FetchManyArticlesByCursorParams {
+    pub cursor: Option<String>,
+    pub per_page: Option<u32>,
+    /// Every one of them must match.
+    pub queries: Vec<ServiceArticleQueryType>,
     pub approved_state: Option<bool>
 }
 
+pub struct FetchArticlesCalendarParams {
+    pub user_role: Role,
+    pub from: NaiveDate,
+    /// Inclusive.
+    pub to: NaiveDate,
+    /// Every one of them must match.
+    pub queries: Vec<ServiceArticleQueryType>,
+    pub approved_state: Option<bool>,
+}
+
+#[derive(Debug)]
+pub struct ArticleCalendarDay {
+    pub day: NaiveDate,
+    /// From the oldest to the newest.
+    pub articles: Vec<ListedArticle>,
+}
+
 pub struct FetchManyArticlesService<ArticleRepository, UserRepository>
 where ArticleRepository: ArticleRepositoryTrait, UserRepository: UserRepositoryTrait
 {
@@ -35,7 +80,13 @@ where ArticleRepository: ArticleRepositoryTrait, UserRepository: UserRepositoryT
 #[derive(Debug)]
 pub struct FetchManyArticlesResponse {
     pub pagination: PaginationResponse,
-    pub data: Vec<Article>
+    pub data: Vec<ListedArticle>
+}
+
+#[derive(Debug)]
+pub struct FetchManyArticlesByCursorResponse {
+    pub pagination: CursorPaginationResponse,
+    pub data: Vec<ListedArticle>
 }
 
 impl<ArticleRepository: ArticleRepositoryTrait, UserRepository: UserRepositoryTrait>
@@ -59,7 +110,7 @@ FetchManyArticlesService<ArticleRepository, UserRepository> {
             if params_page <= 0 { default_page } else { params_page }
         } else { default_page };
         
-        let query = self.parse_query(params.query).await;
+        let query = self.parse_queries(params.queries).await;
 
         if let Err(err) = query {
             error!(
@@ -70,11 +121,12 @@ FetchManyArticlesService<ArticleRepository, UserRepository> {
             return Err(err)
         }
 
-        let query = query.unwrap();
+        let query = Some(query.unwrap());
 
         let response = self.article_repository.find_many(
             PaginationParameters { items_per_page, page, query },
-            params.approved_state
+            params.approved_state,
+            params.sort
         ).await;
 
         if response.is_err() {
@@ -98,7 +150,121 @@ FetchManyArticlesService<ArticleRepository, UserRepository> {
             }
         })
     }
-    
+
+    pub async fn exec_by_cursor(&self, params: FetchManyArticlesByCursorParams) -> Result<FetchManyArticlesByCursorResponse, Error> {
+        let items_per_page = params.per_page.unwrap_or(DEFAULT_PER_PAGE as u32);
+
+        let cursor = match params.cursor {
+            None => None,
+            Some(cursor) => match PaginationCursor::decode(&cursor) {
+                Some(cursor) => Some(cursor),
+                None => return Err(Box::new(BadRequestError::new_with_message("Invalid pagination cursor.".into())))
+            }
+        };
+
+        let query = Some(self.parse_queries(params.queries).await?);
+
+        let response = self.article_repository.find_many_by_cursor(
+            CursorPaginationParameters { items_per_page, cursor: cursor.clone(), query },
+            params.approved_state
+        ).await;
+
+        if response.is_err() {
+            error!(
+                "{R_EOL}{LOG_SEP}{R_EOL}Error occurred on Fetch Many Articles Service, while finding many articles by cursor from database: {R_EOL}{}{R_EOL}{LOG_SEP}{R_EOL}",
+                response.as_ref().unwrap_err()
+            );
+
+            return Err(Box::new(InternalError::new()));
+        }
+
+        let FindManyArticlesByCursorResponse (articles, has_more) = response.unwrap();
+
+        let pagination = CursorPaginationResponse::from_page_edges(
+            cursor.as_ref(),
+            articles.first().map(|listed| (listed.article().created_at(), listed.article().id())),
+            articles.last().map(|listed| (listed.article().created_at(), listed.article().id())),
+            has_more
+        );
+
+        Ok(FetchManyArticlesByCursorResponse {
+            data: articles,
+            pagination
+        })
+    }
+
+    /**
+     Articles created in the period grouped by the day they were created, every day of the period included,
+     so the staff can see what was published and what is still waiting for review. Unapproved articles are
+     listed, so only those who can see them may use it.
+     */
+    pub async fn exec_calendar(&self, params: FetchArticlesCalendarParams) -> Result<Vec<ArticleCalendarDay>, Error> {
+        if !verify_role_has_permission(&params.user_role, RolePermissions::SeeUnapprovedArticle) {
+            return Err(Box::new(UnauthorizedError::new()));
+        }
+
+        let days_in_period = params.to.signed_duration_since(params.from).num_days() + 1;
+
+        if !(1..=MAX_CALENDAR_DAYS).contains(&days_in_period) {
+            return Err(Box::new(BadRequestError::new_with_message(
+                format!("The calendar period must span from 1 to {} days.", MAX_CALENDAR_DAYS)
+            )));
+        }
+
+        let mut queries = params.queries;
+        queries.push(ServiceArticleQueryType::CreatedBetween(params.from, params.to));
+
+        let filters = self.parse_queries(queries).await?;
+
+        // one more than the calendar holds, to tell a full period apart from a cut one
+        let response = self.article_repository.find_all_by_filters(filters, params.approved_state, MAX_CALENDAR_ARTICLES + 1).await;
+
+        if response.is_err() {
+            error!(
+                "{R_EOL}{LOG_SEP}{R_EOL}Error occurred on Fetch Many Articles Service, while finding the calendar's articles from database: {R_EOL}{}{R_EOL}{LOG_SEP}{R_EOL}",
+                response.as_ref().unwrap_err()
+            );
+
+            return Err(Box::new(InternalError::new()));
+        }
+
+        let articles = response.unwrap();
+
+        if articles.len() as u64 > MAX_CALENDAR_ARTICLES {
+            return Err(Box::new(BadRequestError::new_with_message(
+                format!("The period has more than {} articles, pick a shorter one.", MAX_CALENDAR_ARTICLES)
+            )));
+        }
+
+        let mut days: Vec<ArticleCalendarDay> = params.from
+            .iter_days()
+            .take(days_in_period as usize)
+            .map(|day| ArticleCalendarDay { day, articles: vec![] })
+            .collect();
+
+        for listed_article in articles {
+            let day_index = listed_article.article().created_at().date().signed_duration_since(params.from).num_days();
+
+            if let Some(day) = days.get_mut(day_index as usize) {
+                day.articles.push(listed_article);
+            }
+        }
+
+        Ok(days)
+    }
+
+    async fn parse_queries(&self, queries: Vec<ServiceArticleQueryType>) -> Result<Vec<ArticleQueryType>, Error> {
+        let mut filters = vec![];
+
+        for query in queries {
+            if let Some(filter) = self.parse_query(Some(query)).await? {
+                filters.push(filter);
+            }
+        }
+
+        Ok(filters)
+    }
+
     async fn parse_query(&self, query: Option<ServiceArticleQueryType>) -> Result<Option<ArticleQueryType>, Error> {
         if query.is_none() {
             return Ok(None);
@@ -122,6 +288,38 @@ FetchManyArticlesService<ArticleRepository, UserRepository> {
             },
             ServiceArticleQueryType::Title(content) => {
                 Ok(Some(ArticleQueryType::Title(content)))
+            },
+            ServiceArticleQueryType::Tag(tag_id) => {
+                Ok(Some(ArticleQueryType::Tag(tag_id)))
+            },
+            ServiceArticleQueryType::ReadingTime(min, max) => {
+                Ok(Some(ArticleQueryType::ReadingTime(min, max)))
+            },
+            ServiceArticleQueryType::New => {
+                let recent_since = TimeHelper::now() - Duration::try_hours(ArticlePolitics::recent_window_hours()).unwrap();
+
+                Ok(Some(ArticleQueryType::CreatedAfter(recent_since)))
+            },
+            ServiceArticleQueryType::Month(year, month) => {
+                let start = NaiveDate::from_ymd_opt(year, month, 1);
+                let end = start.and_then(|start| start.checked_add_months(Months::new(1)));
+
+                match start.zip(end) {
+                    Some((start, end)) => Ok(Some(ArticleQueryType::CreatedBetween(
+                        start.and_hms_opt(0, 0, 0).unwrap(),
+                        end.and_hms_opt(0, 0, 0).unwrap()
+                    ))),
+                    None => Err(Box::new(BadRequestError::new_with_message(format!("Invalid month '{}/{}'.", month, year))))
+                }
+            },
+            ServiceArticleQueryType::CreatedBetween(first_day, last_day) => {
+                match last_day.succ_opt() {
+                    Some(end) if first_day <= last_day => Ok(Some(ArticleQueryType::CreatedBetween(
+                        first_day.and_hms_opt(0, 0, 0).unwrap(),
+                        end.and_hms_opt(0, 0, 0).unwrap()
+                    ))),
+                    _ => Err(Box::new(BadRequestError::new_with_message("The first day must not be after the last one.".into())))
+                }
             }
         }
     }
@@ -133,10 +331,16 @@ mod test {
     use http::StatusCode;
     use tokio;
 
+    use chrono::Duration;
+    use uuid::Uuid;
+
+    use crate::domain::domain_entities::article::Article;
     use crate::domain::domain_entities::user::User;
     use crate::domain::domain_entities::role::Role;
+    use crate::domain::domain_entities::slug::Slug;
+    use crate::libs::time::TimeHelper;
     use crate::domain::repositories::user_repository::MockUserRepositoryTrait;
-    use crate::tests::repositories::article_repository::get_article_repository;
+    use crate::tests::repositories::article_repository::{get_article, get_article_repository};
 
     #[tokio::test]
     async fn test() {
@@ -149,6 +353,7 @@ mod test {
             user.id(),
             "Article 1 title".to_string(),
             "Article 1 content here".to_string(),
+            None,
             "url".to_string(),
             1,
             "Foo".into()
@@ -159,6 +364,7 @@ mod test {
             user.id(),
             "Article 2 title".to_string(),
             "Article 2 content here".to_string(),
+            None,
             "url".to_string(),
             1,
             "Foo".into()
@@ -182,33 +388,36 @@ mod test {
         let query_by_title_request = fetch_many_articles_service.exec(FetchManyArticlesParams {
             page: Some(2),
             per_page: Some(1),
-            query: Some(ServiceArticleQueryType::Title("article".to_string())),
+            queries: vec![ServiceArticleQueryType::Title("article".to_string())],
             approved_state: None,
+            sort: ArticleSortOrder::Newest,
         }).await.unwrap();
 
         assert_eq!(1, query_by_title_request.data.len(), "Expected exactly one article with the queried title.");
         assert_eq!(query_by_title_request.pagination, PaginationResponse { current_page: 2, total_pages: 2, total_items: 2 });
-        assert_eq!(query_by_title_request.data[0].title(), "Article 2 title", "Expected queried article to have title \"Article 2 title\".");
+        assert_eq!(query_by_title_request.data[0].article().title(), "Article 2 title", "Expected queried article to have title \"Article 2 title\".");
 
         let no_query_request = fetch_many_articles_service.exec(FetchManyArticlesParams {
             page: None,
             per_page: None,
-            query: None,
+            queries: vec![],
             approved_state: None,
+            sort: ArticleSortOrder::Newest,
         }).await.unwrap();
 
         assert_eq!(2, no_query_request.data.len(), "Expected to get all the 2 existing articles.");
         assert_eq!(no_query_request.pagination, PaginationResponse { current_page: 1, total_pages: 1, total_items: 2 });
-        assert_eq!(no_query_request.data[0].title(), "Article 1 title");
-        assert_eq!(no_query_request.data[1].title(), "Article 2 title");
+        assert_eq!(no_query_request.data[0].article().title(), "Article 1 title");
+        assert_eq!(no_query_request.data[1].article().title(), "Article 2 title");
 
         // make a request querying by nickname that does not exist
         let failing_query_by_unexisting_nickname_request = fetch_many_articles_service.exec(
             FetchManyArticlesParams {
                 page: None,
                 per_page: None,
-                query: Some(ServiceArticleQueryType::Author("Vamp".to_string())),
-                approved_state: None
+                queries: vec![ServiceArticleQueryType::Author("Vamp".to_string())],
+                approved_state: None,
+                sort: ArticleSortOrder::Newest
             },
         ).await.unwrap_err();
 
@@ -219,24 +428,235 @@ mod test {
             FetchManyArticlesParams {
                 page: None,
                 per_page: None,
-                query: Some(ServiceArticleQueryType::Author("Floricultor".to_string())),
-                approved_state: None
+                queries: vec![ServiceArticleQueryType::Author("Floricultor".to_string())],
+                approved_state: None,
+                sort: ArticleSortOrder::Newest
             },
         ).await.unwrap();
 
         assert_eq!(2, query_by_nickname_request.data.len());
         assert_eq!(query_by_nickname_request.pagination, PaginationResponse { current_page: 1, total_pages: 1, total_items: 2 });
 
+        let query_by_nickname_and_title_request = fetch_many_articles_service.exec(
+            FetchManyArticlesParams {
+                page: None,
+                per_page: None,
+                queries: vec![
+                    ServiceArticleQueryType::Author("Floricultor".to_string()),
+                    ServiceArticleQueryType::Title("article 2".to_string()),
+                ],
+                approved_state: None,
+                sort: ArticleSortOrder::Newest
+            },
+        ).await.unwrap();
+
+        assert_eq!(1, query_by_nickname_and_title_request.data.len(), "Expected every query to be applied.");
+        assert_eq!("Article 2 title", query_by_nickname_and_title_request.data[0].article().title());
+
         let query_approved_only_articles_request = fetch_many_articles_service.exec(
             FetchManyArticlesParams {
                 page: None,
                 per_page: None,
-                query: None,
-                approved_state: Some(true)
+                queries: vec![],
+                approved_state: Some(true),
+                sort: ArticleSortOrder::Newest
             }
         ).await.unwrap();
 
         assert_eq!(1, query_approved_only_articles_request.data.len(), "Expected only-approved-articles request to be 1 item length.");
         assert_eq!(1, query_approved_only_articles_request.pagination.total_items, "Expected only-approved-articles request pagination total_items to be 1.")
     }
-}
\ No newline at end of file
+
+    #[tokio::test]
+    async fn test_cursor_pagination() {
+        let (article_db, mocked_article_repo) = get_article_repository();
+        let mocked_user_repo: MockUserRepositoryTrait = MockUserRepositoryTrait::new();
+
+        let author_id = Uuid::new_v4();
+        let now = TimeHelper::now();
+
+        for (index, title) in ["Oldest", "Middle", "Newest"].into_iter().enumerate() {
+            let id = Uuid::new_v4();
+            article_db.lock().unwrap().push(Article::new_from_existing(
+                id,
+                author_id,
+                "url".into(),
+                title.into(),
+                "content".into(),
+                None,
+                1,
+                1,
+                true,
+                false,
+                now + Duration::try_minutes(index as i64).unwrap(),
+                None,
+                Some(1),
+                Some("Foo".into()),
+                Slug::new(id, title.into()),
+                1
+            ));
+        }
+
+        let sut = FetchManyArticlesService::new(Box::new(mocked_article_repo), Box::new(mocked_user_repo));
+
+        let first_page = sut.exec_by_cursor(FetchManyArticlesByCursorParams {
+            cursor: None,
+            per_page: Some(2),
+            queries: vec![],
+            approved_state: Some(true),
+        }).await.unwrap();
+
+        assert_eq!(vec!["Newest", "Middle"], first_page.data.iter().map(|listed| listed.article().title()).collect::<Vec<&str>>());
+        assert!(first_page.pagination.prev_cursor.is_none(), "Expected first page not to have a previous cursor.");
+
+        let second_page = sut.exec_by_cursor(FetchManyArticlesByCursorParams {
+            cursor: first_page.pagination.next_cursor,
+            per_page: Some(2),
+            queries: vec![],
+            approved_state: Some(true),
+        }).await.unwrap();
+
+        assert_eq!(vec!["Oldest"], second_page.data.iter().map(|listed| listed.article().title()).collect::<Vec<&str>>());
+        assert!(second_page.pagination.next_cursor.is_none(), "Expected last page not to have a next cursor.");
+
+        let back_to_first_page = sut.exec_by_cursor(FetchManyArticlesByCursorParams {
+            cursor: second_page.pagination.prev_cursor,
+            per_page: Some(2),
+            queries: vec![],
+            approved_state: Some(true),
+        }).await.unwrap();
+
+        assert_eq!(vec!["Newest", "Middle"], back_to_first_page.data.iter().map(|listed| listed.article().title()).collect::<Vec<&str>>());
+        assert!(back_to_first_page.pagination.prev_cursor.is_none());
+        assert!(back_to_first_page.pagination.next_cursor.is_some());
+
+        let invalid_cursor_result = sut.exec_by_cursor(FetchManyArticlesByCursorParams {
+            cursor: Some("foo".into()),
+            per_page: None,
+            queries: vec![],
+            approved_state: None,
+        }).await.unwrap_err();
+
+        assert_eq!(invalid_cursor_result.code(), &StatusCode::BAD_REQUEST);
+    }
+
+    #[tokio::test]
+    async fn test_month_query() {
+        let (article_db, mocked_article_repo) = get_article_repository();
+        let mocked_user_repo: MockUserRepositoryTrait = MockUserRepositoryTrait::new();
+
+        for (title, day) in [("Junho", (2024, 6, 30)), ("Julho", (2024, 7, 1)), ("Agosto", (2024, 8, 1))] {
+            let id = Uuid::new_v4();
+            article_db.lock().unwrap().push(Article::new_from_existing(
+                id,
+                Uuid::new_v4(),
+                "url".into(),
+                title.into(),
+                "content".into(),
+                None,
+                1,
+                1,
+                true,
+                false,
+                NaiveDate::from_ymd_opt(day.0, day.1, day.2).unwrap().and_hms_opt(0, 0, 0).unwrap(),
+                None,
+                Some(1),
+                Some("Foo".into()),
+                Slug::new(id, title.into()),
+                1
+            ));
+        }
+
+        let sut = FetchManyArticlesService::new(Box::new(mocked_article_repo), Box::new(mocked_user_repo));
+
+        let july = sut.exec(FetchManyArticlesParams {
+            page: None,
+            per_page: None,
+            queries: vec![ServiceArticleQueryType::Month(2024, 7)],
+            approved_state: Some(true),
+            sort: ArticleSortOrder::Newest
+        }).await.unwrap();
+
+        assert_eq!(vec!["Julho"], july.data.iter().map(|listed| listed.article().title()).collect::<Vec<&str>>());
+
+        let invalid_month = sut.exec(FetchManyArticlesParams {
+            page: None,
+            per_page: None,
+            queries: vec![ServiceArticleQueryType::Month(2024, 13)],
+            approved_state: Some(true),
+            sort: ArticleSortOrder::Newest
+        }).await.unwrap_err();
+
+        assert_eq!(invalid_month.code(), &StatusCode::BAD_REQUEST);
+    }
+
+    #[tokio::test]
+    async fn test_calendar() {
+        let (article_db, mocked_article_repo) = get_article_repository();
+        let mocked_user_repo: MockUserRepositoryTrait = MockUserRepositoryTrait::new();
+
+        for (title, day, tag_id, approved) in [
+            ("Antes", (2024, 6, 30), 1, true),
+            ("Publicada", (2024, 7, 1), 1, true),
+            ("Em revisão", (2024, 7, 3), 1, false),
+            ("Outra tag", (2024, 7, 3), 2, false),
+        ] {
+            let id = Uuid::new_v4();
+            article_db.lock().unwrap().push(Article::new_from_existing(
+                id,
+                Uuid::new_v4(),
+                "url".into(),
+                title.into(),
+                "content".into(),
+                None,
+                1,
+                1,
+                approved,
+                false,
+                NaiveDate::from_ymd_opt(day.0, day.1, day.2).unwrap().and_hms_opt(12, 0, 0).unwrap(),
+                None,
+                Some(tag_id),
+                Some("Foo".into()),
+                Slug::new(id, title.into()),
+                1
+            ));
+        }
+
+        let sut = FetchManyArticlesService::new(Box::new(mocked_article_repo), Box::new(mocked_user_repo));
+
+        let calendar = |user_role: Role, to_day: u32| FetchArticlesCalendarParams {
+            user_role,
+            from: NaiveDate::from_ymd_opt(2024, 7, 1).unwrap(),
+            to: NaiveDate::from_ymd_opt(2024, 7, to_day).unwrap(),
+            queries: vec![ServiceArticleQueryType::Tag(1)],
+            approved_state: None,
+        };
+
+        let week = sut.exec_calendar(calendar(Role::Editor, 7)).await.unwrap();
+
+        assert_eq!(7, week.len());
+        assert_eq!(vec!["Publicada"], week[0].articles.iter().map(|listed| listed.article().title()).collect::<Vec<&str>>());
+        assert!(week[1].articles.is_empty());
+        assert_eq!(vec!["Em revisão"], week[2].articles.iter().map(|listed| listed.article().title()).collect::<Vec<&str>>());
+
+        let writer = sut.exec_calendar(calendar(Role::Writer, 7)).await.unwrap_err();
+        assert_eq!(writer.code(), &StatusCode::UNAUTHORIZED);
+
+        let backwards = sut.exec_calendar(FetchArticlesCalendarParams {
+            to: NaiveDate::from_ymd_opt(2024, 6, 1).unwrap(),
+            ..calendar(Role::Editor, 7)
+        }).await.unwrap_err();
+        assert_eq!(backwards.code(), &StatusCode::BAD_REQUEST);
+
+        article_db.lock().unwrap().extend((0..=MAX_CALENDAR_ARTICLES).map(|_| get_article(Uuid::new_v4(), Uuid::new_v4())));
+
+        let today = TimeHelper::now().date();
+
+        let too_busy = sut.exec_calendar(FetchArticlesCalendarParams {
+            from: today,
+            to: today,
+            ..calendar(Role::Editor, 7)
+        }).await.unwrap_err();
+        assert_eq!(too_busy.code(), &StatusCode::BAD_REQUEST, "A period with more articles than the calendar holds shouldn't be cut silently.");
+    }
+}
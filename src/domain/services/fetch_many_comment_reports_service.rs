@@ -1,13 +1,14 @@
 use log::error;
 use uuid::Uuid;
-use crate::domain::domain_entities::comment_report::CommentReport;
+use crate::domain::domain_entities::comment_report::{CommentReport, CommentReportIdTrait, CommentReportTrait, ReportReason};
 use crate::domain::repositories::user_repository::UserRepositoryTrait;
+use crate::errors::bad_request_error::BadRequestError;
 use crate::errors::error::DomainErrorTrait;
 use crate::errors::resource_not_found::ResourceNotFoundError;
 use crate::{R_EOL, LOG_SEP};
 
-use crate::core::pagination::{PaginationParameters, PaginationResponse};
-use crate::domain::repositories::comment_report_repository::{CommentReportQueryType, CommentReportRepositoryTrait, FindManyCommentReportsResponse};
+use crate::core::pagination::{CursorPaginationParameters, CursorPaginationResponse, DEFAULT_PER_PAGE, PaginationCursor, PaginationParameters, PaginationResponse};
+use crate::domain::repositories::comment_report_repository::{CommentReportQueryType, CommentReportRepositoryTrait, FindManyCommentReportsByCursorResponse, FindManyCommentReportsResponse};
 use crate::errors::internal_error::InternalError;
 
 type Error = Box<dyn DomainErrorTrait>;
@@ -20,6 +21,7 @@ pub enum CommentReportServiceQuery {
    SolvedBy(String),
    Solved(bool),
    Content(String),
+   Reason(ReportReason),
 }
 
 pub struct FetchManyCommentReportsParams {
@@ -34,6 +36,18 @@ pub struct FetchManyCommentReportsResponse {
     pub data: Vec<CommentReport>
 }
 
+pub struct FetchManyCommentReportsByCursorParams {
+    pub cursor: Option<String>,
+    pub per_page: Option<u32>,
+    pub query: Option<CommentReportServiceQuery>
+}
+
+#[derive(Debug)]
+pub struct FetchManyCommentReportsByCursorResponse {
+    pub pagination: CursorPaginationResponse,
+    pub data: Vec<CommentReport>
+}
+
 pub struct FetchManyCommentReportsService<
     CommentReportRepository: CommentReportRepositoryTrait,
     UserRepository: UserRepositoryTrait
@@ -100,6 +114,51 @@ FetchManyCommentReportsService<CommentReportRepository, UserRepository> {
         })
     }
 
+    pub async fn exec_by_cursor(&self, params: FetchManyCommentReportsByCursorParams) -> Result<FetchManyCommentReportsByCursorResponse, Error> {
+        let items_per_page = params.per_page.unwrap_or(DEFAULT_PER_PAGE as u32);
+
+        let cursor = match params.cursor {
+            None => None,
+            Some(cursor) => match PaginationCursor::decode(&cursor) {
+                Some(cursor) => Some(cursor),
+                None => return Err(Box::new(BadRequestError::new_with_message("Invalid pagination cursor.".into())))
+            }
+        };
+
+        let parsed_query = self.parse_query(params.query).await?;
+
+        let response = self.comment_report_repository.find_many_by_cursor(
+            CursorPaginationParameters {
+                items_per_page,
+                cursor: cursor.clone(),
+                query: parsed_query,
+            }
+        ).await;
+
+        if response.is_err() {
+            error!(
+                "{R_EOL}{LOG_SEP}{R_EOL}Error occurred on Fetch Many Comment Reports Service, while finding many comment reports by cursor from database: {R_EOL}{}{R_EOL}{LOG_SEP}{R_EOL}",
+                response.as_ref().unwrap_err()
+            );
+
+            return Err(Box::new(InternalError::new()));
+        }
+
+        let FindManyCommentReportsByCursorResponse (data, has_more) = response.unwrap();
+
+        let pagination = CursorPaginationResponse::from_page_edges(
+            cursor.as_ref(),
+            data.first().map(|report| (report.created_at(), report.id())),
+            data.last().map(|report| (report.created_at(), report.id())),
+            has_more
+        );
+
+        Ok(FetchManyCommentReportsByCursorResponse {
+            pagination,
+            data,
+        })
+    }
+
     async fn parse_query(&self, service_query: Option<CommentReportServiceQuery>) -> Result<Option<CommentReportQueryType>, Error> {
         if service_query.is_none() {
             return Ok(None);
@@ -108,6 +167,7 @@ FetchManyCommentReportsService<CommentReportRepository, UserRepository> {
         match service_query.unwrap() {
             CommentReportServiceQuery::Content(content) => Ok(Some(CommentReportQueryType::Content(content))),
             CommentReportServiceQuery::Solved(value) => Ok(Some(CommentReportQueryType::Solved(value))),
+            CommentReportServiceQuery::Reason(reason) => Ok(Some(CommentReportQueryType::Reason(reason))),
             CommentReportServiceQuery::SolvedBy(nickname) => {
                 let user = self.get_id_from_nickname(nickname).await;
 
@@ -165,6 +225,7 @@ mod test {
             1,
             Uuid::new_v4(),
             Uuid::new_v4(),
+            ReportReason::Spam,
             "report numero 1".into(),
             None,
             TimeHelper::now()
@@ -174,6 +235,7 @@ mod test {
             2,
             Uuid::new_v4(),
             Uuid::new_v4(),
+            ReportReason::Other,
             "report numero 2".into(),
             Some(user.id()),
             TimeHelper::now()
@@ -222,6 +284,13 @@ mod test {
                                 comment_reports.push(item.clone());
                             }
                         }
+                    },
+                    CommentReportQueryType::Reason(reason) => {
+                        for item in comm_repo_db_clone.lock().unwrap().iter() {
+                            if item.reason() == reason {
+                                comment_reports.push(item.clone());
+                            }
+                        }
                     }
                 };
             } else {
@@ -272,5 +341,14 @@ mod test {
         assert_eq!("report numero 2".to_string(), res.data[0].message());
         assert_eq!(1, res.pagination.total_pages);
         assert_eq!(1, res.pagination.total_items);
+
+        let res = sut.exec(FetchManyCommentReportsParams {
+            page: Some(1),
+            per_page: Some(1),
+            query: Some(CommentReportServiceQuery::Reason(ReportReason::Spam)),
+        }).await.unwrap();
+
+        assert_eq!("report numero 1".to_string(), res.data[0].message());
+        assert_eq!(1, res.pagination.total_items);
     }
 }
\ No newline at end of file
@@ -159,17 +159,20 @@ mod test {
         let mut db: Vec<Comment> = Vec::new();
 
         let user = User::new("Floricultor".to_string(), "password".to_string(), Some(Role::Principal));
-        let article = Article::new(user.id(), "Título da notícia".into(), "Conteúdo da notícia".into(), "url do cover".into(), 1, "Foo".into());
+        let article = Article::new(user.id(), "Título da notícia".into(), "Conteúdo da notícia".into(), None, "url do cover".into(), 1, "Foo".into());
 
-        db.push(Comment::new(user.id(), Some(article.id()), "Comment 1 content here".to_string()));
-        db.push(Comment::new(user.id(), Some(article.id()), "Comment 2 content here".to_string()));
+        db.push(Comment::new(user.id(), Some(article.id()), "Comment 1 content here".to_string(), None));
+        db.push(Comment::new(user.id(), Some(article.id()), "Comment 2 content here".to_string(), None));
         db.push(Comment::new_from_existing(
             Uuid::new_v4(),
             Some(article.id()),
             user.id(),
             "Coment 2 content here".into(),
             false,
-            TimeHelper::now()
+            TimeHelper::now(),
+            None,
+            None,
+            None,
         ));
 
         let mut mocked_comment_repo: MockArticleCommentRepositoryTrait = MockArticleCommentRepositoryTrait::new();
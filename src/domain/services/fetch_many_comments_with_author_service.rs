@@ -1,10 +1,16 @@
 use log::error;
 use uuid::Uuid;
 
-use crate::core::pagination::{DEFAULT_PER_PAGE, PaginationParameters, PaginationResponse};
-use crate::domain::repositories::comment_user_article_repository::{CommentUserArticleRepositoryTrait, FindManyCommentsWithAuthorResponse};
+use crate::core::pagination::{CursorPaginationParameters, CursorPaginationResponse, DEFAULT_PER_PAGE, PaginationCursor, PaginationParameters, PaginationResponse};
+use crate::domain::domain_entities::slug::Slug;
+use crate::domain::repositories::article_repository::ArticleRepositoryTrait;
+use crate::domain::repositories::comment_like_repository::CommentLikeRepositoryTrait;
+use crate::domain::repositories::comment_user_article_repository::{CommentSortOrder, CommentUserArticleRepositoryTrait, FindManyCommentsWithAuthorByCursorResponse, FindManyCommentsWithAuthorResponse};
+use crate::errors::bad_request_error::BadRequestError;
 use crate::errors::error::DomainErrorTrait;
 use crate::errors::internal_error::InternalError;
+use crate::errors::resource_not_found::ResourceNotFoundError;
+use crate::util::generate_service_internal_error;
 
 use crate::{LOG_SEP, R_EOL};
 use crate::domain::domain_entities::comment_with_author::CommentWithAuthor;
@@ -12,11 +18,29 @@ use crate::domain::domain_entities::comment_with_author::CommentWithAuthor;
 pub struct FetchManyArticleCommentsWithAuthorParams {
     pub page: Option<u32>,
     pub per_page: Option<u32>,
+    pub sort: CommentSortOrder,
+    /// The logged-in user, to tell which comments they liked.
+    pub user_id: Option<Uuid>,
 }
 
-pub struct FetchManyArticleCommentsWithAuthorService<CommentUserArticleRepository>
-where CommentUserArticleRepository: CommentUserArticleRepositoryTrait
-{ article_comment_repository: Box<CommentUserArticleRepository> }
+pub struct FetchManyArticleCommentsByCursorParams {
+    pub article_slug: Slug,
+    pub cursor: Option<String>,
+    pub per_page: Option<u32>,
+    pub sort: CommentSortOrder,
+    /// The logged-in user, to tell which comments they liked.
+    pub user_id: Option<Uuid>,
+}
+
+pub struct FetchManyArticleCommentsWithAuthorService<CommentUserArticleRepository, ArticleRepository, CommentLikeRepository>
+where CommentUserArticleRepository: CommentUserArticleRepositoryTrait,
+      ArticleRepository: ArticleRepositoryTrait,
+      CommentLikeRepository: CommentLikeRepositoryTrait
+{
+    article_comment_repository: Box<CommentUserArticleRepository>,
+    article_repository: Box<ArticleRepository>,
+    comment_like_repository: Box<CommentLikeRepository>,
+}
 
 #[derive(Debug)]
 pub struct FetchManyCommentsWithAuthorResponse
@@ -25,14 +49,27 @@ pub struct FetchManyCommentsWithAuthorResponse
     pub data: Vec<CommentWithAuthor>
 }
 
+#[derive(Debug)]
+pub struct FetchManyCommentsWithAuthorByCursorResponse {
+    pub pagination: CursorPaginationResponse,
+    pub data: Vec<CommentWithAuthor>
+}
+
 type ExecFuncReturn = Result<FetchManyCommentsWithAuthorResponse
 , Box<dyn DomainErrorTrait>>;
 
-impl<CommentUserArticleRepository: CommentUserArticleRepositoryTrait> FetchManyArticleCommentsWithAuthorService<CommentUserArticleRepository> {
+impl<CommentUserArticleRepository: CommentUserArticleRepositoryTrait, ArticleRepository: ArticleRepositoryTrait, CommentLikeRepository: CommentLikeRepositoryTrait>
+FetchManyArticleCommentsWithAuthorService<CommentUserArticleRepository, ArticleRepository, CommentLikeRepository> {
     // CONSTRUCTOR
-    pub fn new(article_comment_repository: Box<CommentUserArticleRepository>) -> Self {
+    pub fn new(
+        article_comment_repository: Box<CommentUserArticleRepository>,
+        article_repository: Box<ArticleRepository>,
+        comment_like_repository: Box<CommentLikeRepository>,
+    ) -> Self {
         Self {
             article_comment_repository,
+            article_repository,
+            comment_like_repository,
         }
     }
 
@@ -47,7 +84,7 @@ impl<CommentUserArticleRepository: CommentUserArticleRepositoryTrait> FetchManyA
         } else { default_page };
 
         let response =
-            self.article_comment_repository.find_many_comments(article_id, false, PaginationParameters {
+            self.article_comment_repository.find_many_comments(article_id, false, params.sort, PaginationParameters {
                 items_per_page,
                 page,
                 query: None,
@@ -63,7 +100,9 @@ impl<CommentUserArticleRepository: CommentUserArticleRepositoryTrait> FetchManyA
         }
 
         let response = response.unwrap();
-        let FindManyCommentsWithAuthorResponse (comments, total_items) = response;
+        let FindManyCommentsWithAuthorResponse (mut comments, total_items) = response;
+
+        self.mark_liked_comments(params.user_id, &mut comments).await?;
 
         Ok(FetchManyCommentsWithAuthorResponse {
             data: comments,
@@ -74,6 +113,97 @@ impl<CommentUserArticleRepository: CommentUserArticleRepositoryTrait> FetchManyA
             }
         })
     }
+
+    /// Lists the comments of an approved article found by its slug, walking from the cursor.
+    pub async fn exec_by_cursor(&self, params: FetchManyArticleCommentsByCursorParams) -> Result<FetchManyCommentsWithAuthorByCursorResponse, Box<dyn DomainErrorTrait>> {
+        let items_per_page = params.per_page.unwrap_or(DEFAULT_PER_PAGE as u32);
+
+        let cursor = match params.cursor {
+            None => None,
+            Some(cursor) => match PaginationCursor::decode(&cursor) {
+                // a cursor from another sort can't tell where the listing is
+                Some(cursor) if cursor.rank.is_some() == params.sort.is_ranked() => Some(cursor),
+                _ => return Err(Box::new(BadRequestError::new_with_message("Invalid pagination cursor.".into())))
+            }
+        };
+
+        let article = self.article_repository.find_by_slug(&params.article_slug).await;
+
+        if article.is_err() {
+            return Err(generate_service_internal_error(
+                "Error occurred on Fetch Many Comments With Author Service, while finding the article by slug".into(),
+                &article.unwrap_err(),
+            ));
+        }
+
+        let article = match article.unwrap() {
+            Some(article) if article.approved() => article,
+            _ => return Err(Box::new(ResourceNotFoundError::new())),
+        };
+
+        let response = self.article_comment_repository.find_many_comments_by_cursor(
+            article.id(),
+            false,
+            params.sort,
+            CursorPaginationParameters { items_per_page, cursor: cursor.clone(), query: None }
+        ).await;
+
+        if response.is_err() {
+            return Err(generate_service_internal_error(
+                "Error occurred on Fetch Many Comments With Author Service, while fetching many comments by cursor".into(),
+                &response.unwrap_err(),
+            ));
+        }
+
+        let FindManyCommentsWithAuthorByCursorResponse (mut comments, has_more) = response.unwrap();
+
+        self.mark_liked_comments(params.user_id, &mut comments).await?;
+
+        let edge = |comment: &CommentWithAuthor| (params.sort.rank(comment), comment.created_at(), comment.id());
+
+        // pinned comments are outside of the walk, so they can't be the page's edges
+        let walked_comments: Vec<&CommentWithAuthor> = comments.iter()
+            .filter(|comment| comment.pinned_at().is_none())
+            .collect();
+
+        let pagination = CursorPaginationResponse::from_ranked_page_edges(
+            cursor.as_ref(),
+            walked_comments.first().map(|comment| edge(comment)),
+            walked_comments.last().map(|comment| edge(comment)),
+            has_more
+        );
+
+        Ok(FetchManyCommentsWithAuthorByCursorResponse {
+            data: comments,
+            pagination
+        })
+    }
+
+    async fn mark_liked_comments(&self, user_id: Option<Uuid>, comments: &mut [CommentWithAuthor]) -> Result<(), Box<dyn DomainErrorTrait>> {
+        let user_id = match user_id {
+            Some(user_id) => user_id,
+            None => return Ok(()),
+        };
+
+        let liked_comment_ids = self.comment_like_repository
+            .find_liked_comment_ids(user_id, comments.iter().map(|comment| comment.id()).collect())
+            .await;
+
+        if liked_comment_ids.is_err() {
+            return Err(generate_service_internal_error(
+                "Error occurred on Fetch Many Comments With Author Service, while finding the comments liked by the user".into(),
+                &liked_comment_ids.unwrap_err(),
+            ));
+        }
+
+        let liked_comment_ids = liked_comment_ids.unwrap();
+
+        for comment in comments.iter_mut() {
+            comment.set_liked_by_me(Some(liked_comment_ids.contains(&comment.id())));
+        }
+
+        Ok(())
+    }
 }
 
 #[cfg(test)]
@@ -86,35 +216,43 @@ mod test {
     use crate::domain::domain_entities::user::User;
     use crate::domain::domain_entities::role::Role;
     use crate::libs::time::TimeHelper;
+    use crate::domain::domain_entities::comment_like::CommentLike;
     use crate::tests::repositories::article_repository::get_article_repository;
+    use crate::tests::repositories::comment_like_repository::get_comment_like_repository;
+    use crate::tests::repositories::comment_user_article_repository::get_comment_user_article_repository;
+    use chrono::Duration;
 
     #[tokio::test]
     async fn test() {
         let mut mocked_comment_repo: MockCommentUserArticleRepositoryTrait = MockCommentUserArticleRepositoryTrait::new();
-        let (article_db, _) = get_article_repository();
+        let (article_db, mocked_article_repo) = get_article_repository();
 
         let mut db: Vec<CommentWithAuthor> = Vec::new();
 
         let user = User::new("Floricultor".to_string(), "password".to_string(), Some(Role::Principal));
-        let article = Article::new(user.id(), "Título da notícia".into(), "Conteúdo da notícia".into(), "url do cover".into(), 1, "Foo".into());
+        let article = Article::new(user.id(), "Título da notícia".into(), "Conteúdo da notícia".into(), None, "url do cover".into(), 1, "Foo".into());
         let article_id = article.id();
 
         article_db.lock().unwrap().push(article.clone());
 
-        db.push(CommentWithAuthor::new(Some(article.id()), "Comment 1 content here".to_string(), user.clone()));
-        db.push(CommentWithAuthor::new(Some(article.id()), "Comment 2 content here".to_string(), user.clone()));
+        db.push(CommentWithAuthor::new(Some(article.id()), "Comment 1 content here".to_string(), user.clone(), None));
+        db.push(CommentWithAuthor::new(Some(article.id()), "Comment 2 content here".to_string(), user.clone(), None));
         db.push(CommentWithAuthor::new_from_existing(
             Uuid::new_v4(),
             Some(article.id()),
             "Comment 2 content here".into(),
             false,
             TimeHelper::now(),
-            user.clone()
+            user.clone(),
+            None,
+            0,
+            None,
+            None,
         ));
 
         mocked_comment_repo
             .expect_find_many_comments()
-            .returning(move |_article_id, include_inactive, params| {
+            .returning(move |_article_id, include_inactive, _sort, params| {
                 let PaginationParameters { page, items_per_page, query: _ } = params;
 
                 let mut comments: Vec<CommentWithAuthor> = Vec::new();
@@ -141,13 +279,15 @@ mod test {
             });
 
         let fetch_many_comments_service =
-            FetchManyArticleCommentsWithAuthorService::new(Box::new(mocked_comment_repo));
+            FetchManyArticleCommentsWithAuthorService::new(Box::new(mocked_comment_repo), Box::new(mocked_article_repo), Box::new(get_comment_like_repository().1));
 
         let response = fetch_many_comments_service.exec(
             article_id.clone(),
             FetchManyArticleCommentsWithAuthorParams {
                 page: None,
                 per_page: None,
+                sort: CommentSortOrder::Newest,
+                user_id: None,
             }
         ).await.unwrap();
 
@@ -156,4 +296,151 @@ mod test {
         assert_eq!(response.data[0].content(), "Comment 1 content here");
         assert_eq!(response.data[1].content(), "Comment 2 content here");
     }
+
+    #[tokio::test]
+    async fn test_comments_can_be_sorted_and_paginated_by_cursor() {
+        let (comments_db, mocked_comment_repo) = get_comment_user_article_repository();
+        let (article_db, mocked_article_repo) = get_article_repository();
+
+        let user = User::new("Floricultor".to_string(), "password".to_string(), Some(Role::Principal));
+        let mut article = Article::new(user.id(), "Título da notícia".into(), "Conteúdo da notícia".into(), None, "url do cover".into(), 1, "Foo".into());
+        article.set_approved(true);
+
+        let unapproved_article = Article::new(user.id(), "Rascunho".into(), "Conteúdo do rascunho".into(), None, "url do cover".into(), 1, "Foo".into());
+
+        let now = TimeHelper::now();
+
+        for (index, reply_count) in [2, 0, 5, 2].into_iter().enumerate() {
+            comments_db.lock().unwrap().push(CommentWithAuthor::new_from_existing(
+                Uuid::new_v4(),
+                Some(article.id()),
+                format!("Comment {index}"),
+                true,
+                now - Duration::minutes(10 - index as i64),
+                user.clone(),
+                None,
+                reply_count,
+                None,
+                None,
+            ));
+        }
+
+        article_db.lock().unwrap().push(article.clone());
+        article_db.lock().unwrap().push(unapproved_article.clone());
+
+        let sut = FetchManyArticleCommentsWithAuthorService::new(Box::new(mocked_comment_repo), Box::new(mocked_article_repo), Box::new(get_comment_like_repository().1));
+
+        let contents = |response: &FetchManyCommentsWithAuthorByCursorResponse| -> Vec<String> {
+            response.data.iter().map(|comment| comment.content().to_string()).collect()
+        };
+
+        let first_page = sut.exec_by_cursor(FetchManyArticleCommentsByCursorParams {
+            article_slug: article.slug(),
+            cursor: None,
+            per_page: Some(2),
+            sort: CommentSortOrder::MostReplied,
+            user_id: None,
+        }).await.unwrap();
+
+        assert_eq!(vec!["Comment 2", "Comment 3"], contents(&first_page));
+        assert!(first_page.pagination.prev_cursor.is_none());
+
+        let second_page = sut.exec_by_cursor(FetchManyArticleCommentsByCursorParams {
+            article_slug: article.slug(),
+            cursor: first_page.pagination.next_cursor.clone(),
+            per_page: Some(2),
+            sort: CommentSortOrder::MostReplied,
+            user_id: None,
+        }).await.unwrap();
+
+        assert_eq!(vec!["Comment 0", "Comment 1"], contents(&second_page));
+        assert!(second_page.pagination.next_cursor.is_none());
+
+        let back_to_first_page = sut.exec_by_cursor(FetchManyArticleCommentsByCursorParams {
+            article_slug: article.slug(),
+            cursor: second_page.pagination.prev_cursor.clone(),
+            per_page: Some(2),
+            sort: CommentSortOrder::MostReplied,
+            user_id: None,
+        }).await.unwrap();
+
+        assert_eq!(contents(&first_page), contents(&back_to_first_page));
+
+        let oldest = sut.exec_by_cursor(FetchManyArticleCommentsByCursorParams {
+            article_slug: article.slug(),
+            cursor: None,
+            per_page: Some(3),
+            sort: CommentSortOrder::Oldest,
+            user_id: None,
+        }).await.unwrap();
+
+        assert_eq!(vec!["Comment 0", "Comment 1", "Comment 2"], contents(&oldest));
+
+        let cursor_from_another_sort = sut.exec_by_cursor(FetchManyArticleCommentsByCursorParams {
+            article_slug: article.slug(),
+            cursor: oldest.pagination.next_cursor,
+            per_page: Some(3),
+            sort: CommentSortOrder::MostReplied,
+            user_id: None,
+        }).await;
+
+        assert_eq!(&400, cursor_from_another_sort.unwrap_err().code());
+
+        let unapproved_article_comments = sut.exec_by_cursor(FetchManyArticleCommentsByCursorParams {
+            article_slug: unapproved_article.slug(),
+            cursor: None,
+            per_page: None,
+            sort: CommentSortOrder::Newest,
+            user_id: None,
+        }).await;
+
+        assert_eq!(&404, unapproved_article_comments.unwrap_err().code());
+    }
+
+    #[tokio::test]
+    async fn test_if_top_sort_ranks_by_likes_and_tells_what_the_user_liked() {
+        let (comments_db, mocked_comment_repo) = get_comment_user_article_repository();
+        let (article_db, mocked_article_repo) = get_article_repository();
+        let (like_db, mocked_like_repo) = get_comment_like_repository();
+
+        let user = User::new("Floricultor".to_string(), "password".to_string(), Some(Role::Principal));
+        let mut article = Article::new(user.id(), "Título da notícia".into(), "Conteúdo da notícia".into(), None, "url do cover".into(), 1, "Foo".into());
+        article.set_approved(true);
+
+        for (index, like_count) in [1, 7, 3].into_iter().enumerate() {
+            let mut comment = CommentWithAuthor::new(Some(article.id()), format!("Comment {index}"), user.clone(), None);
+            comment.set_like_count(like_count);
+            comments_db.lock().unwrap().push(comment);
+        }
+
+        article_db.lock().unwrap().push(article.clone());
+
+        let viewer_id = Uuid::new_v4();
+        let liked_comment_id = comments_db.lock().unwrap()[2].id();
+        like_db.lock().unwrap().push(CommentLike::new(liked_comment_id, viewer_id));
+
+        let sut = FetchManyArticleCommentsWithAuthorService::new(Box::new(mocked_comment_repo), Box::new(mocked_article_repo), Box::new(mocked_like_repo));
+
+        let top = sut.exec_by_cursor(FetchManyArticleCommentsByCursorParams {
+            article_slug: article.slug(),
+            cursor: None,
+            per_page: None,
+            sort: CommentSortOrder::Top,
+            user_id: Some(viewer_id),
+        }).await.unwrap();
+
+        let ranking: Vec<(&str, Option<bool>)> = top.data.iter().map(|comment| (comment.content(), comment.liked_by_me())).collect();
+
+        assert_eq!(vec![("Comment 1", Some(false)), ("Comment 2", Some(true)), ("Comment 0", Some(false))], ranking);
+
+        let anonymous = sut.exec_by_cursor(FetchManyArticleCommentsByCursorParams {
+            article_slug: article.slug(),
+            cursor: None,
+            per_page: None,
+            sort: CommentSortOrder::Top,
+            user_id: None,
+        }).await.unwrap();
+
+        assert!(anonymous.data.iter().all(|comment| comment.liked_by_me().is_none()));
+    }
 }
\ No newline at end of file
@@ -1,6 +1,7 @@
-use crate::core::pagination::{PaginationParameters, PaginationResponse, DEFAULT_PER_PAGE};
+use crate::core::pagination::{CursorPaginationParameters, CursorPaginationResponse, PaginationCursor, PaginationParameters, PaginationResponse, DEFAULT_PER_PAGE};
 use crate::domain::domain_entities::user::User;
-use crate::domain::repositories::user_repository::{FindManyUsersResponse, UserQueryType, UserRepositoryTrait};
+use crate::domain::repositories::user_repository::{FindManyUsersByCursorResponse, FindManyUsersResponse, UserQueryType, UserRepositoryTrait};
+use crate::errors::bad_request_error::BadRequestError;
 use crate::errors::error::DomainErrorTrait;
 use crate::util::generate_service_internal_error;
 
@@ -16,6 +17,18 @@ pub struct FetchManyUsersParams {
     pub query: Option<UserQueryType>
 }
 
+#[derive(Debug)]
+pub struct FetchManyUsersByCursorResponse {
+    pub pagination: CursorPaginationResponse,
+    pub data: Vec<User>
+}
+
+pub struct FetchManyUsersByCursorParams {
+    pub cursor: Option<String>,
+    pub per_page: Option<u32>,
+    pub query: Option<UserQueryType>
+}
+
 pub struct FetchManyUsersService<UserRepository: UserRepositoryTrait> {
     user_repository: Box<UserRepository>
 }
@@ -60,15 +73,58 @@ impl<UserRepository: UserRepositoryTrait> FetchManyUsersService<UserRepository>
             }
         })
     }
+
+    pub async fn exec_by_cursor(&self, params: FetchManyUsersByCursorParams) -> Result<FetchManyUsersByCursorResponse, Box<dyn DomainErrorTrait>> {
+        let items_per_page = params.per_page.unwrap_or(DEFAULT_PER_PAGE as u32);
+
+        let cursor = match params.cursor {
+            None => None,
+            Some(cursor) => match PaginationCursor::decode(&cursor) {
+                Some(cursor) => Some(cursor),
+                None => return Err(Box::new(BadRequestError::new_with_message("Invalid pagination cursor.".into())))
+            }
+        };
+
+        let response = self.user_repository.find_many_by_cursor(CursorPaginationParameters {
+            items_per_page,
+            cursor: cursor.clone(),
+            query: params.query
+        }).await;
+
+        if response.is_err() {
+            return Err(generate_service_internal_error(
+                "Error occurred on Fetch Many Users Service, while selecting many users by cursor from the database",
+                response.as_ref().unwrap_err()
+            ));
+        }
+
+        let FindManyUsersByCursorResponse (users, has_more) = response.unwrap();
+
+        let pagination = CursorPaginationResponse::from_page_edges(
+            cursor.as_ref(),
+            users.first().map(|user| (user.created_at(), user.id())),
+            users.last().map(|user| (user.created_at(), user.id())),
+            has_more
+        );
+
+        Ok(FetchManyUsersByCursorResponse {
+            data: users,
+            pagination
+        })
+    }
 }
 
 #[cfg(test)]
 mod test {
     use crate::domain::domain_entities::role::Role;
     use crate::domain::repositories::user_repository::MockUserRepositoryTrait;
+    use crate::libs::time::TimeHelper;
+    use crate::tests::pagination::walk_by_cursor;
 
     use super::*;
     use tokio;
+    use chrono::Duration;
+    use uuid::Uuid;
     use std::sync::{Arc, Mutex};
 
     #[tokio::test]
@@ -163,4 +219,50 @@ mod test {
         assert_eq!(result_2.data[0].nickname(), "Flori".to_string());
         assert_eq!(result_2.data[1].nickname(), "Parme".to_string());
     }
+
+    #[tokio::test]
+    async fn test_by_cursor() {
+        let users: Vec<User> = ["Flori", "Parme", "Gorgon"].into_iter().enumerate().map(|(index, nickname)| User::new_from_existing(
+            Uuid::new_v4(),
+            nickname.into(),
+            "vsjkvdsknjsd".into(),
+            TimeHelper::now() - Duration::minutes(index as i64),
+            None,
+            Some(Role::User),
+            1,
+        )).collect();
+
+        let mut mocked_user_repository = MockUserRepositoryTrait::new();
+
+        mocked_user_repository
+        .expect_find_many_by_cursor()
+        .returning(move |params| {
+            let CursorPaginationParameters { cursor, items_per_page, .. } = params;
+            let (users, has_more) = walk_by_cursor(users.clone(), |user| (user.created_at(), user.id()), cursor, items_per_page);
+
+            Ok(FindManyUsersByCursorResponse (users, has_more))
+        });
+
+        let sut = FetchManyUsersService::new(Box::new(mocked_user_repository));
+
+        let by_cursor = |cursor: Option<String>| FetchManyUsersByCursorParams { cursor, per_page: Some(2), query: None };
+
+        let first_page = sut.exec_by_cursor(by_cursor(None)).await.unwrap();
+
+        assert_eq!(vec!["Flori", "Parme"], first_page.data.iter().map(|user| user.nickname()).collect::<Vec<_>>());
+        assert!(first_page.pagination.prev_cursor.is_none());
+
+        let second_page = sut.exec_by_cursor(by_cursor(first_page.pagination.next_cursor)).await.unwrap();
+
+        assert_eq!(vec!["Gorgon"], second_page.data.iter().map(|user| user.nickname()).collect::<Vec<_>>());
+        assert!(second_page.pagination.next_cursor.is_none());
+
+        let back_to_first_page = sut.exec_by_cursor(by_cursor(second_page.pagination.prev_cursor)).await.unwrap();
+
+        assert_eq!(first_page.data, back_to_first_page.data);
+
+        let invalid_cursor = sut.exec_by_cursor(by_cursor(Some("not-a-cursor".into()))).await;
+
+        assert_eq!(&400, invalid_cursor.unwrap_err().code());
+    }
 }
@@ -0,0 +1,67 @@
+use crate::core::pagination::{DEFAULT_PER_PAGE, PaginationParameters, PaginationResponse};
+use crate::domain::domain_entities::role::Role;
+use crate::domain::domain_entities::word_filter::WordFilter;
+use crate::domain::repositories::word_filter_repository::{FindManyWordFiltersResponse, WordFilterRepositoryTrait};
+use crate::errors::error::DomainErrorTrait;
+use crate::errors::unauthorized_error::UnauthorizedError;
+use crate::util::{generate_service_internal_error, RolePermissions, verify_role_has_permission};
+
+pub struct FetchManyWordFiltersParams {
+    pub user_role: Role,
+    pub page: Option<u32>,
+    pub per_page: Option<u32>,
+}
+
+#[derive(Debug)]
+pub struct FetchManyWordFiltersResponse {
+    pub pagination: PaginationResponse,
+    pub data: Vec<WordFilter>
+}
+
+pub struct FetchManyWordFiltersService<WordFilterRepository: WordFilterRepositoryTrait> {
+    word_filter_repository: Box<WordFilterRepository>
+}
+
+impl<WordFilterRepository: WordFilterRepositoryTrait> FetchManyWordFiltersService<WordFilterRepository> {
+    pub fn new(word_filter_repository: Box<WordFilterRepository>) -> Self {
+        FetchManyWordFiltersService {
+            word_filter_repository
+        }
+    }
+
+    /// The list itself is restricted, it being a guide on how to get around the filters.
+    pub async fn exec(&self, params: FetchManyWordFiltersParams) -> Result<FetchManyWordFiltersResponse, Box<dyn DomainErrorTrait>> {
+        let user_can_manage_word_filters = verify_role_has_permission(&params.user_role, RolePermissions::ManageWordFilters);
+
+        if !user_can_manage_word_filters {
+            return Err(Box::new(UnauthorizedError::new()));
+        }
+
+        let page = params.page.filter(|page| *page > 0).unwrap_or(1);
+        let items_per_page = params.per_page.unwrap_or(DEFAULT_PER_PAGE as u32);
+
+        let result = self.word_filter_repository.find_many(PaginationParameters {
+            page,
+            items_per_page,
+            query: None,
+        }).await;
+
+        if result.is_err() {
+            return Err(generate_service_internal_error(
+                "Error occurred in Fetch Many Word Filters Service, on fetching many word filters from database".into(),
+                &result.unwrap_err()
+            ));
+        }
+
+        let FindManyWordFiltersResponse (word_filters, total_items) = result.unwrap();
+
+        Ok(FetchManyWordFiltersResponse {
+            data: word_filters,
+            pagination: PaginationResponse {
+                current_page: page,
+                total_items,
+                total_pages: (total_items as f64 / items_per_page as f64).ceil() as u32
+            }
+        })
+    }
+}
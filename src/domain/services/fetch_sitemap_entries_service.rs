@@ -0,0 +1,93 @@
+use crate::domain::domain_entities::article_tag::ArticleTag;
+use crate::domain::repositories::article_repository::{ApprovedArticleSlug, ArticleRepositoryTrait};
+use crate::domain::repositories::article_tag_repository::ArticleTagRepositoryTrait;
+use crate::errors::error::DomainErrorTrait;
+use crate::util::generate_service_internal_error;
+
+#[derive(Debug)]
+pub struct FetchSitemapEntriesResponse {
+    pub articles: Vec<ApprovedArticleSlug>,
+    pub tags: Vec<ArticleTag>,
+}
+
+/**
+ # Fetch Sitemap Entries Service
+ Every publicly reachable content that should be indexed by search engines: the approved articles,
+ with their last modification date, and the article tags.
+ */
+pub struct FetchSitemapEntriesService<AR, ATR>
+where   AR: ArticleRepositoryTrait,
+        ATR: ArticleTagRepositoryTrait
+        {
+    article_repository: Box<AR>,
+    article_tag_repository: Box<ATR>,
+}
+
+impl<
+    AR: ArticleRepositoryTrait,
+    ATR: ArticleTagRepositoryTrait
+> FetchSitemapEntriesService<AR, ATR> {
+    pub fn new(article_repository: Box<AR>, article_tag_repository: Box<ATR>) -> Self {
+        FetchSitemapEntriesService {
+            article_repository,
+            article_tag_repository
+        }
+    }
+
+    pub async fn exec(&self) -> Result<FetchSitemapEntriesResponse, Box<dyn DomainErrorTrait>> {
+        let articles = self.article_repository.find_approved_slugs().await;
+
+        if articles.is_err() {
+            return Err(generate_service_internal_error(
+                "Error occurred on Fetch Sitemap Entries Service, while finding the approved articles' slugs",
+                &articles.unwrap_err()
+            ));
+        }
+
+        let tags = self.article_tag_repository.find_all().await;
+
+        if tags.is_err() {
+            return Err(generate_service_internal_error(
+                "Error occurred on Fetch Sitemap Entries Service, while finding the article tags",
+                &tags.unwrap_err()
+            ));
+        }
+
+        Ok(FetchSitemapEntriesResponse {
+            articles: articles.unwrap(),
+            tags: tags.unwrap(),
+        })
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use uuid::Uuid;
+
+    use crate::domain::domain_entities::article::Article;
+    use crate::tests::repositories::article_repository::get_article_repository;
+    use crate::tests::repositories::article_tag_repository::get_article_tag_repository;
+
+    #[tokio::test]
+    async fn test_if_only_approved_articles_are_listed() {
+        let (article_db, article_repository) = get_article_repository();
+        let (tag_db, article_tag_repository) = get_article_tag_repository();
+
+        let mut approved_article = Article::new(Uuid::new_v4(), "Notícia aprovada".into(), "Conteúdo".into(), None, "url".into(), 1, "Tag".into());
+        approved_article.set_approved(true);
+
+        article_db.lock().unwrap().push(approved_article.clone());
+        article_db.lock().unwrap().push(Article::new(Uuid::new_v4(), "Rascunho".into(), "Conteúdo".into(), None, "url".into(), 1, "Tag".into()));
+        tag_db.lock().unwrap().push(ArticleTag::new_from_existing(1, "Tag".into(), 1));
+
+        let sut = FetchSitemapEntriesService::new(Box::new(article_repository), Box::new(article_tag_repository));
+
+        let FetchSitemapEntriesResponse { articles, tags } = sut.exec().await.unwrap();
+
+        assert_eq!(1, articles.len());
+        assert_eq!(approved_article.slug(), articles[0].0);
+        assert_eq!(approved_article.created_at(), articles[0].1);
+        assert_eq!(1, tags.len());
+    }
+}
@@ -0,0 +1,100 @@
+use std::collections::HashMap;
+
+use chrono::Duration;
+use uuid::Uuid;
+
+use crate::domain::domain_entities::article::Article;
+use crate::domain::domain_entities::article_view::ArticleDailyViews;
+use crate::domain::politics::article_politics::ArticlePolitics;
+use crate::domain::repositories::article_view_repository::ArticleViewRepositoryTrait;
+use crate::errors::error::DomainErrorTrait;
+use crate::libs::time::TimeHelper;
+use crate::util::generate_service_internal_error;
+
+pub struct FetchTrendingArticlesParams {
+    pub limit: Option<u32>,
+}
+
+pub struct FetchTrendingArticlesService<ArticleViewRepository: ArticleViewRepositoryTrait> {
+    article_view_repository: Box<ArticleViewRepository>,
+}
+
+impl<ArticleViewRepository: ArticleViewRepositoryTrait> FetchTrendingArticlesService<ArticleViewRepository> {
+    pub fn new(article_view_repository: Box<ArticleViewRepository>) -> Self {
+        FetchTrendingArticlesService {
+            article_view_repository
+        }
+    }
+
+    pub async fn exec(&self, params: FetchTrendingArticlesParams) -> Result<Vec<Article>, Box<dyn DomainErrorTrait>> {
+        let default_limit = 5;
+        let limit = params.limit.unwrap_or(default_limit) as usize;
+
+        let today = TimeHelper::now().date();
+        let since = today - Duration::try_days(ArticlePolitics::trending_window_days()).unwrap();
+
+        let daily_views = self.article_view_repository.find_approved_daily_views_since(since).await;
+
+        if daily_views.is_err() {
+            return Err(generate_service_internal_error(
+                "Error occurred on Fetch Trending Articles Service, while finding the articles' daily views",
+                &daily_views.unwrap_err()
+            ));
+        }
+
+        let mut articles_views: HashMap<Uuid, (Article, Vec<ArticleDailyViews>)> = HashMap::new();
+
+        for (bucket, article) in daily_views.unwrap() {
+            articles_views
+                .entry(article.id())
+                .or_insert_with(|| (article, vec![]))
+                .1
+                .push(bucket);
+        }
+
+        let mut ranking = articles_views
+            .into_values()
+            .map(|(article, buckets)| (ArticlePolitics::trending_score(&buckets, today), article))
+            .collect::<Vec<(f64, Article)>>();
+
+        ranking.sort_by(|(score_a, article_a), (score_b, article_b)| {
+            score_b.total_cmp(score_a).then_with(|| article_b.created_at().cmp(&article_a.created_at()))
+        });
+
+        Ok(ranking.into_iter().take(limit).map(|(_score, article)| article).collect())
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::domain::repositories::article_view_repository::MockArticleViewRepositoryTrait;
+
+    #[tokio::test]
+    async fn test_if_recent_views_weight_more_than_old_ones() {
+        let mut article_view_repository = MockArticleViewRepositoryTrait::new();
+
+        let today = TimeHelper::now().date();
+
+        let old_hit = Article::new(Uuid::new_v4(), "Old hit".into(), "content".into(), None, "url".into(), 1, "Foo".into());
+        let fresh_news = Article::new(Uuid::new_v4(), "Fresh news".into(), "content".into(), None, "url".into(), 1, "Foo".into());
+        let unnoticed = Article::new(Uuid::new_v4(), "Unnoticed".into(), "content".into(), None, "url".into(), 1, "Foo".into());
+
+        let daily_views = vec![
+            (ArticleDailyViews::new_from_existing(old_hit.id(), today - Duration::try_days(6).unwrap(), 100), old_hit.clone()),
+            (ArticleDailyViews::new_from_existing(fresh_news.id(), today, 30), fresh_news.clone()),
+            (ArticleDailyViews::new_from_existing(fresh_news.id(), today - Duration::try_days(1).unwrap(), 10), fresh_news.clone()),
+            (ArticleDailyViews::new_from_existing(unnoticed.id(), today, 1), unnoticed.clone()),
+        ];
+
+        article_view_repository
+            .expect_find_approved_daily_views_since()
+            .returning(move |_since| Ok(daily_views.clone()));
+
+        let sut = FetchTrendingArticlesService::new(Box::new(article_view_repository));
+
+        let result = sut.exec(FetchTrendingArticlesParams { limit: Some(2) }).await.unwrap();
+
+        assert_eq!(vec!["Fresh news", "Old hit"], result.iter().map(|article| article.title()).collect::<Vec<&str>>());
+    }
+}
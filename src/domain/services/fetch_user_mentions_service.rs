@@ -0,0 +1,112 @@
+use uuid::Uuid;
+
+use crate::core::pagination::{CursorPaginationParameters, CursorPaginationResponse, DEFAULT_PER_PAGE, PaginationCursor, PaginationParameters, PaginationResponse};
+use crate::domain::domain_entities::mention::Mention;
+use crate::domain::repositories::mention_repository::{FindManyMentionsByCursorResponse, FindManyMentionsResponse, MentionRepositoryTrait};
+use crate::errors::bad_request_error::BadRequestError;
+use crate::errors::error::DomainErrorTrait;
+use crate::util::generate_service_internal_error;
+
+pub struct FetchUserMentionsParams {
+    pub user_id: Uuid,
+    pub page: Option<u32>,
+    pub per_page: Option<u32>,
+}
+
+#[derive(Debug)]
+pub struct FetchUserMentionsResponse {
+    pub pagination: PaginationResponse,
+    pub data: Vec<Mention>,
+}
+
+pub struct FetchUserMentionsByCursorParams {
+    pub user_id: Uuid,
+    pub cursor: Option<String>,
+    pub per_page: Option<u32>,
+}
+
+#[derive(Debug)]
+pub struct FetchUserMentionsByCursorResponse {
+    pub pagination: CursorPaginationResponse,
+    pub data: Vec<Mention>,
+}
+
+pub struct FetchUserMentionsService<MentionRepository: MentionRepositoryTrait> {
+    mention_repository: Box<MentionRepository>,
+}
+
+impl<MentionRepository: MentionRepositoryTrait> FetchUserMentionsService<MentionRepository> {
+    pub fn new(mention_repository: Box<MentionRepository>) -> Self {
+        FetchUserMentionsService {
+            mention_repository,
+        }
+    }
+
+    pub async fn exec(&self, params: FetchUserMentionsParams) -> Result<FetchUserMentionsResponse, Box<dyn DomainErrorTrait>> {
+        let items_per_page = params.per_page.unwrap_or(DEFAULT_PER_PAGE as u32);
+        let page = params.page.filter(|page| *page > 0).unwrap_or(1);
+
+        let response = self.mention_repository.find_many_by_user(params.user_id, PaginationParameters {
+            items_per_page,
+            page,
+            query: None,
+        }).await;
+
+        if let Err(err) = response {
+            return Err(generate_service_internal_error(
+                "Error occurred on Fetch User Mentions Service, while fetching the user's mentions".into(),
+                &err,
+            ));
+        }
+
+        let FindManyMentionsResponse (mentions, total_items) = response.unwrap();
+
+        Ok(FetchUserMentionsResponse {
+            data: mentions,
+            pagination: PaginationResponse {
+                current_page: page,
+                total_items,
+                total_pages: (total_items as f64 / items_per_page as f64).ceil() as u32
+            }
+        })
+    }
+
+    pub async fn exec_by_cursor(&self, params: FetchUserMentionsByCursorParams) -> Result<FetchUserMentionsByCursorResponse, Box<dyn DomainErrorTrait>> {
+        let items_per_page = params.per_page.unwrap_or(DEFAULT_PER_PAGE as u32);
+
+        let cursor = match params.cursor {
+            None => None,
+            Some(cursor) => match PaginationCursor::decode(&cursor) {
+                Some(cursor) => Some(cursor),
+                None => return Err(Box::new(BadRequestError::new_with_message("Invalid pagination cursor.".into())))
+            }
+        };
+
+        let response = self.mention_repository.find_many_by_user_by_cursor(params.user_id, CursorPaginationParameters {
+            items_per_page,
+            cursor: cursor.clone(),
+            query: None,
+        }).await;
+
+        if let Err(err) = response {
+            return Err(generate_service_internal_error(
+                "Error occurred on Fetch User Mentions Service, while fetching the user's mentions by cursor".into(),
+                &err,
+            ));
+        }
+
+        let FindManyMentionsByCursorResponse (mentions, has_more) = response.unwrap();
+
+        let pagination = CursorPaginationResponse::from_page_edges(
+            cursor.as_ref(),
+            mentions.first().map(|mention| (mention.created_at(), mention.id())),
+            mentions.last().map(|mention| (mention.created_at(), mention.id())),
+            has_more
+        );
+
+        Ok(FetchUserMentionsByCursorResponse {
+            data: mentions,
+            pagination
+        })
+    }
+}
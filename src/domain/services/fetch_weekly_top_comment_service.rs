@@ -0,0 +1,115 @@
+use chrono::Duration;
+use uuid::Uuid;
+
+use crate::domain::domain_entities::comment_with_author::CommentWithAuthor;
+use crate::domain::politics::comment_politics::CommentPolitics;
+use crate::domain::repositories::comment_like_repository::CommentLikeRepositoryTrait;
+use crate::domain::repositories::comment_user_article_repository::CommentUserArticleRepositoryTrait;
+use crate::errors::error::DomainErrorTrait;
+use crate::libs::time::TimeHelper;
+use crate::util::generate_service_internal_error;
+
+pub struct FetchWeeklyTopCommentParams {
+    /// The logged-in user, to tell whether they liked the comment.
+    pub user_id: Option<Uuid>,
+}
+
+/**
+ # Fetch Weekly Top Comment Service
+ The comment that got the most likes over the last `CommentPolitics::top_comment_window_days`,
+ featured on the home page. `None` if no comment was liked in that time.
+ */
+pub struct FetchWeeklyTopCommentService<CUAR, CLR>
+where   CUAR: CommentUserArticleRepositoryTrait,
+        CLR: CommentLikeRepositoryTrait
+        {
+    comment_user_article_repository: Box<CUAR>,
+    comment_like_repository: Box<CLR>,
+}
+
+impl<
+    CUAR: CommentUserArticleRepositoryTrait,
+    CLR: CommentLikeRepositoryTrait
+> FetchWeeklyTopCommentService<CUAR, CLR> {
+    pub fn new(comment_user_article_repository: Box<CUAR>, comment_like_repository: Box<CLR>) -> Self {
+        FetchWeeklyTopCommentService {
+            comment_user_article_repository,
+            comment_like_repository
+        }
+    }
+
+    pub async fn exec(&self, params: FetchWeeklyTopCommentParams) -> Result<Option<CommentWithAuthor>, Box<dyn DomainErrorTrait>> {
+        let since = TimeHelper::now() - Duration::try_days(CommentPolitics::top_comment_window_days()).unwrap();
+
+        let comment = self.comment_user_article_repository.find_most_liked_since(since).await;
+
+        if comment.is_err() {
+            return Err(generate_service_internal_error(
+                "Error occurred on Fetch Weekly Top Comment Service, while finding the most liked comment".into(),
+                &comment.unwrap_err()
+            ));
+        }
+
+        let (mut comment, user_id) = match (comment.unwrap(), params.user_id) {
+            (None, _) => return Ok(None),
+            (Some(comment), None) => return Ok(Some(comment)),
+            (Some(comment), Some(user_id)) => (comment, user_id),
+        };
+
+        let like = self.comment_like_repository.find_by_user(comment.id(), user_id).await;
+
+        if like.is_err() {
+            return Err(generate_service_internal_error(
+                "Error occurred on Fetch Weekly Top Comment Service, while finding the user's like".into(),
+                &like.unwrap_err()
+            ));
+        }
+
+        comment.set_liked_by_me(Some(like.unwrap().is_some()));
+
+        Ok(Some(comment))
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    use crate::domain::domain_entities::comment_like::CommentLike;
+    use crate::domain::domain_entities::role::Role;
+    use crate::domain::domain_entities::user::User;
+    use crate::domain::repositories::comment_user_article_repository::MockCommentUserArticleRepositoryTrait;
+    use crate::tests::repositories::comment_like_repository::get_comment_like_repository;
+
+    #[tokio::test]
+    async fn test() {
+        let mut comment_user_article_repository = MockCommentUserArticleRepositoryTrait::new();
+        let (like_db, comment_like_repository) = get_comment_like_repository();
+
+        let author = User::new("Floricultor".into(), "password".into(), Some(Role::User));
+        let mut top_comment = CommentWithAuthor::new(Some(Uuid::new_v4()), "Melhor evento do ano!".into(), author, None);
+        top_comment.set_like_count(12);
+
+        let viewer_id = Uuid::new_v4();
+        like_db.lock().unwrap().push(CommentLike::new(top_comment.id(), viewer_id));
+
+        comment_user_article_repository
+            .expect_find_most_liked_since()
+            .withf(|since| {
+                let window = TimeHelper::now().signed_duration_since(*since);
+                window.num_days() == CommentPolitics::top_comment_window_days()
+            })
+            .returning(move |_since| Ok(Some(top_comment.clone())));
+
+        let sut = FetchWeeklyTopCommentService::new(Box::new(comment_user_article_repository), Box::new(comment_like_repository));
+
+        let anonymous_result = sut.exec(FetchWeeklyTopCommentParams { user_id: None }).await.unwrap().unwrap();
+
+        assert_eq!(12, anonymous_result.like_count());
+        assert_eq!(None, anonymous_result.liked_by_me());
+
+        let viewer_result = sut.exec(FetchWeeklyTopCommentParams { user_id: Some(viewer_id) }).await.unwrap().unwrap();
+
+        assert_eq!(Some(true), viewer_result.liked_by_me());
+    }
+}
@@ -1,23 +1,32 @@
 use uuid::Uuid;
-use crate::core::pagination::PaginationResponse;
+use crate::core::pagination::{CursorDirection, PaginationCursor, PaginationResponse};
 use crate::core::pagination::DEFAULT_PER_PAGE;
 use crate::domain::domain_entities::article::Article;
+use crate::domain::domain_entities::article_coauthor::ArticleCoauthor;
+use crate::domain::domain_entities::article_content::{ArticleContent, RenderedArticleContent};
+use crate::domain::domain_entities::article_reaction::ArticleReactionsSummary;
 use crate::domain::domain_entities::comment_with_author::CommentWithAuthor;
+use crate::domain::domain_entities::mention::Mention;
 use crate::domain::domain_entities::role::Role;
 use crate::domain::domain_entities::slug::Slug;
 use crate::domain::domain_entities::user::User;
 use crate::domain::repositories::comment_user_article_repository::FindManyCommentsWithAuthorResponse;
-use crate::domain::repositories::comment_user_article_repository::CommentUserArticleRepositoryTrait;
+use crate::domain::repositories::comment_user_article_repository::{CommentSortOrder, CommentUserArticleRepositoryTrait};
 use crate::core::pagination::PaginationParameters;
 use crate::errors::error::DomainErrorTrait;
 use crate::errors::resource_not_found::ResourceNotFoundError;
 use crate::domain::repositories::article_repository::ArticleRepositoryTrait;
+use crate::domain::repositories::article_reaction_repository::ArticleReactionRepositoryTrait;
+use crate::domain::repositories::article_coauthor_repository::ArticleCoauthorRepositoryTrait;
+use crate::domain::repositories::comment_like_repository::CommentLikeRepositoryTrait;
+use crate::domain::repositories::mention_repository::MentionRepositoryTrait;
 use crate::domain::repositories::user_repository::UserRepositoryTrait;
 use crate::util::{verify_role_has_permission, RolePermissions, generate_service_internal_error};
 
 pub struct GetExpandedArticleParams<'exec> {
     pub article_slug: Slug,
     pub comments_per_page: Option<u32>,
+    pub comments_sort: CommentSortOrder,
     pub user_role: Option<&'exec Role>,
     pub user_id: Option<&'exec Uuid>,
 }
@@ -25,40 +34,68 @@ pub struct GetExpandedArticleParams<'exec> {
 #[derive(Debug)]
 pub struct FetchManyCommentsWithAuthorResponse {
     pub pagination: PaginationResponse,
-    pub data: Vec<CommentWithAuthor>
+    pub data: Vec<CommentWithAuthor>,
+    /// Continues the listing on the comments' cursor pagination, with the same sort.
+    pub next_cursor: Option<String>,
 }
 
 #[derive(Debug)]
 pub struct GetExpandedArticleResponse {
     pub article: Article,
+    pub content: RenderedArticleContent,
     pub article_author: User,
+    /// In the order they are credited.
+    pub coauthors: Vec<(ArticleCoauthor, User)>,
     pub comments: FetchManyCommentsWithAuthorResponse,
+    pub reactions: ArticleReactionsSummary,
+    /// Users mentioned in the article's Markdown content.
+    pub mentions: Vec<Mention>,
 }
 
-pub struct GetExpandedArticleService<UR, AR, CUAR>
+pub struct GetExpandedArticleService<UR, AR, CUAR, ARR, ACR, MR, CLR>
 where   UR: UserRepositoryTrait,
         AR: ArticleRepositoryTrait,
-        CUAR: CommentUserArticleRepositoryTrait
+        CUAR: CommentUserArticleRepositoryTrait,
+        ARR: ArticleReactionRepositoryTrait,
+        ACR: ArticleCoauthorRepositoryTrait,
+        MR: MentionRepositoryTrait,
+        CLR: CommentLikeRepositoryTrait
         {
     user_repository: Box<UR>,
     article_repository: Box<AR>,
-    comment_user_article_repository: Box<CUAR>
+    comment_user_article_repository: Box<CUAR>,
+    article_reaction_repository: Box<ARR>,
+    article_coauthor_repository: Box<ACR>,
+    mention_repository: Box<MR>,
+    comment_like_repository: Box<CLR>
 }
 
 impl<
     UR: UserRepositoryTrait,
     AR: ArticleRepositoryTrait,
-    CUAR: CommentUserArticleRepositoryTrait
-> GetExpandedArticleService<UR, AR, CUAR> {
+    CUAR: CommentUserArticleRepositoryTrait,
+    ARR: ArticleReactionRepositoryTrait,
+    ACR: ArticleCoauthorRepositoryTrait,
+    MR: MentionRepositoryTrait,
+    CLR: CommentLikeRepositoryTrait
+> GetExpandedArticleService<UR, AR, CUAR, ARR, ACR, MR, CLR> {
     pub fn new(
         user_repository: Box<UR>,
         article_repository: Box<AR>,
-        comment_user_article_repository: Box<CUAR>
+        comment_user_article_repository: Box<CUAR>,
+        article_reaction_repository: Box<ARR>,
+        article_coauthor_repository: Box<ACR>,
+        mention_repository: Box<MR>,
+        comment_like_repository: Box<CLR>
     ) -> Self {
         GetExpandedArticleService {
             user_repository,
             article_repository,
-            comment_user_article_repository
+            comment_user_article_repository,
+            article_reaction_repository,
+            article_coauthor_repository,
+            mention_repository,
+            comment_like_repository
         }
     }
 
@@ -82,17 +119,22 @@ impl<
 
         let article = article.unwrap();
 
-        let user_can_see_article = {
-            if params.user_id.is_none() || params.user_role.is_none() {
-                false
-            } else if article.author_id().eq(params.user_id.unwrap()) {
-                true
-            } else if verify_role_has_permission(params.user_role.unwrap(), RolePermissions::SeeUnapprovedArticle) {
-                true
-            } else {
-                false
-            }
-        };
+        let coauthors = self.article_coauthor_repository.find_by_article(article.id()).await;
+
+        if coauthors.is_err() {
+            return Err(generate_service_internal_error(
+                "Error occurred on Get Expanded Article Service, while finding the article's co-authors".into(),
+                &coauthors.unwrap_err(),
+            ));
+        }
+
+        let coauthors = coauthors.unwrap();
+
+        let user_can_see_article = params.user_id.zip(params.user_role).is_some_and(|(user_id, user_role)| {
+            article.author_id().eq(user_id)
+                || coauthors.iter().any(|coauthor| coauthor.user_id().eq(user_id))
+                || verify_role_has_permission(user_role, RolePermissions::SeeUnapprovedArticle)
+        });
 
         if !article.approved() && !user_can_see_article {
             return Err(Box::new(ResourceNotFoundError::new()));
@@ -101,6 +143,7 @@ impl<
         let comments = self.comment_user_article_repository.find_many_comments(
             article.id(),
             false,
+            params.comments_sort,
             PaginationParameters {
                 items_per_page,
                 page: 1,
@@ -115,10 +158,40 @@ impl<
             ));
         }
 
-        let FindManyCommentsWithAuthorResponse (data, total_items) = comments.unwrap();
+        let FindManyCommentsWithAuthorResponse (mut data, total_items) = comments.unwrap();
+
+        if let Some(user_id) = params.user_id {
+            let liked_comment_ids = self.comment_like_repository
+                .find_liked_comment_ids(*user_id, data.iter().map(|comment| comment.id()).collect())
+                .await;
+
+            if liked_comment_ids.is_err() {
+                return Err(generate_service_internal_error(
+                    "Error occurred on Get Expanded Article Service, while finding the comments liked by the user".into(),
+                    &liked_comment_ids.unwrap_err(),
+                ));
+            }
+
+            let liked_comment_ids = liked_comment_ids.unwrap();
+
+            for comment in data.iter_mut() {
+                comment.set_liked_by_me(Some(liked_comment_ids.contains(&comment.id())));
+            }
+        }
+
+        // the cursor listing walks past the pinned comments, so the cursor starts from the last unpinned one
+        let next_cursor = match data.iter().rev().find(|comment| comment.pinned_at().is_none()) {
+            Some(last) if total_items > data.len() as u64 => Some(
+                PaginationCursor::new(last.created_at(), last.id(), CursorDirection::After)
+                    .with_rank(params.comments_sort.rank(last))
+                    .encode()
+            ),
+            _ => None,
+        };
 
         let comments = FetchManyCommentsWithAuthorResponse {
             data,
+            next_cursor,
             pagination: PaginationResponse {
                 current_page: 1,
                 total_items,
@@ -144,10 +217,68 @@ impl<
 
         let author = author.unwrap();
 
+        let mut coauthor_users = Vec::with_capacity(coauthors.len());
+
+        for coauthor in coauthors {
+            let user = self.user_repository.find_by_id(&coauthor.user_id()).await;
+
+            if user.is_err() {
+                return Err(generate_service_internal_error(
+                    "Error occurred on Get Expanded Article Service, while finding co-author by id".into(),
+                    &user.unwrap_err(),
+                ));
+            }
+
+            if let Some(user) = user.unwrap() {
+                coauthor_users.push((coauthor, user));
+            }
+        }
+
+        let reaction_counts = self.article_reaction_repository.count_by_article(article.id()).await;
+
+        if reaction_counts.is_err() {
+            return Err(generate_service_internal_error(
+                "Error occurred on Get Expanded Article Service, while counting the article's reactions".into(),
+                &reaction_counts.unwrap_err(),
+            ));
+        }
+
+        let user_reaction = match params.user_id {
+            None => Ok(None),
+            Some(user_id) => self.article_reaction_repository.find_by_user(article.id(), *user_id).await
+        };
+
+        if user_reaction.is_err() {
+            return Err(generate_service_internal_error(
+                "Error occurred on Get Expanded Article Service, while finding the user's reaction".into(),
+                &user_reaction.unwrap_err(),
+            ));
+        }
+
+        let reactions = ArticleReactionsSummary::new(
+            reaction_counts.unwrap(),
+            user_reaction.unwrap().map(|reaction| reaction.kind())
+        );
+
+        let mentions = self.mention_repository.find_many_by_article(article.id()).await;
+
+        if mentions.is_err() {
+            return Err(generate_service_internal_error(
+                "Error occurred on Get Expanded Article Service, while finding the article's mentions".into(),
+                &mentions.unwrap_err(),
+            ));
+        }
+
+        let content = ArticleContent::from_article(&article).render();
+
         Ok(GetExpandedArticleResponse {
+            content,
             article,
             article_author: author,
-            comments
+            coauthors: coauthor_users,
+            comments,
+            reactions,
+            mentions: mentions.unwrap()
         })
     }
 }
@@ -164,13 +295,21 @@ mod test {
     use crate::domain::repositories::comment_user_article_repository::{CommentWithAuthorQueryType, MockCommentUserArticleRepositoryTrait};
     use crate::domain::repositories::user_repository::MockUserRepositoryTrait;
     use crate::libs::time::TimeHelper;
+    use crate::domain::domain_entities::article_reaction::{ArticleReaction, ReactionKind};
+    use crate::tests::repositories::article_reaction_repository::get_article_reaction_repository;
     use crate::tests::repositories::article_repository::get_article_repository;
+    use crate::tests::repositories::article_coauthor_repository::get_article_coauthor_repository;
+    use crate::tests::repositories::mention_repository::get_mention_repository;
+    use crate::tests::repositories::comment_like_repository::get_comment_like_repository;
+    use crate::domain::domain_entities::comment_like::CommentLike;
 
     #[tokio::test]
     async fn test() {
         let mut mocked_user_repo = MockUserRepositoryTrait::new();
         let mut mock_comm_user_art_repo = MockCommentUserArticleRepositoryTrait::new();
         let (articles_db, mocked_article_repository) = get_article_repository();
+        let (reactions_db, mocked_article_reaction_repository) = get_article_reaction_repository();
+        let (coauthors_db, mocked_article_coauthor_repository) = get_article_coauthor_repository();
 
         let comments_db: Arc<Mutex<Vec<CommentWithAuthor>>> = Arc::new(Mutex::new(vec![]));
 
@@ -179,6 +318,7 @@ mod test {
             Uuid::new_v4(),
             "Notícia 1".into(),
             "Conteúdo da notícia 1.".into(),
+            None,
             "url_da_cover.com".into(),
             1,
             "MockedTag".into()
@@ -188,16 +328,21 @@ mod test {
         let mocked_article_slug = mocked_article.slug();
         articles_db.lock().unwrap().push(mocked_article);
 
+        let coauthor_id = Uuid::new_v4();
+        coauthors_db.lock().unwrap().push(ArticleCoauthor::new(mocked_article_id, coauthor_id, 0, Some("imagens".into())));
+
         let mocked_comm_1 = CommentWithAuthor::new(
             Some(mocked_article_id.clone()),
             "comentario 1 conteudo".into(),
-            User::new("Salem".into(), "123".into(), Some(Role::User))
+            User::new("Salem".into(), "123".into(), Some(Role::User)),
+            None,
         );
 
         let mocked_comm_2 = CommentWithAuthor::new(
             Some(mocked_article_id.clone()),
             "comentario 2 conteudo".into(),
-            User::new("Elffi".into(), "123".into(), Some(Role::User))
+            User::new("Elffi".into(), "123".into(), Some(Role::User)),
+            None,
         );
 
         comments_db.lock().unwrap().push(mocked_comm_1.clone());
@@ -209,11 +354,18 @@ mod test {
             "123".into(),
             TimeHelper::now(),
             None,
-            Some(Role::Ceo)
+            Some(Role::Ceo),
+            1
         );
 
         let user_id = user.id();
 
+        reactions_db.lock().unwrap().push(ArticleReaction::new(mocked_article_id, user_id, ReactionKind::Frank));
+        reactions_db.lock().unwrap().push(ArticleReaction::new(mocked_article_id, Uuid::new_v4(), ReactionKind::Frank));
+
+        let (comment_likes_db, mocked_comment_like_repository) = get_comment_like_repository();
+        comment_likes_db.lock().unwrap().push(CommentLike::new(mocked_comm_2.id(), user_id));
+
         // MOCKING REPOSITORIES
         mocked_user_repo
         .expect_find_by_id()
@@ -224,7 +376,7 @@ mod test {
         let comments_db_to_move = Arc::clone(&comments_db);
         mock_comm_user_art_repo
             .expect_find_many_comments()
-            .returning(move |_article_id, include_inactive, params| {
+            .returning(move |_article_id, include_inactive, _sort, params| {
                 let PaginationParameters { page, items_per_page, query } = params;
 
                 let mut comments: Vec<CommentWithAuthor> = Vec::new();
@@ -277,35 +429,63 @@ mod test {
             user_repository: Box::new(mocked_user_repo),
             comment_user_article_repository: Box::new(mock_comm_user_art_repo),
             article_repository: Box::new(mocked_article_repository),
+            article_reaction_repository: Box::new(mocked_article_reaction_repository),
+            article_coauthor_repository: Box::new(mocked_article_coauthor_repository),
+            mention_repository: Box::new(get_mention_repository().1),
+            comment_like_repository: Box::new(mocked_comment_like_repository),
         };
 
         let allowed_result = sut.exec(GetExpandedArticleParams {
             article_slug: mocked_article_slug.clone(),
             comments_per_page: None,
+            comments_sort: CommentSortOrder::Newest,
             user_id: Some(&user_id),
             user_role: Some(&Role::Editor),
         }).await.unwrap();
 
         let GetExpandedArticleResponse {
         article,
+        content,
         article_author,
-        comments
+        coauthors,
+        comments,
+        reactions,
+        ..
         } = allowed_result;
 
         let FetchManyCommentsWithAuthorResponse {
             data,
-            pagination
+            pagination,
+            next_cursor
         } = comments;
 
-        assert_eq!(mocked_comm_1, data[0].clone());
-        assert_eq!(mocked_comm_2, data[1].clone());
+        assert_eq!(mocked_comm_1.id(), data[0].id());
+        assert_eq!(mocked_comm_2.id(), data[1].id());
+        assert_eq!(Some(false), data[0].liked_by_me());
+        assert_eq!(Some(true), data[1].liked_by_me());
         assert_eq!(2, pagination.total_items);
+        assert!(next_cursor.is_none(), "Every comment fits the first page.");
         assert_eq!(mocked_article_id, article.id());
+        assert_eq!("<p>Conteúdo da notícia 1.</p>\n", content.html());
         assert_eq!(user_id, article_author.id());
+        assert_eq!(Some("imagens"), coauthors[0].0.role());
+        assert_eq!(Some(&2), reactions.counts().get(&ReactionKind::Frank));
+        assert_eq!(Some(ReactionKind::Frank), reactions.user_reaction());
+
+        let coauthor_result = sut.exec(GetExpandedArticleParams {
+            article_slug: mocked_article_slug.clone(),
+            comments_per_page: None,
+            comments_sort: CommentSortOrder::Newest,
+            user_id: Some(&coauthor_id),
+            user_role: Some(&Role::Writer),
+        }).await;
+
+        assert!(coauthor_result.is_ok(), "Expected co-authors to be able to see the unapproved article.");
 
         let unauthorized_result = sut.exec(GetExpandedArticleParams {
             article_slug: mocked_article_slug,
             comments_per_page: None,
+            comments_sort: CommentSortOrder::Newest,
             user_id: None,
             user_role: None,
         }).await;
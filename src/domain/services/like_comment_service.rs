@@ -0,0 +1,167 @@
+use uuid::Uuid;
+
+use crate::domain::domain_entities::comment_like::{CommentLike, CommentLikesSummary};
+use crate::domain::repositories::comment_like_repository::CommentLikeRepositoryTrait;
+use crate::domain::repositories::comment_repository::CommentRepositoryTrait;
+use crate::errors::error::DomainErrorTrait;
+use crate::errors::resource_not_found::ResourceNotFoundError;
+use crate::util::generate_service_internal_error;
+
+pub struct LikeCommentParams {
+    pub user_id: Uuid,
+    pub comment_id: Uuid,
+    /// `false` removes the user's like.
+    pub liked: bool,
+}
+
+/**
+ # Like Comment Service
+ Each user can like a comment once. Liking an already liked comment, or unliking one that wasn't
+ liked, changes nothing.
+
+ Returns the comment's likes summary after the change.
+ */
+pub struct LikeCommentService<CR, CLR>
+where   CR: CommentRepositoryTrait,
+        CLR: CommentLikeRepositoryTrait
+        {
+    comment_repository: Box<CR>,
+    comment_like_repository: Box<CLR>,
+}
+
+impl<
+    CR: CommentRepositoryTrait,
+    CLR: CommentLikeRepositoryTrait
+> LikeCommentService<CR, CLR> {
+    pub fn new(comment_repository: Box<CR>, comment_like_repository: Box<CLR>) -> Self {
+        LikeCommentService {
+            comment_repository,
+            comment_like_repository
+        }
+    }
+
+    pub async fn exec(&self, params: LikeCommentParams) -> Result<CommentLikesSummary, Box<dyn DomainErrorTrait>> {
+        let comment = self.comment_repository.find_by_id(params.comment_id).await;
+
+        if comment.is_err() {
+            return Err(generate_service_internal_error(
+                "Error occurred on Like Comment Service, while finding the comment by id".into(),
+                &comment.unwrap_err()
+            ));
+        }
+
+        match comment.unwrap() {
+            Some(comment) if comment.is_active() => (),
+            _ => return Err(Box::new(ResourceNotFoundError::new()))
+        }
+
+        let current_like = self.comment_like_repository.find_by_user(params.comment_id, params.user_id).await;
+
+        if current_like.is_err() {
+            return Err(generate_service_internal_error(
+                "Error occurred on Like Comment Service, while finding the user's like".into(),
+                &current_like.unwrap_err()
+            ));
+        }
+
+        let response = match (current_like.unwrap(), params.liked) {
+            (None, true) => {
+                self.comment_like_repository
+                    .create(CommentLike::new(params.comment_id, params.user_id))
+                    .await
+                    .map(|_| ())
+            },
+            (Some(like), false) => self.comment_like_repository.delete(like).await,
+            _ => Ok(()),
+        };
+
+        if response.is_err() {
+            return Err(generate_service_internal_error(
+                "Error occurred on Like Comment Service, while persisting the user's like".into(),
+                &response.unwrap_err()
+            ));
+        }
+
+        let like_count = self.comment_like_repository.count_by_comment(params.comment_id).await;
+
+        if like_count.is_err() {
+            return Err(generate_service_internal_error(
+                "Error occurred on Like Comment Service, while counting the comment's likes".into(),
+                &like_count.unwrap_err()
+            ));
+        }
+
+        Ok(CommentLikesSummary::new(like_count.unwrap(), params.liked))
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use std::sync::{Arc, Mutex};
+
+    use crate::domain::domain_entities::comment::Comment;
+    use crate::domain::repositories::comment_repository::MockCommentRepositoryTrait;
+    use crate::tests::repositories::comment_like_repository::get_comment_like_repository;
+
+    fn get_comment_repository(db: &Arc<Mutex<Vec<Comment>>>) -> MockCommentRepositoryTrait {
+        let mut repository = MockCommentRepositoryTrait::new();
+
+        let db_clone = Arc::clone(db);
+        repository.expect_find_by_id().returning(move |id| {
+            Ok(db_clone.lock().unwrap().iter().find(|comment| comment.id() == id).cloned())
+        });
+
+        repository
+    }
+
+    #[tokio::test]
+    async fn test_if_likes_can_be_toggled() {
+        let comment_db: Arc<Mutex<Vec<Comment>>> = Arc::new(Mutex::new(vec![]));
+        let (like_db, comment_like_repository) = get_comment_like_repository();
+
+        let comment = Comment::new(Uuid::new_v4(), Some(Uuid::new_v4()), "Primeiro!".into(), None);
+        let comment_id = comment.id();
+        comment_db.lock().unwrap().push(comment);
+
+        let sut = LikeCommentService::new(Box::new(get_comment_repository(&comment_db)), Box::new(comment_like_repository));
+
+        let first_user = Uuid::new_v4();
+        let second_user = Uuid::new_v4();
+
+        let summary = sut.exec(LikeCommentParams { user_id: first_user, comment_id, liked: true }).await.unwrap();
+
+        assert_eq!(CommentLikesSummary::new(1, true), summary);
+
+        let summary = sut.exec(LikeCommentParams { user_id: first_user, comment_id, liked: true }).await.unwrap();
+
+        assert_eq!(1, summary.like_count(), "Liking twice should count once.");
+
+        let summary = sut.exec(LikeCommentParams { user_id: second_user, comment_id, liked: true }).await.unwrap();
+
+        assert_eq!(2, summary.like_count());
+
+        let summary = sut.exec(LikeCommentParams { user_id: first_user, comment_id, liked: false }).await.unwrap();
+
+        assert_eq!(CommentLikesSummary::new(1, false), summary);
+        assert_eq!(1, like_db.lock().unwrap().len());
+    }
+
+    #[tokio::test]
+    async fn test_if_inactive_comments_cannot_be_liked() {
+        let comment_db: Arc<Mutex<Vec<Comment>>> = Arc::new(Mutex::new(vec![]));
+        let (like_db, comment_like_repository) = get_comment_like_repository();
+
+        let mut comment = Comment::new(Uuid::new_v4(), Some(Uuid::new_v4()), "Spam".into(), None);
+        comment.set_is_active(false);
+        let comment_id = comment.id();
+        comment_db.lock().unwrap().push(comment);
+
+        let sut = LikeCommentService::new(Box::new(get_comment_repository(&comment_db)), Box::new(comment_like_repository));
+
+        let result = sut.exec(LikeCommentParams { user_id: Uuid::new_v4(), comment_id, liked: true }).await;
+
+        assert_eq!(&404, result.unwrap_err().code());
+        assert!(like_db.lock().unwrap().is_empty());
+    }
+}
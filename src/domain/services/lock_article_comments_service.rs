@@ -0,0 +1,122 @@
+use uuid::Uuid;
+
+use crate::domain::domain_entities::article::Article;
+use crate::domain::domain_entities::role::Role;
+use crate::domain::repositories::article_repository::ArticleRepositoryTrait;
+use crate::errors::error::DomainErrorTrait;
+use crate::errors::resource_not_found::ResourceNotFoundError;
+use crate::errors::unauthorized_error::UnauthorizedError;
+use crate::util::{generate_service_internal_error, verify_expected_version, verify_role_has_permission, RolePermissions};
+
+pub struct LockArticleCommentsParams<'exec> {
+    pub user_role: &'exec Role,
+    pub article_id: Uuid,
+    /// `false` opens the comments again.
+    pub locked: bool,
+    pub expected_version: Option<i32>,
+}
+
+/**
+ # Lock Article Comments Service
+ Closes or reopens the comments of an article. The comments already posted stay as they are,
+ but no one can comment nor reply on it while it is locked.
+ */
+pub struct LockArticleCommentsService<ArticleRepository: ArticleRepositoryTrait> {
+    article_repository: Box<ArticleRepository>,
+}
+
+impl<ArticleRepository: ArticleRepositoryTrait> LockArticleCommentsService<ArticleRepository> {
+    pub fn new(article_repository: Box<ArticleRepository>) -> Self {
+        LockArticleCommentsService {
+            article_repository
+        }
+    }
+
+    pub async fn exec<'exec>(&self, params: LockArticleCommentsParams<'exec>) -> Result<Article, Box<dyn DomainErrorTrait>> {
+        if !verify_role_has_permission(params.user_role, RolePermissions::LockArticleComments) {
+            return Err(Box::new(UnauthorizedError::new()));
+        }
+
+        let article = self.article_repository.find_by_id(params.article_id).await;
+
+        if article.is_err() {
+            return Err(generate_service_internal_error(
+                "Error occurred on Lock Article Comments Service, while finding the article by id".into(),
+                &article.unwrap_err()
+            ));
+        }
+
+        let mut article = match article.unwrap() {
+            Some(article) => article,
+            None => return Err(Box::new(ResourceNotFoundError::new())),
+        };
+
+        verify_expected_version(params.expected_version, article.version())?;
+
+        if article.comments_locked() == params.locked {
+            return Ok(article);
+        }
+
+        article.set_comments_locked(params.locked);
+
+        let response = self.article_repository.save(article).await;
+
+        if response.is_err() {
+            return Err(generate_service_internal_error(
+                "Error occurred on Lock Article Comments Service, while saving the article".into(),
+                &response.unwrap_err()
+            ));
+        }
+
+        Ok(response.unwrap())
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use tokio;
+
+    use crate::tests::repositories::article_repository::get_article_repository;
+
+    #[tokio::test]
+    async fn test() {
+        let (article_db, mocked_article_repo) = get_article_repository();
+
+        let article = Article::new(Uuid::new_v4(), "Banimentos da semana".into(), "Conteúdo".into(), None, "url do cover".into(), 1, "Foo".into());
+        let article_id = article.id();
+
+        article_db.lock().unwrap().push(article);
+
+        let sut = LockArticleCommentsService::new(Box::new(mocked_article_repo));
+
+        let unauthorized_result = sut.exec(LockArticleCommentsParams {
+            user_role: &Role::Writer,
+            article_id,
+            locked: true,
+            expected_version: None,
+        }).await;
+
+        assert_eq!(&401, unauthorized_result.unwrap_err().code());
+
+        let locked_article = sut.exec(LockArticleCommentsParams {
+            user_role: &Role::Coord,
+            article_id,
+            locked: true,
+            expected_version: Some(1),
+        }).await.unwrap();
+
+        assert!(locked_article.comments_locked());
+        assert!(article_db.lock().unwrap()[0].comments_locked());
+
+        let stale_result = sut.exec(LockArticleCommentsParams {
+            user_role: &Role::Coord,
+            article_id,
+            locked: false,
+            expected_version: Some(2),
+        }).await;
+
+        assert!(stale_result.is_err(), "The lock should not be changed from another version of the article.");
+        assert!(article_db.lock().unwrap()[0].comments_locked());
+    }
+}
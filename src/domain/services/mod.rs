@@ -8,6 +8,19 @@ pub mod update_article_service;
 pub mod delete_article_service;
 pub mod fetch_many_articles_service;
 pub mod fetch_home_page_articles_service;
+pub mod register_article_view_service;
+pub mod fetch_trending_articles_service;
+pub mod count_articles_views_service;
+pub mod react_to_article_service;
+pub mod fetch_sitemap_entries_service;
+pub mod fetch_articles_archive_service;
+pub mod set_article_coauthors_service;
+pub mod create_article_note_service;
+pub mod fetch_article_notes_service;
+pub mod resolve_article_note_service;
+pub mod acquire_article_edit_lock_service;
+pub mod refresh_article_edit_lock_service;
+pub mod release_article_edit_lock_service;
 
 pub mod comment_on_article_service;
 pub mod delete_comment_service;
@@ -34,6 +47,10 @@ pub mod fetch_many_users_service;
 pub mod get_user_service;
 
 pub mod fetch_many_comments_with_author_service;
+pub mod fetch_comment_replies_service;
+pub mod edit_comment_service;
+pub mod fetch_comment_revisions_service;
+pub mod fetch_user_mentions_service;
 
 pub mod create_article_tag_service;
 pub mod update_article_tag_service;
@@ -44,3 +61,11 @@ pub mod create_free_badge_service;
 pub mod update_free_badge_service;
 pub mod fetch_many_free_badges_service;
 pub mod delete_free_badge_service;
+pub mod create_word_filter_service;
+pub mod update_word_filter_service;
+pub mod fetch_many_word_filters_service;
+pub mod delete_word_filter_service;
+pub mod lock_article_comments_service;
+pub mod pin_comment_service;
+pub mod like_comment_service;
+pub mod fetch_weekly_top_comment_service;
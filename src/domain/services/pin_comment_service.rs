@@ -0,0 +1,223 @@
+use uuid::Uuid;
+
+use crate::domain::domain_entities::comment::Comment;
+use crate::domain::domain_entities::role::Role;
+use crate::domain::politics::comment_politics::CommentPolitics;
+use crate::domain::repositories::article_repository::ArticleRepositoryTrait;
+use crate::domain::repositories::comment_repository::CommentRepositoryTrait;
+use crate::errors::bad_request_error::BadRequestError;
+use crate::errors::error::DomainErrorTrait;
+use crate::errors::resource_not_found::ResourceNotFoundError;
+use crate::errors::unauthorized_error::UnauthorizedError;
+use crate::libs::time::TimeHelper;
+use crate::util::{generate_service_internal_error, verify_role_has_permission, RolePermissions};
+
+pub struct PinCommentParams<'exec> {
+    pub user_id: Uuid,
+    pub user_role: &'exec Role,
+    pub comment_id: Uuid,
+    /// `false` unpins the comment.
+    pub pinned: bool,
+}
+
+/**
+ # Pin Comment Service
+ Pins a top-level comment to the top of its article's comments, or unpins it. Only the
+ article's author and those who can deactivate comments may do it, and only up to
+ `CommentPolitics::max_pinned_comments` comments can be pinned on the same article.
+ */
+pub struct PinCommentService<CommentRepository, ArticleRepository>
+where   CommentRepository: CommentRepositoryTrait,
+        ArticleRepository: ArticleRepositoryTrait
+        {
+    comment_repository: Box<CommentRepository>,
+    article_repository: Box<ArticleRepository>,
+}
+
+impl<CommentRepository: CommentRepositoryTrait, ArticleRepository: ArticleRepositoryTrait>
+PinCommentService<CommentRepository, ArticleRepository> {
+    pub fn new(comment_repository: Box<CommentRepository>, article_repository: Box<ArticleRepository>) -> Self {
+        PinCommentService {
+            comment_repository,
+            article_repository,
+        }
+    }
+
+    pub async fn exec<'exec>(&self, params: PinCommentParams<'exec>) -> Result<Comment, Box<dyn DomainErrorTrait>> {
+        let comment = self.comment_repository.find_by_id(params.comment_id).await;
+
+        if comment.is_err() {
+            return Err(generate_service_internal_error(
+                "Error occurred on Pin Comment Service, while finding the comment by id".into(),
+                &comment.unwrap_err()
+            ));
+        }
+
+        let mut comment = match comment.unwrap() {
+            Some(comment) => comment,
+            None => return Err(Box::new(ResourceNotFoundError::new())),
+        };
+
+        let article_id = match comment.article_id() {
+            Some(article_id) => article_id,
+            None => return Err(Box::new(ResourceNotFoundError::new())),
+        };
+
+        let article = self.article_repository.find_by_id(article_id).await;
+
+        if article.is_err() {
+            return Err(generate_service_internal_error(
+                "Error occurred on Pin Comment Service, while finding the comment's article".into(),
+                &article.unwrap_err()
+            ));
+        }
+
+        let article = match article.unwrap() {
+            Some(article) => article,
+            None => return Err(Box::new(ResourceNotFoundError::new())),
+        };
+
+        let user_can_pin = article.author_id() == params.user_id
+            || verify_role_has_permission(params.user_role, RolePermissions::InactivateComment);
+
+        if !user_can_pin {
+            return Err(Box::new(UnauthorizedError::new()));
+        }
+
+        if comment.pinned_at().is_some() == params.pinned {
+            return Ok(comment);
+        }
+
+        if params.pinned {
+            if comment.parent_id().is_some() || !comment.is_active() {
+                return Err(Box::new(BadRequestError::new_with_message(
+                    "Only visible top-level comments can be pinned.".into()
+                )));
+            }
+
+            let pinned_comments = self.comment_repository.find_many_pinned_by_article(article_id).await;
+
+            if pinned_comments.is_err() {
+                return Err(generate_service_internal_error(
+                    "Error occurred on Pin Comment Service, while finding the article's pinned comments".into(),
+                    &pinned_comments.unwrap_err()
+                ));
+            }
+
+            if pinned_comments.unwrap().len() >= CommentPolitics::max_pinned_comments() {
+                return Err(Box::new(BadRequestError::new_with_message(format!(
+                    "An article can't have more than {} pinned comments, unpin one first.",
+                    CommentPolitics::max_pinned_comments()
+                ))));
+            }
+
+            comment.set_pinned_at(Some(TimeHelper::now()));
+        } else {
+            comment.set_pinned_at(None);
+        }
+
+        let response = self.comment_repository.save(comment).await;
+
+        if response.is_err() {
+            return Err(generate_service_internal_error(
+                "Error occurred on Pin Comment Service, while saving the comment".into(),
+                &response.unwrap_err()
+            ));
+        }
+
+        Ok(response.unwrap())
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use tokio;
+    use std::sync::{Arc, Mutex};
+
+    use crate::domain::domain_entities::article::Article;
+    use crate::domain::repositories::comment_repository::MockCommentRepositoryTrait;
+    use crate::tests::repositories::article_repository::get_article_repository;
+
+    fn get_comment_repository(db: &Arc<Mutex<Vec<Comment>>>) -> MockCommentRepositoryTrait {
+        let mut repository = MockCommentRepositoryTrait::new();
+
+        let db_clone = Arc::clone(db);
+        repository.expect_find_by_id().returning(move |id| {
+            Ok(db_clone.lock().unwrap().iter().find(|comment| comment.id() == id).cloned())
+        });
+
+        let db_clone = Arc::clone(db);
+        repository.expect_find_many_pinned_by_article().returning(move |article_id| {
+            Ok(db_clone.lock().unwrap().iter()
+                .filter(|comment| comment.article_id() == Some(article_id) && comment.pinned_at().is_some())
+                .cloned()
+                .collect())
+        });
+
+        let db_clone = Arc::clone(db);
+        repository.expect_save().returning(move |comment| {
+            let mut db = db_clone.lock().unwrap();
+            let index = db.iter().position(|item| item.id() == comment.id()).unwrap();
+            db[index] = comment.clone();
+
+            Ok(comment)
+        });
+
+        repository
+    }
+
+    #[tokio::test]
+    async fn test() {
+        let (article_db, mocked_article_repo) = get_article_repository();
+        let comment_db: Arc<Mutex<Vec<Comment>>> = Arc::new(Mutex::new(vec![]));
+
+        let author_id = Uuid::new_v4();
+        let article = Article::new(author_id, "Anúncio".into(), "Conteúdo".into(), None, "url do cover".into(), 1, "Foo".into());
+        let article_id = article.id();
+
+        article_db.lock().unwrap().push(article);
+
+        let comments: Vec<Comment> = (0..5)
+            .map(|_| Comment::new(Uuid::new_v4(), Some(article_id), "Resposta oficial".into(), None))
+            .collect();
+
+        let reply = Comment::new(Uuid::new_v4(), Some(article_id), "Valeu!".into(), Some(comments[0].id()));
+
+        comment_db.lock().unwrap().extend(comments.clone());
+        comment_db.lock().unwrap().push(reply.clone());
+
+        let sut = PinCommentService::new(Box::new(get_comment_repository(&comment_db)), Box::new(mocked_article_repo));
+
+        let pin = |comment_id: Uuid, user_id: Uuid, user_role: &'static Role, pinned: bool| PinCommentParams {
+            user_id,
+            user_role,
+            comment_id,
+            pinned,
+        };
+
+        let unauthorized_result = sut.exec(pin(comments[0].id(), Uuid::new_v4(), &Role::Writer, true)).await;
+
+        assert_eq!(&401, unauthorized_result.unwrap_err().code());
+
+        let pinned_by_author = sut.exec(pin(comments[0].id(), author_id, &Role::Writer, true)).await.unwrap();
+
+        assert!(pinned_by_author.pinned_at().is_some());
+
+        sut.exec(pin(comments[1].id(), Uuid::new_v4(), &Role::Coord, true)).await.unwrap();
+        sut.exec(pin(comments[2].id(), author_id, &Role::Writer, true)).await.unwrap();
+
+        let over_the_limit = sut.exec(pin(comments[3].id(), author_id, &Role::Writer, true)).await;
+
+        assert_eq!(&400, over_the_limit.unwrap_err().code());
+
+        let pinned_reply = sut.exec(pin(reply.id(), author_id, &Role::Writer, true)).await;
+
+        assert_eq!(&400, pinned_reply.unwrap_err().code());
+
+        let unpinned = sut.exec(pin(comments[0].id(), author_id, &Role::Writer, false)).await.unwrap();
+
+        assert!(unpinned.pinned_at().is_none());
+        assert!(sut.exec(pin(comments[3].id(), author_id, &Role::Writer, true)).await.is_ok(), "Unpinning should free a slot.");
+    }
+}
@@ -0,0 +1,164 @@
+use uuid::Uuid;
+
+use crate::domain::domain_entities::article_reaction::{ArticleReaction, ArticleReactionsSummary, ReactionKind};
+use crate::domain::repositories::article_reaction_repository::ArticleReactionRepositoryTrait;
+use crate::domain::repositories::article_repository::ArticleRepositoryTrait;
+use crate::errors::error::DomainErrorTrait;
+use crate::errors::resource_not_found::ResourceNotFoundError;
+use crate::util::generate_service_internal_error;
+
+pub struct ReactToArticleParams {
+    pub user_id: Uuid,
+    pub article_id: Uuid,
+    /// `None` removes the user's reaction.
+    pub reaction: Option<ReactionKind>,
+}
+
+/**
+ # React To Article Service
+ Each user can leave a single reaction on an article:
+ - reacting with a new kind switches the user's reaction;
+ - reacting again with the same kind toggles it off.
+
+ Returns the article's reactions summary after the change.
+ */
+pub struct ReactToArticleService<AR, ARR>
+where   AR: ArticleRepositoryTrait,
+        ARR: ArticleReactionRepositoryTrait
+        {
+    article_repository: Box<AR>,
+    article_reaction_repository: Box<ARR>,
+}
+
+impl<
+    AR: ArticleRepositoryTrait,
+    ARR: ArticleReactionRepositoryTrait
+> ReactToArticleService<AR, ARR> {
+    pub fn new(article_repository: Box<AR>, article_reaction_repository: Box<ARR>) -> Self {
+        ReactToArticleService {
+            article_repository,
+            article_reaction_repository
+        }
+    }
+
+    pub async fn exec(&self, params: ReactToArticleParams) -> Result<ArticleReactionsSummary, Box<dyn DomainErrorTrait>> {
+        let article = self.article_repository.find_by_id(params.article_id).await;
+
+        if article.is_err() {
+            return Err(generate_service_internal_error(
+                "Error occurred on React To Article Service, while finding the article by id".into(),
+                &article.unwrap_err()
+            ));
+        }
+
+        match article.unwrap() {
+            Some(article) if article.approved() => (),
+            _ => return Err(Box::new(ResourceNotFoundError::new()))
+        }
+
+        let current_reaction = self.article_reaction_repository.find_by_user(params.article_id, params.user_id).await;
+
+        if current_reaction.is_err() {
+            return Err(generate_service_internal_error(
+                "Error occurred on React To Article Service, while finding the user's reaction".into(),
+                &current_reaction.unwrap_err()
+            ));
+        }
+
+        let response = match (current_reaction.unwrap(), params.reaction) {
+            (None, None) => Ok(None),
+            (None, Some(kind)) => {
+                self.article_reaction_repository
+                    .create(ArticleReaction::new(params.article_id, params.user_id, kind))
+                    .await
+                    .map(Some)
+            },
+            (Some(reaction), Some(kind)) if reaction.kind() != kind => {
+                let mut reaction = reaction;
+                reaction.set_kind(kind);
+
+                self.article_reaction_repository.save(reaction).await.map(Some)
+            },
+            (Some(reaction), _) => {
+                self.article_reaction_repository.delete(reaction).await.map(|_| None)
+            }
+        };
+
+        if response.is_err() {
+            return Err(generate_service_internal_error(
+                "Error occurred on React To Article Service, while persisting the user's reaction".into(),
+                &response.unwrap_err()
+            ));
+        }
+
+        let user_reaction = response.unwrap().map(|reaction| reaction.kind());
+
+        let counts = self.article_reaction_repository.count_by_article(params.article_id).await;
+
+        if counts.is_err() {
+            return Err(generate_service_internal_error(
+                "Error occurred on React To Article Service, while counting the article's reactions".into(),
+                &counts.unwrap_err()
+            ));
+        }
+
+        Ok(ArticleReactionsSummary::new(counts.unwrap(), user_reaction))
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    use crate::domain::domain_entities::article::Article;
+    use crate::tests::repositories::article_reaction_repository::get_article_reaction_repository;
+    use crate::tests::repositories::article_repository::get_article_repository;
+
+    #[tokio::test]
+    async fn test_if_reactions_can_be_toggled_and_switched() {
+        let (article_db, article_repository) = get_article_repository();
+        let (reaction_db, article_reaction_repository) = get_article_reaction_repository();
+
+        let mut article = Article::new(Uuid::new_v4(), "Notícia".into(), "Conteúdo".into(), None, "url_da_cover.com".into(), 1, "Tag".into());
+        article.set_approved(true);
+        let article_id = article.id();
+        article_db.lock().unwrap().push(article);
+
+        let user_id = Uuid::new_v4();
+
+        let sut = ReactToArticleService::new(Box::new(article_repository), Box::new(article_reaction_repository));
+
+        let summary = sut.exec(ReactToArticleParams { user_id, article_id, reaction: Some(ReactionKind::Heart) }).await.unwrap();
+
+        assert_eq!(Some(ReactionKind::Heart), summary.user_reaction());
+        assert_eq!(Some(&1), summary.counts().get(&ReactionKind::Heart));
+
+        let summary = sut.exec(ReactToArticleParams { user_id, article_id, reaction: Some(ReactionKind::Duck) }).await.unwrap();
+
+        assert_eq!(Some(ReactionKind::Duck), summary.user_reaction());
+        assert_eq!(Some(&0), summary.counts().get(&ReactionKind::Heart));
+        assert_eq!(Some(&1), summary.counts().get(&ReactionKind::Duck));
+        assert_eq!(1, reaction_db.lock().unwrap().len());
+
+        let summary = sut.exec(ReactToArticleParams { user_id, article_id, reaction: Some(ReactionKind::Duck) }).await.unwrap();
+
+        assert_eq!(None, summary.user_reaction());
+        assert!(reaction_db.lock().unwrap().is_empty());
+    }
+
+    #[tokio::test]
+    async fn test_if_unapproved_articles_cannot_be_reacted() {
+        let (article_db, article_repository) = get_article_repository();
+        let (_reaction_db, article_reaction_repository) = get_article_reaction_repository();
+
+        let article = Article::new(Uuid::new_v4(), "Notícia".into(), "Conteúdo".into(), None, "url_da_cover.com".into(), 1, "Tag".into());
+        let article_id = article.id();
+        article_db.lock().unwrap().push(article);
+
+        let sut = ReactToArticleService::new(Box::new(article_repository), Box::new(article_reaction_repository));
+
+        let result = sut.exec(ReactToArticleParams { user_id: Uuid::new_v4(), article_id, reaction: Some(ReactionKind::Wow) }).await;
+
+        assert!(result.is_err());
+    }
+}
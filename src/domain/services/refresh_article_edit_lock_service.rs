@@ -0,0 +1,62 @@
+use uuid::Uuid;
+
+use crate::domain::domain_entities::article_edit_lock::ArticleEditLock;
+use crate::domain::domain_entities::role::Role;
+use crate::domain::politics::article_politics::ArticlePolitics;
+use crate::domain::repositories::article_edit_lock_repository::ArticleEditLockRepositoryTrait;
+use crate::errors::conflict_error::ConflictError;
+use crate::errors::error::DomainErrorTrait;
+use crate::errors::unauthorized_error::UnauthorizedError;
+use crate::libs::time::TimeHelper;
+use crate::util::generate_service_internal_error;
+
+pub struct RefreshArticleEditLockParams {
+    pub user_id: Uuid,
+    pub user_role: Role,
+    pub article_id: Uuid,
+}
+
+/// Heartbeat sent by the lock's holder while the editor is open, keeping the lock from expiring.
+pub struct RefreshArticleEditLockService<ALR: ArticleEditLockRepositoryTrait> {
+    article_edit_lock_repository: Box<ALR>,
+}
+
+impl<ALR: ArticleEditLockRepositoryTrait> RefreshArticleEditLockService<ALR> {
+    pub fn new(article_edit_lock_repository: Box<ALR>) -> Self {
+        RefreshArticleEditLockService {
+            article_edit_lock_repository
+        }
+    }
+
+    pub async fn exec(&self, params: RefreshArticleEditLockParams) -> Result<ArticleEditLock, Box<dyn DomainErrorTrait>> {
+        if params.user_role == Role::User { return Err(Box::new(UnauthorizedError::new())); }
+
+        let lock = self.article_edit_lock_repository.find_by_article(params.article_id).await;
+
+        if lock.is_err() {
+            return Err(generate_service_internal_error(
+                "Error occurred on Refresh Article Edit Lock Service, while finding the article's edit lock".into(),
+                &lock.unwrap_err()
+            ));
+        }
+
+        // an expired lock nobody took yet is still the user's to keep
+        let mut lock = match lock.unwrap() {
+            Some(lock) if lock.user_id() == params.user_id => lock,
+            _ => return Err(Box::new(ConflictError::new_with_message("The article's edit lock is no longer held by the user.".into())))
+        };
+
+        lock.set_expires_at(ArticlePolitics::edit_lock_expiration(TimeHelper::now()));
+
+        let response = self.article_edit_lock_repository.save(lock).await;
+
+        if response.is_err() {
+            return Err(generate_service_internal_error(
+                "Error occurred on Refresh Article Edit Lock Service, while saving the lock".into(),
+                &response.unwrap_err()
+            ));
+        }
+
+        Ok(response.unwrap())
+    }
+}
@@ -0,0 +1,108 @@
+use uuid::Uuid;
+
+use crate::domain::domain_entities::article_view::{ArticleView, ArticleViewer};
+use crate::domain::politics::article_politics::ArticlePolitics;
+use crate::domain::repositories::article_view_repository::ArticleViewRepositoryTrait;
+use crate::errors::error::DomainErrorTrait;
+use crate::libs::time::TimeHelper;
+use crate::util::generate_service_internal_error;
+
+pub struct RegisterArticleViewParams {
+    pub article_id: Uuid,
+    pub viewer: ArticleViewer,
+}
+
+pub struct RegisterArticleViewService<ArticleViewRepository: ArticleViewRepositoryTrait> {
+    article_view_repository: Box<ArticleViewRepository>,
+    /// Keys the hashes identifying anonymous readers, see `ArticleViewer::key`.
+    viewer_key_secret: String,
+}
+
+impl<ArticleViewRepository: ArticleViewRepositoryTrait> RegisterArticleViewService<ArticleViewRepository> {
+    pub fn new(article_view_repository: Box<ArticleViewRepository>, viewer_key_secret: String) -> Self {
+        RegisterArticleViewService {
+            article_view_repository,
+            viewer_key_secret
+        }
+    }
+
+    /// Returns whether the view has been counted, i.e., it is not a repeated view from the same viewer.
+    pub async fn exec(&self, params: RegisterArticleViewParams) -> Result<bool, Box<dyn DomainErrorTrait>> {
+        let view = ArticleView::new(params.article_id, &params.viewer, &self.viewer_key_secret);
+
+        let last_view = self.article_view_repository.find_by_viewer(view.article_id(), view.viewer_key().to_owned()).await;
+
+        if last_view.is_err() {
+            return Err(generate_service_internal_error(
+                "Error occurred on Register Article View Service, while finding the viewer's last view",
+                &last_view.unwrap_err()
+            ));
+        }
+
+        if let Some(last_view) = last_view.unwrap() {
+            if ArticlePolitics::view_is_repeated(&last_view, TimeHelper::now()) {
+                return Ok(false);
+            }
+        }
+
+        let response = self.article_view_repository.register(view).await;
+
+        if response.is_err() {
+            return Err(generate_service_internal_error(
+                "Error occurred on Register Article View Service, while registering the view",
+                &response.unwrap_err()
+            ));
+        }
+
+        Ok(true)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use std::sync::{Arc, Mutex};
+
+    use super::*;
+    use crate::domain::repositories::article_view_repository::MockArticleViewRepositoryTrait;
+
+    #[tokio::test]
+    async fn test_if_repeated_views_are_not_counted() {
+        let mut article_view_repository = MockArticleViewRepositoryTrait::new();
+        let views_db: Arc<Mutex<Vec<ArticleView>>> = Arc::new(Mutex::new(vec![]));
+
+        let db_clone = Arc::clone(&views_db);
+        article_view_repository
+            .expect_find_by_viewer()
+            .returning(move |article_id, viewer_key| {
+                let last_view = db_clone.lock().unwrap().iter()
+                    .filter(|view| view.article_id() == article_id && view.viewer_key() == viewer_key)
+                    .last()
+                    .cloned();
+
+                Ok(last_view)
+            });
+
+        let db_clone = Arc::clone(&views_db);
+        article_view_repository
+            .expect_register()
+            .returning(move |view| {
+                db_clone.lock().unwrap().push(view.clone());
+                Ok(view)
+            });
+
+        let sut = RegisterArticleViewService::new(Box::new(article_view_repository), "secret".into());
+
+        let article_id = Uuid::new_v4();
+        let reader = ArticleViewer::User(Uuid::new_v4());
+        let anonymous_reader = ArticleViewer::Anonymous { ip: "127.0.0.1".into(), user_agent: "Mozilla/5.0".into() };
+
+        let first_view = sut.exec(RegisterArticleViewParams { article_id, viewer: reader.clone() }).await.unwrap();
+        let repeated_view = sut.exec(RegisterArticleViewParams { article_id, viewer: reader }).await.unwrap();
+        let anonymous_view = sut.exec(RegisterArticleViewParams { article_id, viewer: anonymous_reader }).await.unwrap();
+
+        assert!(first_view);
+        assert!(!repeated_view, "Expected the same reader's view not to be counted twice within the window.");
+        assert!(anonymous_view);
+        assert_eq!(2, views_db.lock().unwrap().len());
+    }
+}
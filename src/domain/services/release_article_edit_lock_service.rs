@@ -0,0 +1,53 @@
+use uuid::Uuid;
+
+use crate::domain::repositories::article_edit_lock_repository::ArticleEditLockRepositoryTrait;
+use crate::errors::error::DomainErrorTrait;
+use crate::errors::unauthorized_error::UnauthorizedError;
+use crate::util::generate_service_internal_error;
+
+pub struct ReleaseArticleEditLockParams {
+    pub user_id: Uuid,
+    pub article_id: Uuid,
+}
+
+pub struct ReleaseArticleEditLockService<ALR: ArticleEditLockRepositoryTrait> {
+    article_edit_lock_repository: Box<ALR>,
+}
+
+impl<ALR: ArticleEditLockRepositoryTrait> ReleaseArticleEditLockService<ALR> {
+    pub fn new(article_edit_lock_repository: Box<ALR>) -> Self {
+        ReleaseArticleEditLockService {
+            article_edit_lock_repository
+        }
+    }
+
+    /// Only the lock's holder releases it. Releasing an article that isn't locked does nothing.
+    pub async fn exec(&self, params: ReleaseArticleEditLockParams) -> Result<(), Box<dyn DomainErrorTrait>> {
+        let lock = self.article_edit_lock_repository.find_by_article(params.article_id).await;
+
+        if lock.is_err() {
+            return Err(generate_service_internal_error(
+                "Error occurred on Release Article Edit Lock Service, while finding the article's edit lock".into(),
+                &lock.unwrap_err()
+            ));
+        }
+
+        let lock = match lock.unwrap() {
+            Some(lock) => lock,
+            None => return Ok(())
+        };
+
+        if lock.user_id() != params.user_id { return Err(Box::new(UnauthorizedError::new())); }
+
+        let response = self.article_edit_lock_repository.delete(lock.article_id()).await;
+
+        if response.is_err() {
+            return Err(generate_service_internal_error(
+                "Error occurred on Release Article Edit Lock Service, while deleting the lock".into(),
+                &response.unwrap_err()
+            ));
+        }
+
+        Ok(())
+    }
+}
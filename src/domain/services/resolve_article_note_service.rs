@@ -0,0 +1,136 @@
+use uuid::Uuid;
+
+use crate::domain::domain_entities::article_note::ArticleNote;
+use crate::domain::domain_entities::role::Role;
+use crate::domain::repositories::article_coauthor_repository::ArticleCoauthorRepositoryTrait;
+use crate::domain::repositories::article_note_repository::ArticleNoteRepositoryTrait;
+use crate::domain::repositories::article_repository::ArticleRepositoryTrait;
+use crate::errors::bad_request_error::BadRequestError;
+use crate::errors::error::DomainErrorTrait;
+use crate::errors::resource_not_found::ResourceNotFoundError;
+use crate::util::generate_service_internal_error;
+
+use super::create_article_note_service::find_article_with_notes_access;
+
+pub struct ResolveArticleNoteParams {
+    pub user_id: Uuid,
+    pub user_role: Role,
+    pub note_id: Uuid,
+    /// `false` reopens the thread.
+    pub resolved: bool,
+}
+
+pub struct ResolveArticleNoteService<AR, ACR, ANR>
+where   AR: ArticleRepositoryTrait,
+        ACR: ArticleCoauthorRepositoryTrait,
+        ANR: ArticleNoteRepositoryTrait
+        {
+    article_repository: Box<AR>,
+    article_coauthor_repository: Box<ACR>,
+    article_note_repository: Box<ANR>,
+}
+
+impl<
+    AR: ArticleRepositoryTrait,
+    ACR: ArticleCoauthorRepositoryTrait,
+    ANR: ArticleNoteRepositoryTrait
+> ResolveArticleNoteService<AR, ACR, ANR> {
+    pub fn new(article_repository: Box<AR>, article_coauthor_repository: Box<ACR>, article_note_repository: Box<ANR>) -> Self {
+        ResolveArticleNoteService {
+            article_repository,
+            article_coauthor_repository,
+            article_note_repository
+        }
+    }
+
+    pub async fn exec(&self, params: ResolveArticleNoteParams) -> Result<ArticleNote, Box<dyn DomainErrorTrait>> {
+        let note = self.article_note_repository.find_by_id(params.note_id).await;
+
+        if note.is_err() {
+            return Err(generate_service_internal_error(
+                "Error occurred on Resolve Article Note Service, while finding the note by id".into(),
+                &note.unwrap_err()
+            ));
+        }
+
+        let mut note = match note.unwrap() {
+            Some(note) => note,
+            None => return Err(Box::new(ResourceNotFoundError::new()))
+        };
+
+        find_article_with_notes_access(
+            self.article_repository.as_ref(),
+            self.article_coauthor_repository.as_ref(),
+            note.article_id(),
+            params.user_id,
+            &params.user_role
+        ).await?;
+
+        if note.parent_id().is_some() {
+            return Err(Box::new(BadRequestError::new_with_message("Only the note starting a thread can be resolved.".into())));
+        }
+
+        if note.is_resolved() == params.resolved { return Ok(note); }
+
+        note.set_resolved_by(if params.resolved { Some(params.user_id) } else { None });
+
+        let response = self.article_note_repository.save(note).await;
+
+        if response.is_err() {
+            return Err(generate_service_internal_error(
+                "Error occurred on Resolve Article Note Service, while saving the note".into(),
+                &response.unwrap_err()
+            ));
+        }
+
+        Ok(response.unwrap())
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::domain::domain_entities::article::Article;
+    use crate::tests::repositories::article_coauthor_repository::get_article_coauthor_repository;
+    use crate::tests::repositories::article_note_repository::get_article_note_repository;
+    use crate::tests::repositories::article_repository::get_article_repository;
+
+    #[tokio::test]
+    async fn test() {
+        let (article_db, article_repository) = get_article_repository();
+        let (_, article_coauthor_repository) = get_article_coauthor_repository();
+        let (note_db, article_note_repository) = get_article_note_repository();
+
+        let article = Article::new(Uuid::new_v4(), "Rascunho".into(), "Conteúdo".into(), None, "cover.com".into(), 1, "Foo".into());
+        let note = ArticleNote::new(article.id(), Uuid::new_v4(), None, "Revisar o título.".into());
+
+        article_db.lock().unwrap().push(article.clone());
+        note_db.lock().unwrap().push(note.clone());
+
+        let service = ResolveArticleNoteService::new(
+            Box::new(article_repository),
+            Box::new(article_coauthor_repository),
+            Box::new(article_note_repository)
+        );
+
+        let resolved = service.exec(ResolveArticleNoteParams {
+            user_id: article.author_id(),
+            user_role: Role::Writer,
+            note_id: note.id(),
+            resolved: true
+        }).await.unwrap();
+
+        assert_eq!(Some(article.author_id()), resolved.resolved_by());
+        assert!(note_db.lock().unwrap()[0].is_resolved());
+
+        let reopened = service.exec(ResolveArticleNoteParams {
+            user_id: Uuid::new_v4(),
+            user_role: Role::Editor,
+            note_id: note.id(),
+            resolved: false
+        }).await.unwrap();
+
+        assert!(!reopened.is_resolved());
+        assert_eq!(None, reopened.resolved_by());
+    }
+}
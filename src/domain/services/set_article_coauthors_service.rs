@@ -0,0 +1,213 @@
+use std::collections::HashSet;
+
+use uuid::Uuid;
+
+use crate::domain::domain_entities::article_coauthor::ArticleCoauthor;
+use crate::domain::domain_entities::role::Role;
+use crate::domain::domain_entities::user::User;
+use crate::domain::politics::article_politics::ArticlePolitics;
+use crate::domain::repositories::article_coauthor_repository::ArticleCoauthorRepositoryTrait;
+use crate::domain::repositories::article_repository::ArticleRepositoryTrait;
+use crate::domain::repositories::user_repository::UserRepositoryTrait;
+use crate::errors::bad_request_error::BadRequestError;
+use crate::errors::error::DomainErrorTrait;
+use crate::errors::resource_not_found::ResourceNotFoundError;
+use crate::errors::unauthorized_error::UnauthorizedError;
+use crate::util::{generate_service_internal_error, verify_role_has_permission, RolePermissions};
+
+pub const MAX_COAUTHOR_ROLE_LENGTH: usize = 30;
+
+pub struct SetArticleCoauthorParams {
+    pub user_id: Uuid,
+    pub role: Option<String>,
+}
+
+pub struct SetArticleCoauthorsParams {
+    pub user_id: Uuid,
+    pub user_role: Role,
+    pub article_id: Uuid,
+    /// In the order they must be credited. An empty list removes every co-author.
+    pub coauthors: Vec<SetArticleCoauthorParams>,
+}
+
+/**
+ # Set Article Coauthors Service
+ Replaces the co-authors credited on an article. It can be done by the article's author or by those who
+ can change the article's author. Co-authors must be staff members, and get the same edit rights on the
+ article as its author.
+
+ Returns the co-authors along with their users, in order.
+ */
+pub struct SetArticleCoauthorsService<AR, ACR, UR>
+where   AR: ArticleRepositoryTrait,
+        ACR: ArticleCoauthorRepositoryTrait,
+        UR: UserRepositoryTrait
+        {
+    article_repository: Box<AR>,
+    article_coauthor_repository: Box<ACR>,
+    user_repository: Box<UR>,
+}
+
+impl<
+    AR: ArticleRepositoryTrait,
+    ACR: ArticleCoauthorRepositoryTrait,
+    UR: UserRepositoryTrait
+> SetArticleCoauthorsService<AR, ACR, UR> {
+    pub fn new(article_repository: Box<AR>, article_coauthor_repository: Box<ACR>, user_repository: Box<UR>) -> Self {
+        SetArticleCoauthorsService {
+            article_repository,
+            article_coauthor_repository,
+            user_repository
+        }
+    }
+
+    pub async fn exec(&self, params: SetArticleCoauthorsParams) -> Result<Vec<(ArticleCoauthor, User)>, Box<dyn DomainErrorTrait>> {
+        let article = self.article_repository.find_by_id(params.article_id).await;
+
+        if article.is_err() {
+            return Err(generate_service_internal_error(
+                "Error occurred on Set Article Coauthors Service, while finding the article by id".into(),
+                &article.unwrap_err()
+            ));
+        }
+
+        let article = match article.unwrap() {
+            Some(article) => article,
+            None => return Err(Box::new(ResourceNotFoundError::new()))
+        };
+
+        let user_is_author = article.author_id() == params.user_id && params.user_role != Role::User;
+        let user_can_change_author = verify_role_has_permission(&params.user_role, RolePermissions::ChangeArticleAuthor);
+
+        if !user_is_author && !user_can_change_author { return Err(Box::new(UnauthorizedError::new())); }
+
+        if params.coauthors.len() > ArticlePolitics::max_coauthors_per_article() {
+            return Err(Box::new(BadRequestError::new_with_message(
+                format!("An article can have at most {} co-authors.", ArticlePolitics::max_coauthors_per_article())
+            )));
+        }
+
+        let mut seen_users = HashSet::new();
+        let mut coauthors = Vec::with_capacity(params.coauthors.len());
+
+        for (position, coauthor) in params.coauthors.into_iter().enumerate() {
+            if coauthor.user_id == article.author_id() {
+                return Err(Box::new(BadRequestError::new_with_message("The article's author can't be one of its co-authors.".into())));
+            }
+
+            if !seen_users.insert(coauthor.user_id) {
+                return Err(Box::new(BadRequestError::new_with_message(format!("User '{}' is listed more than once.", coauthor.user_id))));
+            }
+
+            let user = self.user_repository.find_by_id(&coauthor.user_id).await;
+
+            if user.is_err() {
+                return Err(generate_service_internal_error(
+                    "Error occurred on Set Article Coauthors Service, while finding the co-author by id".into(),
+                    &user.unwrap_err()
+                ));
+            }
+
+            let user = match user.unwrap() {
+                Some(user) if user.role().is_some_and(|role| role != Role::User) => user,
+                _ => return Err(Box::new(BadRequestError::new_with_message(format!("User '{}' is not a staff member.", coauthor.user_id))))
+            };
+
+            let role = coauthor.role
+                .map(|role| role.trim().to_owned())
+                .filter(|role| !role.is_empty());
+
+            if role.as_ref().is_some_and(|role| role.chars().count() > MAX_COAUTHOR_ROLE_LENGTH) {
+                return Err(Box::new(BadRequestError::new_with_message(
+                    format!("Co-author roles must have at most {} characters.", MAX_COAUTHOR_ROLE_LENGTH)
+                )));
+            }
+
+            coauthors.push((ArticleCoauthor::new(article.id(), user.id(), position as u32, role), user));
+        }
+
+        let response = self.article_coauthor_repository
+            .replace(article.id(), coauthors.iter().map(|(coauthor, _)| coauthor.clone()).collect())
+            .await;
+
+        if response.is_err() {
+            return Err(generate_service_internal_error(
+                "Error occurred on Set Article Coauthors Service, while saving the co-authors".into(),
+                &response.unwrap_err()
+            ));
+        }
+
+        Ok(coauthors)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use http::StatusCode;
+
+    use super::*;
+    use crate::domain::domain_entities::article::Article;
+    use crate::domain::repositories::user_repository::MockUserRepositoryTrait;
+    use crate::tests::repositories::article_coauthor_repository::get_article_coauthor_repository;
+    use crate::tests::repositories::article_repository::get_article_repository;
+
+    #[tokio::test]
+    async fn test() {
+        let (article_db, article_repository) = get_article_repository();
+        let (coauthor_db, article_coauthor_repository) = get_article_coauthor_repository();
+        let mut user_repository = MockUserRepositoryTrait::new();
+
+        let article = Article::new(Uuid::new_v4(), "Cobertura".into(), "Conteúdo".into(), None, "cover.com".into(), 1, "Foo".into());
+        article_db.lock().unwrap().push(article.clone());
+
+        let writer = User::new("Salem".into(), "123".into(), Some(Role::Writer));
+        let reviewer = User::new("Elffi".into(), "123".into(), Some(Role::Editor));
+        let reader = User::new("Leitor".into(), "123".into(), Some(Role::User));
+        let users = vec![writer.clone(), reviewer.clone(), reader.clone()];
+
+        user_repository.expect_find_by_id().returning(move |id| {
+            Ok(users.iter().find(|user| user.id().eq(id)).cloned())
+        });
+
+        let service = SetArticleCoauthorsService::new(
+            Box::new(article_repository),
+            Box::new(article_coauthor_repository),
+            Box::new(user_repository)
+        );
+
+        let result = service.exec(SetArticleCoauthorsParams {
+            user_id: article.author_id(),
+            user_role: Role::Writer,
+            article_id: article.id(),
+            coauthors: vec![
+                SetArticleCoauthorParams { user_id: writer.id(), role: Some("imagens".into()) },
+                SetArticleCoauthorParams { user_id: reviewer.id(), role: Some("  ".into()) },
+            ]
+        }).await.unwrap();
+
+        assert_eq!(writer.id(), result[0].1.id());
+        assert_eq!(Some("imagens"), result[0].0.role());
+        assert_eq!(1, result[1].0.position());
+        assert_eq!(None, result[1].0.role());
+        assert_eq!(2, coauthor_db.lock().unwrap().len());
+
+        let not_staff = service.exec(SetArticleCoauthorsParams {
+            user_id: article.author_id(),
+            user_role: Role::Writer,
+            article_id: article.id(),
+            coauthors: vec![SetArticleCoauthorParams { user_id: reader.id(), role: None }]
+        }).await;
+
+        assert_eq!(&StatusCode::BAD_REQUEST, not_staff.unwrap_err().code());
+
+        let not_author = service.exec(SetArticleCoauthorsParams {
+            user_id: writer.id(),
+            user_role: Role::Writer,
+            article_id: article.id(),
+            coauthors: vec![]
+        }).await;
+
+        assert_eq!(&StatusCode::UNAUTHORIZED, not_author.unwrap_err().code());
+        assert_eq!(2, coauthor_db.lock().unwrap().len());
+    }
+}
@@ -78,7 +78,7 @@ mod test {
     use super::*;
     use std::sync::{Arc, Mutex};
 
-    use crate::domain::domain_entities::comment_report::{CommentReport, CommentReportIdTrait, CommentReportTrait};
+    use crate::domain::domain_entities::comment_report::{CommentReport, ReportReason, CommentReportIdTrait, CommentReportTrait};
     use crate::domain::domain_entities::role::Role;
     use crate::domain::repositories::comment_report_repository::MockCommentReportRepositoryTrait;
     use crate::libs::time::TimeHelper;
@@ -97,6 +97,7 @@ mod test {
             1,
             Uuid::new_v4(),
             Uuid::new_v4(),
+            ReportReason::Offensive,
             "Esse comentário é tóxico.".into(),
             None,
             TimeHelper::now()
@@ -108,6 +109,7 @@ mod test {
             2,
             Uuid::new_v4(),
             Uuid::new_v4(),
+            ReportReason::Offensive,
             "Estão me ofendendo neste comentário!".into(),
             None,
             TimeHelper::now()
@@ -91,7 +91,7 @@ mod test {
         // POPULATING THE DATABASE
         let comment_db: Arc<Mutex<Vec<Comment>>> = Arc::new(Mutex::new(vec![]));
 
-        let comment = Comment::new(Uuid::new_v4(), Some(Uuid::new_v4()), "Comment content haha".into());
+        let comment = Comment::new(Uuid::new_v4(), Some(Uuid::new_v4()), "Comment content haha".into(), None);
 
         comment_db.lock().unwrap().push(comment.clone());
 
@@ -2,16 +2,30 @@ use log::error;
 use uuid::Uuid;
 
 use crate::domain::domain_entities::article::Article;
+use crate::domain::domain_entities::article_block::ArticleBlock;
+use crate::domain::domain_entities::article_content::ArticleContent;
+use crate::domain::politics::article_politics::ArticlePolitics;
 use crate::domain::domain_entities::role::Role;
 use crate::domain::repositories::article_repository::ArticleRepositoryTrait;
 use crate::errors::bad_request_error::BadRequestError;
 use crate::errors::error::DomainErrorTrait;
 use crate::errors::resource_not_found::ResourceNotFoundError;
 use crate::errors::{internal_error::InternalError, unauthorized_error::UnauthorizedError};
-use crate::util::{generate_service_internal_error, RolePermissions, verify_role_has_permission};
+use crate::util::{generate_service_internal_error, RolePermissions, verify_expected_version, verify_role_has_permission};
 use crate::{LOG_SEP, R_EOL};
 use crate::domain::domain_entities::article_tag::ArticleTag;
 use crate::domain::repositories::article_tag_repository::ArticleTagRepositoryTrait;
+use crate::domain::repositories::article_coauthor_repository::ArticleCoauthorRepositoryTrait;
+use crate::domain::repositories::article_note_repository::ArticleNoteRepositoryTrait;
+use crate::domain::domain_entities::article_note::ArticleNote;
+use crate::domain::repositories::article_edit_lock_repository::ArticleEditLockRepositoryTrait;
+use crate::domain::domain_entities::article_edit_lock::ArticleEditLock;
+use crate::domain::domain_entities::mention::MentionSource;
+use crate::domain::repositories::mention_repository::MentionRepositoryTrait;
+use crate::domain::repositories::user_repository::UserRepositoryTrait;
+use crate::libs::time::TimeHelper;
+use super::acquire_article_edit_lock_service::verify_article_edit_lock;
+use crate::domain::politics::mention_politics::MentionPolitics;
 
 pub struct UpdateArticleParams {
     pub user_id: Uuid,
@@ -20,32 +34,75 @@ pub struct UpdateArticleParams {
     pub cover_url: Option<String>,
     pub title: Option<String>,
     pub content: Option<String>,
+    /// An empty list goes back to the Markdown content.
+    pub blocks: Option<Vec<ArticleBlock>>,
     pub approved: Option<bool>,
     pub author_id: Option<Uuid>,
-    pub tag_id: Option<i32>
+    pub tag_id: Option<i32>,
+    /// Editorial note explaining a disapproval, only accepted along with `approved: Some(false)`.
+    pub disapproval_note: Option<String>,
+    /// Saves even though someone else holds the article's edit lock, taking it over, if the user is above them on the role hierarchy.
+    pub force_lock: bool,
+    pub expected_version: Option<i32>
 }
-pub struct UpdateArticleService<ArticleRepository: ArticleRepositoryTrait, ArticleTagRepository: ArticleTagRepositoryTrait> {
+pub struct UpdateArticleService<
+ArticleRepository: ArticleRepositoryTrait,
+ArticleTagRepository: ArticleTagRepositoryTrait,
+ArticleCoauthorRepository: ArticleCoauthorRepositoryTrait,
+ArticleNoteRepository: ArticleNoteRepositoryTrait,
+ArticleEditLockRepository: ArticleEditLockRepositoryTrait,
+UserRepository: UserRepositoryTrait,
+MentionRepository: MentionRepositoryTrait
+> {
     article_repository: Box<ArticleRepository>,
-    article_tag_repository: Box<ArticleTagRepository>
+    article_tag_repository: Box<ArticleTagRepository>,
+    article_coauthor_repository: Box<ArticleCoauthorRepository>,
+    article_note_repository: Box<ArticleNoteRepository>,
+    article_edit_lock_repository: Box<ArticleEditLockRepository>,
+    user_repository: Box<UserRepository>,
+    mention_repository: Box<MentionRepository>
 }
 
 impl
-<ArticleRepository: ArticleRepositoryTrait, ArticleTagRepository: ArticleTagRepositoryTrait>
-UpdateArticleService<ArticleRepository, ArticleTagRepository>
+<ArticleRepository: ArticleRepositoryTrait,
+ArticleTagRepository: ArticleTagRepositoryTrait,
+ArticleCoauthorRepository: ArticleCoauthorRepositoryTrait,
+ArticleNoteRepository: ArticleNoteRepositoryTrait,
+ArticleEditLockRepository: ArticleEditLockRepositoryTrait,
+UserRepository: UserRepositoryTrait,
+MentionRepository: MentionRepositoryTrait>
+UpdateArticleService<ArticleRepository, ArticleTagRepository, ArticleCoauthorRepository, ArticleNoteRepository, ArticleEditLockRepository, UserRepository, MentionRepository>
 {
-    pub fn new(article_repository: Box<ArticleRepository>, article_tag_repository: Box<ArticleTagRepository>) -> Self {
+    pub fn new(
+        article_repository: Box<ArticleRepository>,
+        article_tag_repository: Box<ArticleTagRepository>,
+        article_coauthor_repository: Box<ArticleCoauthorRepository>,
+        article_note_repository: Box<ArticleNoteRepository>,
+        article_edit_lock_repository: Box<ArticleEditLockRepository>,
+        user_repository: Box<UserRepository>,
+        mention_repository: Box<MentionRepository>
+    ) -> Self {
         UpdateArticleService {
             article_repository,
-            article_tag_repository
+            article_tag_repository,
+            article_coauthor_repository,
+            article_note_repository,
+            article_edit_lock_repository,
+            user_repository,
+            mention_repository
         }
     }
 
     pub async fn exec(&self, params: UpdateArticleParams) -> Result<Article, Box<dyn DomainErrorTrait>> {
         // checks if there is something to be updated
-        if params.cover_url.is_none() && params.title.is_none() && params.cover_url.is_none() && params.approved.is_none() {
+        if params.cover_url.is_none() && params.title.is_none() && params.content.is_none() && params.blocks.is_none() && params.approved.is_none() {
             return Err(Box::new(BadRequestError::new()));
         }
 
+        if params.disapproval_note.is_some() && params.approved != Some(false) {
+            return Err(Box::new(BadRequestError::new_with_message("A note can only be left along with the article's disapproval.".into())));
+        }
+
         // article verifications
         let article_on_db = self.article_repository.find_by_id(params.article_id).await;
 
@@ -83,7 +140,21 @@ UpdateArticleService<ArticleRepository, ArticleTagRepository>
         if !user_can_approve && params.approved.is_some() { return Err(Box::new(UnauthorizedError::new())); }
         if !user_can_disapprove && params.approved.is_some() && params.approved.unwrap() == false { return Err(Box::new(UnauthorizedError::new())); }
         
-        let user_is_author = article.author_id() == params.user_id;
+        // co-authors have the same rights as the author over the article
+        let user_is_author = if article.author_id() == params.user_id {
+            true
+        } else {
+            let coauthor = self.article_coauthor_repository.find_by_user(article.id(), params.user_id).await;
+
+            if coauthor.is_err() {
+                return Err(generate_service_internal_error(
+                    "Error occurred in Update Article Service, while finding the article's co-author".into(),
+                    &coauthor.unwrap_err()
+                ));
+            }
+
+            coauthor.unwrap().is_some()
+        };
 
         if !user_can_update && !user_is_author { return Err(Box::new(UnauthorizedError::new())); }
 
@@ -97,6 +168,19 @@ UpdateArticleService<ArticleRepository, ArticleTagRepository>
 
         if !user_can_change_article_author && params.author_id.is_some() { return Err(Box::new(UnauthorizedError::new())) }
 
+        verify_expected_version(params.expected_version, article.version())?;
+
+        // someone else editing the article keeps others from overwriting their changes
+        let lock_taken_over = verify_article_edit_lock(
+            self.article_edit_lock_repository.as_ref(),
+            article.id(),
+            params.user_id,
+            &params.user_role,
+            params.force_lock
+        ).await?;
+
+        let content_changed = params.content.is_some() || params.blocks.is_some();
+
         // modifies the article where requested
         if params.author_id.is_some() {
             article.set_author_id(params.author_id.unwrap())
@@ -106,6 +190,15 @@ UpdateArticleService<ArticleRepository, ArticleTagRepository>
             article.set_content(params.content.unwrap())
         }
 
+        if params.blocks.is_some() {
+            let blocks = params.blocks.unwrap();
+            article.set_blocks(if blocks.is_empty() { None } else { Some(blocks) });
+        }
+
+        if article.content().trim().is_empty() && article.blocks().is_none() {
+            return Err(Box::new(BadRequestError::new_with_message("Article must have either content or blocks.".into())));
+        }
+
         if params.title.is_some() {
             article.set_title(params.title.unwrap());
         }
@@ -129,6 +222,8 @@ UpdateArticleService<ArticleRepository, ArticleTagRepository>
             article.set_tag_value(tag.value().to_owned());
         }
 
+        ArticlePolitics::refresh_reading_metadata(&mut article);
+
         let response = self.article_repository.save(article).await;
 
         if response.is_err() {
@@ -139,6 +234,50 @@ UpdateArticleService<ArticleRepository, ArticleTagRepository>
         }
         let article = response.unwrap();
 
+        if content_changed {
+            MentionPolitics::sync_mentions(
+                self.user_repository.as_ref(),
+                self.mention_repository.as_ref(),
+                MentionSource::Article(article.id()),
+                article.author_id(),
+                &ArticleContent::from_article(&article).plain_text(),
+            ).await;
+        }
+
+        if lock_taken_over.is_some() {
+            let lock = ArticleEditLock::new(
+                article.id(),
+                params.user_id,
+                ArticlePolitics::edit_lock_expiration(TimeHelper::now())
+            );
+
+            let response = self.article_edit_lock_repository.save(lock).await;
+
+            if response.is_err() {
+                return Err(generate_service_internal_error(
+                    "Error occurred in Update Article Service, while taking over the article's edit lock".into(),
+                    &response.unwrap_err()
+                ));
+            }
+        }
+
+        let disapproval_note = params.disapproval_note
+            .map(|note| note.trim().to_owned())
+            .filter(|note| !note.is_empty());
+
+        if let Some(note) = disapproval_note {
+            let response = self.article_note_repository
+                .create(ArticleNote::new(article.id(), params.user_id, None, note))
+                .await;
+
+            if response.is_err() {
+                return Err(generate_service_internal_error(
+                    "Error occurred in Update Article Service, while creating the disapproval note".into(),
+                    &response.unwrap_err()
+                ));
+            }
+        }
+
         Ok(article)
     }
 
@@ -170,29 +309,49 @@ mod test {
     use super::{Article, UpdateArticleParams};
     use crate::tests::repositories::article_repository::get_article_repository;
     use crate::tests::repositories::article_tag_repository::get_article_tag_repository;
+    use crate::tests::repositories::article_coauthor_repository::get_article_coauthor_repository;
+    use crate::domain::domain_entities::article_coauthor::ArticleCoauthor;
+    use crate::tests::repositories::article_note_repository::get_article_note_repository;
+    use crate::tests::repositories::article_edit_lock_repository::get_article_edit_lock_repository;
+    use crate::tests::repositories::mention_repository::get_mention_repository;
+    use crate::tests::repositories::user_repository::get_user_repository;
+    use crate::domain::domain_entities::article_edit_lock::ArticleEditLock;
+    use crate::domain::politics::article_politics::ArticlePolitics;
+    use crate::libs::time::TimeHelper;
 
     #[tokio::test]
     async fn test() {
         let (article_db, article_repository) = get_article_repository();
         let (tag_db, article_tag_repository) = get_article_tag_repository();
+        let (coauthor_db, article_coauthor_repository) = get_article_coauthor_repository();
+        let (note_db, article_note_repository) = get_article_note_repository();
+        let (lock_db, article_edit_lock_repository) = get_article_edit_lock_repository();
+        let (_user_db, user_repository) = get_user_repository();
+        let (_mention_db, mention_repository) = get_mention_repository();
 
         let article = Article::new(
             Uuid::new_v4(),
             "Título inicial".to_string(),
             "Conteúdo inicial".to_string(),
+            None,
             "coverurl.inicial".to_string(),
             1,
             "Foo".to_string()
         );
 
-        let article_tag = ArticleTag::new_from_existing(2, "Bar".to_string());
+        let article_tag = ArticleTag::new_from_existing(2, "Bar".to_string(), 1);
 
         tag_db.lock().unwrap().push(article_tag);
         article_db.lock().unwrap().push(article.clone());
 
         let service = super::UpdateArticleService {
             article_repository: Box::new(article_repository),
-            article_tag_repository: Box::new(article_tag_repository)
+            article_tag_repository: Box::new(article_tag_repository),
+            article_coauthor_repository: Box::new(article_coauthor_repository),
+            article_note_repository: Box::new(article_note_repository),
+            article_edit_lock_repository: Box::new(article_edit_lock_repository),
+            user_repository: Box::new(user_repository),
+            mention_repository: Box::new(mention_repository)
         };
 
         let result = service.exec(UpdateArticleParams {
@@ -202,9 +361,13 @@ mod test {
             approved: Some(true),
             title: None,
             content: None,
+            blocks: None,
             cover_url: None,
             author_id: None,
-            tag_id: None
+            tag_id: None,
+            disapproval_note: None,
+            force_lock: false,
+            expected_version: None
         }).await;
 
         assert_eq!(result.unwrap_err().code(), &StatusCode::UNAUTHORIZED); // writer can't approve any article
@@ -216,14 +379,99 @@ mod test {
             approved: None,
             title: Some("Título atualizado".to_string()),
             content: Some("Conteúdo atualizado".to_string()),
+            blocks: None,
             cover_url: None,
             author_id: None,
-            tag_id: Some(2)
+            tag_id: Some(2),
+            disapproval_note: None,
+            force_lock: false,
+            expected_version: None
         }).await;
 
         let result = result.unwrap();
 
         assert_eq!("Título atualizado", result.title());
+        assert_eq!(2, result.word_count());
         assert_eq!("Bar".to_string(), result.tag_value().unwrap());
+
+        let coauthor_id = Uuid::new_v4();
+        let coauthor_update = || UpdateArticleParams {
+            user_id: coauthor_id,
+            user_role: Role::Writer,
+            article_id: article.id(),
+            approved: None,
+            title: Some("Título do co-autor".to_string()),
+            content: None,
+            blocks: None,
+            cover_url: None,
+            author_id: None,
+            tag_id: None,
+            disapproval_note: None,
+            force_lock: false,
+            expected_version: None
+        };
+
+        let result = service.exec(coauthor_update()).await;
+        assert_eq!(result.unwrap_err().code(), &StatusCode::UNAUTHORIZED); // not a co-author yet
+
+        coauthor_db.lock().unwrap().push(ArticleCoauthor::new(article.id(), coauthor_id, 0, Some("revisão".into())));
+
+        let result = service.exec(coauthor_update()).await;
+        assert_eq!("Título do co-autor", result.unwrap().title());
+
+        let result = service.exec(UpdateArticleParams {
+            user_id: Uuid::new_v4(),
+            user_role: Role::Coord,
+            article_id: article.id(),
+            approved: Some(false),
+            title: None,
+            content: None,
+            blocks: None,
+            cover_url: None,
+            author_id: None,
+            tag_id: None,
+            disapproval_note: Some("Faltou citar a fonte.".to_string()),
+            force_lock: false,
+            expected_version: None
+        }).await;
+
+        assert!(!result.unwrap().approved());
+        assert_eq!("Faltou citar a fonte.", note_db.lock().unwrap()[0].content());
+
+        // the co-author is editing the article
+        lock_db.lock().unwrap().push(ArticleEditLock::new_from_existing(
+            article.id(),
+            coauthor_id,
+            Some("Coautor".into()),
+            Some(Role::Writer),
+            TimeHelper::now(),
+            ArticlePolitics::edit_lock_expiration(TimeHelper::now())
+        ));
+
+        let editor_update = |force_lock: bool| UpdateArticleParams {
+            user_id: article.author_id(),
+            user_role: Role::Editor,
+            article_id: article.id(),
+            approved: None,
+            title: Some("Título do editor".to_string()),
+            content: None,
+            blocks: None,
+            cover_url: None,
+            author_id: None,
+            tag_id: None,
+            disapproval_note: None,
+            force_lock,
+            expected_version: None
+        };
+
+        let result = service.exec(editor_update(false)).await;
+        assert_eq!(result.unwrap_err().code(), &StatusCode::CONFLICT);
+
+        let result = service.exec(editor_update(true)).await;
+        assert_eq!("Título do editor", result.unwrap().title());
+        assert_eq!(lock_db.lock().unwrap()[0].user_id(), article.author_id()); // the lock was taken over
+
+        let result = service.exec(coauthor_update()).await;
+        assert_eq!(result.unwrap_err().code(), &StatusCode::CONFLICT);
     }
 }
@@ -5,12 +5,13 @@ use crate::errors::bad_request_error::BadRequestError;
 use crate::errors::error::DomainErrorTrait;
 use crate::errors::resource_not_found::ResourceNotFoundError;
 use crate::errors::unauthorized_error::UnauthorizedError;
-use crate::util::{generate_service_internal_error, RolePermissions, verify_role_has_permission};
+use crate::util::{generate_service_internal_error, RolePermissions, verify_expected_version, verify_role_has_permission};
 
 pub struct UpdateArticleTagParams {
     pub user_role: Role,
     pub value: Option<String>,
     pub tag_id: i32,
+    pub expected_version: Option<i32>,
 }
 
 pub struct UpdateArticleTagService<ArticleTagRepository: ArticleTagRepositoryTrait> {
@@ -54,6 +55,9 @@ impl<ArticleTagRepository: ArticleTagRepositoryTrait> UpdateArticleTagService<Ar
         }
 
         let mut tag = tag.unwrap();
+
+        verify_expected_version(params.expected_version, tag.version())?;
+
         tag.set_value(params.value.unwrap());
 
         let result = self.article_tag_repository.save(tag).await;
@@ -82,13 +86,14 @@ mod test {
         let (tag_db, tag_repository) = get_article_tag_repository();
         let sut = super::UpdateArticleTagService::new(tag_repository);
 
-        let tag = ArticleTag::new_from_existing(1, "Foo".into());
+        let tag = ArticleTag::new_from_existing(1, "Foo".into(), 1);
         tag_db.lock().unwrap().push(tag);
 
         let result = sut.exec(UpdateArticleTagParams {
             value: Some("Bar".to_string()),
             user_role: Role::Principal,
             tag_id: 1,
+            expected_version: None,
         }).await;
 
         assert!(result.is_ok());
@@ -100,15 +105,36 @@ mod test {
         let (tag_db, tag_repository) = get_article_tag_repository();
         let sut = super::UpdateArticleTagService::new(tag_repository);
 
-        let tag = ArticleTag::new_from_existing(1, "Foo".into());
+        let tag = ArticleTag::new_from_existing(1, "Foo".into(), 1);
         tag_db.lock().unwrap().push(tag);
 
         let result = sut.exec(UpdateArticleTagParams {
             value: Some("Bar".into()),
             user_role: Role::Admin,
             tag_id: 1,
+            expected_version: None,
         }).await;
 
         assert!(result.is_err(), "Only Principal-role or above users should be able to update an article tag.");
     }
+
+    #[tokio::test]
+    async fn test_if_stale_version_cannot_update_article_tag() {
+        let (tag_db, tag_repository) = get_article_tag_repository();
+        let sut = super::UpdateArticleTagService::new(tag_repository);
+
+        let tag = ArticleTag::new_from_existing(1, "Foo".into(), 2);
+        tag_db.lock().unwrap().push(tag);
+
+        let result = sut.exec(UpdateArticleTagParams {
+            value: Some("Bar".into()),
+            user_role: Role::Principal,
+            tag_id: 1,
+            expected_version: Some(1),
+        }).await;
+
+        assert!(result.is_err(), "An update based on an outdated version should be rejected.");
+        assert_eq!(&412, result.unwrap_err().code());
+        assert_eq!(&"Foo".to_string(), tag_db.lock().unwrap()[0].value());
+    }
 }
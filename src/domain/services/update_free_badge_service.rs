@@ -6,7 +6,7 @@ use crate::domain::repositories::free_badge_repository::FreeBadgeRepositoryTrait
 use crate::errors::bad_request_error::BadRequestError;
 use crate::errors::error::DomainErrorTrait;
 use crate::errors::unauthorized_error::UnauthorizedError;
-use crate::util::{generate_service_internal_error, RolePermissions, verify_role_has_permission};
+use crate::util::{generate_service_internal_error, RolePermissions, verify_expected_version, verify_role_has_permission};
 
 pub struct UpdateFreeBadgeParams {
     pub free_badge_id: Uuid,
@@ -15,7 +15,8 @@ pub struct UpdateFreeBadgeParams {
     pub image: Option<String>,
     pub link: Option<String>,
     pub link_is_external: Option<bool>,
-    pub available_until: Option<Option<NaiveDateTime>>
+    pub available_until: Option<Option<NaiveDateTime>>,
+    pub expected_version: Option<i32>
 }
 
 pub struct UpdateFreeBadgeService<FreeBadgeRepository: FreeBadgeRepositoryTrait> {
@@ -53,6 +54,8 @@ impl<FreeBadgeRepository: FreeBadgeRepositoryTrait> UpdateFreeBadgeService<FreeB
 
         let mut free_badge = free_badge.unwrap();
 
+        verify_expected_version(params.expected_version, free_badge.version())?;
+
         if params.code.is_some() {
             free_badge.set_code(params.code.unwrap());
         }
@@ -114,6 +117,7 @@ mod test {
             link: None,
             link_is_external: None,
             available_until: Some(Some(badge_deadline.clone())),
+            expected_version: None,
         }).await;
 
         assert!(result.is_ok());
@@ -144,6 +148,7 @@ mod test {
             link: None,
             link_is_external: None,
             available_until: Some(Some(badge_deadline.clone())),
+            expected_version: None,
         }).await;
 
         assert!(result.is_err());
@@ -9,7 +9,7 @@ use crate::{LOG_SEP, R_EOL};
 use crate::domain::domain_entities::role::Role;
 use crate::domain::repositories::team_user_repository::TeamUserRepositoryTrait;
 use crate::errors::unauthorized_error::UnauthorizedError;
-use crate::util::verify_role_has_permission;
+use crate::util::{generate_service_internal_error, verify_expected_version, verify_role_has_permission};
 
 pub struct UpdateTeamUserParams {
     pub staff_role: Role,
@@ -19,6 +19,7 @@ pub struct UpdateTeamUserParams {
     pub user_function: Option<String>,
     pub twitter: Option<Option<String>>,
     pub discord: Option<Option<String>>,
+    pub expected_version: Option<i32>,
 }
 
 pub struct UpdateTeamUserService<TeamUserRepository: TeamUserRepositoryTrait> {
@@ -65,6 +66,8 @@ impl<TeamUserRepository: TeamUserRepositoryTrait> UpdateTeamUserService<TeamUser
 
         let mut team_user = team_user_on_db.unwrap();
 
+        verify_expected_version(params.expected_version, team_user.version())?;
+
         // updating the team user properties
         if params.team_role_id.is_some() {
             team_user.set_team_role_id(params.team_role_id.unwrap());
@@ -90,12 +93,10 @@ impl<TeamUserRepository: TeamUserRepositoryTrait> UpdateTeamUserService<TeamUser
         let result = self.team_user_repository.save(team_user).await;
 
         if result.is_err() {
-            error!(
-                "{R_EOL}{LOG_SEP}{R_EOL}Error occurred on Update Article Service, while finding article by id: {R_EOL}{}{R_EOL}{LOG_SEP}{R_EOL}",
+            return Err(generate_service_internal_error(
+                "Error occurred on Update Team User Service, while saving the team user",
                 result.as_ref().unwrap_err()
-            );
-
-            return Err(Box::new(InternalError::new()));
+            ));
         }
 
         Ok(result.unwrap())
@@ -163,6 +164,7 @@ mod test {
             staff_role: Role::Ceo,
             team_role_id: None,
             team_user_id: team_user.id(),
+            expected_version: None,
         }).await;
 
         assert!(res.is_ok());
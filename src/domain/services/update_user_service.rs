@@ -4,13 +4,16 @@ use uuid::Uuid;
 use crate::domain::cryptography::hasher::HasherTrait;
 use crate::domain::domain_entities::role::Role;
 use crate::domain::domain_entities::user::User;
+use crate::domain::politics::word_filter_politics::WordFilterPolitics;
 use crate::errors::error::DomainErrorTrait;
 use crate::errors::internal_error::InternalError;
 use crate::errors::resource_not_found::ResourceNotFoundError;
 use crate::errors::unauthorized_error::UnauthorizedError;
 use crate::domain::repositories::user_repository::UserRepositoryTrait;
+use crate::domain::repositories::word_filter_repository::WordFilterRepositoryTrait;
 use crate::util::verify_role_hierarchy_matches;
 use crate::util::verify_role_has_permission;
+use crate::util::{generate_service_internal_error, verify_expected_version};
 
 use crate::{LOG_SEP, R_EOL};
 
@@ -20,17 +23,25 @@ pub struct UpdateUserParams {
     pub user_id: Uuid,
     pub nickname: Option<String>,
     pub password: Option<String>,
-    pub role: Option<Role>
+    pub role: Option<Role>,
+    pub expected_version: Option<i32>
 }
-pub struct UpdateUserService<UserRepository: UserRepositoryTrait> {
+pub struct UpdateUserService<UserRepository: UserRepositoryTrait, WordFilterRepository: WordFilterRepositoryTrait> {
     user_repository: Box<UserRepository>,
+    word_filter_repository: Box<WordFilterRepository>,
     hasher: Box<dyn HasherTrait>
 }
 
-impl<UserRepositoryType: UserRepositoryTrait> UpdateUserService<UserRepositoryType> {
-    pub fn new(user_repository: Box<UserRepositoryType>, hasher: Box<dyn HasherTrait>) -> Self {
+impl<UserRepositoryType: UserRepositoryTrait, WordFilterRepositoryType: WordFilterRepositoryTrait>
+UpdateUserService<UserRepositoryType, WordFilterRepositoryType> {
+    pub fn new(
+        user_repository: Box<UserRepositoryType>,
+        word_filter_repository: Box<WordFilterRepositoryType>,
+        hasher: Box<dyn HasherTrait>
+    ) -> Self {
         UpdateUserService {
             user_repository,
+            word_filter_repository,
             hasher
         }
     }
@@ -70,6 +81,23 @@ impl<UserRepositoryType: UserRepositoryTrait> UpdateUserService<UserRepositoryTy
             );
         }
 
+        verify_expected_version(params.expected_version, user.version())?;
+
+        if let Some(nickname) = params.nickname.as_ref().filter(|nickname| *nickname != user.nickname()) {
+            let word_filters = self.word_filter_repository.find_all().await;
+
+            if let Err(err) = word_filters {
+                return Err(generate_service_internal_error(
+                    "Error occurred on Update User Service, while fetching the word filters",
+                    &err
+                ));
+            }
+
+            if let Err(err) = WordFilterPolitics::verify_nickname(&word_filters.unwrap(), nickname) {
+                return Err(Box::new(err));
+            }
+        }
+
         user.set_nickname(if params.nickname.is_some() { params.nickname.unwrap() } else { user.nickname().to_string() });
 
         user.set_password(if params.password.is_some() {
@@ -84,12 +112,10 @@ impl<UserRepositoryType: UserRepositoryTrait> UpdateUserService<UserRepositoryTy
         match result {
             Ok(_) => (),
             Err(err) => {
-                error!(
-                    "{R_EOL}{LOG_SEP}{R_EOL}Error occurred on Update User Service, while saving the user on the database: {R_EOL}{}{R_EOL}{LOG_SEP}{R_EOL}",
-                    err
-                );
-
-                return Err(Box::new(InternalError::new()));
+                return Err(generate_service_internal_error(
+                    "Error occurred on Update User Service, while saving the user on the database",
+                    &err
+                ));
             }
         };
 
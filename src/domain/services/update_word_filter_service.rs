@@ -0,0 +1,123 @@
+use uuid::Uuid;
+use crate::domain::domain_entities::role::Role;
+use crate::domain::domain_entities::word_filter::{WordFilter, WordFilterAction};
+use crate::domain::repositories::word_filter_repository::WordFilterRepositoryTrait;
+use crate::errors::bad_request_error::BadRequestError;
+use crate::errors::error::DomainErrorTrait;
+use crate::errors::resource_not_found::ResourceNotFoundError;
+use crate::errors::unauthorized_error::UnauthorizedError;
+use crate::libs::time::TimeHelper;
+use crate::util::{generate_service_internal_error, RolePermissions, verify_expected_version, verify_role_has_permission};
+
+pub struct UpdateWordFilterParams {
+    pub user_role: Role,
+    pub word_filter_id: Uuid,
+    pub pattern: Option<String>,
+    pub is_regex: Option<bool>,
+    pub action: Option<WordFilterAction>,
+    pub expected_version: Option<i32>
+}
+
+pub struct UpdateWordFilterService<WordFilterRepository: WordFilterRepositoryTrait> {
+    word_filter_repository: Box<WordFilterRepository>
+}
+
+impl<WordFilterRepository: WordFilterRepositoryTrait> UpdateWordFilterService<WordFilterRepository> {
+    pub fn new(word_filter_repository: Box<WordFilterRepository>) -> Self {
+        UpdateWordFilterService {
+            word_filter_repository
+        }
+    }
+
+    pub async fn exec(&self, params: UpdateWordFilterParams) -> Result<WordFilter, Box<dyn DomainErrorTrait>> {
+        let user_can_manage_word_filters = verify_role_has_permission(&params.user_role, RolePermissions::ManageWordFilters);
+
+        if !user_can_manage_word_filters {
+            return Err(Box::new(UnauthorizedError::new()));
+        }
+
+        let word_filter = self.word_filter_repository.find_by_id(params.word_filter_id).await;
+
+        if word_filter.is_err() {
+            return Err(generate_service_internal_error(
+                "Error occurred in Update Word Filter Service, on finding the word filter by id".into(),
+                &word_filter.unwrap_err()
+            ));
+        }
+
+        let mut word_filter = match word_filter.unwrap() {
+            Some(word_filter) => word_filter,
+            None => return Err(Box::new(ResourceNotFoundError::new())),
+        };
+
+        verify_expected_version(params.expected_version, word_filter.version())?;
+
+        if let Some(pattern) = params.pattern {
+            word_filter.set_pattern(pattern);
+        }
+
+        if let Some(is_regex) = params.is_regex {
+            word_filter.set_is_regex(is_regex);
+        }
+
+        if let Some(action) = params.action {
+            word_filter.set_action(action);
+        }
+
+        if let Err(err) = word_filter.compile() {
+            return Err(Box::new(BadRequestError::new_with_message(format!("Invalid pattern: {err}"))));
+        }
+
+        word_filter.set_updated_at(Some(TimeHelper::now()));
+
+        let word_filter = self.word_filter_repository.save(word_filter).await;
+
+        if word_filter.is_err() {
+            return Err(generate_service_internal_error(
+                "Error occurred in Update Word Filter Service, on saving the word filter in the database".into(),
+                &word_filter.unwrap_err()
+            ));
+        }
+
+        Ok(word_filter.unwrap())
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::tests::repositories::word_filter_repository::get_word_filter_repository;
+
+    #[tokio::test]
+    async fn test_if_moderators_can_update_word_filters() {
+        let (word_filters_db, word_filter_repository) = get_word_filter_repository();
+        let sut = UpdateWordFilterService::new(Box::new(word_filter_repository));
+
+        let word_filter = WordFilter::new("bobba".into(), false, WordFilterAction::Mask);
+        word_filters_db.lock().unwrap().push(word_filter.clone());
+
+        let result = sut.exec(UpdateWordFilterParams {
+            user_role: Role::Coord,
+            word_filter_id: word_filter.id(),
+            pattern: None,
+            is_regex: None,
+            action: Some(WordFilterAction::Deactivate),
+            expected_version: Some(1),
+        }).await;
+
+        assert!(result.is_ok());
+        assert_eq!(WordFilterAction::Deactivate, word_filters_db.lock().unwrap()[0].action());
+
+        let invalid_regex = sut.exec(UpdateWordFilterParams {
+            user_role: Role::Coord,
+            word_filter_id: word_filter.id(),
+            pattern: Some("[bobba".into()),
+            is_regex: Some(true),
+            action: None,
+            expected_version: None,
+        }).await;
+
+        assert_eq!(&400, invalid_regex.unwrap_err().code());
+        assert_eq!("bobba", word_filters_db.lock().unwrap()[0].pattern());
+    }
+}
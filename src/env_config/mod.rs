@@ -11,7 +11,16 @@ pub struct EnvConfig {
     pub jwt_secret: String,
     pub host: String,
     pub port: u16,
-    pub workers: usize
+    pub workers: usize,
+    /// Minutes authors have to edit their comments, see `CommentPolitics::default_edit_window_minutes`.
+    pub comment_edit_window_minutes: Option<i64>,
+    /// Comma separated, see `CommentSpamSettings::with_blocklists`.
+    pub comment_blocked_words: Option<String>,
+    pub comment_blocked_domains: Option<String>,
+    /// Keys the hashes of the anonymous readers' IPs, see `ArticleViewer::key`. Defaults to the JWT secret.
+    pub article_view_secret: Option<String>,
+    /// Comma separated IPs of the reverse proxies trusted to forward the readers' IPs, see `ArticlesController::viewer_ip`.
+    pub trusted_proxies: Option<String>,
 }
 
 impl EnvConfig {
@@ -0,0 +1,50 @@
+use std::fmt;
+use http::StatusCode;
+
+use super::error::DomainErrorTrait;
+
+#[derive(Debug, Clone)]
+pub struct ConflictError {
+    code: u16,
+    message: String,
+}
+
+impl ConflictError {
+    pub fn new() -> Self {
+        ConflictError {
+            code: StatusCode::CONFLICT.as_u16(),
+            message: "The resource is in a conflicting state.".to_string()
+        }
+    }
+
+    pub fn new_with_message(message: String) -> Self {
+        ConflictError {
+            code: StatusCode::CONFLICT.as_u16(),
+            message,
+        }
+    }
+}
+
+impl Default for ConflictError {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl DomainErrorTrait for ConflictError {
+    fn code(&self) -> &u16 {
+        &self.code
+    }
+
+    fn message(&self) -> &String {
+        &self.message
+    }
+}
+
+impl fmt::Display for ConflictError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.message)
+    }
+}
+
+impl std::error::Error for ConflictError {}
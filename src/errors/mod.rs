@@ -5,4 +5,7 @@ pub mod internal_error;
 pub mod unauthorized_error;
 pub mod resource_not_found;
 pub mod bad_request_error;
-pub mod enum_coercion_error;
\ No newline at end of file
+pub mod enum_coercion_error;
+pub mod conflict_error;
+pub mod precondition_failed_error;
+pub mod too_many_requests_error;
\ No newline at end of file
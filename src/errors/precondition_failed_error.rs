@@ -0,0 +1,43 @@
+use std::fmt;
+use http::StatusCode;
+
+use super::error::DomainErrorTrait;
+
+#[derive(Debug, Clone)]
+pub struct PreconditionFailedError {
+    code: u16,
+    message: String,
+}
+
+impl PreconditionFailedError {
+    pub fn new() -> Self {
+        PreconditionFailedError {
+            code: StatusCode::PRECONDITION_FAILED.as_u16(),
+            message: "The resource was changed since it was last read.".to_string()
+        }
+    }
+}
+
+impl Default for PreconditionFailedError {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl DomainErrorTrait for PreconditionFailedError {
+    fn code(&self) -> &u16 {
+        &self.code
+    }
+
+    fn message(&self) -> &String {
+        &self.message
+    }
+}
+
+impl fmt::Display for PreconditionFailedError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.message)
+    }
+}
+
+impl std::error::Error for PreconditionFailedError {}
@@ -0,0 +1,50 @@
+use std::fmt;
+use http::StatusCode;
+
+use super::error::DomainErrorTrait;
+
+#[derive(Debug, Clone)]
+pub struct TooManyRequestsError {
+    code: u16,
+    message: String,
+}
+
+impl TooManyRequestsError {
+    pub fn new() -> Self {
+        TooManyRequestsError {
+            code: StatusCode::TOO_MANY_REQUESTS.as_u16(),
+            message: "Too many requests, try again later.".to_string()
+        }
+    }
+
+    pub fn new_with_message(message: String) -> Self {
+        TooManyRequestsError {
+            code: StatusCode::TOO_MANY_REQUESTS.as_u16(),
+            message,
+        }
+    }
+}
+
+impl Default for TooManyRequestsError {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl DomainErrorTrait for TooManyRequestsError {
+    fn code(&self) -> &u16 {
+        &self.code
+    }
+
+    fn message(&self) -> &String {
+        &self.message
+    }
+}
+
+impl fmt::Display for TooManyRequestsError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.message)
+    }
+}
+
+impl std::error::Error for TooManyRequestsError {}
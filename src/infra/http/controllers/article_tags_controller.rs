@@ -1,3 +1,4 @@
+use actix_web::http::header;
 use actix_web::{web, HttpResponse, Responder};
 use actix_web_lab::middleware::from_fn;
 use either::{Left, Right};
@@ -17,12 +18,14 @@ use crate::domain::services::update_article_tag_service::UpdateArticleTagParams;
 use crate::infra::http::dtos::create_article_tag::CreateArticleTagDto;
 use crate::infra::http::dtos::list_article_tags::ListArticleTagsDto;
 use crate::infra::http::dtos::update_article_tag::UpdateArticleTagDto;
+use crate::infra::http::extractors::if_match::IfMatch;
 use crate::infra::http::extractors::req_user::ReqUser;
 use crate::infra::http::middlewares::authentication_middleware;
 use crate::infra::http::presenters::article_tag::{ArticleTagPresenter, MappedArticleTag};
 use crate::infra::http::presenters::error::ErrorPresenter;
 use crate::infra::http::presenters::pagination::PaginationPresenter;
 use crate::infra::http::presenters::presenter::{JsonWrappedEntity, PresenterTrait};
+use crate::infra::http::sitemap_cache::SitemapCache;
 use crate::util::generate_error_response;
 
 pub struct ArticleTagsController;
@@ -68,6 +71,8 @@ impl ArticleTagsController {
             return generate_error_response(result.unwrap_err());
         }
 
+        SitemapCache::invalidate();
+
         let article_tag = result.unwrap();
         let mapped_article_tag = ArticleTagPresenter::to_http(article_tag);
 
@@ -113,7 +118,8 @@ impl ArticleTagsController {
     async fn update(
         body: web::Json<UpdateArticleTagDto>,
         user: web::ReqData<ReqUser>,
-        tag_id: web::Path<i32>
+        tag_id: web::Path<i32>,
+        IfMatch(expected_version): IfMatch
     ) -> impl Responder {
         match body.validate() {
             Ok(()) => (),
@@ -131,6 +137,7 @@ impl ArticleTagsController {
             value: body.value,
             tag_id: tag_id.into_inner(),
             user_role: user.into_inner().user_role.unwrap(),
+            expected_version,
         }).await;
 
         if service_response.is_err() {
@@ -138,9 +145,10 @@ impl ArticleTagsController {
         }
 
         let article_tag = service_response.unwrap();
+        let etag = IfMatch::etag(article_tag.version());
         let mapped_article_tag = ArticleTagPresenter::to_http(article_tag);
 
-        return HttpResponse::NoContent().json(JsonWrappedEntity {
+        return HttpResponse::Ok().insert_header((header::ETAG, etag)).json(JsonWrappedEntity {
             data: mapped_article_tag
         });
     }
@@ -160,6 +168,8 @@ impl ArticleTagsController {
             return generate_error_response(service_response.unwrap_err());
         }
 
+        SitemapCache::invalidate();
+
         return HttpResponse::NoContent().finish();
     }
 }
@@ -1,35 +1,88 @@
-use actix_web::{web, HttpResponse, Responder};
+use std::net::IpAddr;
+use std::str::FromStr;
+
+use actix_web::http::header;
+use actix_web::{web, HttpRequest, HttpResponse, Responder};
 use actix_web_lab::middleware::from_fn;
 use serde_json::json;
 use either::Either::*;
+use log::error;
 use uuid::Uuid;
 use validator::Validate;
 
 use crate::core::pagination::DEFAULT_PER_PAGE;
+use crate::{ENV_VARS, LOG_SEP, R_EOL};
+use crate::domain::domain_entities::article_reaction::ReactionKind;
+use crate::domain::domain_entities::article_view::ArticleViewer;
+use crate::domain::domain_entities::listed_article::ListedArticle;
+use crate::domain::domain_entities::role::Role;
 use crate::domain::domain_entities::slug::Slug;
+use crate::domain::repositories::article_repository::ArticleSortOrder;
 use crate::domain::factories::{
     create_article_service_factory,
     fetch_many_articles_service_factory,
     get_expanded_article_service_factory,
     update_article_service_factory,
-    delete_article_service_factory
+    delete_article_service_factory,
+    register_article_view_service_factory,
+    fetch_trending_articles_service_factory,
+    count_articles_views_service_factory,
+    react_to_article_service_factory,
+    fetch_articles_archive_service_factory,
+    set_article_coauthors_service_factory,
+    create_article_note_service_factory,
+    fetch_article_notes_service_factory,
+    resolve_article_note_service_factory,
+    acquire_article_edit_lock_service_factory,
+    refresh_article_edit_lock_service_factory,
+    release_article_edit_lock_service_factory,
+    lock_article_comments_service_factory
 };
+use crate::domain::services::count_articles_views_service::CountArticlesViewsParams;
 use crate::domain::services::create_article_service::CreateArticleParams;
+use crate::domain::services::create_article_note_service::CreateArticleNoteParams;
+use crate::domain::services::fetch_article_notes_service::FetchArticleNotesParams;
+use crate::domain::services::resolve_article_note_service::ResolveArticleNoteParams;
+use crate::domain::services::acquire_article_edit_lock_service::AcquireArticleEditLockParams;
+use crate::domain::services::refresh_article_edit_lock_service::RefreshArticleEditLockParams;
+use crate::domain::services::release_article_edit_lock_service::ReleaseArticleEditLockParams;
 use crate::domain::services::delete_article_service::DeleteArticleParams;
-use crate::domain::services::fetch_many_articles_service::{FetchManyArticlesParams, ServiceArticleQueryType};
+use crate::domain::services::lock_article_comments_service::LockArticleCommentsParams;
+use crate::domain::services::fetch_trending_articles_service::FetchTrendingArticlesParams;
+use crate::domain::services::react_to_article_service::ReactToArticleParams;
+use crate::domain::services::register_article_view_service::RegisterArticleViewParams;
+use crate::domain::services::set_article_coauthors_service::{SetArticleCoauthorParams, SetArticleCoauthorsParams};
+use crate::domain::services::fetch_many_articles_service::{FetchArticlesCalendarParams, FetchManyArticlesByCursorParams, FetchManyArticlesParams, ServiceArticleQueryType};
 use crate::domain::services::get_expanded_article_service::{FetchManyCommentsWithAuthorResponse, GetExpandedArticleParams, GetExpandedArticleResponse};
 use crate::domain::services::update_article_service::UpdateArticleParams;
+use crate::errors::bad_request_error::BadRequestError;
+use crate::infra::http::dtos::acquire_article_edit_lock::AcquireArticleEditLockDto;
 use crate::infra::http::dtos::create_article::CreateArticleDto;
+use crate::infra::http::dtos::create_article_note::{CreateArticleNoteDto, ResolveArticleNoteDto};
+use crate::infra::http::dtos::get_expanded_article::GetExpandedArticleDto;
 use crate::infra::http::dtos::list_article_admin::AdminListArticlesDto;
-use crate::infra::http::dtos::list_articles::ListArticlesDto;
+use crate::infra::http::dtos::list_articles::{ArticleSortDto, ListArticlesDto};
+use crate::infra::http::dtos::list_trending_articles::ListTrendingArticlesDto;
+use crate::infra::http::dtos::lock_article_comments::LockArticleCommentsDto;
+use crate::infra::http::dtos::react_to_article::ReactToArticleDto;
+use crate::infra::http::dtos::set_article_coauthors::SetArticleCoauthorsDto;
+use crate::infra::http::dtos::simple_pagination_query::{PaginationModeDto, SimplePaginationQueryDto};
 use crate::infra::http::dtos::update_article::UpdateArticleDto;
+use crate::infra::http::extractors::if_match::IfMatch;
 use crate::infra::http::extractors::req_user::ReqUser;
 use crate::infra::http::middlewares::authentication_middleware;
 use crate::infra::http::presenters::presenter::PresenterTrait;
 use crate::infra::http::presenters::article::MappedArticle;
+use crate::infra::http::presenters::article_archive::{ArticleArchivePresenter, MappedArticleArchiveMonth};
+use crate::infra::http::presenters::article_calendar::{ArticleCalendarPresenter, MappedArticleCalendarDay};
+use crate::infra::http::presenters::article_coauthor::{ArticleCoauthorPresenter, MappedArticleCoauthor};
+use crate::infra::http::presenters::article_edit_lock::ArticleEditLockPresenter;
+use crate::infra::http::presenters::article_note::ArticleNotePresenter;
+use crate::infra::http::presenters::article_reactions::ArticleReactionsPresenter;
 use crate::infra::http::presenters::error::ErrorPresenter;
 use crate::infra::http::presenters::pagination::PaginationPresenter;
 use crate::infra::http::presenters::{article::ArticlePresenter, expanded_article::ExpandedArticlePresenter};
+use crate::infra::http::sitemap_cache::SitemapCache;
 use crate::util::generate_error_response;
 
 use super::controller::ControllerTrait;
@@ -46,17 +99,31 @@ impl ControllerTrait for ArticlesController {
                     .to(Self::create)
                     .wrap(from_fn(authentication_middleware))
             ) 
+            .route("/{id}/notes", web::post().to(Self::create_note).wrap(from_fn(authentication_middleware)))
+            .route("/{id}/lock", web::post().to(Self::acquire_lock).wrap(from_fn(authentication_middleware)))
 
             // READ
             .route("/{slug}/get", web::get().to(Self::get))
             .route("/list", web::get().to(Self::list))
             .route("/list/admin", web::get().to(Self::admin_list).wrap(from_fn(authentication_middleware)))
+            .route("/trending", web::get().to(Self::trending))
+            .route("/calendar", web::get().to(Self::calendar).wrap(from_fn(authentication_middleware)))
+            .route("/archive", web::get().to(Self::archive))
+            .route("/archive/{year}/{month}", web::get().to(Self::archive_month))
+            .route("/{id}/notes", web::get().to(Self::notes).wrap(from_fn(authentication_middleware)))
             
             // UPDATE
             .route("/{id}/update", web::put().to(Self::update).wrap(from_fn(authentication_middleware)))
+            .route("/{id}/reaction", web::put().to(Self::react).wrap(from_fn(authentication_middleware)))
+            .route("/{id}/coauthors", web::put().to(Self::set_coauthors).wrap(from_fn(authentication_middleware)))
+            .route("/{id}/comments_lock", web::put().to(Self::lock_comments).wrap(from_fn(authentication_middleware)))
+            .route("/notes/{note_id}/resolve", web::put().to(Self::resolve_note).wrap(from_fn(authentication_middleware)))
+            .route("/{id}/lock", web::put().to(Self::refresh_lock).wrap(from_fn(authentication_middleware)))
 
             // DELETE
             .route("/{id}/delete", web::delete().to(Self::delete).wrap(from_fn(authentication_middleware)))
+            .route("/{id}/reaction", web::delete().to(Self::remove_reaction).wrap(from_fn(authentication_middleware)))
+            .route("/{id}/lock", web::delete().to(Self::release_lock).wrap(from_fn(authentication_middleware)))
         );
     }
 }
@@ -82,6 +149,7 @@ impl ArticlesController {
         let CreateArticleDto {
             author_id,
             content,
+            blocks,
             cover_url,
             title,
             tag_id
@@ -91,6 +159,7 @@ impl ArticlesController {
             custom_author_id: author_id,
             staff_id: auth_user.user_id,
             content,
+            blocks,
             cover_url,
             title,
             tag_id
@@ -101,6 +170,8 @@ impl ArticlesController {
             return generate_error_response(err);
         }
 
+        SitemapCache::invalidate();
+
         let article = result.unwrap();
         let mapped_article = ArticlePresenter::to_http(article);
 
@@ -108,9 +179,18 @@ impl ArticlesController {
     }
 
     async fn get(
+        req: HttpRequest,
         article_slug: web::Path<String>,
+        query: web::Query<GetExpandedArticleDto>,
         user: Option<web::ReqData<ReqUser>>
     ) -> impl Responder {
+        let GetExpandedArticleDto { comments_per_page, comments_sort } = match query.validate() {
+            Ok(()) => query.into_inner(),
+            Err(err) => return HttpResponse::BadRequest().json(ErrorPresenter::to_http_from_validator(err.field_errors())),
+        };
+
+        let comments_per_page = comments_per_page.unwrap_or(DEFAULT_PER_PAGE);
+
         let service = match get_expanded_article_service_factory::exec().await {
             Left(service) => service,
             Right(error) => return error
@@ -128,7 +208,8 @@ impl ArticlesController {
 
         let result = service.exec(GetExpandedArticleParams {
             article_slug: Slug::new_from_existing(article_slug.into_inner()),
-            comments_per_page: Some(DEFAULT_PER_PAGE as u32),
+            comments_per_page: Some(comments_per_page as u32),
+            comments_sort: comments_sort.map(Into::into).unwrap_or_default(),
             user_id,
             user_role,
         }).await;
@@ -138,63 +219,263 @@ impl ArticlesController {
             return generate_error_response(err)
         }
 
-        let GetExpandedArticleResponse { article, article_author, comments: comment_response } = result.unwrap();
-        let FetchManyCommentsWithAuthorResponse {data: comments, pagination: comments_pagination} = comment_response;
+        let GetExpandedArticleResponse { article, content, article_author, coauthors, comments: comment_response, reactions, mentions } = result.unwrap();
+
+        if article.approved() {
+            let viewer = match user_id {
+                Some(user_id) => ArticleViewer::User(*user_id),
+                None => ArticleViewer::Anonymous {
+                    ip: Self::viewer_ip(&req),
+                    user_agent: req.headers().get(header::USER_AGENT).and_then(|agent| agent.to_str().ok()).unwrap_or_default().to_owned(),
+                }
+            };
+
+            // failing to count a view must not prevent the article from being read
+            match register_article_view_service_factory::exec().await {
+                Left(register_view_service) => {
+                    let registered = register_view_service.exec(RegisterArticleViewParams {
+                        article_id: article.id(),
+                        viewer,
+                    }).await;
+
+                    if let Err(err) = registered {
+                        error!("{R_EOL}{LOG_SEP}{R_EOL}Error occurred while registering a view of article '{}':{R_EOL}{}{R_EOL}{LOG_SEP}{R_EOL}", article.id(), err);
+                    }
+                },
+                Right(_) => error!("{R_EOL}{LOG_SEP}{R_EOL}Error occurred while building the view registration of article '{}'.{R_EOL}{LOG_SEP}{R_EOL}", article.id()),
+            }
+        }
+        let FetchManyCommentsWithAuthorResponse {data: comments, pagination: comments_pagination, next_cursor: comments_next_cursor} = comment_response;
+
+        let etag = IfMatch::etag(article.version());
 
         let mapped_article = ExpandedArticlePresenter::to_http(
             article,
+            content,
             article_author,
+            coauthors,
             comments,
-            (comments_pagination, DEFAULT_PER_PAGE)
+            (comments_pagination, comments_next_cursor, comments_per_page),
+            reactions,
+            mentions
         );
 
-        return HttpResponse::Ok().json(json!({
-            "data": mapped_article,
-        }));
+        return HttpResponse::Ok()
+            .insert_header((header::ETAG, etag))
+            .json(json!({
+                "data": mapped_article,
+            }));
     }
 
-    async fn list(query: web::Query<ListArticlesDto>) -> impl Responder {
+    async fn list(query: web::Query<ListArticlesDto>, user: Option<web::ReqData<ReqUser>>) -> impl Responder {
         let query_body: ListArticlesDto = match query.validate() {
             Ok(()) => query.into_inner(),
             Err(err) => return HttpResponse::BadRequest().json(ErrorPresenter::to_http_from_validator(err.field_errors())),
         };
 
+        let ListArticlesDto {
+            page,
+            per_page,
+            title,
+            author,
+            min_reading_time,
+            max_reading_time,
+            is_new,
+            sort,
+            pagination_mode,
+            cursor
+        } = query_body;
+
         return Self::get_list_of_articles(
-            query_body.title,
-            query_body.author,
-            query_body.page,
-            query_body.per_page,
-            Some(true)
+            AdminListArticlesDto {
+                page,
+                per_page,
+                title,
+                author,
+                tag_id: None,
+                from: None,
+                to: None,
+                min_reading_time,
+                max_reading_time,
+                is_new,
+                sort,
+                approved_state: Some(true),
+                pagination_mode,
+                cursor,
+            },
+            user.and_then(|user| user.into_inner().user_role),
         ).await;
     }
 
-    async fn admin_list(query: web::Query<AdminListArticlesDto>) -> impl Responder {
+    async fn admin_list(query: web::Query<AdminListArticlesDto>, user: web::ReqData<ReqUser>) -> impl Responder {
         let query_body: AdminListArticlesDto = match query.validate() {
             Ok(()) => query.into_inner(),
             Err(err) => return HttpResponse::BadRequest().json(ErrorPresenter::to_http_from_validator(err.field_errors())),
         };
 
-        return Self::get_list_of_articles(
-            query_body.title,
-            query_body.author,
-            query_body.page,
-            query_body.per_page,
-            query_body.approved_state,
-        ).await;
+        return Self::get_list_of_articles(query_body, user.into_inner().user_role).await;
+    }
+
+    async fn trending(query: web::Query<ListTrendingArticlesDto>) -> impl Responder {
+        let ListTrendingArticlesDto { limit } = match query.validate() {
+            Ok(()) => query.into_inner(),
+            Err(err) => return HttpResponse::BadRequest().json(ErrorPresenter::to_http_from_validator(err.field_errors())),
+        };
+
+        let service = match fetch_trending_articles_service_factory::exec().await {
+            Left(service) => service,
+            Right(error) => return error
+        };
+
+        let result = service.exec(FetchTrendingArticlesParams {
+            limit: limit.map(|limit| limit as u32),
+        }).await;
+
+        if result.is_err() {
+            return generate_error_response(result.unwrap_err());
+        }
+
+        let mapped_articles = result.unwrap().into_iter().map(ArticlePresenter::to_http_listed).collect::<Vec<MappedArticle>>();
+
+        return HttpResponse::Ok().json(json!({
+            "data": mapped_articles
+        }));
+    }
+
+    /**
+     Articles created between `from` and `to` grouped by day, with every filter of the admin listing
+     applied at once. Pagination and sorting are ignored, the articles of each day go from the oldest to the newest.
+     */
+    async fn calendar(query: web::Query<AdminListArticlesDto>, user: web::ReqData<ReqUser>) -> impl Responder {
+        let AdminListArticlesDto {
+            title,
+            author,
+            tag_id,
+            from,
+            to,
+            min_reading_time,
+            max_reading_time,
+            is_new,
+            approved_state,
+            ..
+        } = match query.validate() {
+            Ok(()) => query.into_inner(),
+            Err(err) => return HttpResponse::BadRequest().json(ErrorPresenter::to_http_from_validator(err.field_errors())),
+        };
+
+        let (from, to) = match from.zip(to) {
+            Some(period) => period,
+            None => return generate_error_response(Box::new(BadRequestError::new_with_message(
+                "The calendar requires both 'from' and 'to' days.".into()
+            )))
+        };
+
+        let queries = Self::article_queries(title, author, tag_id, is_new, min_reading_time, max_reading_time);
+
+        let service = match fetch_many_articles_service_factory::exec().await {
+            Left(service) => service,
+            Right(error) => return error
+        };
+
+        let result = service.exec_calendar(FetchArticlesCalendarParams {
+            user_role: user.into_inner().user_role.unwrap(),
+            from,
+            to,
+            queries,
+            approved_state,
+        }).await;
+
+        if result.is_err() {
+            return generate_error_response(result.unwrap_err());
+        }
+
+        let mapped_days = result.unwrap().into_iter().map(ArticleCalendarPresenter::to_http).collect::<Vec<MappedArticleCalendarDay>>();
+
+        return HttpResponse::Ok().json(json!({
+            "data": mapped_days
+        }));
+    }
+
+    /// The months that have approved articles, with how many of them, from the newest to the oldest.
+    async fn archive() -> impl Responder {
+        let service = match fetch_articles_archive_service_factory::exec().await {
+            Left(service) => service,
+            Right(error) => return error
+        };
+
+        let result = service.exec().await;
+
+        if result.is_err() {
+            return generate_error_response(result.unwrap_err());
+        }
+
+        let mapped_months = result.unwrap().into_iter().map(ArticleArchivePresenter::to_http).collect::<Vec<MappedArticleArchiveMonth>>();
+
+        return HttpResponse::Ok().json(json!({
+            "data": mapped_months
+        }));
+    }
+
+    async fn archive_month(
+        path: web::Path<(i32, u32)>,
+        query: web::Query<SimplePaginationQueryDto>,
+        user: Option<web::ReqData<ReqUser>>
+    ) -> impl Responder {
+        let SimplePaginationQueryDto { page, per_page } = match query.validate() {
+            Ok(()) => query.into_inner(),
+            Err(err) => return HttpResponse::BadRequest().json(ErrorPresenter::to_http_from_validator(err.field_errors())),
+        };
+
+        let (year, month) = path.into_inner();
+
+        let service = match fetch_many_articles_service_factory::exec().await {
+            Left(service) => service,
+            Right(error) => return error
+        };
+
+        let result = service.exec(FetchManyArticlesParams {
+            page,
+            per_page: per_page.map(|per_page| per_page as u32),
+            queries: vec![ServiceArticleQueryType::Month(year, month)],
+            approved_state: Some(true),
+            sort: ArticleSortOrder::Newest,
+        }).await;
+
+        if result.is_err() {
+            return generate_error_response(result.unwrap_err());
+        }
+
+        let result = result.unwrap();
+
+        let user_role = user.and_then(|user| user.into_inner().user_role);
+
+        let mapped_articles = match Self::map_articles(result.data, user_role.as_ref()).await {
+            Ok(mapped_articles) => mapped_articles,
+            Err(error) => return error
+        };
+
+        return HttpResponse::Ok().json(json!({
+            "pagination": PaginationPresenter::to_http(result.pagination, per_page.unwrap_or(DEFAULT_PER_PAGE)),
+            "data": mapped_articles
+        }));
     }
 
     async fn update(
         user: web::ReqData<ReqUser>,
         body: web::Json<UpdateArticleDto>,
-        article_id: web::Path<Uuid>
+        article_id: web::Path<Uuid>,
+        IfMatch(expected_version): IfMatch
     ) -> impl Responder {
         let UpdateArticleDto {
             title,
             approved,
             cover_url,
             content,
+            blocks,
             author_id,
-            tag_id
+            tag_id,
+            disapproval_note,
+            force_lock
         } = match body.validate() {
             Err(e) => return HttpResponse::BadRequest().json(ErrorPresenter::to_http_from_validator(e.field_errors())),
             Ok(()) => body.into_inner()
@@ -211,21 +492,295 @@ impl ArticlesController {
             user_id,
             user_role: user_role.unwrap(),
             content,
+            blocks,
             cover_url,
             approved,
             article_id: article_id.into_inner(),
             title,
             author_id,
-            tag_id
+            tag_id,
+            disapproval_note,
+            force_lock: force_lock.unwrap_or(false),
+            expected_version
+        }).await;
+
+        if result.is_err() {
+            return generate_error_response(result.unwrap_err());
+        }
+
+        SitemapCache::invalidate();
+
+        let article = result.unwrap();
+        let etag = IfMatch::etag(article.version());
+        let mapped_article = ArticlePresenter::to_http(article);
+
+        return HttpResponse::Ok()
+            .insert_header((header::ETAG, etag))
+            .json(json!({"data": mapped_article}));
+    }
+
+    async fn lock_comments(
+        user: web::ReqData<ReqUser>,
+        body: web::Json<LockArticleCommentsDto>,
+        article_id: web::Path<Uuid>,
+        IfMatch(expected_version): IfMatch
+    ) -> impl Responder {
+        let service = match lock_article_comments_service_factory::exec().await {
+            Left(service) => service,
+            Right(error) => return error
+        };
+
+        let ReqUser { user_role, .. } = user.into_inner();
+
+        let result = service.exec(LockArticleCommentsParams {
+            user_role: user_role.as_ref().unwrap(),
+            article_id: article_id.into_inner(),
+            locked: body.into_inner().locked,
+            expected_version
+        }).await;
+
+        if result.is_err() {
+            return generate_error_response(result.unwrap_err());
+        }
+
+        let article = result.unwrap();
+        let etag = IfMatch::etag(article.version());
+        let mapped_article = ArticlePresenter::to_http(article);
+
+        return HttpResponse::Ok()
+            .insert_header((header::ETAG, etag))
+            .json(json!({"data": mapped_article}));
+    }
+
+    async fn set_coauthors(
+        user: web::ReqData<ReqUser>,
+        body: web::Json<SetArticleCoauthorsDto>,
+        article_id: web::Path<Uuid>
+    ) -> impl Responder {
+        let SetArticleCoauthorsDto { coauthors } = match body.validate() {
+            Ok(()) => body.into_inner(),
+            Err(err) => return HttpResponse::BadRequest().json(ErrorPresenter::to_http_from_validator(err.field_errors())),
+        };
+
+        let service = match set_article_coauthors_service_factory::exec().await {
+            Left(service) => service,
+            Right(error) => return error
+        };
+
+        let ReqUser {user_role, user_id, ..} = user.into_inner();
+
+        let result = service.exec(SetArticleCoauthorsParams {
+            user_id,
+            user_role: user_role.unwrap(),
+            article_id: article_id.into_inner(),
+            coauthors: coauthors
+                .into_iter()
+                .map(|coauthor| SetArticleCoauthorParams { user_id: coauthor.user_id, role: coauthor.role })
+                .collect(),
         }).await;
 
         if result.is_err() {
             return generate_error_response(result.unwrap_err());
         }
 
-        let mapped_article = ArticlePresenter::to_http(result.unwrap());
+        let mapped_coauthors = result.unwrap().into_iter().map(ArticleCoauthorPresenter::to_http).collect::<Vec<MappedArticleCoauthor>>();
 
-        return HttpResponse::Ok().json(json!({"data": mapped_article}));
+        return HttpResponse::Ok().json(json!({"data": mapped_coauthors}));
+    }
+
+    /// Editorial notes grouped in threads. They are internal to the staff, so they are never part of the article itself.
+    async fn notes(user: web::ReqData<ReqUser>, article_id: web::Path<Uuid>) -> impl Responder {
+        let service = match fetch_article_notes_service_factory::exec().await {
+            Left(service) => service,
+            Right(error) => return error
+        };
+
+        let ReqUser {user_role, user_id, ..} = user.into_inner();
+
+        let result = service.exec(FetchArticleNotesParams {
+            user_id,
+            user_role: user_role.unwrap(),
+            article_id: article_id.into_inner(),
+        }).await;
+
+        if result.is_err() {
+            return generate_error_response(result.unwrap_err());
+        }
+
+        return HttpResponse::Ok().json(json!({"data": ArticleNotePresenter::to_http_threads(result.unwrap())}));
+    }
+
+    async fn create_note(
+        user: web::ReqData<ReqUser>,
+        body: web::Json<CreateArticleNoteDto>,
+        article_id: web::Path<Uuid>
+    ) -> impl Responder {
+        let CreateArticleNoteDto { content, parent_id } = match body.validate() {
+            Err(e) => return HttpResponse::BadRequest().json(ErrorPresenter::to_http_from_validator(e.field_errors())),
+            Ok(()) => body.into_inner()
+        };
+
+        let service = match create_article_note_service_factory::exec().await {
+            Left(service) => service,
+            Right(error) => return error
+        };
+
+        let ReqUser {user_role, user_id, ..} = user.into_inner();
+
+        let result = service.exec(CreateArticleNoteParams {
+            user_id,
+            user_role: user_role.unwrap(),
+            article_id: article_id.into_inner(),
+            parent_id,
+            content,
+        }).await;
+
+        if result.is_err() {
+            return generate_error_response(result.unwrap_err());
+        }
+
+        return HttpResponse::Created().json(json!({"data": ArticleNotePresenter::to_http(result.unwrap())}));
+    }
+
+    async fn resolve_note(
+        user: web::ReqData<ReqUser>,
+        body: web::Json<ResolveArticleNoteDto>,
+        note_id: web::Path<Uuid>
+    ) -> impl Responder {
+        let ResolveArticleNoteDto { resolved } = body.into_inner();
+
+        let service = match resolve_article_note_service_factory::exec().await {
+            Left(service) => service,
+            Right(error) => return error
+        };
+
+        let ReqUser {user_role, user_id, ..} = user.into_inner();
+
+        let result = service.exec(ResolveArticleNoteParams {
+            user_id,
+            user_role: user_role.unwrap(),
+            note_id: note_id.into_inner(),
+            resolved,
+        }).await;
+
+        if result.is_err() {
+            return generate_error_response(result.unwrap_err());
+        }
+
+        return HttpResponse::Ok().json(json!({"data": ArticleNotePresenter::to_http(result.unwrap())}));
+    }
+
+    /// The body is optional, it is only needed to force taking the lock over.
+    async fn acquire_lock(
+        user: web::ReqData<ReqUser>,
+        body: Option<web::Json<AcquireArticleEditLockDto>>,
+        article_id: web::Path<Uuid>
+    ) -> impl Responder {
+        let force = body.and_then(|body| body.into_inner().force).unwrap_or(false);
+
+        let service = match acquire_article_edit_lock_service_factory::exec().await {
+            Left(service) => service,
+            Right(error) => return error
+        };
+
+        let ReqUser {user_role, user_id, ..} = user.into_inner();
+
+        let result = service.exec(AcquireArticleEditLockParams {
+            user_id,
+            user_role: user_role.unwrap(),
+            article_id: article_id.into_inner(),
+            force,
+        }).await;
+
+        if result.is_err() {
+            return generate_error_response(result.unwrap_err());
+        }
+
+        return HttpResponse::Ok().json(json!({"data": ArticleEditLockPresenter::to_http(result.unwrap())}));
+    }
+
+    /// Heartbeat keeping the user's edit lock from expiring while the editor is open.
+    async fn refresh_lock(user: web::ReqData<ReqUser>, article_id: web::Path<Uuid>) -> impl Responder {
+        let service = match refresh_article_edit_lock_service_factory::exec().await {
+            Left(service) => service,
+            Right(error) => return error
+        };
+
+        let ReqUser {user_role, user_id, ..} = user.into_inner();
+
+        let result = service.exec(RefreshArticleEditLockParams {
+            user_id,
+            user_role: user_role.unwrap(),
+            article_id: article_id.into_inner(),
+        }).await;
+
+        if result.is_err() {
+            return generate_error_response(result.unwrap_err());
+        }
+
+        return HttpResponse::Ok().json(json!({"data": ArticleEditLockPresenter::to_http(result.unwrap())}));
+    }
+
+    async fn release_lock(user: web::ReqData<ReqUser>, article_id: web::Path<Uuid>) -> impl Responder {
+        let service = match release_article_edit_lock_service_factory::exec().await {
+            Left(service) => service,
+            Right(error) => return error
+        };
+
+        let result = service.exec(ReleaseArticleEditLockParams {
+            user_id: user.user_id,
+            article_id: article_id.into_inner(),
+        }).await;
+
+        if result.is_err() {
+            return generate_error_response(result.unwrap_err());
+        }
+
+        return HttpResponse::NoContent().finish();
+    }
+
+    /// Toggles the user's reaction off if it is the same as the sent one, otherwise it is switched to the new one.
+    async fn react(
+        req_user: web::ReqData<ReqUser>,
+        body: web::Json<ReactToArticleDto>,
+        article_id: web::Path<Uuid>
+    ) -> impl Responder {
+        let ReactToArticleDto { reaction } = match body.validate() {
+            Err(e) => return HttpResponse::BadRequest().json(ErrorPresenter::to_http_from_validator(e.field_errors())),
+            Ok(()) => body.into_inner()
+        };
+
+        let reaction = match ReactionKind::from_str(&reaction) {
+            Ok(reaction) => reaction,
+            Err(err) => return HttpResponse::BadRequest().json(json!({"error": err.message()}))
+        };
+
+        return Self::set_reaction(req_user.user_id, article_id.into_inner(), Some(reaction)).await;
+    }
+
+    async fn remove_reaction(req_user: web::ReqData<ReqUser>, article_id: web::Path<Uuid>) -> impl Responder {
+        return Self::set_reaction(req_user.user_id, article_id.into_inner(), None).await;
+    }
+
+    async fn set_reaction(user_id: Uuid, article_id: Uuid, reaction: Option<ReactionKind>) -> HttpResponse {
+        let service = match react_to_article_service_factory::exec().await {
+            Left(service) => service,
+            Right(error) => return error
+        };
+
+        let result = service.exec(ReactToArticleParams {
+            user_id,
+            article_id,
+            reaction,
+        }).await;
+
+        if result.is_err() {
+            return generate_error_response(result.unwrap_err());
+        }
+
+        let mapped_reactions = ArticleReactionsPresenter::to_http(result.unwrap());
+
+        return HttpResponse::Ok().json(json!({"data": mapped_reactions}));
     }
 
     async fn delete(req_user: web::ReqData<ReqUser>, article_id: web::Path<Uuid>) -> impl Responder {
@@ -244,30 +799,88 @@ impl ArticlesController {
             return generate_error_response(error);
         }
 
+        SitemapCache::invalidate();
+
         return HttpResponse::NoContent().finish();
     }
 
-    async fn get_list_of_articles(title: Option<String>, author: Option<String>, page: Option<u32>, per_page: Option<u8>, approved_state: Option<bool>) -> HttpResponse {
+    async fn get_list_of_articles(query: AdminListArticlesDto, user_role: Option<Role>) -> HttpResponse {
         let service = match fetch_many_articles_service_factory::exec().await {
             Left(service) => service,
             Right(error) => return error
         };
 
-        let query = {
-            if title.is_some() {
-                Some(ServiceArticleQueryType::Title(title.unwrap()))
-            } else if author.is_some() {
-                Some(ServiceArticleQueryType::Author(author.unwrap()))
-            } else {   
-                None
-            }
+        let AdminListArticlesDto {
+            page,
+            per_page,
+            title,
+            author,
+            tag_id,
+            from,
+            to,
+            min_reading_time,
+            max_reading_time,
+            is_new,
+            sort,
+            approved_state,
+            pagination_mode,
+            cursor
+        } = query;
+
+        let mut queries = Self::article_queries(title, author, tag_id, is_new, min_reading_time, max_reading_time);
+
+        match (from, to) {
+            (Some(from), Some(to)) => queries.push(ServiceArticleQueryType::CreatedBetween(from, to)),
+            (None, None) => (),
+            _ => return generate_error_response(Box::new(BadRequestError::new_with_message(
+                "Both 'from' and 'to' days are required to filter by period.".into()
+            ))),
+        }
+
+        let sort = match sort.unwrap_or(ArticleSortDto::Newest) {
+            ArticleSortDto::Newest => ArticleSortOrder::Newest,
+            ArticleSortDto::Oldest => ArticleSortOrder::Oldest,
+            ArticleSortDto::Shortest => ArticleSortOrder::ShortestReadingTime,
+            ArticleSortDto::Longest => ArticleSortOrder::LongestReadingTime,
         };
 
+        if cursor.is_some() || pagination_mode == Some(PaginationModeDto::Cursor) {
+            if sort != ArticleSortOrder::Newest {
+                return generate_error_response(Box::new(BadRequestError::new_with_message(
+                    "Articles can only be sorted by the newest when paginating by cursor.".into()
+                )));
+            }
+
+            let result = service.exec_by_cursor(FetchManyArticlesByCursorParams {
+                cursor,
+                per_page: per_page.map(|per_page| per_page as u32),
+                queries,
+                approved_state,
+            }).await;
+
+            if result.is_err() {
+                return generate_error_response(result.unwrap_err());
+            }
+
+            let result = result.unwrap();
+
+            let mapped_articles = match Self::map_articles(result.data, user_role.as_ref()).await {
+                Ok(mapped_articles) => mapped_articles,
+                Err(error) => return error
+            };
+
+            return HttpResponse::Ok().json(json!({
+                "pagination": PaginationPresenter::to_http_cursor(result.pagination, per_page.unwrap_or(DEFAULT_PER_PAGE)),
+                "data": mapped_articles
+            }));
+        }
+
         let result = service.exec(FetchManyArticlesParams {
             page,
             per_page: if per_page.is_some() { Some(per_page.unwrap() as u32) } else { None },
-            query,
+            queries,
             approved_state,
+            sort,
         }).await;
 
         if result.is_err() {
@@ -276,11 +889,88 @@ impl ArticlesController {
 
         let result = result.unwrap();
 
-        let mapped_articles = result.data.into_iter().map(ArticlePresenter::to_http).collect::<Vec<MappedArticle>>();
+        let mapped_articles = match Self::map_articles(result.data, user_role.as_ref()).await {
+            Ok(mapped_articles) => mapped_articles,
+            Err(error) => return error
+        };
 
         return HttpResponse::Ok().json(json!({
             "pagination": PaginationPresenter::to_http(result.pagination, per_page.unwrap_or(DEFAULT_PER_PAGE)),
             "data": mapped_articles
         }));
     }
+
+    /**
+     The reader's IP. Forwarding headers are set by the client as it pleases, so they are only read when the
+     connection comes from one of the `TRUSTED_PROXIES`, otherwise the connection's own address is used.
+     */
+    fn viewer_ip(req: &HttpRequest) -> String {
+        let peer_ip = match req.peer_addr() {
+            Some(peer_addr) => peer_addr.ip(),
+            None => return String::new(),
+        };
+
+        let peer_is_trusted_proxy = ENV_VARS.trusted_proxies
+            .as_deref()
+            .unwrap_or_default()
+            .split(',')
+            .filter_map(|proxy| IpAddr::from_str(proxy.trim()).ok())
+            .any(|proxy| proxy == peer_ip);
+
+        if !peer_is_trusted_proxy {
+            return peer_ip.to_string();
+        }
+
+        req.connection_info().realip_remote_addr().unwrap_or_default().to_owned()
+    }
+
+    /// The filters of the article listings, all of them applied at once.
+    fn article_queries(
+        title: Option<String>,
+        author: Option<String>,
+        tag_id: Option<i32>,
+        is_new: Option<bool>,
+        min_reading_time: Option<u32>,
+        max_reading_time: Option<u32>,
+    ) -> Vec<ServiceArticleQueryType> {
+        let mut queries = vec![];
+
+        if let Some(title) = title { queries.push(ServiceArticleQueryType::Title(title)); }
+        if let Some(author) = author { queries.push(ServiceArticleQueryType::Author(author)); }
+        if let Some(tag_id) = tag_id { queries.push(ServiceArticleQueryType::Tag(tag_id)); }
+        if is_new == Some(true) { queries.push(ServiceArticleQueryType::New); }
+        if min_reading_time.is_some() || max_reading_time.is_some() {
+            queries.push(ServiceArticleQueryType::ReadingTime(min_reading_time, max_reading_time));
+        }
+
+        queries
+    }
+
+    /// Maps the articles of a listing, including their views count if the user is a staff member.
+    async fn map_articles(articles: Vec<ListedArticle>, user_role: Option<&Role>) -> Result<Vec<MappedArticle>, HttpResponse> {
+        let service = match count_articles_views_service_factory::exec().await {
+            Left(service) => service,
+            Right(error) => return Err(error)
+        };
+
+        let views = service.exec(CountArticlesViewsParams {
+            user_role,
+            article_ids: articles.iter().map(|listed_article| listed_article.article().id()).collect(),
+        }).await;
+
+        let views = match views {
+            Ok(views) => views,
+            Err(error) => return Err(generate_error_response(error))
+        };
+
+        let mapped_articles = match views {
+            None => articles.into_iter().map(ArticlePresenter::to_http_from_listed).collect(),
+            Some(views) => articles.into_iter().map(|listed_article| {
+                let article_views = views.get(&listed_article.article().id()).copied().unwrap_or(0);
+                ArticlePresenter::to_http_with_views(listed_article, article_views)
+            }).collect()
+        };
+
+        Ok(mapped_articles)
+    }
 }
@@ -1,10 +1,12 @@
 use actix_web::{web, HttpResponse, Responder};
 use actix_web_lab::middleware::from_fn;
 use either::{Left, Right};
+use std::str::FromStr;
 use serde_json::json;
 use uuid::Uuid;
 use validator::Validate;
 use crate::core::pagination::DEFAULT_PER_PAGE;
+use crate::domain::domain_entities::comment_report::ReportReason;
 use crate::domain::factories::{
     create_comment_report_service_factory,
     delete_comment_report_service_factory,
@@ -13,10 +15,11 @@ use crate::domain::factories::{
 };
 use crate::domain::services::create_comment_report_service::CreateCommentReportParams;
 use crate::domain::services::delete_comment_report_service::DeleteCommentReportParams;
-use crate::domain::services::fetch_many_comment_reports_service::{CommentReportServiceQuery, FetchManyCommentReportsParams};
+use crate::domain::services::fetch_many_comment_reports_service::{CommentReportServiceQuery, FetchManyCommentReportsByCursorParams, FetchManyCommentReportsParams};
 use crate::domain::services::solve_comment_report_service::SolveCommentReportParams;
 use crate::infra::http::dtos::create_comment_report::CreateCommentReportDto;
 use crate::infra::http::dtos::list_comment_reports::ListCommentReportsDto;
+use crate::infra::http::dtos::simple_pagination_query::PaginationModeDto;
 use crate::infra::http::extractors::req_user::ReqUser;
 use crate::infra::http::middlewares::authentication_middleware;
 use crate::infra::http::presenters::comment_report::{CommentReportPresenter, MappedCommentReport};
@@ -58,6 +61,7 @@ impl CommentReportsController {
         };
 
         let body = body.into_inner();
+        let reason = ReportReason::from_str(&body.reason).unwrap();
 
         let service = match create_comment_report_service_factory::exec().await {
             Left(service) => service,
@@ -66,6 +70,7 @@ impl CommentReportsController {
 
         let result = service.exec(CreateCommentReportParams {
             user_id: user.user_id,
+            reason,
             content: body.content,
             comment_id: comment_id.into_inner(),
         }).await;
@@ -91,7 +96,10 @@ impl CommentReportsController {
             page,
             solved,
             solved_by,
-            content
+            content,
+            reason,
+            pagination_mode,
+            cursor
         } = query.into_inner();
 
         let query = {
@@ -101,11 +109,36 @@ impl CommentReportsController {
                 Some(CommentReportServiceQuery::Solved(solved.unwrap()))
             } else if content.is_some() {
                 Some(CommentReportServiceQuery::Content(content.unwrap()))
+            } else if reason.is_some() {
+                match ReportReason::from_str(&reason.unwrap()) {
+                    Ok(reason) => Some(CommentReportServiceQuery::Reason(reason)),
+                    Err(err) => return HttpResponse::BadRequest().json(json!({"error": err.message()})),
+                }
             } else {
                 None
             }
         };
 
+        if cursor.is_some() || pagination_mode == Some(PaginationModeDto::Cursor) {
+            let result = service.exec_by_cursor(FetchManyCommentReportsByCursorParams {
+                query,
+                per_page: per_page.map(|per_page| per_page as u32),
+                cursor
+            }).await;
+
+            if result.is_err() {
+                return generate_error_response(result.unwrap_err());
+            }
+
+            let result = result.unwrap();
+            let mapped_reports = result.data.into_iter().map(CommentReportPresenter::to_http).collect::<Vec<MappedCommentReport>>();
+
+            return HttpResponse::Ok().json(json!({
+                "pagination": PaginationPresenter::to_http_cursor(result.pagination, per_page.unwrap_or(DEFAULT_PER_PAGE)),
+                "data": mapped_reports
+            }));
+        }
+
         let result = service.exec(FetchManyCommentReportsParams {
             query,
             per_page: if per_page.is_some() { Some(per_page.unwrap() as u32) } else { None },
@@ -3,23 +3,37 @@ use actix_web_lab::middleware::from_fn;
 use either::{Right, Left};
 use serde_json::json;
 use uuid::Uuid;
+use validator::Validate;
 use crate::core::pagination::DEFAULT_PER_PAGE;
 use super::controller::ControllerTrait;
-use crate::domain::factories::{comment_on_article_service_factory, delete_comment_service_factory, fetch_many_comments_with_author_service_factory};
+use crate::domain::factories::{comment_on_article_service_factory, delete_comment_service_factory, edit_comment_service_factory, fetch_comment_replies_service_factory, fetch_comment_revisions_service_factory, fetch_many_comments_with_author_service_factory, fetch_weekly_top_comment_service_factory, like_comment_service_factory, pin_comment_service_factory};
 use crate::domain::factories::fetch_many_comments_service_factory;
 use crate::domain::factories::toggle_comment_visibility_service_factory;
 use crate::domain::services::comment_on_article_service::CommentOnArticleParams;
 use crate::domain::services::delete_comment_service::DeleteCommentParams;
+use crate::domain::services::edit_comment_service::EditCommentParams;
+use crate::domain::services::fetch_comment_replies_service::FetchCommentRepliesParams;
+use crate::domain::services::fetch_comment_revisions_service::FetchCommentRevisionsParams;
 use crate::domain::services::fetch_many_comments_service::{FetchManyCommentsParams, ServiceCommentQueryType};
-use crate::domain::services::fetch_many_comments_with_author_service::FetchManyArticleCommentsWithAuthorParams;
+use crate::domain::domain_entities::slug::Slug;
+use crate::domain::repositories::comment_user_article_repository::CommentSortOrder;
+use crate::domain::services::fetch_many_comments_with_author_service::{FetchManyArticleCommentsByCursorParams, FetchManyArticleCommentsWithAuthorParams};
+use crate::domain::services::fetch_weekly_top_comment_service::FetchWeeklyTopCommentParams;
+use crate::domain::services::like_comment_service::LikeCommentParams;
+use crate::domain::services::pin_comment_service::PinCommentParams;
 use crate::domain::services::toggle_comment_visibility_service::ToggleCommentVisibilityParams;
 use crate::infra::http::dtos::comment_on_article::CommentOnArticleDto;
+use crate::infra::http::dtos::edit_comment::EditCommentDto;
+use crate::infra::http::dtos::list_article_comments::ListArticleCommentsDto;
 use crate::infra::http::dtos::list_comments::ListCommentsDto;
+use crate::infra::http::dtos::pin_comment::PinCommentDto;
 use crate::infra::http::dtos::simple_pagination_query::SimplePaginationQueryDto;
 use crate::infra::http::extractors::req_user::ReqUser;
 use crate::infra::http::middlewares::authentication_middleware;
 use crate::infra::http::presenters::presenter::PresenterTrait;
 use crate::infra::http::presenters::comment::{CommentPresenter, MappedComment, MappedRawComment};
+use crate::infra::http::presenters::comment_revision::{CommentRevisionPresenter, MappedCommentRevision};
+use crate::infra::http::presenters::error::ErrorPresenter;
 use crate::infra::http::presenters::pagination::PaginationPresenter;
 use crate::util::generate_error_response;
 
@@ -32,8 +46,24 @@ impl ControllerTrait for CommentsController {
             .route("/{article_id}/new", web::post().to(Self::create).wrap(from_fn(authentication_middleware)))
             // Get an article's comments with author list
             .route("/{article_id}/list", web::get().to(Self::list))
+            // Get an article's comments with author list, by the article's slug and paginated by cursor
+            .route("/article/{slug}/list", web::get().to(Self::list_by_slug))
+            // Get the comment that got the most likes this week
+            .route("/top/weekly", web::get().to(Self::weekly_top))
+            // Get a comment's replies with author list
+            .route("/{id}/replies", web::get().to(Self::list_replies))
             // Get a comments list
             .route("/list/admin", web::get().to(Self::admin_list).wrap(from_fn(authentication_middleware)))
+            // Edit own comment
+            .route("/{id}/edit", web::patch().to(Self::edit).wrap(from_fn(authentication_middleware)))
+            // Get a comment's edit history
+            .route("/{id}/revisions", web::get().to(Self::list_revisions).wrap(from_fn(authentication_middleware)))
+            // Pin or unpin a comment on top of its article's comments
+            .route("/{id}/pin", web::put().to(Self::pin).wrap(from_fn(authentication_middleware)))
+            // Like a comment
+            .route("/{id}/like", web::put().to(Self::like).wrap(from_fn(authentication_middleware)))
+            // Remove the like from a comment
+            .route("/{id}/like", web::delete().to(Self::unlike).wrap(from_fn(authentication_middleware)))
             // Deactivate comment visibility
             .route("/{id}/deactivate", web::patch().to(Self::disable_visibility).wrap(from_fn(authentication_middleware)))
             // Definitely delete a comment
@@ -53,10 +83,13 @@ impl CommentsController {
             Right(error) => return error
         };
 
+        let CommentOnArticleDto { content, parent_id } = body.into_inner();
+
         let result = service.exec(CommentOnArticleParams {
             author_id: user.user_id,
-            content: body.into_inner().content,
-            article_id: article_id.into_inner()
+            content,
+            article_id: article_id.into_inner(),
+            parent_id,
         }).await;
 
         if result.is_err() {
@@ -64,12 +97,19 @@ impl CommentsController {
             return generate_error_response(error);
         }
 
+        if !result.unwrap().is_active() {
+            return HttpResponse::Accepted().json(json!({
+                "message": "The comment will be published once a moderator reviews it."
+            }));
+        }
+
         return HttpResponse::Created().finish();
     }
 
     async fn list(
         article_id: web::Path<Uuid>,
-        query: web::Query<SimplePaginationQueryDto>
+        query: web::Query<SimplePaginationQueryDto>,
+        user: Option<web::ReqData<ReqUser>>
     ) -> impl Responder {
         let service = match fetch_many_comments_with_author_service_factory::exec().await {
             Left(service) => service,
@@ -84,6 +124,8 @@ impl CommentsController {
         let result = service.exec(article_id.into_inner(), FetchManyArticleCommentsWithAuthorParams {
             page,
             per_page: if per_page.is_some() { Some(per_page.unwrap() as u32) } else { None },
+            sort: CommentSortOrder::Newest,
+            user_id: user.map(|user| user.user_id),
         }).await;
 
         if result.is_err() {
@@ -99,6 +141,77 @@ impl CommentsController {
         }));
     }
 
+    async fn list_by_slug(
+        article_slug: web::Path<String>,
+        query: web::Query<ListArticleCommentsDto>,
+        user: Option<web::ReqData<ReqUser>>
+    ) -> impl Responder {
+        let ListArticleCommentsDto { per_page, cursor, sort } = match query.validate() {
+            Ok(()) => query.into_inner(),
+            Err(err) => return HttpResponse::BadRequest().json(ErrorPresenter::to_http_from_validator(err.field_errors())),
+        };
+
+        let service = match fetch_many_comments_with_author_service_factory::exec().await {
+            Left(service) => service,
+            Right(error) => return error
+        };
+
+        let result = service.exec_by_cursor(FetchManyArticleCommentsByCursorParams {
+            article_slug: Slug::new_from_existing(article_slug.into_inner()),
+            cursor,
+            per_page: per_page.map(|per_page| per_page as u32),
+            sort: sort.map(Into::into).unwrap_or_default(),
+            user_id: user.map(|user| user.user_id),
+        }).await;
+
+        if result.is_err() {
+            return generate_error_response(result.unwrap_err());
+        }
+
+        let result = result.unwrap();
+        let mapped_comments: Vec<MappedComment> = result.data.into_iter().map(CommentPresenter::to_http).collect();
+
+        return HttpResponse::Ok().json(json!({
+            "pagination": PaginationPresenter::to_http_cursor(result.pagination, per_page.unwrap_or(DEFAULT_PER_PAGE)),
+            "data": mapped_comments
+        }));
+    }
+
+    async fn list_replies(
+        comment_id: web::Path<Uuid>,
+        query: web::Query<SimplePaginationQueryDto>,
+        user: Option<web::ReqData<ReqUser>>
+    ) -> impl Responder {
+        let service = match fetch_comment_replies_service_factory::exec().await {
+            Left(service) => service,
+            Right(error) => return error
+        };
+
+        let SimplePaginationQueryDto {
+            per_page,
+            page
+        } = query.into_inner();
+
+        let result = service.exec(FetchCommentRepliesParams {
+            comment_id: comment_id.into_inner(),
+            page,
+            per_page: per_page.map(|per_page| per_page as u32),
+            user_id: user.map(|user| user.user_id),
+        }).await;
+
+        if result.is_err() {
+            return generate_error_response(result.unwrap_err());
+        }
+
+        let result = result.unwrap();
+        let mapped_replies: Vec<MappedComment> = result.data.into_iter().map(CommentPresenter::to_http).collect();
+
+        return HttpResponse::Ok().json(json!({
+            "pagination": PaginationPresenter::to_http(result.pagination, per_page.unwrap_or(DEFAULT_PER_PAGE)),
+            "data": mapped_replies
+        }));
+    }
+
     async fn admin_list(
         query: web::Query<ListCommentsDto>
     ) -> impl Responder {
@@ -147,6 +260,133 @@ impl CommentsController {
         }));
     }
 
+    async fn edit(
+        comment_id: web::Path<Uuid>,
+        user: web::ReqData<ReqUser>,
+        body: web::Json<EditCommentDto>
+    ) -> impl Responder {
+        match body.validate() {
+            Ok(()) => (),
+            Err(error) => return HttpResponse::BadRequest().json(ErrorPresenter::to_http_from_validator(error.field_errors())),
+        };
+
+        let service = match edit_comment_service_factory::exec().await {
+            Left(service) => service,
+            Right(error) => return error
+        };
+
+        let result = service.exec(EditCommentParams {
+            user_id: user.user_id,
+            comment_id: comment_id.into_inner(),
+            content: body.into_inner().content,
+        }).await;
+
+        if result.is_err() {
+            return generate_error_response(result.unwrap_err());
+        }
+
+        return HttpResponse::Ok().json(CommentPresenter::to_http_raw(result.unwrap()));
+    }
+
+    async fn list_revisions(
+        comment_id: web::Path<Uuid>,
+        user: web::ReqData<ReqUser>
+    ) -> impl Responder {
+        let service = match fetch_comment_revisions_service_factory::exec().await {
+            Left(service) => service,
+            Right(error) => return error
+        };
+
+        let result = service.exec(FetchCommentRevisionsParams {
+            user_role: user.into_inner().user_role.unwrap(),
+            comment_id: comment_id.into_inner(),
+        }).await;
+
+        if result.is_err() {
+            return generate_error_response(result.unwrap_err());
+        }
+
+        let result = result.unwrap();
+        let mapped_revisions: Vec<MappedCommentRevision> = result.revisions.into_iter().map(CommentRevisionPresenter::to_http).collect();
+
+        return HttpResponse::Ok().json(json!({
+            "comment": CommentPresenter::to_http_raw(result.comment),
+            "revisions": mapped_revisions
+        }));
+    }
+
+    async fn weekly_top(user: Option<web::ReqData<ReqUser>>) -> impl Responder {
+        let service = match fetch_weekly_top_comment_service_factory::exec().await {
+            Left(service) => service,
+            Right(error) => return error
+        };
+
+        let result = service.exec(FetchWeeklyTopCommentParams {
+            user_id: user.map(|user| user.user_id),
+        }).await;
+
+        if result.is_err() {
+            return generate_error_response(result.unwrap_err());
+        }
+
+        return HttpResponse::Ok().json(json!({
+            "data": result.unwrap().map(CommentPresenter::to_http)
+        }));
+    }
+
+    async fn like(user: web::ReqData<ReqUser>, comment_id: web::Path<Uuid>) -> impl Responder {
+        return Self::set_like(user.user_id, comment_id.into_inner(), true).await;
+    }
+
+    async fn unlike(user: web::ReqData<ReqUser>, comment_id: web::Path<Uuid>) -> impl Responder {
+        return Self::set_like(user.user_id, comment_id.into_inner(), false).await;
+    }
+
+    async fn set_like(user_id: Uuid, comment_id: Uuid, liked: bool) -> HttpResponse {
+        let service = match like_comment_service_factory::exec().await {
+            Left(service) => service,
+            Right(error) => return error
+        };
+
+        let result = service.exec(LikeCommentParams {
+            user_id,
+            comment_id,
+            liked,
+        }).await;
+
+        if result.is_err() {
+            return generate_error_response(result.unwrap_err());
+        }
+
+        return HttpResponse::Ok().json(json!({"data": CommentPresenter::to_http_likes(result.unwrap())}));
+    }
+
+    async fn pin(
+        comment_id: web::Path<Uuid>,
+        user: web::ReqData<ReqUser>,
+        body: web::Json<PinCommentDto>
+    ) -> impl Responder {
+        let service = match pin_comment_service_factory::exec().await {
+            Left(service) => service,
+            Right(error) => return error
+        };
+
+        let ReqUser {user_role, user_id, exp: _} = user.into_inner();
+
+        let result = service.exec(PinCommentParams {
+            user_id,
+            user_role: user_role.as_ref().unwrap(),
+            comment_id: comment_id.into_inner(),
+            pinned: body.into_inner().pinned,
+        }).await;
+
+        if result.is_err() {
+            return generate_error_response(result.unwrap_err());
+        }
+
+        return HttpResponse::Ok().json(CommentPresenter::to_http_raw(result.unwrap()));
+    }
+
     async fn disable_visibility(
         user: web::ReqData<ReqUser>,
         comment_id: web::Path<Uuid>
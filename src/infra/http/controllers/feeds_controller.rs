@@ -0,0 +1,129 @@
+use actix_web::http::header;
+use actix_web::{web, HttpRequest, HttpResponse, Responder};
+use either::Either::*;
+use validator::Validate;
+
+use crate::domain::domain_entities::article::Article;
+use crate::domain::domain_entities::listed_article::ListedArticle;
+use crate::domain::factories::fetch_many_articles_service_factory;
+use crate::domain::repositories::article_repository::ArticleSortOrder;
+use crate::domain::services::fetch_many_articles_service::{FetchManyArticlesParams, ServiceArticleQueryType};
+use crate::infra::http::dtos::feed_query::FeedQueryDto;
+use crate::infra::http::etag::ETag;
+use crate::infra::http::presenters::error::ErrorPresenter;
+use crate::infra::http::presenters::feed::{FeedChannel, FeedPresenter};
+use crate::infra::http::site_links::SiteLinks;
+use crate::util::generate_error_response;
+use crate::ENV_VARS;
+
+use super::controller::ControllerTrait;
+
+const ARTICLES_PER_FEED: u32 = 20;
+
+#[derive(Clone, Copy)]
+enum FeedFormat {
+    Rss,
+    Atom,
+}
+
+impl FeedFormat {
+    fn path(&self) -> &'static str {
+        match self {
+            FeedFormat::Rss => "feed.rss",
+            FeedFormat::Atom => "feed.atom",
+        }
+    }
+
+    fn content_type(&self) -> &'static str {
+        match self {
+            FeedFormat::Rss => "application/rss+xml; charset=utf-8",
+            FeedFormat::Atom => "application/atom+xml; charset=utf-8",
+        }
+    }
+}
+
+pub struct FeedsController;
+
+impl ControllerTrait for FeedsController {
+    fn register(cfg: &mut web::ServiceConfig) {
+        cfg
+            .route("/feed.rss", web::get().to(Self::rss))
+            .route("/feed.atom", web::get().to(Self::atom));
+    }
+}
+
+impl FeedsController {
+    async fn rss(req: HttpRequest, query: web::Query<FeedQueryDto>) -> impl Responder {
+        return Self::render(req, query, FeedFormat::Rss).await;
+    }
+
+    async fn atom(req: HttpRequest, query: web::Query<FeedQueryDto>) -> impl Responder {
+        return Self::render(req, query, FeedFormat::Atom).await;
+    }
+
+    async fn render(req: HttpRequest, query: web::Query<FeedQueryDto>, format: FeedFormat) -> HttpResponse {
+        let FeedQueryDto { tag } = match query.validate() {
+            Ok(()) => query.into_inner(),
+            Err(err) => return HttpResponse::BadRequest().json(ErrorPresenter::to_http_from_validator(err.field_errors())),
+        };
+
+        let service = match fetch_many_articles_service_factory::exec().await {
+            Left(service) => service,
+            Right(error) => return error
+        };
+
+        let result = service.exec(FetchManyArticlesParams {
+            page: Some(1),
+            per_page: Some(ARTICLES_PER_FEED),
+            queries: tag.map(ServiceArticleQueryType::Tag).into_iter().collect(),
+            approved_state: Some(true),
+            sort: ArticleSortOrder::Newest,
+        }).await;
+
+        if result.is_err() {
+            return generate_error_response(result.unwrap_err());
+        }
+
+        let articles = result.unwrap().data.into_iter().map(ListedArticle::into_article).collect::<Vec<Article>>();
+
+        let (title, self_link) = match tag {
+            None => (
+                format!("{} — Notícias", ENV_VARS.domain),
+                SiteLinks::absolute(format.path())
+            ),
+            Some(tag_id) => (
+                format!(
+                    "{} — {}",
+                    ENV_VARS.domain,
+                    articles.first().and_then(|article| article.tag_value()).unwrap_or_else(|| "Notícias".into())
+                ),
+                SiteLinks::absolute(&format!("{}?tag={}", format.path(), tag_id))
+            ),
+        };
+
+        let channel = FeedChannel {
+            title,
+            description: format!("Últimas notícias publicadas em {}.", ENV_VARS.domain),
+            self_link,
+        };
+
+        let feed = match format {
+            FeedFormat::Rss => FeedPresenter::to_rss(&channel, &articles),
+            FeedFormat::Atom => FeedPresenter::to_atom(&channel, &articles),
+        };
+
+        let etag = ETag::from_content(&feed);
+
+        if ETag::matches_if_none_match(&req, &etag) {
+            return HttpResponse::NotModified()
+                .insert_header((header::ETAG, etag))
+                .finish();
+        }
+
+        return HttpResponse::Ok()
+            .content_type(format.content_type())
+            .insert_header((header::ETAG, etag))
+            .insert_header((header::CACHE_CONTROL, "public, max-age=300"))
+            .body(feed);
+    }
+}
@@ -1,3 +1,4 @@
+use actix_web::http::header;
 use actix_web::{web, HttpResponse, Responder};
 use actix_web_lab::middleware::from_fn;
 use either::{Left, Right};
@@ -18,6 +19,7 @@ use crate::domain::services::update_free_badge_service::UpdateFreeBadgeParams;
 use crate::infra::http::dtos::create_free_badge::CreateFreeBadgeDto;
 use crate::infra::http::dtos::simple_pagination_query::SimplePaginationQueryDto;
 use crate::infra::http::dtos::update_free_badge::UpdateFreeBadgeDto;
+use crate::infra::http::extractors::if_match::IfMatch;
 use crate::infra::http::extractors::req_user::ReqUser;
 use crate::infra::http::middlewares::authentication_middleware;
 use crate::infra::http::presenters::error::ErrorPresenter;
@@ -106,7 +108,8 @@ impl FreeBadgesController {
     async fn update(
         user: web::ReqData<ReqUser>,
         body: web::Json<UpdateFreeBadgeDto>,
-        free_badge_id: web::Path<Uuid>
+        free_badge_id: web::Path<Uuid>,
+        IfMatch(expected_version): IfMatch
     ) -> impl Responder {
         match body.validate() {
             Err(error) => return HttpResponse::BadRequest().json(ErrorPresenter::to_http_from_validator(error.field_errors())),
@@ -127,16 +130,19 @@ impl FreeBadgesController {
             link: body.link,
             link_is_external: body.link_is_external,
             available_until: body.available_until,
-            free_badge_id: free_badge_id.into_inner()
+            free_badge_id: free_badge_id.into_inner(),
+            expected_version
         }).await;
 
         if result.is_err() {
             return generate_error_response(result.unwrap_err());
         }
 
-        let mapped_free_badge = FreeBadgePresenter::to_http(result.unwrap());
+        let free_badge = result.unwrap();
+        let etag = IfMatch::etag(free_badge.version());
+        let mapped_free_badge = FreeBadgePresenter::to_http(free_badge);
 
-        return HttpResponse::Ok().json(JsonWrappedEntity {
+        return HttpResponse::Ok().insert_header((header::ETAG, etag)).json(JsonWrappedEntity {
             data: mapped_free_badge
         });
     }
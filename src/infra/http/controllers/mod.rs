@@ -8,3 +8,6 @@ pub mod team_roles_controller;
 pub mod team_users_controller;
 pub mod article_tags_controller;
 pub mod free_badges_controller;
+pub mod feeds_controller;
+pub mod sitemaps_controller;
+pub mod word_filters_controller;
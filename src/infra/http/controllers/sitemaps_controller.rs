@@ -0,0 +1,81 @@
+use std::sync::Arc;
+
+use actix_web::http::header;
+use actix_web::{web, HttpRequest, HttpResponse, Responder};
+use either::Either::*;
+
+use crate::domain::factories::fetch_sitemap_entries_service_factory;
+use crate::infra::http::etag::ETag;
+use crate::infra::http::presenters::sitemap::{SitemapDocuments, SitemapPresenter};
+use crate::infra::http::sitemap_cache::SitemapCache;
+use crate::util::generate_error_response;
+
+use super::controller::ControllerTrait;
+
+pub struct SitemapsController;
+
+impl ControllerTrait for SitemapsController {
+    fn register(cfg: &mut web::ServiceConfig) {
+        cfg
+            .route("/sitemap.xml", web::get().to(Self::root))
+            .route("/sitemap-{page}.xml", web::get().to(Self::page));
+    }
+}
+
+impl SitemapsController {
+    async fn root(req: HttpRequest) -> impl Responder {
+        let documents = match Self::get_documents().await {
+            Ok(documents) => documents,
+            Err(error) => return error
+        };
+
+        return Self::xml_response(&req, documents.root());
+    }
+
+    async fn page(req: HttpRequest, page: web::Path<usize>) -> impl Responder {
+        let documents = match Self::get_documents().await {
+            Ok(documents) => documents,
+            Err(error) => return error
+        };
+
+        return match documents.page(page.into_inner()) {
+            Some(sitemap) => Self::xml_response(&req, sitemap),
+            None => HttpResponse::NotFound().finish(),
+        };
+    }
+
+    async fn get_documents() -> Result<Arc<SitemapDocuments>, HttpResponse> {
+        if let Some(documents) = SitemapCache::get() {
+            return Ok(documents);
+        }
+
+        let generation = SitemapCache::generation();
+
+        let service = match fetch_sitemap_entries_service_factory::exec().await {
+            Left(service) => service,
+            Right(error) => return Err(error)
+        };
+
+        let entries = match service.exec().await {
+            Ok(entries) => entries,
+            Err(error) => return Err(generate_error_response(error))
+        };
+
+        Ok(SitemapCache::set(SitemapPresenter::to_documents(entries), generation))
+    }
+
+    fn xml_response(req: &HttpRequest, document: &str) -> HttpResponse {
+        let etag = ETag::from_content(document);
+
+        if ETag::matches_if_none_match(req, &etag) {
+            return HttpResponse::NotModified()
+                .insert_header((header::ETAG, etag))
+                .finish();
+        }
+
+        return HttpResponse::Ok()
+            .content_type("application/xml; charset=utf-8")
+            .insert_header((header::ETAG, etag))
+            .body(document.to_owned());
+    }
+}
@@ -1,3 +1,4 @@
+use actix_web::http::header;
 use actix_web::{web, HttpResponse, Responder};
 use actix_web_lab::middleware::from_fn;
 use either::{Left, Right};
@@ -19,6 +20,7 @@ use crate::domain::services::update_team_user_service::UpdateTeamUserParams;
 use crate::infra::http::dtos::create_team_user::CreateTeamUserDto;
 use crate::infra::http::dtos::list_team_user::ListTeamUsersDto;
 use crate::infra::http::dtos::update_team_user::UpdateTeamUserDto;
+use crate::infra::http::extractors::if_match::IfMatch;
 use crate::infra::http::extractors::req_user::ReqUser;
 use crate::infra::http::middlewares::authentication_middleware;
 use crate::infra::http::presenters::error::ErrorPresenter;
@@ -128,7 +130,8 @@ impl TeamUsersController {
     async fn update(
         body: web::Json<UpdateTeamUserDto>,
         user: web::ReqData<ReqUser>,
-        team_user_id: web::Path<Uuid>
+        team_user_id: web::Path<Uuid>,
+        IfMatch(expected_version): IfMatch
     ) -> impl Responder {
         match body.validate() {
             Err(e) => return HttpResponse::BadRequest().json(ErrorPresenter::to_http_from_validator(e.field_errors())),
@@ -149,7 +152,8 @@ impl TeamUsersController {
             discord: body.discord,
             user_function: body.user_function,
             staff_role: user.into_inner().user_role.unwrap(),
-            team_user_id: team_user_id.into_inner()
+            team_user_id: team_user_id.into_inner(),
+            expected_version
         }).await;
 
         if result.is_err() {
@@ -157,9 +161,10 @@ impl TeamUsersController {
         }
 
         let team_user = result.unwrap();
+        let etag = IfMatch::etag(team_user.version());
         let mapped_team_user = TeamUserPresenter::to_http(team_user);
 
-        return HttpResponse::Ok().json(JsonWrappedEntity {
+        return HttpResponse::Ok().insert_header((header::ETAG, etag)).json(JsonWrappedEntity {
             data: mapped_team_user
         });
     }
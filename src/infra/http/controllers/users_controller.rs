@@ -1,6 +1,6 @@
 use std::str::FromStr;
 
-use actix_web::http::StatusCode;
+use actix_web::http::{header, StatusCode};
 use actix_web::{web, HttpResponse, HttpResponseBuilder, Responder};
 use actix_web_lab::middleware::from_fn;
 use serde_json::json;
@@ -10,20 +10,24 @@ use either::Either::*;
 
 use crate::core::pagination::DEFAULT_PER_PAGE;
 use crate::domain::domain_entities::role::Role;
-use crate::domain::factories::{change_password_service_factory, create_user_service_factory, fetch_many_users_service_factory, get_user_service_factory, update_user_service_factory};
+use crate::domain::factories::{change_password_service_factory, create_user_service_factory, fetch_many_users_service_factory, fetch_user_mentions_service_factory, get_user_service_factory, update_user_service_factory};
 use crate::domain::repositories::user_repository::UserQueryType;
 use crate::domain::services::change_password_service::ChangePasswordParams;
 use crate::domain::services::create_user_service::CreateUserParams;
-use crate::domain::services::fetch_many_users_service::FetchManyUsersParams;
+use crate::domain::services::fetch_many_users_service::{FetchManyUsersByCursorParams, FetchManyUsersParams};
+use crate::domain::services::fetch_user_mentions_service::{FetchUserMentionsByCursorParams, FetchUserMentionsParams};
 use crate::domain::services::get_user_service::GetUserServiceParams;
 use crate::domain::services::update_user_service::UpdateUserParams;
 use crate::infra::http::dtos::change_password::ChangePasswordDto;
 use crate::infra::http::dtos::create_user::CreateUserDto;
 use crate::infra::http::dtos::list_users::ListUsersDto;
+use crate::infra::http::dtos::simple_pagination_query::{CursorPaginationQueryDto, PaginationModeDto};
 use crate::infra::http::dtos::update_user::UpdateUserDto;
+use crate::infra::http::extractors::if_match::IfMatch;
 use crate::infra::http::extractors::req_user::ReqUser;
 use crate::infra::http::middlewares::authentication_middleware;
 use crate::infra::http::presenters::error::ErrorPresenter;
+use crate::infra::http::presenters::mention::{MappedMention, MentionPresenter};
 use crate::infra::http::presenters::pagination::PaginationPresenter;
 use crate::infra::http::presenters::presenter::PresenterTrait;
 use crate::infra::http::presenters::user::UserPresenter;
@@ -62,6 +66,14 @@ impl ControllerTrait for UsersController {
                 .wrap(from_fn(authentication_middleware))
             )
 
+            // LIST MENTIONS OF THE AUTHENTICATED USER
+            .route(
+                "/mentions",
+                web::get()
+                .to(Self::list_mentions)
+                .wrap(from_fn(authentication_middleware))
+            )
+
             // GET SINGLE USER BY ID
             .route(
                 "/{id}",
@@ -111,7 +123,8 @@ impl UsersController {
     async fn update(
         body: web::Json<UpdateUserDto>,
         user_id: web::Path<Uuid>,
-        user: web::ReqData<ReqUser>
+        user: web::ReqData<ReqUser>,
+        IfMatch(expected_version): IfMatch
     ) -> impl Responder {
         match body.validate() {
             Err(e) => {
@@ -162,7 +175,8 @@ impl UsersController {
                 password,
                 role,
                 staff_id,
-                staff_role: staff_role.unwrap()
+                staff_role: staff_role.unwrap(),
+                expected_version
             }).await;
 
         if result.is_err() {
@@ -173,9 +187,12 @@ impl UsersController {
         }
 
         let user = result.unwrap();
+        let etag = IfMatch::etag(user.version());
         let mapped_user = UserPresenter::to_http(user);
 
-        return HttpResponse::Ok().json(json!({"user": mapped_user}));
+        return HttpResponse::Ok()
+            .insert_header((header::ETAG, etag))
+            .json(json!({"user": mapped_user}));
     }
 
     async fn edit_password(
@@ -230,16 +247,17 @@ impl UsersController {
             .json(ErrorPresenter::to_http(err));
         }
 
-        let mapped_user = {
-            let user = result.unwrap();
+        let user = result.unwrap();
 
-            match user {
-                None => None,
-                Some(user) => Some(UserPresenter::to_http(user))
-            }
-        };
+        let mut response = HttpResponse::Ok();
+
+        if let Some(user) = user.as_ref() {
+            response.insert_header((header::ETAG, IfMatch::etag(user.version())));
+        }
 
-        return HttpResponse::Ok().json(json!({"user": mapped_user}));
+        let mapped_user = user.map(UserPresenter::to_http);
+
+        return response.json(json!({"user": mapped_user}));
     }
 
     async fn list(query: web::Query<ListUsersDto>) -> impl Responder {
@@ -260,7 +278,9 @@ impl UsersController {
             nickname,
             page,
             per_page,
-            role
+            role,
+            pagination_mode,
+            cursor
         } = query.into_inner();
 
         let query: Option<UserQueryType>;
@@ -282,6 +302,28 @@ impl UsersController {
             query = Some(UserQueryType::Role(parsed_role.unwrap()));
         }
 
+        if cursor.is_some() || pagination_mode == Some(PaginationModeDto::Cursor) {
+            let result = fetch_many_users_service.exec_by_cursor(FetchManyUsersByCursorParams {
+                cursor,
+                per_page: per_page.map(|per_page| per_page as u32),
+                query,
+            }).await;
+
+            if result.is_err() {
+                let err = result.unwrap_err();
+
+                return HttpResponseBuilder::new(StatusCode::from_u16(err.code().to_owned()).unwrap())
+                .json(ErrorPresenter::to_http(err));
+            }
+
+            let result = result.unwrap();
+            let mapped_users: Vec<_> = result.data.into_iter().map(UserPresenter::to_http).collect();
+
+            return HttpResponse::Ok().json(json!({
+                "pagination": PaginationPresenter::to_http_cursor(result.pagination, per_page.unwrap_or(DEFAULT_PER_PAGE)),
+                "data": mapped_users
+            }));
+        }
 
         let result = fetch_many_users_service.exec(FetchManyUsersParams {
             page,
@@ -311,4 +353,65 @@ impl UsersController {
             "data": mapped_users
         }));
     }
+
+    async fn list_mentions(
+        query: web::Query<CursorPaginationQueryDto>,
+        user: web::ReqData<ReqUser>
+    ) -> impl Responder {
+        let service = match fetch_user_mentions_service_factory::exec().await {
+            Left(service) => service,
+            Right(error) => return error
+        };
+
+        let CursorPaginationQueryDto {
+            per_page,
+            page,
+            pagination_mode,
+            cursor
+        } = query.into_inner();
+
+        if cursor.is_some() || pagination_mode == Some(PaginationModeDto::Cursor) {
+            let result = service.exec_by_cursor(FetchUserMentionsByCursorParams {
+                user_id: user.user_id,
+                cursor,
+                per_page: per_page.map(|per_page| per_page as u32),
+            }).await;
+
+            if result.is_err() {
+                let err = result.unwrap_err();
+
+                return HttpResponseBuilder::new(StatusCode::from_u16(err.code().to_owned()).unwrap())
+                .json(ErrorPresenter::to_http(err));
+            }
+
+            let result = result.unwrap();
+            let mapped_mentions: Vec<MappedMention> = result.data.into_iter().map(MentionPresenter::to_http).collect();
+
+            return HttpResponse::Ok().json(json!({
+                "pagination": PaginationPresenter::to_http_cursor(result.pagination, per_page.unwrap_or(DEFAULT_PER_PAGE)),
+                "data": mapped_mentions
+            }));
+        }
+
+        let result = service.exec(FetchUserMentionsParams {
+            user_id: user.user_id,
+            page,
+            per_page: per_page.map(|per_page| per_page as u32),
+        }).await;
+
+        if result.is_err() {
+            let err = result.unwrap_err();
+
+            return HttpResponseBuilder::new(StatusCode::from_u16(err.code().to_owned()).unwrap())
+            .json(ErrorPresenter::to_http(err));
+        }
+
+        let result = result.unwrap();
+        let mapped_mentions: Vec<MappedMention> = result.data.into_iter().map(MentionPresenter::to_http).collect();
+
+        return HttpResponse::Ok().json(json!({
+            "pagination": PaginationPresenter::to_http(result.pagination, per_page.unwrap_or(DEFAULT_PER_PAGE)),
+            "data": mapped_mentions
+        }));
+    }
 }
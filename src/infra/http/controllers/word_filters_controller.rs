@@ -0,0 +1,165 @@
+use std::str::FromStr;
+
+use actix_web::http::header;
+use actix_web::{web, HttpResponse, Responder};
+use actix_web_lab::middleware::from_fn;
+use either::{Left, Right};
+use uuid::Uuid;
+use validator::Validate;
+use crate::core::pagination::DEFAULT_PER_PAGE;
+use super::controller::ControllerTrait;
+use crate::domain::domain_entities::word_filter::WordFilterAction;
+use crate::domain::factories::{
+    create_word_filter_service_factory,
+    fetch_many_word_filters_service_factory,
+    update_word_filter_service_factory,
+    delete_word_filter_service_factory
+};
+use crate::domain::services::create_word_filter_service::CreateWordFilterParams;
+use crate::domain::services::delete_word_filter_service::DeleteWordFilterParams;
+use crate::domain::services::fetch_many_word_filters_service::FetchManyWordFiltersParams;
+use crate::domain::services::update_word_filter_service::UpdateWordFilterParams;
+use crate::infra::http::dtos::create_word_filter::CreateWordFilterDto;
+use crate::infra::http::dtos::simple_pagination_query::SimplePaginationQueryDto;
+use crate::infra::http::dtos::update_word_filter::UpdateWordFilterDto;
+use crate::infra::http::extractors::if_match::IfMatch;
+use crate::infra::http::extractors::req_user::ReqUser;
+use crate::infra::http::middlewares::authentication_middleware;
+use crate::infra::http::presenters::error::ErrorPresenter;
+use crate::infra::http::presenters::pagination::PaginationPresenter;
+use crate::infra::http::presenters::presenter::{JsonWrappedEntity, PresenterTrait};
+use crate::infra::http::presenters::word_filter::{MappedWordFilter, WordFilterPresenter};
+use crate::util::generate_error_response;
+
+pub struct WordFiltersController;
+
+impl ControllerTrait for WordFiltersController {
+    fn register(cfg: &mut web::ServiceConfig) {
+        cfg.service(web::scope("/word_filters")
+            // CREATE
+            .route("/new", web::post().to(Self::create).wrap(from_fn(authentication_middleware)))
+
+            // READ
+            .route("/list", web::get().to(Self::list).wrap(from_fn(authentication_middleware)))
+
+            // UPDATE
+            .route("/{id}/update", web::put().to(Self::update).wrap(from_fn(authentication_middleware)))
+
+            // DELETE
+            .route("/{id}/delete", web::delete().to(Self::delete).wrap(from_fn(authentication_middleware)))
+        );
+    }
+}
+
+impl WordFiltersController {
+    async fn create(user: web::ReqData<ReqUser>, body: web::Json<CreateWordFilterDto>) -> impl Responder {
+        match body.validate() {
+            Ok(()) => (),
+            Err(error) => return HttpResponse::BadRequest().json(ErrorPresenter::to_http_from_validator(error.field_errors()))
+        };
+
+        let body = body.into_inner();
+
+        let service = match create_word_filter_service_factory::exec().await {
+            Left(service) => service,
+            Right(error) => return error,
+        };
+
+        let word_filter = service.exec(CreateWordFilterParams {
+            user_role: user.into_inner().user_role.unwrap(),
+            pattern: body.pattern,
+            is_regex: body.is_regex,
+            // validated by the DTO
+            action: WordFilterAction::from_str(&body.action).unwrap(),
+        }).await;
+
+        if word_filter.is_err() {
+            return generate_error_response(word_filter.unwrap_err());
+        }
+
+        return HttpResponse::Created().json(JsonWrappedEntity {
+            data: WordFilterPresenter::to_http(word_filter.unwrap())
+        });
+    }
+
+    async fn list(user: web::ReqData<ReqUser>, query: web::Query<SimplePaginationQueryDto>) -> impl Responder {
+        let service = match fetch_many_word_filters_service_factory::exec().await {
+            Left(service) => service,
+            Right(error) => return error
+        };
+
+        let result = service.exec(FetchManyWordFiltersParams {
+            user_role: user.into_inner().user_role.unwrap(),
+            page: query.page,
+            per_page: query.per_page.map(|per_page| per_page as u32),
+        }).await;
+
+        if result.is_err() {
+            return generate_error_response(result.unwrap_err());
+        }
+
+        let service_response = result.unwrap();
+
+        let mapped_word_filters = service_response.data.into_iter().map(WordFilterPresenter::to_http).collect::<Vec<MappedWordFilter>>();
+        let mapped_pagination = PaginationPresenter::to_http(service_response.pagination, query.per_page.unwrap_or(DEFAULT_PER_PAGE));
+
+        return HttpResponse::Ok().json(WordFilterPresenter::to_json_paginated_wrapper(mapped_word_filters, mapped_pagination));
+    }
+
+    async fn update(
+        user: web::ReqData<ReqUser>,
+        body: web::Json<UpdateWordFilterDto>,
+        word_filter_id: web::Path<Uuid>,
+        IfMatch(expected_version): IfMatch
+    ) -> impl Responder {
+        if let Err(error) = body.validate() {
+            return HttpResponse::BadRequest().json(ErrorPresenter::to_http_from_validator(error.field_errors()));
+        }
+
+        let body = body.into_inner();
+
+        let service = match update_word_filter_service_factory::exec().await {
+            Left(service) => service,
+            Right(error) => return error,
+        };
+
+        let result = service.exec(UpdateWordFilterParams {
+            user_role: user.into_inner().user_role.unwrap(),
+            word_filter_id: word_filter_id.into_inner(),
+            pattern: body.pattern,
+            is_regex: body.is_regex,
+            // validated by the DTO
+            action: body.action.map(|action| WordFilterAction::from_str(&action).unwrap()),
+            expected_version
+        }).await;
+
+        if result.is_err() {
+            return generate_error_response(result.unwrap_err());
+        }
+
+        let word_filter = result.unwrap();
+        let etag = IfMatch::etag(word_filter.version());
+
+        return HttpResponse::Ok().insert_header((header::ETAG, etag)).json(JsonWrappedEntity {
+            data: WordFilterPresenter::to_http(word_filter)
+        });
+    }
+
+    async fn delete(user: web::ReqData<ReqUser>, word_filter_id: web::Path<Uuid>) -> impl Responder {
+        let service = match delete_word_filter_service_factory::exec().await {
+            Left(service) => service,
+            Right(error) => return error,
+        };
+
+        let result = service.exec(DeleteWordFilterParams {
+            word_filter_id: word_filter_id.into_inner(),
+            user_role: user.into_inner().user_role.unwrap()
+        }).await;
+
+        if result.is_err() {
+            return generate_error_response(result.unwrap_err());
+        }
+
+        return HttpResponse::NoContent().finish();
+    }
+}
@@ -0,0 +1,7 @@
+use serde::{Deserialize, Serialize};
+
+#[derive(Serialize, Deserialize)]
+pub struct AcquireArticleEditLockDto {
+    /// Takes the lock over from its current holder.
+    pub force: Option<bool>,
+}
@@ -2,13 +2,21 @@ use serde::{Deserialize, Serialize};
 use uuid::Uuid;
 use validator::Validate;
 
+use crate::domain::domain_entities::article_block::ArticleBlock;
+use crate::libs::custom_validators::validate_article_blocks;
+
 #[derive(Serialize, Deserialize, Validate)]
 pub struct CreateArticleDto {
     #[serde(rename = "authorId")]
     pub author_id: Option<Uuid>,
 
+    /// Can be left empty when the article is written with `blocks`.
+    #[serde(default)]
     pub content: String,
 
+    #[validate(custom = "validate_article_blocks")]
+    pub blocks: Option<Vec<ArticleBlock>>,
+
     #[validate(url(message = "Cover url must be a valid url."))]
     #[serde(rename = "coverUrl")]
     pub cover_url: String,
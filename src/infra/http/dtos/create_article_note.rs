@@ -0,0 +1,18 @@
+use serde::{Deserialize, Serialize};
+use uuid::Uuid;
+use validator::Validate;
+
+#[derive(Serialize, Deserialize, Validate)]
+pub struct CreateArticleNoteDto {
+    #[validate(length(min = 1, max = 2000, message = "Note must have between 1 and 2000 characters."))]
+    pub content: String,
+
+    /// The note starting the thread being replied to.
+    #[serde(rename = "parentId")]
+    pub parent_id: Option<Uuid>,
+}
+
+#[derive(Serialize, Deserialize)]
+pub struct ResolveArticleNoteDto {
+    pub resolved: bool,
+}
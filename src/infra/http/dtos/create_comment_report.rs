@@ -1,8 +1,13 @@
 use serde::{Deserialize, Serialize};
 use validator::Validate;
+use crate::libs::custom_validators::validate_report_reason;
 
 #[derive(Serialize, Deserialize, Validate)]
 pub struct CreateCommentReportDto {
+    #[validate(custom(function = "validate_report_reason"))]
+    pub reason: String,
+
+    /// Required when the reason is `other`.
     #[validate(length(min=1, message = "Comment report can't be empty."))]
-    pub content: String
+    pub content: Option<String>
 }
\ No newline at end of file
@@ -0,0 +1,14 @@
+use serde::{Deserialize, Serialize};
+use validator::Validate;
+use crate::libs::custom_validators::validate_word_filter_action;
+
+#[derive(Serialize, Deserialize, Validate)]
+pub struct CreateWordFilterDto {
+    #[validate(length(min = 1, max = 255, message = "Word filter pattern must have between 1 and 255 characters."))]
+    pub pattern: String,
+    #[serde(rename="isRegex", default)]
+    pub is_regex: bool,
+    /// One of `mask`, `deactivate` or `reject`.
+    #[validate(custom(function = "validate_word_filter_action"))]
+    pub action: String,
+}
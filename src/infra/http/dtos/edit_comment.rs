@@ -0,0 +1,8 @@
+use serde::{Deserialize, Serialize};
+use validator::Validate;
+
+#[derive(Serialize, Deserialize, Validate)]
+pub struct EditCommentDto {
+    #[validate(length(min=1, message = "Comment can't be empty."))]
+    pub content: String,
+}
@@ -0,0 +1,8 @@
+use serde::{Deserialize, Serialize};
+use validator::Validate;
+
+#[derive(Serialize, Deserialize, Validate)]
+pub struct FeedQueryDto {
+    #[validate(range(min = 1, message = "Feed's tag must be a valid tag id."))]
+    pub tag: Option<i32>,
+}
@@ -0,0 +1,14 @@
+use serde::{Deserialize, Serialize};
+use validator::Validate;
+
+use super::list_article_comments::CommentSortDto;
+
+#[derive(Serialize, Deserialize, Validate)]
+pub struct GetExpandedArticleDto {
+    #[serde(rename="commentsPerPage")]
+    #[validate(range(min=1, message="Comments per page amount must be at least 1."))]
+    pub comments_per_page: Option<u8>,
+
+    #[serde(rename="commentsSort")]
+    pub comments_sort: Option<CommentSortDto>,
+}
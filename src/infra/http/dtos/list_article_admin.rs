@@ -1,6 +1,10 @@
+use chrono::NaiveDate;
 use serde::{Deserialize, Serialize};
 use validator::Validate;
 
+use super::list_articles::ArticleSortDto;
+use super::simple_pagination_query::PaginationModeDto;
+
 #[derive(Serialize, Deserialize, Validate)]
 pub struct AdminListArticlesDto {
     pub page: Option<u32>,
@@ -12,6 +16,31 @@ pub struct AdminListArticlesDto {
 
     pub author: Option<String>,
 
+    #[serde(rename="tagId")]
+    pub tag_id: Option<i32>,
+
+    /// First day of the period, inclusive. Required by the calendar.
+    pub from: Option<NaiveDate>,
+
+    /// Last day of the period, inclusive. Required by the calendar.
+    pub to: Option<NaiveDate>,
+
+    #[serde(rename="minReadingTime")]
+    pub min_reading_time: Option<u32>,
+
+    #[serde(rename="maxReadingTime")]
+    pub max_reading_time: Option<u32>,
+
+    #[serde(rename="isNew")]
+    pub is_new: Option<bool>,
+
+    pub sort: Option<ArticleSortDto>,
+
     #[serde(rename="approvedState")]
     pub approved_state: Option<bool>,
+
+    #[serde(rename="paginationMode")]
+    pub pagination_mode: Option<PaginationModeDto>,
+
+    pub cursor: Option<String>,
 }
\ No newline at end of file
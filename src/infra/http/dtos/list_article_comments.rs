@@ -0,0 +1,38 @@
+use serde::{Deserialize, Serialize};
+use validator::Validate;
+
+use crate::domain::repositories::comment_user_article_repository::CommentSortOrder;
+
+#[derive(Serialize, Deserialize, Validate)]
+pub struct ListArticleCommentsDto {
+    #[serde(rename="perPage")]
+    #[validate(range(min=1, message="Per page amount must be at least 1."))]
+    pub per_page: Option<u8>,
+
+    /// Either the `nextCursor` or the `prevCursor` of a previous page, listed with the same sort.
+    pub cursor: Option<String>,
+
+    pub sort: Option<CommentSortDto>,
+}
+
+#[derive(Serialize, Deserialize, Clone, Copy, PartialEq, Eq)]
+#[serde(rename_all="snake_case")]
+pub enum CommentSortDto {
+    Newest,
+    Oldest,
+    MostReplied,
+    /// Likes are the only reaction comments have, so this is the same ranking as `Top`.
+    MostReacted,
+    Top,
+}
+
+impl From<CommentSortDto> for CommentSortOrder {
+    fn from(sort: CommentSortDto) -> Self {
+        match sort {
+            CommentSortDto::Newest => CommentSortOrder::Newest,
+            CommentSortDto::Oldest => CommentSortOrder::Oldest,
+            CommentSortDto::MostReplied => CommentSortOrder::MostReplied,
+            CommentSortDto::MostReacted | CommentSortDto::Top => CommentSortOrder::Top,
+        }
+    }
+}
@@ -1,6 +1,8 @@
 use serde::{Deserialize, Serialize};
 use validator::Validate;
 
+use super::simple_pagination_query::PaginationModeDto;
+
 #[derive(Serialize, Deserialize, Validate)]
 pub struct ListArticlesDto {
     pub page: Option<u32>,
@@ -11,4 +13,30 @@ pub struct ListArticlesDto {
     pub title: Option<String>,
 
     pub author: Option<String>,
+
+    #[serde(rename="minReadingTime")]
+    pub min_reading_time: Option<u32>,
+
+    #[serde(rename="maxReadingTime")]
+    pub max_reading_time: Option<u32>,
+
+    #[serde(rename="isNew")]
+    pub is_new: Option<bool>,
+
+    pub sort: Option<ArticleSortDto>,
+
+    #[serde(rename="paginationMode")]
+    pub pagination_mode: Option<PaginationModeDto>,
+
+    pub cursor: Option<String>,
+}
+
+/// Sorting other than by newest only works with page pagination.
+#[derive(Serialize, Deserialize, Clone, Copy, PartialEq, Eq)]
+#[serde(rename_all="lowercase")]
+pub enum ArticleSortDto {
+    Newest,
+    Oldest,
+    Shortest,
+    Longest,
 }
\ No newline at end of file
@@ -1,6 +1,8 @@
 use serde::{Deserialize, Serialize};
 use validator::Validate;
 
+use super::simple_pagination_query::PaginationModeDto;
+
 #[derive(Serialize, Deserialize, Validate)]
 pub struct ListCommentReportsDto {
     pub page: Option<u32>,
@@ -14,4 +16,11 @@ pub struct ListCommentReportsDto {
     pub solved: Option<bool>,
 
     pub content: Option<String>,
+
+    pub reason: Option<String>,
+
+    #[serde(rename="paginationMode")]
+    pub pagination_mode: Option<PaginationModeDto>,
+
+    pub cursor: Option<String>,
 }
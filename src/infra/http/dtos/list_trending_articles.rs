@@ -0,0 +1,8 @@
+use serde::{Deserialize, Serialize};
+use validator::Validate;
+
+#[derive(Serialize, Deserialize, Validate)]
+pub struct ListTrendingArticlesDto {
+    #[validate(range(min = 1, max = 20, message = "Limit must be between 1 and 20."))]
+    pub limit: Option<u8>,
+}
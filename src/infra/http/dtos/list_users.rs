@@ -2,6 +2,8 @@ use serde::{Deserialize, Serialize};
 use validator::Validate;
 use crate::libs::custom_validators::validate_user_role;
 
+use super::simple_pagination_query::PaginationModeDto;
+
 #[derive(Serialize, Deserialize, Validate)]
 pub struct ListUsersDto {
     pub page: Option<u32>,
@@ -12,5 +14,10 @@ pub struct ListUsersDto {
     pub role: Option<String>,
 
     #[validate(length(min = 1))]
-    pub nickname: Option<String>
+    pub nickname: Option<String>,
+
+    #[serde(rename="paginationMode")]
+    pub pagination_mode: Option<PaginationModeDto>,
+
+    pub cursor: Option<String>,
 }
\ No newline at end of file
@@ -0,0 +1,8 @@
+use serde::{Deserialize, Serialize};
+use validator::Validate;
+
+#[derive(Serialize, Deserialize, Validate)]
+pub struct LockArticleCommentsDto {
+    /// `false` opens the comments again.
+    pub locked: bool,
+}
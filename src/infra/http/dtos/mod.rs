@@ -6,8 +6,15 @@ pub mod list_users;
 pub mod create_article;
 pub mod list_articles;
 pub mod list_article_admin;
+pub mod list_trending_articles;
+pub mod react_to_article;
+pub mod feed_query;
 pub mod update_article;
+pub mod set_article_coauthors;
+pub mod create_article_note;
+pub mod acquire_article_edit_lock;
 pub mod comment_on_article;
+pub mod edit_comment;
 pub mod list_comments;
 pub mod simple_pagination_query;
 pub mod create_comment_report;
@@ -23,3 +30,9 @@ pub mod list_article_tags;
 pub mod update_article_tag;
 pub mod create_free_badge;
 pub mod update_free_badge;
+pub mod create_word_filter;
+pub mod update_word_filter;
+pub mod list_article_comments;
+pub mod get_expanded_article;
+pub mod lock_article_comments;
+pub mod pin_comment;
@@ -0,0 +1,8 @@
+use serde::{Deserialize, Serialize};
+use validator::Validate;
+
+#[derive(Serialize, Deserialize, Validate)]
+pub struct PinCommentDto {
+    /// `false` unpins the comment.
+    pub pinned: bool,
+}
@@ -0,0 +1,9 @@
+use serde::{Deserialize, Serialize};
+use validator::Validate;
+use crate::libs::custom_validators::validate_article_reaction;
+
+#[derive(Serialize, Deserialize, Validate)]
+pub struct ReactToArticleDto {
+    #[validate(custom(function = "validate_article_reaction"))]
+    pub reaction: String,
+}
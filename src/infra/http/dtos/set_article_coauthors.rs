@@ -0,0 +1,51 @@
+use std::collections::HashSet;
+
+use serde::{Deserialize, Serialize};
+use uuid::Uuid;
+use validator::{Validate, ValidationError};
+
+use crate::domain::politics::article_politics::ArticlePolitics;
+use crate::domain::services::set_article_coauthors_service::MAX_COAUTHOR_ROLE_LENGTH;
+
+#[derive(Serialize, Deserialize)]
+pub struct ArticleCoauthorDto {
+    #[serde(rename = "userId")]
+    pub user_id: Uuid,
+
+    /// What the co-author did on the article, e.g. "texto", "imagens" or "revisão".
+    pub role: Option<String>,
+}
+
+/// The article's author can't be one of its co-authors either, which is checked once the article is found.
+#[derive(Serialize, Deserialize, Validate)]
+pub struct SetArticleCoauthorsDto {
+    /// In the order they must be credited. An empty list removes every co-author.
+    #[validate(custom = "validate_coauthors")]
+    pub coauthors: Vec<ArticleCoauthorDto>,
+}
+
+fn validate_coauthors(coauthors: &[ArticleCoauthorDto]) -> Result<(), ValidationError> {
+    let invalid = |message: String| {
+        let mut error = ValidationError::new("Invalid co-authors.");
+        error.message = Some(message.into());
+        error
+    };
+
+    if coauthors.len() > ArticlePolitics::max_coauthors_per_article() {
+        return Err(invalid(format!("An article can have at most {} co-authors.", ArticlePolitics::max_coauthors_per_article())));
+    }
+
+    let mut seen_users = HashSet::new();
+
+    for coauthor in coauthors {
+        if !seen_users.insert(coauthor.user_id) {
+            return Err(invalid(format!("User '{}' is listed more than once.", coauthor.user_id)));
+        }
+
+        if coauthor.role.as_ref().is_some_and(|role| role.trim().chars().count() > MAX_COAUTHOR_ROLE_LENGTH) {
+            return Err(invalid(format!("Co-author roles must have at most {} characters.", MAX_COAUTHOR_ROLE_LENGTH)));
+        }
+    }
+
+    Ok(())
+}
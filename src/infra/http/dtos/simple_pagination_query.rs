@@ -8,3 +8,24 @@ pub struct SimplePaginationQueryDto {
     #[serde(rename="perPage")]
     pub per_page: Option<u8>,
 }
+
+/// Same as `SimplePaginationQueryDto`, on listings that can also be walked by cursor.
+#[derive(Serialize, Deserialize, Validate)]
+pub struct CursorPaginationQueryDto {
+    pub page: Option<u32>,
+
+    #[serde(rename="perPage")]
+    pub per_page: Option<u8>,
+
+    #[serde(rename="paginationMode")]
+    pub pagination_mode: Option<PaginationModeDto>,
+
+    pub cursor: Option<String>,
+}
+
+#[derive(Serialize, Deserialize, Clone, Copy, PartialEq, Eq)]
+#[serde(rename_all="lowercase")]
+pub enum PaginationModeDto {
+    Page,
+    Cursor,
+}
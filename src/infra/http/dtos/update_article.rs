@@ -2,6 +2,9 @@ use serde::{Deserialize, Serialize};
 use uuid::Uuid;
 use validator::Validate;
 
+use crate::domain::domain_entities::article_block::ArticleBlock;
+use crate::libs::custom_validators::validate_article_blocks;
+
 #[derive(Serialize, Deserialize, Validate)]
 pub struct UpdateArticleDto {
     #[serde(rename = "authorId")]
@@ -9,6 +12,10 @@ pub struct UpdateArticleDto {
 
     pub content: Option<String>,
 
+    /// An empty list removes the blocks, so the article goes back to its Markdown content.
+    #[validate(custom = "validate_article_blocks")]
+    pub blocks: Option<Vec<ArticleBlock>>,
+
     #[validate(url(message = "Cover url must be a valid url."))]
     #[serde(rename = "coverUrl")]
     pub cover_url: Option<String>,
@@ -18,5 +25,14 @@ pub struct UpdateArticleDto {
     pub approved: Option<bool>,
 
     #[serde(rename = "tagId")]
-    pub tag_id: Option<i32>
+    pub tag_id: Option<i32>,
+
+    /// Left as an editorial note on the article, only accepted along with `approved: false`.
+    #[validate(length(max = 2000, message = "Disapproval note must have at most 2000 characters."))]
+    #[serde(rename = "disapprovalNote")]
+    pub disapproval_note: Option<String>,
+
+    /// Saves even though someone else holds the article's edit lock, taking it over.
+    #[serde(rename = "forceLock")]
+    pub force_lock: Option<bool>
 }
\ No newline at end of file
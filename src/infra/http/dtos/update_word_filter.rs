@@ -0,0 +1,13 @@
+use serde::{Deserialize, Serialize};
+use validator::Validate;
+use crate::libs::custom_validators::validate_word_filter_action;
+
+#[derive(Serialize, Deserialize, Validate)]
+pub struct UpdateWordFilterDto {
+    #[validate(length(min = 1, max = 255, message = "Word filter pattern must have between 1 and 255 characters."))]
+    pub pattern: Option<String>,
+    #[serde(rename="isRegex")]
+    pub is_regex: Option<bool>,
+    #[validate(custom(function = "validate_word_filter_action"))]
+    pub action: Option<String>,
+}
@@ -0,0 +1,31 @@
+use actix_web::http::header;
+use actix_web::HttpRequest;
+use sha2::{Digest, Sha256};
+
+/**
+ # ETag
+ Strong entity tags for responses that are rendered as a whole, such as feeds, so pollers can
+ send the tag back on `If-None-Match` and be answered with a `304 Not Modified`.
+ */
+pub struct ETag;
+
+impl ETag {
+    pub fn from_content(content: &str) -> String {
+        let hash = Sha256::digest(content.as_bytes());
+        let hash = hash.iter().take(16).map(|byte| format!("{:02x}", byte)).collect::<String>();
+
+        format!("\"{}\"", hash)
+    }
+
+    pub fn matches_if_none_match(req: &HttpRequest, etag: &str) -> bool {
+        let if_none_match = match req.headers().get(header::IF_NONE_MATCH).and_then(|value| value.to_str().ok()) {
+            None => return false,
+            Some(value) => value,
+        };
+
+        if_none_match
+            .split(',')
+            .map(|tag| tag.trim().trim_start_matches("W/"))
+            .any(|tag| tag == "*" || tag == etag)
+    }
+}
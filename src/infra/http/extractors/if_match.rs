@@ -0,0 +1,57 @@
+use std::future::{ready, Ready};
+
+use actix_web::dev::Payload;
+use actix_web::http::header;
+use actix_web::{FromRequest, HttpRequest, HttpResponse};
+use http::StatusCode;
+use serde_json::json;
+
+/**
+ # If-Match
+ The version of the resource the client last read, sent back on the `If-Match` header as the `ETag` it received,
+ so that updates based on an outdated read are refused. Required on update routes; `*` skips the check.
+ */
+pub struct IfMatch(pub Option<i32>);
+
+impl IfMatch {
+    /// The `ETag` sent along with the resource at that version.
+    pub fn etag(version: i32) -> String {
+        format!("\"{}\"", version)
+    }
+
+    fn parse(value: &str) -> Option<Option<i32>> {
+        let value = value.trim();
+
+        if value == "*" { return Some(None); }
+
+        let value = value.strip_prefix("W/").unwrap_or(value);
+
+        value.trim_matches('"').parse::<i32>().ok().map(Some)
+    }
+}
+
+impl FromRequest for IfMatch {
+    type Error = actix_web::Error;
+    type Future = Ready<Result<Self, Self::Error>>;
+
+    fn from_request(req: &HttpRequest, _: &mut Payload) -> Self::Future {
+        let version = req.headers()
+            .get(header::IF_MATCH)
+            .and_then(|value| value.to_str().ok())
+            .and_then(Self::parse);
+
+        let result = match version {
+            Some(version) => Ok(IfMatch(version)),
+            None => {
+                let response = HttpResponse::PreconditionRequired().json(json!({
+                    "code": StatusCode::PRECONDITION_REQUIRED.as_u16(),
+                    "message": "The If-Match header must carry the ETag of the resource being updated."
+                }));
+
+                Err(actix_web::error::InternalError::from_response("Missing If-Match header", response).into())
+            }
+        };
+
+        ready(result)
+    }
+}
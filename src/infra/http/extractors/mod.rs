@@ -1 +1,2 @@
-pub mod req_user;
\ No newline at end of file
+pub mod req_user;
+pub mod if_match;
\ No newline at end of file
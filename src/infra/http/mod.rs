@@ -3,4 +3,7 @@ pub mod middlewares;
 pub mod routes;
 pub mod extractors;
 pub mod dtos;
-pub mod presenters;
\ No newline at end of file
+pub mod presenters;
+pub mod site_links;
+pub mod etag;
+pub mod sitemap_cache;
\ No newline at end of file
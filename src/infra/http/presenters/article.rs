@@ -3,7 +3,13 @@ use chrono::NaiveDateTime as DateTime;
 use uuid::Uuid;
 
 use crate::domain::domain_entities::article::Article;
+use crate::domain::domain_entities::article_block::ArticleBlock;
+use crate::domain::domain_entities::listed_article::ListedArticle;
+use crate::domain::domain_entities::role::Role;
+use crate::domain::politics::article_politics::ArticlePolitics;
+use crate::libs::time::TimeHelper;
 use crate::infra::http::presenters::presenter::PresenterTrait;
+use super::article_edit_lock::{ArticleEditLockPresenter, MappedArticleEditLock};
 
 #[derive(Serialize, Deserialize)]
 pub struct MappedArticle {
@@ -13,13 +19,53 @@ pub struct MappedArticle {
     #[serde(rename = "coverUrl")]
     cover_url: String,
     title: String,
-    content: String,
+    /// The Markdown source, left out of listings.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    content: Option<String>,
+    /// The blocks, for articles written with the block editor, left out of listings.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    blocks: Option<Vec<ArticleBlock>>,
+    excerpt: String,
+    #[serde(rename = "wordCount")]
+    word_count: u32,
+    /// In minutes.
+    #[serde(rename = "readingTime")]
+    reading_time: u32,
+    #[serde(rename = "isNew")]
+    is_new: bool,
     approved: bool,
+    #[serde(rename = "commentsLocked")]
+    comments_locked: bool,
     #[serde(rename = "createdAt")]
     created_at: DateTime,
     #[serde(rename = "updatedAt")]
     updated_at: Option<DateTime>,
     slug: String,
+    version: i32,
+    tag: Option<MappedArticleTag>,
+    /// Only on listings, which include the author's summary.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    author: Option<MappedArticleAuthor>,
+    #[serde(rename = "commentCount", skip_serializing_if = "Option::is_none")]
+    comment_count: Option<u64>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    views: Option<u64>,
+    /// Who is editing the article, shown to the staff along with the views.
+    #[serde(rename = "editLock", skip_serializing_if = "Option::is_none")]
+    edit_lock: Option<MappedArticleEditLock>,
+}
+
+#[derive(Serialize, Deserialize)]
+pub struct MappedArticleTag {
+    id: i32,
+    value: String,
+}
+
+#[derive(Serialize, Deserialize)]
+pub struct MappedArticleAuthor {
+    id: Uuid,
+    nickname: String,
+    role: Option<Role>,
 }
 
 pub struct ArticlePresenter;
@@ -31,11 +77,65 @@ impl PresenterTrait<Article, MappedArticle> for ArticlePresenter {
             author_id: article.author_id(),
             title: article.title().into(),
             cover_url: article.cover_url().into(),
-            content: article.content().into(),
+            content: Some(article.content().into()),
+            blocks: article.blocks().map(|blocks| blocks.to_vec()),
+            excerpt: ArticlePolitics::excerpt(&article),
+            word_count: article.word_count(),
+            reading_time: article.reading_time(),
+            is_new: ArticlePolitics::article_is_recent(article.clone(), TimeHelper::now()),
             slug: article.slug().to_string(),
+            version: article.version(),
             approved: article.approved(),
+            comments_locked: article.comments_locked(),
             created_at: article.created_at(),
             updated_at: article.updated_at(),
+            tag: article.tag_id().zip(article.tag_value()).map(|(id, value)| MappedArticleTag { id, value }),
+            author: None,
+            comment_count: None,
+            views: None,
+            edit_lock: None,
         }
     }
 }
+
+impl ArticlePresenter {
+    /// Maps the article for listings, which only carry the content's excerpt.
+    #[allow(clippy::wrong_self_convention)]
+    pub fn to_http_listed(article: Article) -> MappedArticle {
+        MappedArticle {
+            content: None,
+            blocks: None,
+            ..Self::to_http(article)
+        }
+    }
+
+    /// Maps the article for listings, along with its author's summary and its comments count.
+    #[allow(clippy::wrong_self_convention)]
+    pub fn to_http_from_listed(listed_article: ListedArticle) -> MappedArticle {
+        let author = MappedArticleAuthor {
+            id: listed_article.article().author_id(),
+            nickname: listed_article.author_nickname().into(),
+            role: listed_article.author_role(),
+        };
+        let comment_count = listed_article.comment_count();
+
+        MappedArticle {
+            author: Some(author),
+            comment_count: Some(comment_count),
+            ..Self::to_http_listed(listed_article.into_article())
+        }
+    }
+
+    /// Maps the article for listings along with its views count and edit lock, which must only be shown to the staff.
+    #[allow(clippy::wrong_self_convention)]
+    pub fn to_http_with_views(listed_article: ListedArticle, views: u64) -> MappedArticle {
+        let edit_lock = listed_article.edit_lock().cloned().map(ArticleEditLockPresenter::to_http);
+
+        MappedArticle {
+            views: Some(views),
+            edit_lock,
+            ..Self::to_http_from_listed(listed_article)
+        }
+    }
+}
+
@@ -0,0 +1,25 @@
+use serde::{Deserialize, Serialize};
+
+use crate::domain::repositories::article_repository::ArticleArchiveMonth;
+use crate::infra::http::presenters::presenter::PresenterTrait;
+
+#[derive(Serialize, Deserialize)]
+pub struct MappedArticleArchiveMonth {
+    year: i32,
+    month: u32,
+    count: u64,
+}
+
+pub struct ArticleArchivePresenter;
+
+impl PresenterTrait<ArticleArchiveMonth, MappedArticleArchiveMonth> for ArticleArchivePresenter {
+    fn to_http(month: ArticleArchiveMonth) -> MappedArticleArchiveMonth {
+        let ArticleArchiveMonth (year, month, count) = month;
+
+        MappedArticleArchiveMonth {
+            year,
+            month,
+            count
+        }
+    }
+}
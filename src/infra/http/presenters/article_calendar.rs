@@ -0,0 +1,44 @@
+use chrono::NaiveDate;
+use serde::{Deserialize, Serialize};
+
+use crate::domain::domain_entities::listed_article::ListedArticle;
+use crate::domain::services::fetch_many_articles_service::ArticleCalendarDay;
+use crate::infra::http::presenters::presenter::PresenterTrait;
+use super::article::{ArticlePresenter, MappedArticle};
+
+#[derive(Serialize, Deserialize)]
+pub struct MappedArticleCalendarDay {
+    day: NaiveDate,
+    articles: Vec<MappedArticleCalendarEntry>,
+}
+
+#[derive(Serialize, Deserialize)]
+pub struct MappedArticleCalendarEntry {
+    /// "published" or "pendingReview".
+    state: String,
+    #[serde(flatten)]
+    article: MappedArticle,
+}
+
+pub struct ArticleCalendarPresenter;
+
+impl PresenterTrait<ArticleCalendarDay, MappedArticleCalendarDay> for ArticleCalendarPresenter {
+    fn to_http(calendar_day: ArticleCalendarDay) -> MappedArticleCalendarDay {
+        MappedArticleCalendarDay {
+            day: calendar_day.day,
+            articles: calendar_day.articles.into_iter().map(Self::to_http_entry).collect(),
+        }
+    }
+}
+
+impl ArticleCalendarPresenter {
+    #[allow(clippy::wrong_self_convention)]
+    fn to_http_entry(listed_article: ListedArticle) -> MappedArticleCalendarEntry {
+        let state = if listed_article.article().approved() { "published" } else { "pendingReview" };
+
+        MappedArticleCalendarEntry {
+            state: state.into(),
+            article: ArticlePresenter::to_http_from_listed(listed_article),
+        }
+    }
+}
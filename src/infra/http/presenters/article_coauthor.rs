@@ -0,0 +1,25 @@
+use serde::{Deserialize, Serialize};
+
+use crate::domain::domain_entities::article_coauthor::ArticleCoauthor;
+use crate::domain::domain_entities::user::User;
+use crate::infra::http::presenters::presenter::PresenterTrait;
+use super::user::{MappedUser, UserPresenter};
+
+#[derive(Serialize, Deserialize)]
+pub struct MappedArticleCoauthor {
+    position: u32,
+    role: Option<String>,
+    user: MappedUser,
+}
+
+pub struct ArticleCoauthorPresenter;
+
+impl PresenterTrait<(ArticleCoauthor, User), MappedArticleCoauthor> for ArticleCoauthorPresenter {
+    fn to_http((coauthor, user): (ArticleCoauthor, User)) -> MappedArticleCoauthor {
+        MappedArticleCoauthor {
+            position: coauthor.position(),
+            role: coauthor.role().map(|role| role.to_owned()),
+            user: UserPresenter::to_http(user),
+        }
+    }
+}
@@ -0,0 +1,31 @@
+use serde::{Deserialize, Serialize};
+use chrono::NaiveDateTime as DateTime;
+use uuid::Uuid;
+
+use crate::domain::domain_entities::article_edit_lock::ArticleEditLock;
+use crate::infra::http::presenters::presenter::PresenterTrait;
+
+#[derive(Serialize, Deserialize)]
+pub struct MappedArticleEditLock {
+    #[serde(rename = "userId")]
+    user_id: Uuid,
+    #[serde(rename = "userNickname", skip_serializing_if = "Option::is_none")]
+    user_nickname: Option<String>,
+    #[serde(rename = "acquiredAt")]
+    acquired_at: DateTime,
+    #[serde(rename = "expiresAt")]
+    expires_at: DateTime,
+}
+
+pub struct ArticleEditLockPresenter;
+
+impl PresenterTrait<ArticleEditLock, MappedArticleEditLock> for ArticleEditLockPresenter {
+    fn to_http(lock: ArticleEditLock) -> MappedArticleEditLock {
+        MappedArticleEditLock {
+            user_id: lock.user_id(),
+            user_nickname: lock.holder_nickname().map(|nickname| nickname.to_owned()),
+            acquired_at: lock.acquired_at(),
+            expires_at: lock.expires_at(),
+        }
+    }
+}
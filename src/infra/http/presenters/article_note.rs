@@ -0,0 +1,70 @@
+use serde::{Deserialize, Serialize};
+use chrono::NaiveDateTime as DateTime;
+use uuid::Uuid;
+
+use crate::domain::domain_entities::article_note::ArticleNote;
+use crate::infra::http::presenters::presenter::PresenterTrait;
+
+#[derive(Serialize, Deserialize)]
+pub struct MappedArticleNote {
+    id: Uuid,
+    #[serde(rename = "authorId")]
+    author_id: Uuid,
+    content: String,
+    resolved: bool,
+    #[serde(rename = "resolvedBy")]
+    resolved_by: Option<Uuid>,
+    #[serde(rename = "resolvedAt")]
+    resolved_at: Option<DateTime>,
+    #[serde(rename = "createdAt")]
+    created_at: DateTime,
+    /// Only notes starting a thread have replies, and only they are serialized with them.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    replies: Option<Vec<MappedArticleNote>>,
+}
+
+pub struct ArticleNotePresenter;
+
+impl PresenterTrait<ArticleNote, MappedArticleNote> for ArticleNotePresenter {
+    fn to_http(note: ArticleNote) -> MappedArticleNote {
+        MappedArticleNote {
+            id: note.id(),
+            author_id: note.author_id(),
+            content: note.content().into(),
+            resolved: note.is_resolved(),
+            resolved_by: note.resolved_by(),
+            resolved_at: note.resolved_at(),
+            created_at: note.created_at(),
+            replies: None,
+        }
+    }
+}
+
+impl ArticleNotePresenter {
+    /// Groups the notes into threads, keeping the order they were received in.
+    #[allow(clippy::wrong_self_convention)]
+    pub fn to_http_threads(notes: Vec<ArticleNote>) -> Vec<MappedArticleNote> {
+        let (threads, replies): (Vec<ArticleNote>, Vec<ArticleNote>) = notes
+            .into_iter()
+            .partition(|note| note.parent_id().is_none());
+
+        threads
+            .into_iter()
+            .map(|thread| {
+                let thread_id = thread.id();
+                let mut mapped_thread = Self::to_http(thread);
+
+                mapped_thread.replies = Some(
+                    replies
+                        .iter()
+                        .filter(|reply| reply.parent_id() == Some(thread_id))
+                        .cloned()
+                        .map(Self::to_http)
+                        .collect()
+                );
+
+                mapped_thread
+            })
+            .collect()
+    }
+}
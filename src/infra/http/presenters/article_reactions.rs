@@ -0,0 +1,23 @@
+use std::collections::BTreeMap;
+
+use serde::{Deserialize, Serialize};
+use crate::domain::domain_entities::article_reaction::ArticleReactionsSummary;
+use crate::infra::http::presenters::presenter::PresenterTrait;
+
+#[derive(Serialize, Deserialize)]
+pub struct MappedArticleReactions {
+    counts: BTreeMap<String, u64>,
+    #[serde(rename = "userReaction")]
+    user_reaction: Option<String>,
+}
+
+pub struct ArticleReactionsPresenter;
+
+impl PresenterTrait<ArticleReactionsSummary, MappedArticleReactions> for ArticleReactionsPresenter {
+    fn to_http(summary: ArticleReactionsSummary) -> MappedArticleReactions {
+        MappedArticleReactions {
+            counts: summary.counts().iter().map(|(kind, count)| (kind.as_str().to_owned(), *count)).collect(),
+            user_reaction: summary.user_reaction().map(|kind| kind.as_str().to_owned()),
+        }
+    }
+}
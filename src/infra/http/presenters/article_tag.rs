@@ -6,6 +6,7 @@ use crate::infra::http::presenters::presenter::PresenterTrait;
 pub struct MappedArticleTag {
     id: i32,
     value: String,
+    version: i32,
 }
 
 pub struct ArticleTagPresenter;
@@ -14,7 +15,8 @@ impl PresenterTrait<ArticleTag, MappedArticleTag> for ArticleTagPresenter {
     fn to_http(tag: ArticleTag) -> MappedArticleTag {
         MappedArticleTag {
             id: tag.id(),
-            value: tag.value().into()
+            value: tag.value().into(),
+            version: tag.version()
         }
     }
 }
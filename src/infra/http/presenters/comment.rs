@@ -3,6 +3,8 @@ use chrono::NaiveDateTime as DateTime;
 use serde::{Serialize, Deserialize};
 use uuid::Uuid;
 use crate::domain::domain_entities::comment::Comment;
+use crate::domain::domain_entities::comment_like::CommentLikesSummary;
+use crate::infra::http::presenters::mention::{MappedMentionSpan, MentionPresenter};
 use crate::infra::http::presenters::presenter::PresenterTrait;
 
 #[derive(Serialize, Deserialize)]
@@ -14,10 +16,25 @@ struct MappedCommentAuthor {
 #[derive(Serialize, Deserialize)]
 pub struct MappedComment {
     id: Uuid,
+    #[serde(rename = "articleId")]
+    article_id: Option<Uuid>,
     content: String,
     #[serde(rename = "createdAt")]
     created_at: DateTime,
-    author: MappedCommentAuthor
+    author: MappedCommentAuthor,
+    #[serde(rename = "parentId")]
+    parent_id: Option<Uuid>,
+    #[serde(rename = "replyCount")]
+    reply_count: u64,
+    #[serde(rename = "editedAt")]
+    edited_at: Option<DateTime>,
+    #[serde(rename = "pinnedAt")]
+    pinned_at: Option<DateTime>,
+    mentions: Vec<MappedMentionSpan>,
+    #[serde(rename = "likeCount")]
+    like_count: u64,
+    #[serde(rename = "likedByMe", skip_serializing_if = "Option::is_none")]
+    liked_by_me: Option<bool>,
 }
 
 #[derive(Serialize, Deserialize)]
@@ -28,7 +45,21 @@ pub struct MappedRawComment {
     created_at: DateTime,
     #[serde(rename = "isActive")]
     is_active: bool,
-    author_id: Uuid
+    author_id: Uuid,
+    #[serde(rename = "parentId")]
+    parent_id: Option<Uuid>,
+    #[serde(rename = "editedAt")]
+    edited_at: Option<DateTime>,
+    #[serde(rename = "pinnedAt")]
+    pinned_at: Option<DateTime>,
+}
+
+#[derive(Serialize, Deserialize)]
+pub struct MappedCommentLikes {
+    #[serde(rename = "likeCount")]
+    like_count: u64,
+    #[serde(rename = "likedByMe")]
+    liked_by_me: bool,
 }
 
 pub struct CommentPresenter;
@@ -38,12 +69,20 @@ impl PresenterTrait<CommentWithAuthor, MappedComment> for CommentPresenter {
         let author = comment.author();
         MappedComment {
             id: comment.id(),
+            article_id: comment.article_id(),
             content: comment.content().to_owned(),
             created_at: comment.created_at(),
             author: MappedCommentAuthor {
                 nickname: author.nickname().to_owned(),
                 role: author.role().unwrap(),
-            }
+            },
+            parent_id: comment.parent_id(),
+            reply_count: comment.reply_count(),
+            edited_at: comment.edited_at(),
+            pinned_at: comment.pinned_at(),
+            mentions: MentionPresenter::to_http_spans(comment.content(), comment.mentions()),
+            like_count: comment.like_count(),
+            liked_by_me: comment.liked_by_me(),
         }
     }
 }
@@ -56,7 +95,17 @@ impl CommentPresenter {
             author_id: comment.author_id(),
             content: comment.content().to_owned(),
             is_active: comment.is_active(),
-            created_at: comment.created_at()
+            created_at: comment.created_at(),
+            parent_id: comment.parent_id(),
+            edited_at: comment.edited_at(),
+            pinned_at: comment.pinned_at(),
+        }
+    }
+
+    pub fn to_http_likes(summary: CommentLikesSummary) -> MappedCommentLikes {
+        MappedCommentLikes {
+            like_count: summary.like_count(),
+            liked_by_me: summary.liked_by_me(),
         }
     }
 }
@@ -18,6 +18,7 @@ pub struct MappedCommentReport {
     comment_id: Uuid,
     #[serde(rename = "userId")]
     user_id: Uuid,
+    reason: String,
     message: String,
     #[serde(rename = "solvedBy")]
     solved_by: Option<Uuid>,
@@ -34,6 +35,7 @@ impl PresenterTrait<CommentReport, MappedCommentReport> for CommentReportPresent
             user_id: report.user_id(),
             solved_by: report.solved_by(),
             comment_id: report.comment_id(),
+            reason: report.reason().as_str().to_owned(),
             message: report.message(),
             created_at: report.created_at()
         }
@@ -0,0 +1,26 @@
+use serde::{Deserialize, Serialize};
+use chrono::NaiveDateTime as DateTime;
+use uuid::Uuid;
+
+use crate::domain::domain_entities::comment_revision::CommentRevision;
+use crate::infra::http::presenters::presenter::PresenterTrait;
+
+#[derive(Serialize, Deserialize)]
+pub struct MappedCommentRevision {
+    id: Uuid,
+    content: String,
+    #[serde(rename = "revisedAt")]
+    revised_at: DateTime,
+}
+
+pub struct CommentRevisionPresenter;
+
+impl PresenterTrait<CommentRevision, MappedCommentRevision> for CommentRevisionPresenter {
+    fn to_http(revision: CommentRevision) -> MappedCommentRevision {
+        MappedCommentRevision {
+            id: revision.id(),
+            content: revision.content().into(),
+            revised_at: revision.revised_at(),
+        }
+    }
+}
@@ -1,9 +1,11 @@
 use serde::{Deserialize, Serialize};
 use chrono::NaiveDateTime as DateTime;
 use uuid::Uuid;
-use crate::{core::pagination::PaginationResponse, domain::domain_entities::{article::Article, comment_with_author::CommentWithAuthor, user::User}};
+use crate::{core::pagination::PaginationResponse, domain::domain_entities::{article::Article, article_coauthor::ArticleCoauthor, article_block::ArticleBlock, article_content::{RenderedArticleContent, TableOfContentsEntry}, article_reaction::ArticleReactionsSummary, comment_with_author::CommentWithAuthor, mention::Mention, user::User}};
+use crate::domain::politics::article_politics::ArticlePolitics;
 use crate::infra::http::presenters::presenter::PresenterTrait;
-use super::{comment::{CommentPresenter, MappedComment}, pagination::{MappedPagination, PaginationPresenter}, user::{MappedUser, UserPresenter}};
+use crate::libs::time::TimeHelper;
+use super::{article_coauthor::{ArticleCoauthorPresenter, MappedArticleCoauthor}, article_reactions::{ArticleReactionsPresenter, MappedArticleReactions}, comment::{CommentPresenter, MappedComment}, mention::{MappedMentionSpan, MentionPresenter}, pagination::{MappedPagination, PaginationPresenter}, user::{MappedUser, UserPresenter}};
 
 #[derive(Serialize, Deserialize)]
 pub struct MappedExpandedArticle {
@@ -11,8 +13,29 @@ pub struct MappedExpandedArticle {
     #[serde(rename = "coverUrl")]
     cover_url: String,
     title: String,
+    /// Sanitized HTML, rendered from the blocks if there are any, from the Markdown source otherwise.
     content: String,
+    #[serde(rename = "textContent")]
+    text_content: String,
+    #[serde(rename = "markdownContent")]
+    markdown_content: String,
+    /// Located in `markdownContent`, the content the mentions were parsed from.
+    mentions: Vec<MappedMentionSpan>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    blocks: Option<Vec<ArticleBlock>>,
+    #[serde(rename = "tableOfContents")]
+    table_of_contents: Vec<MappedTableOfContentsEntry>,
+    #[serde(rename = "wordCount")]
+    word_count: u32,
+    /// In minutes.
+    #[serde(rename = "readingTime")]
+    reading_time: u32,
+    #[serde(rename = "isNew")]
+    is_new: bool,
     approved: bool,
+    /// The frontend hides the comment form while it is set.
+    #[serde(rename = "commentsLocked")]
+    comments_locked: bool,
     #[serde(rename = "createdAt")]
     created_at: DateTime,
     #[serde(rename = "updatedAt")]
@@ -21,36 +44,83 @@ pub struct MappedExpandedArticle {
 
     author: MappedUser,
 
-    comments: MappedExpandedArticleComments
+    coauthors: Vec<MappedArticleCoauthor>,
+
+    comments: MappedExpandedArticleComments,
+
+    reactions: MappedArticleReactions
+}
+
+#[derive(Serialize, Deserialize)]
+struct MappedTableOfContentsEntry {
+    level: u8,
+    title: String,
+    anchor: String,
+}
+
+impl From<&TableOfContentsEntry> for MappedTableOfContentsEntry {
+    fn from(entry: &TableOfContentsEntry) -> Self {
+        MappedTableOfContentsEntry {
+            level: entry.level(),
+            title: entry.title().into(),
+            anchor: entry.anchor().into(),
+        }
+    }
 }
 
 #[derive(Serialize, Deserialize)]
 struct MappedExpandedArticleComments {
     data: Vec<MappedComment>,
-    pagination: MappedPagination
+    pagination: MappedPagination,
+    /// Continues on `/comments/article/{slug}/list` with the same sort.
+    #[serde(rename="nextCursor")]
+    next_cursor: Option<String>
 }
 
 pub struct ExpandedArticlePresenter;
 
 impl ExpandedArticlePresenter {
-    #[allow(clippy::wrong_self_convention)]
-    pub fn to_http(article: Article, author: User, comments: Vec<CommentWithAuthor>, pagination: (PaginationResponse, u8)) -> MappedExpandedArticle {
+    #[allow(clippy::wrong_self_convention, clippy::too_many_arguments)]
+    pub fn to_http(
+        article: Article,
+        content: RenderedArticleContent,
+        author: User,
+        coauthors: Vec<(ArticleCoauthor, User)>,
+        comments: Vec<CommentWithAuthor>,
+        pagination: (PaginationResponse, Option<String>, u8),
+        reactions: ArticleReactionsSummary,
+        mentions: Vec<Mention>
+    ) -> MappedExpandedArticle {
         MappedExpandedArticle {
             id: article.id(),
             title: article.title().into(),
             cover_url: article.cover_url().into(),
-            content: article.content().into(),
+            content: content.html().into(),
+            text_content: content.text().into(),
+            markdown_content: article.content().into(),
+            mentions: MentionPresenter::to_http_spans(article.content(), &mentions),
+            blocks: article.blocks().map(|blocks| blocks.to_vec()),
+            table_of_contents: content.table_of_contents().iter().map(MappedTableOfContentsEntry::from).collect(),
+            word_count: article.word_count(),
+            reading_time: article.reading_time(),
+            is_new: ArticlePolitics::article_is_recent(article.clone(), TimeHelper::now()),
             slug: article.slug().to_string(),
             approved: article.approved(),
+            comments_locked: article.comments_locked(),
             created_at: article.created_at(),
             updated_at: article.updated_at(),
 
             author: UserPresenter::to_http(author),
 
+            coauthors: coauthors.into_iter().map(ArticleCoauthorPresenter::to_http).collect(),
+
             comments: MappedExpandedArticleComments {
                 data: comments.into_iter().map(CommentPresenter::to_http).collect(),
-                pagination: PaginationPresenter::to_http(pagination.0, pagination.1)
-            }
+                pagination: PaginationPresenter::to_http(pagination.0, pagination.2),
+                next_cursor: pagination.1
+            },
+
+            reactions: ArticleReactionsPresenter::to_http(reactions)
         }
     }
 }
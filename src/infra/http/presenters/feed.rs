@@ -0,0 +1,134 @@
+use chrono::NaiveDateTime as DateTime;
+
+use crate::domain::domain_entities::article::Article;
+use crate::domain::politics::article_politics::ArticlePolitics;
+use crate::infra::http::site_links::SiteLinks;
+
+pub struct FeedChannel {
+    pub title: String,
+    pub description: String,
+    /// Absolute link to the feed document itself.
+    pub self_link: String,
+}
+
+pub struct FeedPresenter;
+
+impl FeedPresenter {
+    #[allow(clippy::wrong_self_convention)]
+    pub fn to_rss(channel: &FeedChannel, articles: &[Article]) -> String {
+        let mut items = String::new();
+
+        for article in articles {
+            let link = SiteLinks::article(&article.slug());
+
+            items.push_str("<item>");
+            items.push_str(&format!("<title>{}</title>", escape_xml(article.title())));
+            items.push_str(&format!("<link>{}</link>", escape_xml(&link)));
+            items.push_str(&format!("<guid isPermaLink=\"true\">{}</guid>", escape_xml(&link)));
+            items.push_str(&format!("<pubDate>{}</pubDate>", article.created_at().and_utc().to_rfc2822()));
+
+            if let Some(tag) = article.tag_value() {
+                items.push_str(&format!("<category>{}</category>", escape_xml(&tag)));
+            }
+
+            items.push_str(&format!("<description>{}</description>", escape_xml(&ArticlePolitics::excerpt(article))));
+            items.push_str("</item>");
+        }
+
+        format!(
+            concat!(
+                "<?xml version=\"1.0\" encoding=\"UTF-8\"?>",
+                "<rss version=\"2.0\" xmlns:atom=\"http://www.w3.org/2005/Atom\">",
+                "<channel>",
+                "<title>{title}</title>",
+                "<link>{home}</link>",
+                "<description>{description}</description>",
+                "<language>pt-BR</language>",
+                "<atom:link href=\"{self_link}\" rel=\"self\" type=\"application/rss+xml\"/>",
+                "<lastBuildDate>{updated}</lastBuildDate>",
+                "{items}",
+                "</channel>",
+                "</rss>"
+            ),
+            title = escape_xml(&channel.title),
+            home = escape_xml(&SiteLinks::home()),
+            description = escape_xml(&channel.description),
+            self_link = escape_xml(&channel.self_link),
+            updated = last_update(articles).and_utc().to_rfc2822(),
+            items = items,
+        )
+    }
+
+    #[allow(clippy::wrong_self_convention)]
+    pub fn to_atom(channel: &FeedChannel, articles: &[Article]) -> String {
+        let mut entries = String::new();
+
+        for article in articles {
+            let link = SiteLinks::article(&article.slug());
+            let updated_at = article.updated_at().unwrap_or(article.created_at());
+
+            entries.push_str("<entry>");
+            entries.push_str(&format!("<title>{}</title>", escape_xml(article.title())));
+            entries.push_str(&format!("<id>urn:uuid:{}</id>", article.id()));
+            entries.push_str(&format!("<link rel=\"alternate\" href=\"{}\"/>", escape_xml(&link)));
+            entries.push_str(&format!("<published>{}</published>", article.created_at().and_utc().to_rfc3339()));
+            entries.push_str(&format!("<updated>{}</updated>", updated_at.and_utc().to_rfc3339()));
+
+            if let Some(tag) = article.tag_value() {
+                entries.push_str(&format!("<category term=\"{}\"/>", escape_xml(&tag)));
+            }
+
+            entries.push_str(&format!("<summary>{}</summary>", escape_xml(&ArticlePolitics::excerpt(article))));
+            entries.push_str("</entry>");
+        }
+
+        format!(
+            concat!(
+                "<?xml version=\"1.0\" encoding=\"UTF-8\"?>",
+                "<feed xmlns=\"http://www.w3.org/2005/Atom\" xml:lang=\"pt-BR\">",
+                "<title>{title}</title>",
+                "<subtitle>{description}</subtitle>",
+                "<id>{self_link}</id>",
+                "<link rel=\"self\" type=\"application/atom+xml\" href=\"{self_link}\"/>",
+                "<link rel=\"alternate\" href=\"{home}\"/>",
+                "<author><name>{author}</name></author>",
+                "<updated>{updated}</updated>",
+                "{entries}",
+                "</feed>"
+            ),
+            title = escape_xml(&channel.title),
+            description = escape_xml(&channel.description),
+            self_link = escape_xml(&channel.self_link),
+            home = escape_xml(&SiteLinks::home()),
+            author = escape_xml(&channel.title),
+            updated = last_update(articles).and_utc().to_rfc3339(),
+            entries = entries,
+        )
+    }
+}
+
+/// The latest change among the articles, so the feed only looks updated when one of them is.
+fn last_update(articles: &[Article]) -> DateTime {
+    articles
+        .iter()
+        .map(|article| article.updated_at().unwrap_or(article.created_at()))
+        .max()
+        .unwrap_or_default()
+}
+
+pub fn escape_xml(value: &str) -> String {
+    let mut escaped = String::with_capacity(value.len());
+
+    for c in value.chars() {
+        match c {
+            '&' => escaped.push_str("&amp;"),
+            '<' => escaped.push_str("&lt;"),
+            '>' => escaped.push_str("&gt;"),
+            '"' => escaped.push_str("&quot;"),
+            '\'' => escaped.push_str("&apos;"),
+            _ => escaped.push(c),
+        }
+    }
+
+    escaped
+}
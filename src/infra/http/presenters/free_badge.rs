@@ -13,7 +13,8 @@ pub struct MappedFreeBadge {
     pub link_is_external: bool,
     #[serde(rename="availableUntil")]
     pub available_until: Option<NaiveDateTime>,
-    pub image: String
+    pub image: String,
+    pub version: i32
 }
 
 pub struct FreeBadgePresenter;
@@ -26,7 +27,8 @@ impl PresenterTrait<FreeBadge, MappedFreeBadge> for FreeBadgePresenter {
             link: free_badge.link().into(),
             image: free_badge.image().into(),
             available_until: free_badge.available_until(),
-            link_is_external: free_badge.link_is_external()
+            link_is_external: free_badge.link_is_external(),
+            version: free_badge.version()
         }
     }
 }
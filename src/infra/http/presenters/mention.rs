@@ -0,0 +1,58 @@
+use serde::{Deserialize, Serialize};
+use chrono::NaiveDateTime as DateTime;
+use uuid::Uuid;
+
+use crate::domain::domain_entities::mention::{Mention, MentionSpan};
+use crate::infra::http::presenters::presenter::PresenterTrait;
+
+#[derive(Serialize, Deserialize)]
+pub struct MappedMention {
+    id: Uuid,
+    #[serde(rename = "mentionedBy")]
+    mentioned_by: Uuid,
+    #[serde(rename = "articleId")]
+    article_id: Option<Uuid>,
+    #[serde(rename = "commentId")]
+    comment_id: Option<Uuid>,
+    #[serde(rename = "createdAt")]
+    created_at: DateTime,
+}
+
+/// Offsets are in characters, `start` pointing to the `@` and `end` being exclusive.
+#[derive(Serialize, Deserialize)]
+pub struct MappedMentionSpan {
+    start: usize,
+    end: usize,
+    #[serde(rename = "userId")]
+    user_id: Uuid,
+    nickname: String,
+}
+
+pub struct MentionPresenter;
+
+impl PresenterTrait<Mention, MappedMention> for MentionPresenter {
+    fn to_http(mention: Mention) -> MappedMention {
+        MappedMention {
+            id: mention.id(),
+            mentioned_by: mention.mentioned_by(),
+            article_id: mention.article_id(),
+            comment_id: mention.comment_id(),
+            created_at: mention.created_at(),
+        }
+    }
+}
+
+impl MentionPresenter {
+    #[allow(clippy::wrong_self_convention)]
+    pub fn to_http_spans(content: &str, mentions: &[Mention]) -> Vec<MappedMentionSpan> {
+        MentionSpan::find_in(content, mentions)
+            .into_iter()
+            .map(|span| MappedMentionSpan {
+                start: span.start,
+                end: span.end,
+                user_id: span.user_id,
+                nickname: span.nickname,
+            })
+            .collect()
+    }
+}
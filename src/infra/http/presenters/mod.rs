@@ -3,10 +3,21 @@ pub mod error;
 pub mod pagination;
 pub mod article;
 pub mod expanded_article;
+pub mod article_reactions;
+pub mod article_archive;
+pub mod article_calendar;
+pub mod article_coauthor;
+pub mod article_edit_lock;
+pub mod article_note;
+pub mod feed;
+pub mod sitemap;
 pub mod comment;
+pub mod comment_revision;
+pub mod mention;
 pub mod comment_report;
 pub mod presenter;
 pub mod team_role;
 pub mod team_user;
 pub mod article_tag;
 pub mod free_badge;
+pub mod word_filter;
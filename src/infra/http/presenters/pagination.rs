@@ -1,6 +1,6 @@
 use serde::{Deserialize, Serialize};
 
-use crate::core::pagination::PaginationResponse;
+use crate::core::pagination::{CursorPaginationResponse, PaginationResponse};
 
 #[derive(Serialize, Deserialize)]
 pub struct MappedPagination {
@@ -14,6 +14,16 @@ pub struct MappedPagination {
     items_per_page: u8
 }
 
+#[derive(Serialize, Deserialize)]
+pub struct MappedCursorPagination {
+    #[serde(rename="nextCursor")]
+    next_cursor: Option<String>,
+    #[serde(rename="prevCursor")]
+    prev_cursor: Option<String>,
+    #[serde(rename="itemsPerPage")]
+    items_per_page: u8
+}
+
 pub struct PaginationPresenter;
 
 impl PaginationPresenter {
@@ -26,4 +36,13 @@ impl PaginationPresenter {
             items_per_page: per_page
         }
     }
+
+    #[allow(clippy::wrong_self_convention)]
+    pub fn to_http_cursor(pagination_details: CursorPaginationResponse, per_page: u8) -> MappedCursorPagination {
+        MappedCursorPagination {
+            next_cursor: pagination_details.next_cursor,
+            prev_cursor: pagination_details.prev_cursor,
+            items_per_page: per_page
+        }
+    }
 }
@@ -0,0 +1,100 @@
+use chrono::NaiveDateTime as DateTime;
+
+use crate::domain::services::fetch_sitemap_entries_service::FetchSitemapEntriesResponse;
+use crate::infra::http::presenters::feed::escape_xml;
+use crate::infra::http::site_links::SiteLinks;
+
+/// Search engines reject sitemaps with more URLs than this, so bigger ones are split and indexed.
+const MAX_URLS_PER_SITEMAP: usize = 50_000;
+
+/// Fan-site pages that do not depend on any content.
+const STATIC_PAGES: [&str; 4] = ["", "articles", "team", "free-badges"];
+
+struct SitemapUrl {
+    loc: String,
+    lastmod: Option<DateTime>,
+}
+
+/**
+ # Sitemap Documents
+ The rendered sitemaps. If every URL fits in a single sitemap, there is no index and the only
+ sitemap is served as `/sitemap.xml`, otherwise the index is, and the sitemaps are served
+ as `/sitemap-{page}.xml`, counting from 1.
+ */
+pub struct SitemapDocuments {
+    pub index: Option<String>,
+    pub sitemaps: Vec<String>,
+}
+
+impl SitemapDocuments {
+    pub fn root(&self) -> &str {
+        match &self.index {
+            Some(index) => index,
+            None => &self.sitemaps[0],
+        }
+    }
+
+    pub fn page(&self, page: usize) -> Option<&str> {
+        self.index.as_ref()?;
+        page.checked_sub(1).and_then(|index| self.sitemaps.get(index)).map(|sitemap| sitemap.as_str())
+    }
+}
+
+pub struct SitemapPresenter;
+
+impl SitemapPresenter {
+    #[allow(clippy::wrong_self_convention)]
+    pub fn to_documents(entries: FetchSitemapEntriesResponse) -> SitemapDocuments {
+        let FetchSitemapEntriesResponse { articles, tags } = entries;
+
+        let mut urls = Vec::with_capacity(STATIC_PAGES.len() + tags.len() + articles.len());
+
+        urls.extend(STATIC_PAGES.iter().map(|path| SitemapUrl { loc: SiteLinks::absolute(path), lastmod: None }));
+        urls.extend(tags.iter().map(|tag| SitemapUrl { loc: SiteLinks::article_tag(tag), lastmod: None }));
+        urls.extend(articles.iter().map(|article| SitemapUrl { loc: SiteLinks::article(&article.0), lastmod: Some(article.1) }));
+
+        let chunks = urls.chunks(MAX_URLS_PER_SITEMAP).collect::<Vec<&[SitemapUrl]>>();
+
+        let sitemaps = chunks.iter().map(|chunk| Self::to_urlset(chunk)).collect::<Vec<String>>();
+
+        if chunks.len() <= 1 {
+            return SitemapDocuments { index: None, sitemaps };
+        }
+
+        let mut index = String::from("<?xml version=\"1.0\" encoding=\"UTF-8\"?><sitemapindex xmlns=\"http://www.sitemaps.org/schemas/sitemap/0.9\">");
+
+        for (position, chunk) in chunks.iter().enumerate() {
+            index.push_str("<sitemap>");
+            index.push_str(&format!("<loc>{}</loc>", escape_xml(&SiteLinks::absolute(&format!("sitemap-{}.xml", position + 1)))));
+
+            if let Some(lastmod) = chunk.iter().filter_map(|url| url.lastmod).max() {
+                index.push_str(&format!("<lastmod>{}</lastmod>", lastmod.and_utc().to_rfc3339()));
+            }
+
+            index.push_str("</sitemap>");
+        }
+
+        index.push_str("</sitemapindex>");
+
+        SitemapDocuments { index: Some(index), sitemaps }
+    }
+
+    fn to_urlset(urls: &[SitemapUrl]) -> String {
+        let mut urlset = String::from("<?xml version=\"1.0\" encoding=\"UTF-8\"?><urlset xmlns=\"http://www.sitemaps.org/schemas/sitemap/0.9\">");
+
+        for url in urls {
+            urlset.push_str("<url>");
+            urlset.push_str(&format!("<loc>{}</loc>", escape_xml(&url.loc)));
+
+            if let Some(lastmod) = url.lastmod {
+                urlset.push_str(&format!("<lastmod>{}</lastmod>", lastmod.and_utc().to_rfc3339()));
+            }
+
+            urlset.push_str("</url>");
+        }
+
+        urlset.push_str("</urlset>");
+
+        urlset
+    }
+}
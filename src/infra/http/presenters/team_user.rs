@@ -12,7 +12,8 @@ pub struct MappedTeamUser {
     twitter: Option<String>,
     discord: Option<String>,
     #[serde(rename = "hiredAt")]
-    created_at: DateTime
+    created_at: DateTime,
+    version: i32
 }
 
 pub struct TeamUserPresenter;
@@ -25,6 +26,7 @@ impl PresenterTrait<TeamUser, MappedTeamUser> for TeamUserPresenter {
             created_at: user.created_at(),
             user_function: user.user_function().to_owned(),
             twitter: user.twitter(),
+            version: user.version(),
         }
     }
 }
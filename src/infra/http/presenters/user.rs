@@ -11,6 +11,7 @@ pub struct MappedUser {
     nickname: String,
     createdAt: DateTime,
     role: Role,
+    version: i32,
 }
 
 pub struct UserPresenter;
@@ -21,7 +22,8 @@ impl PresenterTrait<User, MappedUser> for UserPresenter {
             nickname: user.nickname().into(),
             id: user.id(),
             role: user.role().unwrap(),
-            createdAt: user.created_at()
+            createdAt: user.created_at(),
+            version: user.version()
         }
     }
 }
@@ -0,0 +1,35 @@
+use serde::{Deserialize, Serialize};
+use chrono::NaiveDateTime;
+use uuid::Uuid;
+use crate::domain::domain_entities::word_filter::WordFilter;
+use crate::infra::http::presenters::presenter::PresenterTrait;
+
+#[derive(Serialize, Deserialize)]
+pub struct MappedWordFilter {
+    pub id: Uuid,
+    pub pattern: String,
+    #[serde(rename="isRegex")]
+    pub is_regex: bool,
+    pub action: String,
+    #[serde(rename="createdAt")]
+    pub created_at: NaiveDateTime,
+    #[serde(rename="updatedAt")]
+    pub updated_at: Option<NaiveDateTime>,
+    pub version: i32
+}
+
+pub struct WordFilterPresenter;
+
+impl PresenterTrait<WordFilter, MappedWordFilter> for WordFilterPresenter {
+    fn to_http(word_filter: WordFilter) -> MappedWordFilter {
+        MappedWordFilter {
+            id: word_filter.id(),
+            pattern: word_filter.pattern().into(),
+            is_regex: word_filter.is_regex(),
+            action: word_filter.action().as_str().into(),
+            created_at: word_filter.created_at(),
+            updated_at: word_filter.updated_at(),
+            version: word_filter.version()
+        }
+    }
+}
@@ -10,6 +10,7 @@ use crate::infra::http::controllers::team_roles_controller::TeamRolesController;
 use crate::infra::http::controllers::team_users_controller::TeamUsersController;
 use crate::infra::http::middlewares::RequestUserMiddleware;
 use crate::infra::http::controllers::users_controller::UsersController;
+use crate::infra::http::controllers::word_filters_controller::WordFiltersController;
 use crate::infra::http::routes::route::RouteTrait;
 
 pub struct ApiRoutes;
@@ -28,6 +29,7 @@ impl RouteTrait for ApiRoutes {
             .configure(TeamUsersController::register)
             .configure(ArticleTagsController::register)
             .configure(FreeBadgesController::register)
+            .configure(WordFiltersController::register)
         );
     }
 }
@@ -0,0 +1,13 @@
+use actix_web::web;
+use crate::infra::http::controllers::controller::ControllerTrait;
+use crate::infra::http::controllers::feeds_controller::FeedsController;
+use crate::infra::http::routes::route::RouteTrait;
+
+/// Documents served from the root of the server, outside of the `api` scope, such as feeds.
+pub struct FeedRoutes;
+
+impl RouteTrait for FeedRoutes {
+    fn register(cfg: &mut web::ServiceConfig) {
+        cfg.configure(FeedsController::register);
+    }
+}
@@ -1,2 +1,4 @@
 pub mod route;
-pub mod api;
\ No newline at end of file
+pub mod api;
+pub mod feed;
+pub mod sitemap;
\ No newline at end of file
@@ -0,0 +1,13 @@
+use actix_web::web;
+use crate::infra::http::controllers::controller::ControllerTrait;
+use crate::infra::http::controllers::sitemaps_controller::SitemapsController;
+use crate::infra::http::routes::route::RouteTrait;
+
+/// Sitemaps must be served from the root of the server, where search engines look for them.
+pub struct SitemapRoutes;
+
+impl RouteTrait for SitemapRoutes {
+    fn register(cfg: &mut web::ServiceConfig) {
+        cfg.configure(SitemapsController::register);
+    }
+}
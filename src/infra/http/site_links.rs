@@ -0,0 +1,28 @@
+use crate::domain::domain_entities::article_tag::ArticleTag;
+use crate::domain::domain_entities::slug::Slug;
+use crate::ENV_VARS;
+
+/**
+ # Site Links
+ Absolute links to the fan-site's pages, built upon the `DOMAIN` environment variable.
+ They are meant for documents consumed outside of the fan-site, such as feeds.
+ */
+pub struct SiteLinks;
+
+impl SiteLinks {
+    pub fn absolute(path: &str) -> String {
+        format!("https://{}/{}", ENV_VARS.domain, path.trim_start_matches('/'))
+    }
+
+    pub fn home() -> String {
+        Self::absolute("")
+    }
+
+    pub fn article(slug: &Slug) -> String {
+        Self::absolute(&format!("articles/{}", slug.to_string()))
+    }
+
+    pub fn article_tag(tag: &ArticleTag) -> String {
+        Self::absolute(&format!("articles?tag={}", tag.id()))
+    }
+}
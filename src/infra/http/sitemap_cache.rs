@@ -0,0 +1,50 @@
+use std::sync::atomic::{AtomicU64, Ordering};
+use std::sync::{Arc, RwLock};
+
+use once_cell::sync::Lazy;
+
+use crate::infra::http::presenters::sitemap::SitemapDocuments;
+
+static SITEMAP_CACHE: Lazy<RwLock<Option<Arc<SitemapDocuments>>>> = Lazy::new(|| RwLock::new(None));
+static SITEMAP_GENERATION: AtomicU64 = AtomicU64::new(0);
+
+/**
+ # Sitemap Cache
+ Keeps the rendered sitemaps in memory, so they are only generated again after some content
+ listed on them changes. Any route that creates, updates or deletes articles or article tags
+ must `invalidate` it.
+
+ Sitemaps rendered from data read before an invalidation are not cached: take the `generation`
+ before fetching the entries and hand it back to `set`.
+ */
+pub struct SitemapCache;
+
+impl SitemapCache {
+    pub fn get() -> Option<Arc<SitemapDocuments>> {
+        SITEMAP_CACHE.read().ok().and_then(|cache| cache.clone())
+    }
+
+    pub fn generation() -> u64 {
+        SITEMAP_GENERATION.load(Ordering::SeqCst)
+    }
+
+    pub fn set(documents: SitemapDocuments, generation: u64) -> Arc<SitemapDocuments> {
+        let documents = Arc::new(documents);
+
+        if let Ok(mut cache) = SITEMAP_CACHE.write() {
+            if generation == Self::generation() {
+                *cache = Some(Arc::clone(&documents));
+            }
+        }
+
+        documents
+    }
+
+    pub fn invalidate() {
+        SITEMAP_GENERATION.fetch_add(1, Ordering::SeqCst);
+
+        if let Ok(mut cache) = SITEMAP_CACHE.write() {
+            *cache = None;
+        }
+    }
+}
@@ -0,0 +1,63 @@
+use sea_orm::{ColumnTrait, Condition, QueryFilter, QueryOrder, Value};
+
+use crate::core::pagination::{CursorDirection, PaginationCursor};
+
+/**
+ Narrows a listing ordered by `created_at` and `id`, both descending, to the rows past the cursor, and orders
+ them from the cursor on. Comparing both keys keeps rows sharing the same timestamp from being skipped or repeated.
+
+ Walking before the cursor orders the rows the other way around, `into_cursor_page` restores the listing's order.
+ */
+pub fn walk_from_cursor<Query, Column, Id>(
+    query_builder: Query,
+    created_at: Column,
+    id: Column,
+    cursor: Option<&PaginationCursor<Id>>,
+) -> Query
+where
+    Query: QueryFilter + QueryOrder,
+    Column: ColumnTrait,
+    Id: Clone + Into<Value>,
+{
+    let direction = cursor.map(|cursor| cursor.direction).unwrap_or(CursorDirection::After);
+
+    let query_builder = match cursor {
+        None => query_builder,
+        Some(cursor) => {
+            let keyset_filter = match cursor.direction {
+                CursorDirection::After => Condition::any()
+                    .add(created_at.lt(cursor.created_at))
+                    .add(Condition::all().add(created_at.eq(cursor.created_at)).add(id.lt(cursor.id.clone()))),
+                CursorDirection::Before => Condition::any()
+                    .add(created_at.gt(cursor.created_at))
+                    .add(Condition::all().add(created_at.eq(cursor.created_at)).add(id.gt(cursor.id.clone()))),
+            };
+
+            query_builder.filter(keyset_filter)
+        }
+    };
+
+    match direction {
+        CursorDirection::After => query_builder.order_by_desc(created_at).order_by_desc(id),
+        CursorDirection::Before => query_builder.order_by_asc(created_at).order_by_asc(id),
+    }
+}
+
+/**
+ Turns the rows of a listing walked by `walk_from_cursor`, fetched with one row more than the page holds, into
+ the page, from the newest to the oldest, and whether there are more rows past it. The extra row spares a `COUNT` query.
+ */
+pub fn into_cursor_page<Row, Id>(
+    mut rows: Vec<Row>,
+    items_per_page: u64,
+    cursor: Option<&PaginationCursor<Id>>,
+) -> (Vec<Row>, bool) {
+    let has_more = rows.len() as u64 > items_per_page;
+    rows.truncate(items_per_page as usize);
+
+    if cursor.is_some_and(|cursor| cursor.direction == CursorDirection::Before) {
+        rows.reverse();
+    }
+
+    (rows, has_more)
+}
@@ -1,10 +1,19 @@
 pub mod sea_user_mapper;
 pub mod sea_role_mapper;
 pub mod sea_article_mapper;
+pub mod sea_article_view_mapper;
+pub mod sea_article_reaction_mapper;
+pub mod sea_comment_like_mapper;
+pub mod sea_article_coauthor_mapper;
+pub mod sea_article_edit_lock_mapper;
+pub mod sea_article_note_mapper;
 pub mod sea_comment_mapper;
 pub mod sea_comment_report_mapper;
 pub mod sea_comment_with_author_mapper;
+pub mod sea_comment_revision_mapper;
+pub mod sea_mention_mapper;
 pub mod sea_team_role_mapper;
 pub mod sea_team_user_mapper;
 pub mod sea_article_tag_mapper;
-pub mod sea_free_badge_mapper;
\ No newline at end of file
+pub mod sea_free_badge_mapper;
+pub mod sea_word_filter_mapper;
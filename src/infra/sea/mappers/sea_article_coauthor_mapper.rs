@@ -0,0 +1,29 @@
+use entities::article_coauthor::Model as ArticleCoauthorModel;
+use entities::article_coauthor::ActiveModel as ArticleCoauthorActiveModel;
+use sea_orm::IntoActiveValue;
+
+use crate::domain::domain_entities::article_coauthor::ArticleCoauthor;
+
+pub struct SeaArticleCoauthorMapper;
+
+impl SeaArticleCoauthorMapper {
+    pub fn article_coauthor_to_sea_active_model(coauthor: ArticleCoauthor) -> ArticleCoauthorActiveModel {
+        ArticleCoauthorActiveModel {
+            article_id: coauthor.article_id().into_active_value(),
+            user_id: coauthor.user_id().into_active_value(),
+            position: (coauthor.position() as i32).into_active_value(),
+            role: coauthor.role().map(|role| role.to_owned()).into_active_value(),
+            created_at: coauthor.created_at().into_active_value(),
+        }
+    }
+
+    pub fn model_to_article_coauthor(model: ArticleCoauthorModel) -> ArticleCoauthor {
+        ArticleCoauthor::new_from_existing(
+            model.article_id,
+            model.user_id,
+            model.position.max(0) as u32,
+            model.role,
+            model.created_at
+        )
+    }
+}
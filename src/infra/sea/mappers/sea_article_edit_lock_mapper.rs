@@ -0,0 +1,36 @@
+use entities::article_edit_lock::Model as ArticleEditLockModel;
+use entities::article_edit_lock::ActiveModel as ArticleEditLockActiveModel;
+use entities::sea_orm_active_enums::Role as RoleModel;
+use sea_orm::IntoActiveValue;
+
+use crate::domain::domain_entities::article_edit_lock::ArticleEditLock;
+
+use super::sea_role_mapper::SeaRoleMapper;
+
+pub struct SeaArticleEditLockMapper;
+
+impl SeaArticleEditLockMapper {
+    pub fn article_edit_lock_to_sea_active_model(lock: ArticleEditLock) -> ArticleEditLockActiveModel {
+        ArticleEditLockActiveModel {
+            article_id: lock.article_id().into_active_value(),
+            user_id: lock.user_id().into_active_value(),
+            acquired_at: lock.acquired_at().into_active_value(),
+            expires_at: lock.expires_at().into_active_value(),
+        }
+    }
+
+    pub fn model_to_article_edit_lock(
+        model: ArticleEditLockModel,
+        holder_nickname: Option<String>,
+        holder_role: Option<RoleModel>
+    ) -> ArticleEditLock {
+        ArticleEditLock::new_from_existing(
+            model.article_id,
+            model.user_id,
+            holder_nickname,
+            holder_role.map(SeaRoleMapper::to_domain),
+            model.acquired_at,
+            model.expires_at
+        )
+    }
+}
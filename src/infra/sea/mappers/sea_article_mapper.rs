@@ -1,10 +1,18 @@
 use entities::article::Model as ArticleModel;
 use entities::article::ActiveModel as ArticleActiveModel;
+use entities::article_edit_lock::Model as ArticleEditLockModel;
+use entities::sea_orm_active_enums::Role as RoleModel;
 use sea_orm::IntoActiveValue;
+use serde_json::Value as JsonValue;
 
 use crate::domain::domain_entities::article::Article;
+use crate::domain::domain_entities::article_block::ArticleBlock;
+use crate::domain::domain_entities::listed_article::ListedArticle;
 use crate::domain::domain_entities::slug::Slug;
 
+use super::sea_article_edit_lock_mapper::SeaArticleEditLockMapper;
+use super::sea_role_mapper::SeaRoleMapper;
+
 pub struct SeaArticleMapper {}
 
 impl SeaArticleMapper {
@@ -15,12 +23,17 @@ impl SeaArticleMapper {
             cover_url: article.cover_url().to_owned(),
             title: article.title().to_owned(),
             content: article.content().to_owned(),
+            blocks: Self::blocks_to_json(article.blocks()),
+            word_count: article.word_count() as i32,
+            reading_time: article.reading_time() as i32,
             approved: article.approved(),
+            comments_locked: article.comments_locked(),
             created_at: article.created_at(),
             updated_at: article.updated_at(),
             slug: article.slug().to_string(),
             tag_id: article.tag_id(),
-            tag_value: article.tag_value()
+            tag_value: article.tag_value(),
+            version: article.version()
         };
 
         sea_model
@@ -33,12 +46,17 @@ impl SeaArticleMapper {
             cover_url: article.cover_url().to_owned().into_active_value(),
             title: article.title().to_owned().into_active_value(),
             content: article.content().to_owned().into_active_value(),
+            blocks: Self::blocks_to_json(article.blocks()).into_active_value(),
+            word_count: (article.word_count() as i32).into_active_value(),
+            reading_time: (article.reading_time() as i32).into_active_value(),
             approved: article.approved().into_active_value(),
+            comments_locked: article.comments_locked().into_active_value(),
             created_at: article.created_at().into_active_value(),
             updated_at: article.updated_at().into_active_value(),
             slug: article.slug().to_string().into_active_value(),
             tag_value: article.tag_value().into_active_value(),
-            tag_id: article.tag_id().into_active_value()
+            tag_id: article.tag_id().into_active_value(),
+            version: article.version().into_active_value()
         };
 
         sea_active_model
@@ -51,12 +69,17 @@ impl SeaArticleMapper {
             active_model_article.cover_url.unwrap(),
             active_model_article.title.unwrap(),
             active_model_article.content.unwrap(),
+            Self::json_to_blocks(active_model_article.blocks.unwrap()),
+            active_model_article.word_count.unwrap() as u32,
+            active_model_article.reading_time.unwrap() as u32,
             active_model_article.approved.unwrap(),
+            active_model_article.comments_locked.unwrap(),
             active_model_article.created_at.unwrap(),
             active_model_article.updated_at.unwrap(),
             active_model_article.tag_id.unwrap(),
             active_model_article.tag_value.unwrap(),
             Slug::new_from_existing(active_model_article.slug.unwrap()),
+            active_model_article.version.unwrap(),
         );
 
         article
@@ -69,14 +92,43 @@ impl SeaArticleMapper {
             model_article.cover_url,
             model_article.title,
             model_article.content,
+            Self::json_to_blocks(model_article.blocks),
+            model_article.word_count as u32,
+            model_article.reading_time as u32,
             model_article.approved,
+            model_article.comments_locked,
             model_article.created_at,
             model_article.updated_at,
             model_article.tag_id,
             model_article.tag_value,
-            Slug::new_from_existing(model_article.slug)
+            Slug::new_from_existing(model_article.slug),
+            model_article.version
         );
 
         article
     }
+
+    pub fn model_to_listed_article(
+        model_article: ArticleModel,
+        author_nickname: String,
+        author_role: Option<RoleModel>,
+        comment_count: i64,
+        edit_lock: Option<(ArticleEditLockModel, String)>
+    ) -> ListedArticle {
+        ListedArticle::new(
+            Self::model_to_article(model_article),
+            author_nickname,
+            author_role.map(SeaRoleMapper::to_domain),
+            comment_count as u64,
+            edit_lock.map(|(lock, holder_nickname)| SeaArticleEditLockMapper::model_to_article_edit_lock(lock, Some(holder_nickname), None))
+        )
+    }
+
+    fn blocks_to_json(blocks: Option<&[ArticleBlock]>) -> Option<JsonValue> {
+        blocks.and_then(|blocks| serde_json::to_value(blocks).ok())
+    }
+
+    fn json_to_blocks(blocks: Option<JsonValue>) -> Option<Vec<ArticleBlock>> {
+        blocks.and_then(|blocks| serde_json::from_value(blocks).ok())
+    }
 }
\ No newline at end of file
@@ -0,0 +1,35 @@
+use entities::article_note::Model as ArticleNoteModel;
+use entities::article_note::ActiveModel as ArticleNoteActiveModel;
+use sea_orm::IntoActiveValue;
+
+use crate::domain::domain_entities::article_note::ArticleNote;
+
+pub struct SeaArticleNoteMapper;
+
+impl SeaArticleNoteMapper {
+    pub fn article_note_to_sea_active_model(note: ArticleNote) -> ArticleNoteActiveModel {
+        ArticleNoteActiveModel {
+            id: note.id().into_active_value(),
+            article_id: note.article_id().into_active_value(),
+            author_id: note.author_id().into_active_value(),
+            parent_id: note.parent_id().into_active_value(),
+            content: note.content().to_owned().into_active_value(),
+            resolved_by: note.resolved_by().into_active_value(),
+            resolved_at: note.resolved_at().into_active_value(),
+            created_at: note.created_at().into_active_value(),
+        }
+    }
+
+    pub fn model_to_article_note(model: ArticleNoteModel) -> ArticleNote {
+        ArticleNote::new_from_existing(
+            model.id,
+            model.article_id,
+            model.author_id,
+            model.parent_id,
+            model.content,
+            model.resolved_by,
+            model.resolved_at,
+            model.created_at
+        )
+    }
+}
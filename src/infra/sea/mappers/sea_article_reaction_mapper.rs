@@ -0,0 +1,34 @@
+use std::str::FromStr;
+
+use entities::article_reaction::Model as ArticleReactionModel;
+use entities::article_reaction::ActiveModel as ArticleReactionActiveModel;
+use sea_orm::IntoActiveValue;
+
+use crate::domain::domain_entities::article_reaction::{ArticleReaction, ReactionKind};
+
+pub struct SeaArticleReactionMapper;
+
+impl SeaArticleReactionMapper {
+    pub fn article_reaction_to_sea_active_model(reaction: ArticleReaction) -> ArticleReactionActiveModel {
+        ArticleReactionActiveModel {
+            article_id: reaction.article_id().into_active_value(),
+            user_id: reaction.user_id().into_active_value(),
+            reaction: reaction.kind().as_str().to_owned().into_active_value(),
+            created_at: reaction.created_at().into_active_value(),
+            updated_at: reaction.updated_at().into_active_value(),
+        }
+    }
+
+    /// Returns `None` for reactions whose kind is no longer supported.
+    pub fn model_to_article_reaction(model: ArticleReactionModel) -> Option<ArticleReaction> {
+        let kind = ReactionKind::from_str(&model.reaction).ok()?;
+
+        Some(ArticleReaction::new_from_existing(
+            model.article_id,
+            model.user_id,
+            kind,
+            model.created_at,
+            model.updated_at
+        ))
+    }
+}
@@ -10,7 +10,8 @@ impl SeaArticleTagMapper {
     pub fn article_tag_to_sea_model(tag: ArticleTag) -> ArticleTagModel {
         return ArticleTagModel {
             id: tag.id(),
-            value: tag.value().to_owned()
+            value: tag.value().to_owned(),
+            version: tag.version()
         };
     }
 
@@ -25,13 +26,15 @@ impl SeaArticleTagMapper {
         return ArticleTagActiveModel {
             id: tag.id().into_active_value(),
             value: tag.value().to_owned().into_active_value(),
+            version: tag.version().into_active_value(),
         };
     }
 
     pub fn active_model_to_article_tag(active_model_tag: ArticleTagActiveModel) -> ArticleTag {
         return ArticleTag::new_from_existing(
             active_model_tag.id.unwrap(),
-            active_model_tag.value.unwrap()
+            active_model_tag.value.unwrap(),
+            active_model_tag.version.unwrap()
         );
     }
 
@@ -39,6 +42,7 @@ impl SeaArticleTagMapper {
         return ArticleTag::new_from_existing(
             model_tag.id,
             model_tag.value,
+            model_tag.version,
         );
     }
 }
\ No newline at end of file
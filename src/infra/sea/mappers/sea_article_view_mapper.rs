@@ -0,0 +1,34 @@
+use entities::article_view::Model as ArticleViewModel;
+use entities::article_view::ActiveModel as ArticleViewActiveModel;
+use entities::article_daily_views::Model as ArticleDailyViewsModel;
+use sea_orm::IntoActiveValue;
+
+use crate::domain::domain_entities::article_view::{ArticleDailyViews, ArticleView};
+
+pub struct SeaArticleViewMapper;
+
+impl SeaArticleViewMapper {
+    pub fn article_view_to_sea_active_model(article_view: ArticleView) -> ArticleViewActiveModel {
+        ArticleViewActiveModel {
+            article_id: article_view.article_id().into_active_value(),
+            viewer_key: article_view.viewer_key().to_owned().into_active_value(),
+            viewed_at: article_view.viewed_at().into_active_value(),
+        }
+    }
+
+    pub fn model_to_article_view(model_article_view: ArticleViewModel) -> ArticleView {
+        ArticleView::new_from_existing(
+            model_article_view.article_id,
+            model_article_view.viewer_key,
+            model_article_view.viewed_at
+        )
+    }
+
+    pub fn model_to_article_daily_views(model_daily_views: ArticleDailyViewsModel) -> ArticleDailyViews {
+        ArticleDailyViews::new_from_existing(
+            model_daily_views.article_id,
+            model_daily_views.day,
+            model_daily_views.views.max(0) as u64
+        )
+    }
+}
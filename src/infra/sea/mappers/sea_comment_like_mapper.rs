@@ -0,0 +1,21 @@
+use entities::comment_like::Model as CommentLikeModel;
+use entities::comment_like::ActiveModel as CommentLikeActiveModel;
+use sea_orm::IntoActiveValue;
+
+use crate::domain::domain_entities::comment_like::CommentLike;
+
+pub struct SeaCommentLikeMapper;
+
+impl SeaCommentLikeMapper {
+    pub fn comment_like_to_sea_active_model(like: CommentLike) -> CommentLikeActiveModel {
+        CommentLikeActiveModel {
+            comment_id: like.comment_id().into_active_value(),
+            user_id: like.user_id().into_active_value(),
+            created_at: like.created_at().into_active_value(),
+        }
+    }
+
+    pub fn model_to_comment_like(model: CommentLikeModel) -> CommentLike {
+        CommentLike::new_from_existing(model.comment_id, model.user_id, model.created_at)
+    }
+}
@@ -15,6 +15,9 @@ impl SeaCommentMapper {
             content: comment.content().into(),
             is_active: comment.is_active(),
             created_at: comment.created_at(),
+            parent_id: comment.parent_id(),
+            edited_at: comment.edited_at(),
+            pinned_at: comment.pinned_at(),
         };
 
         sea_model
@@ -28,6 +31,9 @@ impl SeaCommentMapper {
             content: comment.content().to_string().into_active_value(),
             is_active: comment.is_active().into_active_value(),
             created_at: comment.created_at().into_active_value(),
+            parent_id: comment.parent_id().into_active_value(),
+            edited_at: comment.edited_at().into_active_value(),
+            pinned_at: comment.pinned_at().into_active_value(),
         };
 
         sea_active_model
@@ -41,6 +47,9 @@ impl SeaCommentMapper {
             active_model_comment.content.unwrap(),
             active_model_comment.is_active.unwrap(),
             active_model_comment.created_at.unwrap(),
+            active_model_comment.parent_id.unwrap(),
+            active_model_comment.edited_at.unwrap(),
+            active_model_comment.pinned_at.unwrap(),
         );
 
         comment
@@ -54,6 +63,9 @@ impl SeaCommentMapper {
             model_comment.content.into(),
             model_comment.is_active.into(),
             model_comment.created_at.into(),
+            model_comment.parent_id,
+            model_comment.edited_at,
+            model_comment.pinned_at,
         );
 
         comment
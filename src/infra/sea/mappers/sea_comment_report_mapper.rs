@@ -1,3 +1,5 @@
+use std::str::FromStr;
+
 use entities::comment_report::Model as CommentReportModel;
 use entities::comment_report::ActiveModel as CommentReportActiveModel;
 use sea_orm::IntoActiveValue;
@@ -6,6 +8,7 @@ use crate::domain::domain_entities::comment_report::CommentReport;
 use crate::domain::domain_entities::comment_report::CommentReportIdTrait;
 use crate::domain::domain_entities::comment_report::CommentReportTrait;
 use crate::domain::domain_entities::comment_report::DraftCommentReport;
+use crate::domain::domain_entities::comment_report::ReportReason;
 
 pub struct SeaCommentReportMapper {}
 
@@ -14,6 +17,7 @@ impl SeaCommentReportMapper {
         let sea_model = CommentReportModel {
             comment_id: comment_report.comment_id(),
             created_at: comment_report.created_at(),
+            reason: comment_report.reason().as_str().to_owned(),
             message: comment_report.message(),
             solved_by: comment_report.solved_by(),
             user_id: comment_report.user_id(),
@@ -27,6 +31,7 @@ impl SeaCommentReportMapper {
         let sea_active_model = CommentReportActiveModel {
             user_id: comment_report.user_id().into_active_value(),
             comment_id: comment_report.comment_id().into_active_value(),
+            reason: comment_report.reason().as_str().to_owned().into_active_value(),
             message: comment_report.message().into_active_value(),
             solved_by: comment_report.solved_by().into_active_value(),
             created_at: comment_report.created_at().into_active_value(),
@@ -40,6 +45,7 @@ impl SeaCommentReportMapper {
         let sea_active_model = CommentReportActiveModel {
             user_id: comment_report.user_id().into_active_value(),
             comment_id: comment_report.comment_id().into_active_value(),
+            reason: comment_report.reason().as_str().to_owned().into_active_value(),
             message: comment_report.message().into_active_value(),
             solved_by: comment_report.solved_by().into_active_value(),
             created_at: comment_report.created_at().into_active_value(),
@@ -54,6 +60,7 @@ impl SeaCommentReportMapper {
             active_model_comment_report.id.unwrap(),
             active_model_comment_report.comment_id.unwrap(),
             active_model_comment_report.user_id.unwrap(),
+            Self::parse_reason(&active_model_comment_report.reason.unwrap()),
             active_model_comment_report.message.unwrap(),
             active_model_comment_report.solved_by.unwrap(),
             active_model_comment_report.created_at.unwrap(),
@@ -67,6 +74,7 @@ impl SeaCommentReportMapper {
             model_comment_report.id.into(),
             model_comment_report.comment_id.into(),
             model_comment_report.user_id.into(),
+            Self::parse_reason(&model_comment_report.reason),
             model_comment_report.message.into(),
             model_comment_report.solved_by.into(),
             model_comment_report.created_at.into(),
@@ -74,4 +82,9 @@ impl SeaCommentReportMapper {
 
         comment_report
     }
+
+    /// Reasons that are no longer supported are read as `Other`, the message still tells what happened.
+    fn parse_reason(reason: &str) -> ReportReason {
+        ReportReason::from_str(reason).unwrap_or(ReportReason::Other)
+    }
 }
\ No newline at end of file
@@ -0,0 +1,27 @@
+use entities::comment_revision::Model as CommentRevisionModel;
+use entities::comment_revision::ActiveModel as CommentRevisionActiveModel;
+use sea_orm::IntoActiveValue;
+
+use crate::domain::domain_entities::comment_revision::CommentRevision;
+
+pub struct SeaCommentRevisionMapper;
+
+impl SeaCommentRevisionMapper {
+    pub fn comment_revision_to_sea_active_model(revision: CommentRevision) -> CommentRevisionActiveModel {
+        CommentRevisionActiveModel {
+            id: revision.id().into_active_value(),
+            comment_id: revision.comment_id().into_active_value(),
+            content: revision.content().to_owned().into_active_value(),
+            revised_at: revision.revised_at().into_active_value(),
+        }
+    }
+
+    pub fn model_to_comment_revision(model: CommentRevisionModel) -> CommentRevision {
+        CommentRevision::new_from_existing(
+            model.id,
+            model.comment_id,
+            model.content,
+            model.revised_at
+        )
+    }
+}
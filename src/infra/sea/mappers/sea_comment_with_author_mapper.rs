@@ -8,7 +8,7 @@ use super::sea_user_mapper::SeaUserMapper;
 pub struct SeaCommentWithAuthorMapper {}
 
 impl SeaCommentWithAuthorMapper {
-    pub fn model_to_comment_with_author(models: (CommentModel, UserModel)) -> CommentWithAuthor {
+    pub fn model_to_comment_with_author(models: (CommentModel, UserModel), reply_count: u64) -> CommentWithAuthor {
         let (model_comment, model_user) = models;
 
         let domain_author = SeaUserMapper::model_to_user(model_user);
@@ -20,6 +20,10 @@ impl SeaCommentWithAuthorMapper {
             model_comment.is_active,
             model_comment.created_at,
             domain_author,
+            model_comment.parent_id,
+            reply_count,
+            model_comment.edited_at,
+            model_comment.pinned_at,
         );
 
         comment_with_author
@@ -16,6 +16,7 @@ impl SeaFreeBadgeMapper {
             link_is_external: free_badge.link_is_external(),
             created_at: free_badge.created_at(),
             available_until: free_badge.available_until(),
+            version: free_badge.version(),
         }
     }
 
@@ -28,6 +29,7 @@ impl SeaFreeBadgeMapper {
             link_is_external: free_badge.link_is_external().into_active_value(),
             created_at: free_badge.created_at().into_active_value(),
             available_until: free_badge.available_until().into_active_value(),
+            version: free_badge.version().into_active_value(),
         }
     }
 
@@ -40,6 +42,7 @@ impl SeaFreeBadgeMapper {
             active_model_free_badge.link_is_external.unwrap(),
             active_model_free_badge.created_at.unwrap(),
             active_model_free_badge.available_until.unwrap(),
+            active_model_free_badge.version.unwrap(),
         )
     }
 
@@ -51,7 +54,8 @@ impl SeaFreeBadgeMapper {
             model_free_badge.link.into(),
             model_free_badge.link_is_external.into(),
             model_free_badge.created_at.into(),
-            model_free_badge.available_until.into()
+            model_free_badge.available_until.into(),
+            model_free_badge.version
         )
     }
 }
\ No newline at end of file
@@ -0,0 +1,32 @@
+use entities::mention::Model as MentionModel;
+use entities::mention::ActiveModel as MentionActiveModel;
+use sea_orm::IntoActiveValue;
+
+use crate::domain::domain_entities::mention::Mention;
+
+pub struct SeaMentionMapper;
+
+impl SeaMentionMapper {
+    pub fn mention_to_sea_active_model(mention: Mention) -> MentionActiveModel {
+        MentionActiveModel {
+            id: mention.id().into_active_value(),
+            user_id: mention.user_id().into_active_value(),
+            mentioned_by: mention.mentioned_by().into_active_value(),
+            article_id: mention.article_id().into_active_value(),
+            comment_id: mention.comment_id().into_active_value(),
+            created_at: mention.created_at().into_active_value(),
+        }
+    }
+
+    pub fn model_to_mention(model: MentionModel, nickname: Option<String>) -> Mention {
+        Mention::new_from_existing(
+            model.id,
+            model.user_id,
+            model.mentioned_by,
+            model.article_id,
+            model.comment_id,
+            model.created_at,
+            nickname
+        )
+    }
+}
@@ -16,6 +16,7 @@ impl SeaTeamUserMapper {
             twitter: team_user.twitter(),
             discord: team_user.discord(),
             created_at: team_user.created_at(),
+            version: team_user.version(),
         };
 
         sea_model
@@ -30,6 +31,7 @@ impl SeaTeamUserMapper {
             twitter: team_user.twitter().into_active_value(),
             discord: team_user.discord().into_active_value(),
             created_at: team_user.created_at().into_active_value(),
+            version: team_user.version().into_active_value(),
         };
 
         sea_active_model
@@ -44,6 +46,7 @@ impl SeaTeamUserMapper {
             active_model_team_user.twitter.unwrap(),
             active_model_team_user.discord.unwrap(),
             active_model_team_user.created_at.unwrap(),
+            active_model_team_user.version.unwrap(),
         );
 
         team_user
@@ -58,6 +61,7 @@ impl SeaTeamUserMapper {
             model_team_user.twitter.into(),
             model_team_user.discord.into(),
             model_team_user.created_at.into(),
+            model_team_user.version,
         );
 
         team_user
@@ -21,7 +21,8 @@ impl SeaUserMapper {
             password: user.password().to_string(),
             role,
             created_at: user.created_at(),
-            last_login: user.last_login()
+            last_login: user.last_login(),
+            version: user.version()
         };
 
         sea_model
@@ -39,7 +40,8 @@ impl SeaUserMapper {
             password: user.password().to_string().into_active_value(),
             role: sea_orm::ActiveValue::Set(role),
             created_at: user.created_at().into_active_value(),
-            last_login: user.last_login().into_active_value()
+            last_login: user.last_login().into_active_value(),
+            version: user.version().into_active_value()
         };
 
         sea_active_model
@@ -60,6 +62,7 @@ impl SeaUserMapper {
             active_model_user.created_at.unwrap(),
             active_model_user.last_login.unwrap(),
             role,
+            active_model_user.version.unwrap(),
         );
 
         user
@@ -80,6 +83,7 @@ impl SeaUserMapper {
             model_user.created_at.to_owned(),
             model_user.last_login.to_owned(),
             role,
+            model_user.version,
         );
 
         user
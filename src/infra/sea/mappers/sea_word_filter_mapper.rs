@@ -0,0 +1,36 @@
+use std::str::FromStr;
+
+use entities::word_filter::Model as WordFilterModel;
+use entities::word_filter::ActiveModel as WordFilterActiveModel;
+use sea_orm::IntoActiveValue;
+
+use crate::domain::domain_entities::word_filter::{WordFilter, WordFilterAction};
+
+pub struct SeaWordFilterMapper;
+
+impl SeaWordFilterMapper {
+    pub fn word_filter_to_sea_active_model(word_filter: WordFilter) -> WordFilterActiveModel {
+        WordFilterActiveModel {
+            id: word_filter.id().into_active_value(),
+            pattern: word_filter.pattern().to_owned().into_active_value(),
+            is_regex: word_filter.is_regex().into_active_value(),
+            action: word_filter.action().as_str().to_owned().into_active_value(),
+            created_at: word_filter.created_at().into_active_value(),
+            updated_at: word_filter.updated_at().into_active_value(),
+            version: word_filter.version().into_active_value(),
+        }
+    }
+
+    pub fn model_to_word_filter(model: WordFilterModel) -> WordFilter {
+        WordFilter::new_from_existing(
+            model.id,
+            model.pattern,
+            model.is_regex,
+            // only actions written by the mapper above are stored, rejecting is the safest fallback
+            WordFilterAction::from_str(&model.action).unwrap_or(WordFilterAction::Reject),
+            model.created_at,
+            model.updated_at,
+            model.version,
+        )
+    }
+}
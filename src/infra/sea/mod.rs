@@ -1,3 +1,5 @@
 pub mod repositories;
 pub mod sea_service;
-pub mod mappers;
\ No newline at end of file
+pub mod mappers;
+pub mod versioning;
+pub mod keyset;
\ No newline at end of file
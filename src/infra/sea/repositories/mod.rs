@@ -1,10 +1,19 @@
 pub mod sea_article_repository;
+pub mod sea_article_view_repository;
+pub mod sea_article_reaction_repository;
+pub mod sea_comment_like_repository;
+pub mod sea_article_coauthor_repository;
+pub mod sea_article_edit_lock_repository;
+pub mod sea_article_note_repository;
 pub mod sea_user_repository;
 pub mod sea_comment_repository;
 pub mod sea_article_comment_repository;
 pub mod sea_comment_report_repository;
 pub mod sea_comment_user_article_repository;
+pub mod sea_comment_revision_repository;
+pub mod sea_mention_repository;
 pub mod sea_team_role_repository;
 pub mod sea_team_user_repository;
 pub mod sea_article_tag_repository;
-pub mod sea_free_badge_repository;
\ No newline at end of file
+pub mod sea_free_badge_repository;
+pub mod sea_word_filter_repository;
@@ -0,0 +1,68 @@
+use std::error::Error;
+
+use async_trait::async_trait;
+use sea_orm::{ColumnTrait, EntityTrait, QueryFilter, QueryOrder, TransactionTrait};
+use uuid::Uuid;
+
+use crate::domain::domain_entities::article_coauthor::ArticleCoauthor;
+use crate::domain::repositories::article_coauthor_repository::ArticleCoauthorRepositoryTrait;
+use crate::infra::sea::mappers::sea_article_coauthor_mapper::SeaArticleCoauthorMapper;
+use crate::infra::sea::sea_service::SeaService;
+
+use entities::article_coauthor::Entity as ArticleCoauthorEntity;
+use entities::article_coauthor::Column as ArticleCoauthorColumn;
+
+pub struct SeaArticleCoauthorRepository {
+    sea_service: SeaService,
+}
+
+impl SeaArticleCoauthorRepository {
+    // constructor
+    pub async fn new(service: SeaService) -> Self {
+        SeaArticleCoauthorRepository {
+            sea_service: service,
+        }
+    }
+}
+
+#[async_trait]
+impl ArticleCoauthorRepositoryTrait for SeaArticleCoauthorRepository {
+    async fn find_by_article(&self, article_id: Uuid) -> Result<Vec<ArticleCoauthor>, Box<dyn Error>> {
+        let coauthors = ArticleCoauthorEntity::find()
+        .filter(ArticleCoauthorColumn::ArticleId.eq(article_id))
+        .order_by_asc(ArticleCoauthorColumn::Position)
+        .all(&self.sea_service.db)
+        .await?;
+
+        Ok(coauthors.into_iter().map(SeaArticleCoauthorMapper::model_to_article_coauthor).collect())
+    }
+
+    async fn find_by_user(&self, article_id: Uuid, user_id: Uuid) -> Result<Option<ArticleCoauthor>, Box<dyn Error>> {
+        let coauthor = ArticleCoauthorEntity::find_by_id((article_id, user_id))
+        .one(&self.sea_service.db)
+        .await?;
+
+        Ok(coauthor.map(SeaArticleCoauthorMapper::model_to_article_coauthor))
+    }
+
+    async fn replace(&self, article_id: Uuid, coauthors: Vec<ArticleCoauthor>) -> Result<Vec<ArticleCoauthor>, Box<dyn Error>> {
+        let transaction = self.sea_service.db.begin().await?;
+
+        ArticleCoauthorEntity::delete_many()
+        .filter(ArticleCoauthorColumn::ArticleId.eq(article_id))
+        .exec(&transaction)
+        .await?;
+
+        if !coauthors.is_empty() {
+            ArticleCoauthorEntity::insert_many(
+                coauthors.iter().cloned().map(SeaArticleCoauthorMapper::article_coauthor_to_sea_active_model)
+            )
+            .exec(&transaction)
+            .await?;
+        }
+
+        transaction.commit().await?;
+
+        Ok(coauthors)
+    }
+}
@@ -0,0 +1,70 @@
+use std::error::Error;
+
+use async_trait::async_trait;
+use migration::OnConflict;
+use sea_orm::EntityTrait;
+use uuid::Uuid;
+
+use crate::domain::domain_entities::article_edit_lock::ArticleEditLock;
+use crate::domain::repositories::article_edit_lock_repository::ArticleEditLockRepositoryTrait;
+use crate::infra::sea::mappers::sea_article_edit_lock_mapper::SeaArticleEditLockMapper;
+use crate::infra::sea::sea_service::SeaService;
+
+use entities::article_edit_lock::Entity as ArticleEditLockEntity;
+use entities::article_edit_lock::Column as ArticleEditLockColumn;
+use entities::user::Entity as UserEntity;
+
+pub struct SeaArticleEditLockRepository {
+    sea_service: SeaService,
+}
+
+impl SeaArticleEditLockRepository {
+    // constructor
+    pub async fn new(service: SeaService) -> Self {
+        SeaArticleEditLockRepository {
+            sea_service: service,
+        }
+    }
+}
+
+#[async_trait]
+impl ArticleEditLockRepositoryTrait for SeaArticleEditLockRepository {
+    async fn find_by_article(&self, article_id: Uuid) -> Result<Option<ArticleEditLock>, Box<dyn Error>> {
+        let lock = ArticleEditLockEntity::find_by_id(article_id)
+        .find_also_related(UserEntity)
+        .one(&self.sea_service.db)
+        .await?;
+
+        let lock = lock.map(|(lock, holder)| {
+            let (holder_nickname, holder_role) = match holder {
+                Some(holder) => (Some(holder.nickname), holder.role),
+                None => (None, None)
+            };
+
+            SeaArticleEditLockMapper::model_to_article_edit_lock(lock, holder_nickname, holder_role)
+        });
+
+        Ok(lock)
+    }
+
+    async fn save(&self, lock: ArticleEditLock) -> Result<ArticleEditLock, Box<dyn Error>> {
+        ArticleEditLockEntity::insert(SeaArticleEditLockMapper::article_edit_lock_to_sea_active_model(lock.clone()))
+        .on_conflict(
+            OnConflict::column(ArticleEditLockColumn::ArticleId)
+            .update_columns([ArticleEditLockColumn::UserId, ArticleEditLockColumn::AcquiredAt, ArticleEditLockColumn::ExpiresAt])
+            .to_owned()
+        )
+        .exec(&self.sea_service.db)
+        .await?;
+
+        Ok(lock)
+    }
+
+    async fn delete(&self, article_id: Uuid) -> Result<(), Box<dyn Error>> {
+        ArticleEditLockEntity::delete_by_id(article_id)
+        .exec(&self.sea_service.db)
+        .await?;
+
+        Ok(())
+    }
+}
@@ -0,0 +1,61 @@
+use std::error::Error;
+
+use async_trait::async_trait;
+use sea_orm::{ActiveModelTrait, ColumnTrait, EntityTrait, QueryFilter, QueryOrder};
+use uuid::Uuid;
+
+use crate::domain::domain_entities::article_note::ArticleNote;
+use crate::domain::repositories::article_note_repository::ArticleNoteRepositoryTrait;
+use crate::infra::sea::mappers::sea_article_note_mapper::SeaArticleNoteMapper;
+use crate::infra::sea::sea_service::SeaService;
+
+use entities::article_note::Entity as ArticleNoteEntity;
+use entities::article_note::Column as ArticleNoteColumn;
+
+pub struct SeaArticleNoteRepository {
+    sea_service: SeaService,
+}
+
+impl SeaArticleNoteRepository {
+    // constructor
+    pub async fn new(service: SeaService) -> Self {
+        SeaArticleNoteRepository {
+            sea_service: service,
+        }
+    }
+}
+
+#[async_trait]
+impl ArticleNoteRepositoryTrait for SeaArticleNoteRepository {
+    async fn create(&self, note: ArticleNote) -> Result<ArticleNote, Box<dyn Error>> {
+        let active_model = SeaArticleNoteMapper::article_note_to_sea_active_model(note);
+        let model = active_model.insert(&self.sea_service.db).await?;
+
+        Ok(SeaArticleNoteMapper::model_to_article_note(model))
+    }
+
+    async fn save(&self, note: ArticleNote) -> Result<ArticleNote, Box<dyn Error>> {
+        let active_model = SeaArticleNoteMapper::article_note_to_sea_active_model(note);
+        let model = active_model.update(&self.sea_service.db).await?;
+
+        Ok(SeaArticleNoteMapper::model_to_article_note(model))
+    }
+
+    async fn find_by_id(&self, id: Uuid) -> Result<Option<ArticleNote>, Box<dyn Error>> {
+        let note = ArticleNoteEntity::find_by_id(id)
+        .one(&self.sea_service.db)
+        .await?;
+
+        Ok(note.map(SeaArticleNoteMapper::model_to_article_note))
+    }
+
+    async fn find_many_by_article(&self, article_id: Uuid) -> Result<Vec<ArticleNote>, Box<dyn Error>> {
+        let notes = ArticleNoteEntity::find()
+        .filter(ArticleNoteColumn::ArticleId.eq(article_id))
+        .order_by_asc(ArticleNoteColumn::CreatedAt)
+        .all(&self.sea_service.db)
+        .await?;
+
+        Ok(notes.into_iter().map(SeaArticleNoteMapper::model_to_article_note).collect())
+    }
+}
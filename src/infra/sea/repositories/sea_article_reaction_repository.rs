@@ -0,0 +1,92 @@
+use std::collections::HashMap;
+use std::error::Error;
+use std::str::FromStr;
+
+use async_trait::async_trait;
+use migration::OnConflict;
+use sea_orm::{ActiveModelTrait, ColumnTrait, EntityTrait, QueryFilter, QuerySelect};
+use uuid::Uuid;
+
+use crate::domain::domain_entities::article_reaction::{ArticleReaction, ReactionKind};
+use crate::domain::repositories::article_reaction_repository::ArticleReactionRepositoryTrait;
+use crate::infra::sea::mappers::sea_article_reaction_mapper::SeaArticleReactionMapper;
+use crate::infra::sea::sea_service::SeaService;
+
+use entities::article_reaction::Entity as ArticleReactionEntity;
+use entities::article_reaction::Column as ArticleReactionColumn;
+
+pub struct SeaArticleReactionRepository {
+    sea_service: SeaService,
+}
+
+impl SeaArticleReactionRepository {
+    // constructor
+    pub async fn new(service: SeaService) -> Self {
+        SeaArticleReactionRepository {
+            sea_service: service,
+        }
+    }
+}
+
+#[async_trait]
+impl ArticleReactionRepositoryTrait for SeaArticleReactionRepository {
+    async fn create(&self, reaction: ArticleReaction) -> Result<ArticleReaction, Box<dyn Error>> {
+        let active_model = SeaArticleReactionMapper::article_reaction_to_sea_active_model(reaction.clone());
+
+        // a first reaction sent twice at once must not fail on the primary key, the last one sent wins
+        ArticleReactionEntity::insert(active_model)
+        .on_conflict(
+            OnConflict::columns([ArticleReactionColumn::ArticleId, ArticleReactionColumn::UserId])
+            .update_column(ArticleReactionColumn::Reaction)
+            .value(ArticleReactionColumn::UpdatedAt, reaction.created_at())
+            .to_owned()
+        )
+        .exec(&self.sea_service.db)
+        .await?;
+
+        Ok(reaction)
+    }
+
+    async fn save(&self, reaction: ArticleReaction) -> Result<ArticleReaction, Box<dyn Error>> {
+        let active_model = SeaArticleReactionMapper::article_reaction_to_sea_active_model(reaction.clone());
+        active_model.update(&self.sea_service.db).await?;
+
+        Ok(reaction)
+    }
+
+    async fn delete(&self, reaction: ArticleReaction) -> Result<(), Box<dyn Error>> {
+        let active_model = SeaArticleReactionMapper::article_reaction_to_sea_active_model(reaction);
+        active_model.delete(&self.sea_service.db).await?;
+
+        Ok(())
+    }
+
+    async fn find_by_user(&self, article_id: Uuid, user_id: Uuid) -> Result<Option<ArticleReaction>, Box<dyn Error>> {
+        let reaction = ArticleReactionEntity::find_by_id((article_id, user_id))
+        .one(&self.sea_service.db)
+        .await?;
+
+        Ok(reaction.and_then(SeaArticleReactionMapper::model_to_article_reaction))
+    }
+
+    async fn count_by_article(&self, article_id: Uuid) -> Result<HashMap<ReactionKind, u64>, Box<dyn Error>> {
+        let counts = ArticleReactionEntity::find()
+        .select_only()
+        .column(ArticleReactionColumn::Reaction)
+        .column_as(ArticleReactionColumn::UserId.count(), "count")
+        .filter(ArticleReactionColumn::ArticleId.eq(article_id))
+        .group_by(ArticleReactionColumn::Reaction)
+        .into_tuple::<(String, i64)>()
+        .all(&self.sea_service.db)
+        .await?;
+
+        let counts = counts
+        .into_iter()
+        .filter_map(|(reaction, count)| {
+            ReactionKind::from_str(&reaction).ok().map(|kind| (kind, count.max(0) as u64))
+        })
+        .collect();
+
+        Ok(counts)
+    }
+}
@@ -1,19 +1,73 @@
 use async_trait::async_trait;
-use migration::{Expr, Func};
-use sea_orm::{ColumnTrait, PaginatorTrait, QueryOrder, QuerySelect, QueryTrait};
+use migration::{Alias, Expr, Func, IntoCondition, JoinType};
+use sea_orm::{ColumnTrait, DbErr, FromQueryResult, PaginatorTrait, QueryOrder, QueryResult, QuerySelect, QueryTrait, RelationTrait};
 use sea_orm::{ActiveModelTrait, EntityTrait, QueryFilter};
 use uuid::Uuid;
+use chrono::NaiveDateTime as DateTime;
 use std::error::Error;
 
-use crate::core::pagination::PaginationParameters;
+use crate::core::pagination::{CursorPaginationParameters, PaginationParameters};
 use crate::domain::domain_entities::slug::Slug;
-use crate::domain::repositories::article_repository::{ArticleQueryType, ArticleRepositoryTrait, FindManyArticlesResponse};
+use crate::domain::repositories::article_repository::{ApprovedArticleSlug, ArticleArchiveMonth, ArticleQueryType, ArticleRepositoryTrait, ArticleSortOrder, FindManyArticlesByCursorResponse, FindManyArticlesResponse};
 use crate::domain::domain_entities::article::Article;
+use crate::domain::domain_entities::listed_article::ListedArticle;
+use crate::infra::sea::keyset::{into_cursor_page, walk_from_cursor};
 use crate::infra::sea::mappers::sea_article_mapper::SeaArticleMapper;
 use crate::infra::sea::sea_service::SeaService;
+use crate::infra::sea::versioning::map_versioned_update_error;
+use crate::libs::time::TimeHelper;
 
 use entities::article::Entity as ArticleEntity;
 use entities::article::Column as ArticleColumn;
+use entities::article::Model as ArticleModel;
+use entities::article::Relation as ArticleRelation;
+use entities::article_edit_lock::Entity as ArticleEditLockEntity;
+use entities::article_edit_lock::Column as ArticleEditLockColumn;
+use entities::article_edit_lock::Model as ArticleEditLockModel;
+use entities::article_edit_lock::Relation as ArticleEditLockRelation;
+use entities::comment::Column as CommentColumn;
+use entities::sea_orm_active_enums::Role as RoleModel;
+use entities::user::Entity as UserEntity;
+use entities::user::Column as UserColumn;
+
+/**
+ A row of the listing query: the article along with its author's summary, its active comments count
+ and its edit lock, if not expired, with the lock holder's nickname.
+ */
+struct ListedArticleRow {
+    article: ArticleModel,
+    author_nickname: String,
+    author_role: Option<RoleModel>,
+    comment_count: i64,
+    edit_lock: Option<(ArticleEditLockModel, String)>,
+}
+
+impl FromQueryResult for ListedArticleRow {
+    fn from_query_result(res: &QueryResult, pre: &str) -> Result<Self, DbErr> {
+        let article = ArticleModel::from_query_result(res, pre)?;
+
+        let lock_user_id: Option<Uuid> = res.try_get(pre, "lock_user_id")?;
+        let lock_acquired_at: Option<DateTime> = res.try_get(pre, "lock_acquired_at")?;
+        let lock_expires_at: Option<DateTime> = res.try_get(pre, "lock_expires_at")?;
+        let lock_holder_nickname: Option<String> = res.try_get(pre, "lock_holder_nickname")?;
+
+        let edit_lock = match (lock_user_id, lock_acquired_at, lock_expires_at, lock_holder_nickname) {
+            (Some(user_id), Some(acquired_at), Some(expires_at), Some(holder_nickname)) => Some((
+                ArticleEditLockModel { article_id: article.id, user_id, acquired_at, expires_at },
+                holder_nickname
+            )),
+            _ => None
+        };
+
+        Ok(ListedArticleRow {
+            article,
+            author_nickname: res.try_get(pre, "author_nickname")?,
+            author_role: res.try_get(pre, "author_role")?,
+            comment_count: res.try_get(pre, "comment_count")?,
+            edit_lock,
+        })
+    }
+}
 
 pub struct SeaArticleRepository {
     sea_service: SeaService,
@@ -65,7 +119,7 @@ impl ArticleRepositoryTrait for SeaArticleRepository {
         Ok(Some(mapped_article))
     }
 
-    async fn find_many(&self, params: PaginationParameters<ArticleQueryType>, show_only_approved_state: Option<bool>) -> Result<FindManyArticlesResponse, Box<dyn Error>> {
+    async fn find_many(&self, params: PaginationParameters<Vec<ArticleQueryType>>, show_only_approved_state: Option<bool>, sort: ArticleSortOrder) -> Result<FindManyArticlesResponse, Box<dyn Error>> {
         #[allow(unused_mut)]
         let mut articles_response;
 
@@ -74,29 +128,74 @@ impl ArticleRepositoryTrait for SeaArticleRepository {
 
         let leap = (&current_page - 1) * items_per_page;
 
-        articles_response = ArticleEntity::find()
-        .order_by_desc(ArticleColumn::CreatedAt)
-        .apply_if(params.clone().query, |#[allow(unused_mut)] mut query_builder, query| self.find_many_get_filters(query_builder, query))
+        let query_builder = match sort {
+            ArticleSortOrder::Newest => Self::listing_query().order_by_desc(ArticleColumn::CreatedAt),
+            ArticleSortOrder::Oldest => Self::listing_query().order_by_asc(ArticleColumn::CreatedAt),
+            ArticleSortOrder::ShortestReadingTime => Self::listing_query().order_by_asc(ArticleColumn::WordCount).order_by_desc(ArticleColumn::CreatedAt),
+            ArticleSortOrder::LongestReadingTime => Self::listing_query().order_by_desc(ArticleColumn::WordCount).order_by_desc(ArticleColumn::CreatedAt),
+        };
+
+        articles_response = query_builder
+        .apply_if(params.clone().query, |query_builder, filters| self.apply_filters(query_builder, filters))
         .apply_if(show_only_approved_state, |query_builder, approved| query_builder.filter(ArticleColumn::Approved.eq(approved)))
         .limit(items_per_page)
         .offset(leap)
+        .into_model::<ListedArticleRow>()
         .all(&self.sea_service.db).await?;
 
         let articles_count = ArticleEntity::find()
-        .apply_if(params.query, |#[allow(unused_mut)] mut query_builder, query| self.find_many_get_filters(query_builder, query))
+        .apply_if(params.query, |query_builder, filters| self.apply_filters(query_builder, filters))
         .apply_if(show_only_approved_state, |query_builder, approved| query_builder.filter(ArticleColumn::Approved.eq(approved)))
         .offset(leap)
         .count(&self.sea_service.db).await?;
 
-        let mut articles: Vec<Article> = vec![];
+        let mut articles: Vec<ListedArticle> = vec![];
 
-        for article in articles_response.into_iter() {
-            articles.push(SeaArticleMapper::model_to_article(article));
+        for row in articles_response.into_iter() {
+            articles.push(SeaArticleMapper::model_to_listed_article(row.article, row.author_nickname, row.author_role, row.comment_count, row.edit_lock));
         }
 
         Ok(FindManyArticlesResponse(articles, articles_count))
     }
 
+    async fn find_all_by_filters(&self, filters: Vec<ArticleQueryType>, show_only_approved_state: Option<bool>, limit: u64) -> Result<Vec<ListedArticle>, Box<dyn Error>> {
+        let articles_response = self.apply_filters(Self::listing_query(), filters)
+        .apply_if(show_only_approved_state, |query_builder, approved| query_builder.filter(ArticleColumn::Approved.eq(approved)))
+        .order_by_asc(ArticleColumn::CreatedAt)
+        .limit(limit)
+        .into_model::<ListedArticleRow>()
+        .all(&self.sea_service.db).await?;
+
+        let articles = articles_response
+        .into_iter()
+        .map(|row| SeaArticleMapper::model_to_listed_article(row.article, row.author_nickname, row.author_role, row.comment_count, row.edit_lock))
+        .collect();
+
+        Ok(articles)
+    }
+
+    async fn find_many_by_cursor(&self, params: CursorPaginationParameters<Vec<ArticleQueryType>>, show_only_approved_state: Option<bool>) -> Result<FindManyArticlesByCursorResponse, Box<dyn Error>> {
+        let items_per_page = params.items_per_page as u64;
+
+        let query_builder = Self::listing_query()
+        .apply_if(params.query, |query_builder, filters| self.apply_filters(query_builder, filters))
+        .apply_if(show_only_approved_state, |query_builder, approved| query_builder.filter(ArticleColumn::Approved.eq(approved)));
+
+        let articles_response = walk_from_cursor(query_builder, ArticleColumn::CreatedAt, ArticleColumn::Id, params.cursor.as_ref())
+        .limit(items_per_page + 1)
+        .into_model::<ListedArticleRow>()
+        .all(&self.sea_service.db).await?;
+
+        let (articles_response, has_more) = into_cursor_page(articles_response, items_per_page, params.cursor.as_ref());
+
+        let articles = articles_response
+        .into_iter()
+        .map(|row| SeaArticleMapper::model_to_listed_article(row.article, row.author_nickname, row.author_role, row.comment_count, row.edit_lock))
+        .collect();
+
+        Ok(FindManyArticlesByCursorResponse(articles, has_more))
+    }
+
     async fn get_home_articles(&self) -> Result<Vec<Article>, Box<dyn Error>> {
         let articles = ArticleEntity::find()
         .limit(3)
@@ -115,21 +214,112 @@ impl ArticleRepositoryTrait for SeaArticleRepository {
         Ok(mapped_articles)
     }
 
+    async fn find_approved_slugs(&self) -> Result<Vec<ApprovedArticleSlug>, Box<dyn Error>> {
+        let slugs = ArticleEntity::find()
+        .select_only()
+        .column(ArticleColumn::Slug)
+        .column(ArticleColumn::CreatedAt)
+        .column(ArticleColumn::UpdatedAt)
+        .filter(ArticleColumn::Approved.eq(true))
+        .order_by_desc(ArticleColumn::CreatedAt)
+        .into_tuple::<(String, DateTime, Option<DateTime>)>()
+        .all(&self.sea_service.db)
+        .await?;
+
+        let slugs = slugs
+        .into_iter()
+        .map(|(slug, created_at, updated_at)| ApprovedArticleSlug (Slug::new_from_existing(slug), updated_at.unwrap_or(created_at)))
+        .collect();
+
+        Ok(slugs)
+    }
+
+    async fn count_approved_by_month(&self) -> Result<Vec<ArticleArchiveMonth>, Box<dyn Error>> {
+        let year = Expr::cust("CAST(EXTRACT(YEAR FROM \"article\".\"created_at\") AS integer)");
+        let month = Expr::cust("CAST(EXTRACT(MONTH FROM \"article\".\"created_at\") AS integer)");
+
+        let months = ArticleEntity::find()
+        .select_only()
+        .column_as(year.clone(), "year")
+        .column_as(month.clone(), "month")
+        .column_as(ArticleColumn::Id.count(), "count")
+        .filter(ArticleColumn::Approved.eq(true))
+        .group_by(year)
+        .group_by(month)
+        .order_by_desc(Expr::cust("\"year\""))
+        .order_by_desc(Expr::cust("\"month\""))
+        .into_tuple::<(i32, i32, i64)>()
+        .all(&self.sea_service.db)
+        .await?;
+
+        let months = months
+        .into_iter()
+        .map(|(year, month, count)| ArticleArchiveMonth (year, month as u32, count as u64))
+        .collect();
+
+        Ok(months)
+    }
+
     async fn save(&self, article: Article) -> Result<Article, Box<dyn Error>> {
         let article_id = &article.id().clone();
+        let version = article.version();
 
-        let article = SeaArticleMapper::article_to_sea_active_model(article);
+        let mut article = SeaArticleMapper::article_to_sea_active_model(article);
+        article.version = sea_orm::ActiveValue::Set(version + 1);
 
+        // only updates the row if it wasn't changed since the article was read
         let article = ArticleEntity::update(article.clone())
         .filter(ArticleColumn::Id.eq(*article_id))
+        .filter(ArticleColumn::Version.eq(version))
         .exec(&self.sea_service.db)
-        .await?;
+        .await
+        .map_err(map_versioned_update_error)?;
 
         Ok(SeaArticleMapper::model_to_article(article))
     }
 }
 
 impl SeaArticleRepository {
+    /// Selects the articles joined with their authors and grouped with their active comments, so a listing takes a single query.
+    fn listing_query() -> sea_orm::Select<ArticleEntity> {
+        let lock_holder = Alias::new("lock_holder");
+        let now = TimeHelper::now();
+
+        ArticleEntity::find()
+        .join(JoinType::InnerJoin, ArticleRelation::User.def())
+        .join(
+            JoinType::LeftJoin,
+            ArticleRelation::Comment.def().on_condition(|_article, comment| {
+                Expr::col((comment, CommentColumn::IsActive)).eq(true).into_condition()
+            })
+        )
+        .column_as(Expr::col((UserEntity, UserColumn::Nickname)), "author_nickname")
+        .column_as(Expr::expr(Func::cast_as(Expr::col((UserEntity, UserColumn::Role)), Alias::new("text"))), "author_role")
+        .join(
+            JoinType::LeftJoin,
+            ArticleRelation::ArticleEditLock.def().on_condition(move |_article, lock| {
+                Expr::col((lock, ArticleEditLockColumn::ExpiresAt)).gt(now).into_condition()
+            })
+        )
+        .join_as(JoinType::LeftJoin, ArticleEditLockRelation::User.def(), lock_holder.clone())
+        .column_as(Expr::col((entities::comment::Entity, CommentColumn::Id)).count(), "comment_count")
+        .column_as(Expr::col((ArticleEditLockEntity, ArticleEditLockColumn::UserId)), "lock_user_id")
+        .column_as(Expr::col((ArticleEditLockEntity, ArticleEditLockColumn::AcquiredAt)), "lock_acquired_at")
+        .column_as(Expr::col((ArticleEditLockEntity, ArticleEditLockColumn::ExpiresAt)), "lock_expires_at")
+        .column_as(Expr::col((lock_holder.clone(), UserColumn::Nickname)), "lock_holder_nickname")
+        .group_by(ArticleColumn::Id)
+        .group_by(Expr::col((UserEntity, UserColumn::Id)))
+        .group_by(Expr::col((ArticleEditLockEntity, ArticleEditLockColumn::ArticleId)))
+        .group_by(Expr::col((lock_holder, UserColumn::Id)))
+    }
+
+    /// Narrows the query to the articles matching every one of the filters.
+    fn apply_filters(&self, query_builder: sea_orm::Select<ArticleEntity>, filters: Vec<ArticleQueryType>) -> sea_orm::Select<ArticleEntity> {
+        filters
+        .into_iter()
+        .fold(query_builder, |query_builder, filter| self.find_many_get_filters(query_builder, filter))
+    }
+
     fn find_many_get_filters(&self, #[allow(unused_mut)] mut query_builder: sea_orm::Select<ArticleEntity>, query: ArticleQueryType) -> sea_orm::Select<ArticleEntity> {
         match query {
             ArticleQueryType::Author(content) => {
@@ -142,6 +332,19 @@ impl SeaArticleRepository {
             },
             ArticleQueryType::Tag(tag_id) => {
                 query_builder.filter(ArticleColumn::TagId.eq(tag_id))
+            },
+            ArticleQueryType::ReadingTime(min, max) => {
+                query_builder
+                .apply_if(min, |query_builder, min| query_builder.filter(ArticleColumn::ReadingTime.gte(min as i32)))
+                .apply_if(max, |query_builder, max| query_builder.filter(ArticleColumn::ReadingTime.lte(max as i32)))
+            },
+            ArticleQueryType::CreatedAfter(date) => {
+                query_builder.filter(ArticleColumn::CreatedAt.gte(date))
+            },
+            ArticleQueryType::CreatedBetween(start, end) => {
+                query_builder
+                .filter(ArticleColumn::CreatedAt.gte(start))
+                .filter(ArticleColumn::CreatedAt.lt(end))
             }
         }
     }
@@ -9,6 +9,7 @@ use crate::domain::domain_entities::article_tag::DraftArticleTag;
 use crate::domain::domain_entities::article_tag::ArticleTag;
 use crate::infra::sea::mappers::sea_article_tag_mapper::SeaArticleTagMapper;
 use crate::infra::sea::sea_service::SeaService;
+use crate::infra::sea::versioning::map_versioned_update_error;
 
 use entities::article_tag::Entity as ArticleTagEntity;
 use entities::article_tag::Column as ArticleTagColumn;
@@ -103,16 +104,28 @@ impl ArticleTagRepositoryTrait for SeaArticleTagRepository {
         Ok(FindManyArticleTagsResponse(article_tags, article_tags_count))
     }
 
+    async fn find_all(&self) -> Result<Vec<ArticleTag>, Box<dyn Error>> {
+        let article_tags = ArticleTagEntity::find()
+            .order_by_asc(ArticleTagColumn::Id)
+            .all(&self.sea_service.db).await?;
+
+        Ok(article_tags.into_iter().map(SeaArticleTagMapper::model_to_article_tag).collect())
+    }
+
     async fn save(&self, article_tag: ArticleTag) -> Result<ArticleTag, Box<dyn Error>> {
         let comm_rep_id = article_tag.id();
+        let version = article_tag.version();
 
-        let article_tag = SeaArticleTagMapper::article_tag_to_sea_active_model(article_tag);
+        let mut article_tag = SeaArticleTagMapper::article_tag_to_sea_active_model(article_tag);
+        article_tag.version = sea_orm::ActiveValue::Set(version + 1);
 
         let article_tag = ArticleTagEntity
         ::update(article_tag)
             .filter(ArticleTagColumn::Id.eq(comm_rep_id))
+            .filter(ArticleTagColumn::Version.eq(version))
             .exec(&self.sea_service.db)
-            .await?;
+            .await
+            .map_err(map_versioned_update_error)?;
 
         let article_tag = SeaArticleTagMapper::model_to_article_tag(article_tag);
 
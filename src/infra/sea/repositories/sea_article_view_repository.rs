@@ -0,0 +1,123 @@
+use std::collections::HashMap;
+use std::error::Error;
+
+use async_trait::async_trait;
+use chrono::NaiveDate;
+use migration::{Alias, Expr, Func, OnConflict, SimpleExpr};
+use sea_orm::{ActiveValue, ColumnTrait, EntityTrait, QueryFilter, QuerySelect, TransactionTrait};
+use uuid::Uuid;
+
+use crate::domain::domain_entities::article::Article;
+use crate::domain::domain_entities::article_view::{ArticleDailyViews, ArticleView};
+use crate::domain::repositories::article_view_repository::ArticleViewRepositoryTrait;
+use crate::infra::sea::mappers::sea_article_mapper::SeaArticleMapper;
+use crate::infra::sea::mappers::sea_article_view_mapper::SeaArticleViewMapper;
+use crate::infra::sea::sea_service::SeaService;
+
+use entities::article::Entity as ArticleEntity;
+use entities::article::Column as ArticleColumn;
+use entities::article_view::Entity as ArticleViewEntity;
+use entities::article_view::Column as ArticleViewColumn;
+use entities::article_daily_views::Entity as ArticleDailyViewsEntity;
+use entities::article_daily_views::Column as ArticleDailyViewsColumn;
+use entities::article_daily_views::ActiveModel as ArticleDailyViewsActiveModel;
+
+pub struct SeaArticleViewRepository {
+    sea_service: SeaService,
+}
+
+impl SeaArticleViewRepository {
+    // constructor
+    pub async fn new(service: SeaService) -> Self {
+        SeaArticleViewRepository {
+            sea_service: service,
+        }
+    }
+}
+
+#[async_trait]
+impl ArticleViewRepositoryTrait for SeaArticleViewRepository {
+    async fn find_by_viewer(&self, article_id: Uuid, viewer_key: String) -> Result<Option<ArticleView>, Box<dyn Error>> {
+        let article_view = ArticleViewEntity::find_by_id((article_id, viewer_key))
+        .one(&self.sea_service.db)
+        .await?;
+
+        Ok(article_view.map(SeaArticleViewMapper::model_to_article_view))
+    }
+
+    async fn register(&self, view: ArticleView) -> Result<ArticleView, Box<dyn Error>> {
+        let day = view.viewed_at().date();
+        let article_id = view.article_id();
+
+        let transaction = self.sea_service.db.begin().await?;
+
+        ArticleViewEntity::insert(SeaArticleViewMapper::article_view_to_sea_active_model(view.clone()))
+        .on_conflict(
+            OnConflict::columns([ArticleViewColumn::ArticleId, ArticleViewColumn::ViewerKey])
+            .update_column(ArticleViewColumn::ViewedAt)
+            .to_owned()
+        )
+        .exec(&transaction)
+        .await?;
+
+        ArticleDailyViewsEntity::insert(ArticleDailyViewsActiveModel {
+            article_id: ActiveValue::Set(article_id),
+            day: ActiveValue::Set(day),
+            views: ActiveValue::Set(1),
+        })
+        .on_conflict(
+            OnConflict::columns([ArticleDailyViewsColumn::ArticleId, ArticleDailyViewsColumn::Day])
+            .value(ArticleDailyViewsColumn::Views, Expr::col((ArticleDailyViewsEntity, ArticleDailyViewsColumn::Views)).add(1))
+            .to_owned()
+        )
+        .exec(&transaction)
+        .await?;
+
+        transaction.commit().await?;
+
+        Ok(view)
+    }
+
+    async fn count_views(&self, article_ids: Vec<Uuid>) -> Result<HashMap<Uuid, u64>, Box<dyn Error>> {
+        let views_sum = SimpleExpr::from(Func::cast_as(Func::sum(Expr::col(ArticleDailyViewsColumn::Views)), Alias::new("bigint")));
+
+        let counts = ArticleDailyViewsEntity::find()
+        .select_only()
+        .column(ArticleDailyViewsColumn::ArticleId)
+        .column_as(views_sum, "views")
+        .filter(ArticleDailyViewsColumn::ArticleId.is_in(article_ids))
+        .group_by(ArticleDailyViewsColumn::ArticleId)
+        .into_tuple::<(Uuid, Option<i64>)>()
+        .all(&self.sea_service.db)
+        .await?;
+
+        let counts = counts
+        .into_iter()
+        .map(|(article_id, views)| (article_id, views.unwrap_or(0).max(0) as u64))
+        .collect();
+
+        Ok(counts)
+    }
+
+    async fn find_approved_daily_views_since(&self, since: NaiveDate) -> Result<Vec<(ArticleDailyViews, Article)>, Box<dyn Error>> {
+        let daily_views = ArticleDailyViewsEntity::find()
+        .filter(ArticleDailyViewsColumn::Day.gte(since))
+        .find_also_related(ArticleEntity)
+        .filter(ArticleColumn::Approved.eq(true))
+        .all(&self.sea_service.db)
+        .await?;
+
+        let mut mapped_daily_views = vec![];
+
+        for (daily_views_model, article_model) in daily_views {
+            if let Some(article_model) = article_model {
+                mapped_daily_views.push((
+                    SeaArticleViewMapper::model_to_article_daily_views(daily_views_model),
+                    SeaArticleMapper::model_to_article(article_model)
+                ));
+            }
+        }
+
+        Ok(mapped_daily_views)
+    }
+}
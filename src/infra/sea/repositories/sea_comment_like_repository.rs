@@ -0,0 +1,77 @@
+use std::error::Error;
+
+use async_trait::async_trait;
+use sea_orm::{ActiveModelTrait, ColumnTrait, EntityTrait, PaginatorTrait, QueryFilter, QuerySelect};
+use uuid::Uuid;
+
+use crate::domain::domain_entities::comment_like::CommentLike;
+use crate::domain::repositories::comment_like_repository::CommentLikeRepositoryTrait;
+use crate::infra::sea::mappers::sea_comment_like_mapper::SeaCommentLikeMapper;
+use crate::infra::sea::sea_service::SeaService;
+
+use entities::comment_like::Entity as CommentLikeEntity;
+use entities::comment_like::Column as CommentLikeColumn;
+
+pub struct SeaCommentLikeRepository {
+    sea_service: SeaService,
+}
+
+impl SeaCommentLikeRepository {
+    // constructor
+    pub async fn new(service: SeaService) -> Self {
+        SeaCommentLikeRepository {
+            sea_service: service,
+        }
+    }
+}
+
+#[async_trait]
+impl CommentLikeRepositoryTrait for SeaCommentLikeRepository {
+    async fn create(&self, like: CommentLike) -> Result<CommentLike, Box<dyn Error>> {
+        let active_model = SeaCommentLikeMapper::comment_like_to_sea_active_model(like.clone());
+        active_model.insert(&self.sea_service.db).await?;
+
+        Ok(like)
+    }
+
+    async fn delete(&self, like: CommentLike) -> Result<(), Box<dyn Error>> {
+        let active_model = SeaCommentLikeMapper::comment_like_to_sea_active_model(like);
+        active_model.delete(&self.sea_service.db).await?;
+
+        Ok(())
+    }
+
+    async fn find_by_user(&self, comment_id: Uuid, user_id: Uuid) -> Result<Option<CommentLike>, Box<dyn Error>> {
+        let like = CommentLikeEntity::find_by_id((comment_id, user_id))
+        .one(&self.sea_service.db)
+        .await?;
+
+        Ok(like.map(SeaCommentLikeMapper::model_to_comment_like))
+    }
+
+    async fn count_by_comment(&self, comment_id: Uuid) -> Result<u64, Box<dyn Error>> {
+        let count = CommentLikeEntity::find()
+        .filter(CommentLikeColumn::CommentId.eq(comment_id))
+        .count(&self.sea_service.db)
+        .await?;
+
+        Ok(count)
+    }
+
+    async fn find_liked_comment_ids(&self, user_id: Uuid, comment_ids: Vec<Uuid>) -> Result<Vec<Uuid>, Box<dyn Error>> {
+        if comment_ids.is_empty() {
+            return Ok(vec![]);
+        }
+
+        let liked_comment_ids = CommentLikeEntity::find()
+        .select_only()
+        .column(CommentLikeColumn::CommentId)
+        .filter(CommentLikeColumn::UserId.eq(user_id))
+        .filter(CommentLikeColumn::CommentId.is_in(comment_ids))
+        .into_tuple::<Uuid>()
+        .all(&self.sea_service.db)
+        .await?;
+
+        Ok(liked_comment_ids)
+    }
+}
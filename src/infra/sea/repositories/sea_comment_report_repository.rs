@@ -1,14 +1,17 @@
 use async_trait::async_trait;
 use migration::{Expr, Func};
 use sea_orm::{ColumnTrait, PaginatorTrait, QueryFilter, QueryOrder, QuerySelect, QueryTrait};
-use sea_orm::{ActiveModelTrait, EntityTrait};
+use sea_orm::{ActiveModelTrait, EntityTrait, SqlErr};
 use std::error::Error;
+use uuid::Uuid;
 
-use crate::core::pagination::PaginationParameters;
+use crate::core::pagination::{CursorPaginationParameters, PaginationParameters};
 use crate::domain::domain_entities::comment_report::CommentReportIdTrait;
 use crate::domain::domain_entities::comment_report::DraftCommentReport;
-use crate::domain::repositories::comment_report_repository::{CommentReportQueryType, CommentReportRepositoryTrait, FindManyCommentReportsResponse};
+use crate::domain::repositories::comment_report_repository::{CommentReportQueryType, CommentReportRepositoryTrait, FindManyCommentReportsByCursorResponse, FindManyCommentReportsResponse};
 use crate::domain::domain_entities::comment_report::CommentReport;
+use crate::errors::conflict_error::ConflictError;
+use crate::infra::sea::keyset::{into_cursor_page, walk_from_cursor};
 use crate::infra::sea::mappers::sea_comment_report_mapper::SeaCommentReportMapper;
 use crate::infra::sea::sea_service::SeaService;
 
@@ -35,7 +38,15 @@ impl CommentReportRepositoryTrait for SeaCommentReportRepository {
 
         let db = &self.sea_service.db;
 
-        let created_comment_report = new_comment_report.insert(db).await?;
+        // a single report per user per comment is enforced by a unique index, which also catches concurrent reports
+        let created_comment_report = new_comment_report.insert(db).await.map_err(|err| -> Box<dyn Error> {
+            match err.sql_err() {
+                Some(SqlErr::UniqueConstraintViolation(_)) => Box::new(ConflictError::new_with_message(
+                    "You already reported this comment.".into()
+                )),
+                _ => Box::new(err),
+            }
+        })?;
         let created_comment_report = SeaCommentReportMapper::model_to_comment_report(created_comment_report);
 
         Ok(created_comment_report)
@@ -55,6 +66,16 @@ impl CommentReportRepositoryTrait for SeaCommentReportRepository {
         }
     }
 
+    async fn find_by_reporter(&self, comment_id: Uuid, user_id: Uuid) -> Result<Option<CommentReport>, Box<dyn Error>> {
+        let comm_report = CommentReportEntity::find()
+        .filter(CommentReportColumn::CommentId.eq(comment_id))
+        .filter(CommentReportColumn::UserId.eq(user_id))
+        .one(&self.sea_service.db)
+        .await?;
+
+        Ok(comm_report.map(SeaCommentReportMapper::model_to_comment_report))
+    }
+
     async fn find_many(&self, params: PaginationParameters<CommentReportQueryType>) -> Result<FindManyCommentReportsResponse, Box<dyn Error>> {
         let comment_reports_response;
 
@@ -84,6 +105,23 @@ impl CommentReportRepositoryTrait for SeaCommentReportRepository {
         Ok(FindManyCommentReportsResponse(comment_reports, comment_reports_count))
     }
 
+    async fn find_many_by_cursor(&self, params: CursorPaginationParameters<CommentReportQueryType, i32>) -> Result<FindManyCommentReportsByCursorResponse, Box<dyn Error>> {
+        let items_per_page = params.items_per_page as u64;
+
+        let query_builder = CommentReportEntity::find()
+        .apply_if(params.query, |query_builder, query| self.find_many_get_filters(query_builder, query));
+
+        let comment_reports_response = walk_from_cursor(query_builder, CommentReportColumn::CreatedAt, CommentReportColumn::Id, params.cursor.as_ref())
+        .limit(items_per_page + 1)
+        .all(&self.sea_service.db).await?;
+
+        let (comment_reports_response, has_more) = into_cursor_page(comment_reports_response, items_per_page, params.cursor.as_ref());
+
+        let comment_reports = comment_reports_response.into_iter().map(SeaCommentReportMapper::model_to_comment_report).collect();
+
+        Ok(FindManyCommentReportsByCursorResponse(comment_reports, has_more))
+    }
+
     async fn save(&self, comment_report: CommentReport) -> Result<CommentReport, Box<dyn Error>> {
         let comm_rep_id = comment_report.id();
 
@@ -129,6 +167,10 @@ impl SeaCommentReportRepository {
             CommentReportQueryType::Solved(solved) => {                
                 let filter = CommentReportColumn::SolvedBy.is_null().eq(!solved);
                 query_builder.filter(filter)
+            },
+            CommentReportQueryType::Reason(reason) => {
+                let filter = CommentReportColumn::Reason.eq(reason.as_str());
+                query_builder.filter(filter)
             }
         }
     }
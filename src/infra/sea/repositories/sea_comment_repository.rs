@@ -1,16 +1,20 @@
 use std::error::Error;
 
 use async_trait::async_trait;
+use chrono::NaiveDateTime;
 use sea_orm::EntityTrait;
-use sea_orm::{ActiveModelTrait, ModelTrait};
+use sea_orm::{ActiveModelTrait, ColumnTrait, ModelTrait, QueryFilter, QueryOrder, TransactionTrait};
 use uuid::Uuid;
 
 use crate::domain::domain_entities::comment::Comment;
+use crate::domain::domain_entities::comment_revision::CommentRevision;
 use crate::domain::repositories::comment_repository::CommentRepositoryTrait;
 use crate::infra::sea::mappers::sea_comment_mapper::SeaCommentMapper;
+use crate::infra::sea::mappers::sea_comment_revision_mapper::SeaCommentRevisionMapper;
 use crate::infra::sea::sea_service::SeaService;
 
 use entities::comment::Entity as CommentEntity;
+use entities::comment::Column as CommentColumn;
 
 pub struct SeaCommentRepository {
     sea_service: SeaService,
@@ -48,6 +52,28 @@ impl CommentRepositoryTrait for SeaCommentRepository {
         }
     }
 
+    async fn find_many_by_author_since(&self, author_id: Uuid, since: NaiveDateTime) -> Result<Vec<Comment>, Box<dyn Error>> {
+        let comments = CommentEntity::find()
+        .filter(CommentColumn::AuthorId.eq(author_id))
+        .filter(CommentColumn::CreatedAt.gte(since))
+        .order_by_desc(CommentColumn::CreatedAt)
+        .all(&self.sea_service.db)
+        .await?;
+
+        Ok(comments.into_iter().map(SeaCommentMapper::model_to_comment).collect())
+    }
+
+    async fn find_many_pinned_by_article(&self, article_id: Uuid) -> Result<Vec<Comment>, Box<dyn Error>> {
+        let comments = CommentEntity::find()
+        .filter(CommentColumn::ArticleId.eq(article_id))
+        .filter(CommentColumn::PinnedAt.is_not_null())
+        .order_by_asc(CommentColumn::PinnedAt)
+        .all(&self.sea_service.db)
+        .await?;
+
+        Ok(comments.into_iter().map(SeaCommentMapper::model_to_comment).collect())
+    }
+
     async fn delete(&self, comment: Comment) -> Result<(), Box<dyn Error>> {
         let comment = SeaCommentMapper::comment_to_sea_model(comment);
 
@@ -63,4 +89,18 @@ impl CommentRepositoryTrait for SeaCommentRepository {
 
         Ok(SeaCommentMapper::model_to_comment(comment))
     }
+
+    async fn save_with_revision(&self, comment: Comment, revision: CommentRevision) -> Result<Comment, Box<dyn Error>> {
+        let comment = SeaCommentMapper::comment_to_sea_active_model(comment);
+        let revision = SeaCommentRevisionMapper::comment_revision_to_sea_active_model(revision);
+
+        let transaction = self.sea_service.db.begin().await?;
+
+        revision.insert(&transaction).await?;
+        let comment = comment.update(&transaction).await?;
+
+        transaction.commit().await?;
+
+        Ok(SeaCommentMapper::model_to_comment(comment))
+    }
 }
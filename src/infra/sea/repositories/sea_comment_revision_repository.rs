@@ -0,0 +1,39 @@
+use std::error::Error;
+
+use async_trait::async_trait;
+use sea_orm::{ColumnTrait, EntityTrait, QueryFilter, QueryOrder};
+use uuid::Uuid;
+
+use crate::domain::domain_entities::comment_revision::CommentRevision;
+use crate::domain::repositories::comment_revision_repository::CommentRevisionRepositoryTrait;
+use crate::infra::sea::mappers::sea_comment_revision_mapper::SeaCommentRevisionMapper;
+use crate::infra::sea::sea_service::SeaService;
+
+use entities::comment_revision::Entity as CommentRevisionEntity;
+use entities::comment_revision::Column as CommentRevisionColumn;
+
+pub struct SeaCommentRevisionRepository {
+    sea_service: SeaService,
+}
+
+impl SeaCommentRevisionRepository {
+    // constructor
+    pub async fn new(service: SeaService) -> Self {
+        SeaCommentRevisionRepository {
+            sea_service: service,
+        }
+    }
+}
+
+#[async_trait]
+impl CommentRevisionRepositoryTrait for SeaCommentRevisionRepository {
+    async fn find_many_by_comment(&self, comment_id: Uuid) -> Result<Vec<CommentRevision>, Box<dyn Error>> {
+        let revisions = CommentRevisionEntity::find()
+        .filter(CommentRevisionColumn::CommentId.eq(comment_id))
+        .order_by_desc(CommentRevisionColumn::RevisedAt)
+        .all(&self.sea_service.db)
+        .await?;
+
+        Ok(revisions.into_iter().map(SeaCommentRevisionMapper::model_to_comment_revision).collect())
+    }
+}
@@ -1,20 +1,33 @@
+use std::collections::HashMap;
 use std::error::Error;
 
 use async_trait::async_trait;
-use migration::{Expr, Func};
-use sea_orm::{ColumnTrait, EntityTrait, PaginatorTrait, QueryFilter, QueryOrder, QuerySelect, QueryTrait};
+use chrono::NaiveDateTime as DateTime;
+use migration::{Expr, Func, Query, SimpleExpr};
+use sea_orm::{ColumnTrait, Condition, EntityTrait, Order, PaginatorTrait, QueryFilter, QueryOrder, QuerySelect, QueryTrait, Select};
 use uuid::Uuid;
 
-use crate::core::pagination::PaginationParameters;
+use crate::core::pagination::{CursorDirection, CursorPaginationParameters, PaginationCursor, PaginationParameters};
 use crate::domain::domain_entities::comment_with_author::CommentWithAuthor;
-use crate::domain::repositories::comment_user_article_repository::{CommentUserArticleRepositoryTrait, CommentWithAuthorQueryType, FindManyCommentsWithAuthorResponse};
+use crate::domain::domain_entities::mention::Mention;
+use crate::domain::repositories::mention_repository::MentionRepositoryTrait;
+use crate::domain::repositories::comment_user_article_repository::{CommentSortOrder, CommentUserArticleRepositoryTrait, CommentWithAuthorQueryType, FindManyCommentsWithAuthorByCursorResponse, FindManyCommentsWithAuthorResponse};
 use crate::infra::sea::mappers::sea_comment_with_author_mapper::SeaCommentWithAuthorMapper;
+use crate::infra::sea::repositories::sea_mention_repository::SeaMentionRepository;
 use crate::infra::sea::sea_service::SeaService;
 
 use entities::comment::Entity as CommentEntity;
 use entities::comment::Column as CommentColumn;
+use entities::comment::Model as CommentModel;
+
+use entities::comment_like::Entity as CommentLikeEntity;
+use entities::comment_like::Column as CommentLikeColumn;
+
+use entities::article::Entity as ArticleEntity;
+use entities::article::Column as ArticleColumn;
 
 use entities::user::Entity as UserEntity;
+use entities::user::Model as UserModel;
 
 pub struct SeaCommentUserArticleRepository {
     sea_service: SeaService,
@@ -35,6 +48,7 @@ impl CommentUserArticleRepositoryTrait for SeaCommentUserArticleRepository {
         &self,
         article_id: Uuid,
         include_inactive: bool,
+        sort: CommentSortOrder,
         params: PaginationParameters<CommentWithAuthorQueryType>
     ) -> Result<FindManyCommentsWithAuthorResponse, Box<dyn Error>> {
         let current_page = params.page as u64;
@@ -42,47 +56,311 @@ impl CommentUserArticleRepositoryTrait for SeaCommentUserArticleRepository {
 
         let leap = ((&current_page - 1) * items_per_page) as u64;
 
-        let include_inactive = Some(include_inactive);
+        let base_query = self.top_level_comments_query(article_id, include_inactive, params.query);
+
+        // pinned comments come first, in the order they were pinned
+        let pinned_first = base_query.clone()
+        .order_by(Expr::col((CommentEntity, CommentColumn::PinnedAt)).is_null(), Order::Asc)
+        .order_by_asc(CommentColumn::PinnedAt);
+
+        let comments = Self::order_comments(pinned_first, sort, include_inactive, false)
+        .find_also_related(UserEntity)
+        .limit(items_per_page)
+        .offset(leap)
+        .all(&self.sea_service.db)
+        .await?;
+
+        let comments_count = base_query
+        .count(&self.sea_service.db)
+        .await?;
+
+        let mapped_comments = self.map_with_counts_and_mentions(comments, include_inactive).await?;
+
+        Ok(FindManyCommentsWithAuthorResponse (mapped_comments, comments_count))
+    }
+
+    async fn find_many_comments_by_cursor(
+        &self,
+        article_id: Uuid,
+        include_inactive: bool,
+        sort: CommentSortOrder,
+        params: CursorPaginationParameters<CommentWithAuthorQueryType>
+    ) -> Result<FindManyCommentsWithAuthorByCursorResponse, Box<dyn Error>> {
+        let items_per_page = params.items_per_page as u64;
+
+        // pinned comments are left out of the walk and only put on top of the first page
+        let mut query_builder = self.top_level_comments_query(article_id, include_inactive, params.query.clone())
+        .filter(CommentColumn::PinnedAt.is_null());
+
+        let direction = params.cursor.as_ref().map(|cursor| cursor.direction).unwrap_or(CursorDirection::After);
+
+        if let Some(cursor) = &params.cursor {
+            query_builder = query_builder.filter(Self::keyset_filter(cursor, sort, include_inactive));
+        }
+
+        // walking back, the comments are fetched the other way around and put back in order afterwards
+        let reversed = direction == CursorDirection::Before;
+
+        // fetches one extra row to find out whether there is another page, without a COUNT query
+        let mut comments = Self::order_comments(query_builder, sort, include_inactive, reversed)
+        .find_also_related(UserEntity)
+        .limit(items_per_page + 1)
+        .all(&self.sea_service.db)
+        .await?;
+
+        let has_more = comments.len() as u64 > items_per_page;
+        comments.truncate(items_per_page as usize);
 
-        let comments = CommentEntity::find()
-        .apply_if(include_inactive, |query_builder, val| {
+        if reversed {
+            comments.reverse();
+        }
+
+        let is_first_page = params.cursor.is_none() || (reversed && !has_more);
+
+        if is_first_page {
+            let mut pinned_comments = self.top_level_comments_query(article_id, include_inactive, params.query)
+            .filter(CommentColumn::PinnedAt.is_not_null())
+            .order_by_asc(CommentColumn::PinnedAt)
+            .find_also_related(UserEntity)
+            .all(&self.sea_service.db)
+            .await?;
+
+            pinned_comments.append(&mut comments);
+            comments = pinned_comments;
+        }
+
+        let mapped_comments = self.map_with_counts_and_mentions(comments, include_inactive).await?;
+
+        Ok(FindManyCommentsWithAuthorByCursorResponse (mapped_comments, has_more))
+    }
+
+    async fn find_many_replies(
+        &self,
+        parent_id: Uuid,
+        include_inactive: bool,
+        params: PaginationParameters<CommentWithAuthorQueryType>
+    ) -> Result<FindManyCommentsWithAuthorResponse, Box<dyn Error>> {
+        let current_page = params.page as u64;
+        let items_per_page = params.items_per_page as u64;
+
+        let leap = (current_page - 1) * items_per_page;
+
+        let base_query = CommentEntity::find()
+        .filter(CommentColumn::ParentId.eq(parent_id))
+        .apply_if(Some(include_inactive), |query_builder, val| {
             if !val {
                 query_builder.filter(CommentColumn::IsActive.eq(true))
             } else {
                 query_builder
             }
         })
-        .filter(CommentColumn::ArticleId.eq(article_id))
-        .apply_if(params.clone().query, |query_builder, query| self.find_many_get_filters(query_builder, query))
-        .order_by_desc(CommentColumn::CreatedAt)
+        .apply_if(params.query, |query_builder, query| self.find_many_get_filters(query_builder, query));
+
+        let replies_count = base_query.clone()
+        .count(&self.sea_service.db)
+        .await?;
+
+        let replies = base_query
+        .order_by_asc(CommentColumn::CreatedAt)
         .find_also_related(UserEntity)
         .limit(items_per_page)
         .offset(leap)
         .all(&self.sea_service.db)
         .await?;
 
-        let comments_count = CommentEntity::find()
-        .filter(CommentColumn::ArticleId.eq(article_id))
-        .apply_if(params.clone().query, |query_builder, query| self.find_many_get_filters(query_builder, query))
-        .offset(leap)
-        .count(&self.sea_service.db)
+        let mapped_replies = self.map_with_counts_and_mentions(replies, include_inactive).await?;
+
+        Ok(FindManyCommentsWithAuthorResponse (mapped_replies, replies_count))
+    }
+
+    async fn find_most_liked_since(&self, since: DateTime) -> Result<Option<CommentWithAuthor>, Box<dyn Error>> {
+        let likes_since = Expr::cust_with_values(
+            r#"(SELECT COUNT(*) FROM "comment_like" WHERE "comment_like"."comment_id" = "comment"."id" AND "comment_like"."created_at" >= $1)"#,
+            [since]
+        );
+
+        let approved_articles = Query::select()
+        .column(ArticleColumn::Id)
+        .from(ArticleEntity)
+        .and_where(ArticleColumn::Approved.eq(true))
+        .to_owned();
+
+        let comment = CommentEntity::find()
+        .filter(CommentColumn::IsActive.eq(true))
+        .filter(CommentColumn::ArticleId.in_subquery(approved_articles))
+        .filter(Expr::expr(likes_since.clone()).gt(0))
+        .order_by(likes_since, Order::Desc)
+        .order_by_desc(CommentColumn::CreatedAt)
+        .order_by_desc(CommentColumn::Id)
+        .find_also_related(UserEntity)
+        .one(&self.sea_service.db)
         .await?;
 
+        let comment = match comment {
+            Some(comment) => comment,
+            None => return Ok(None),
+        };
+
+        let mapped_comment = self.map_with_counts_and_mentions(vec![comment], false).await?;
+
+        Ok(mapped_comment.into_iter().next())
+    }
+}
+
+impl SeaCommentUserArticleRepository {
+    fn top_level_comments_query(
+        &self,
+        article_id: Uuid,
+        include_inactive: bool,
+        query: Option<CommentWithAuthorQueryType>,
+    ) -> Select<CommentEntity> {
+        CommentEntity::find()
+        .apply_if(Some(include_inactive), |query_builder, val| {
+            if !val {
+                query_builder.filter(CommentColumn::IsActive.eq(true))
+            } else {
+                query_builder
+            }
+        })
+        .filter(CommentColumn::ArticleId.eq(article_id))
+        .filter(CommentColumn::ParentId.is_null())
+        .apply_if(query, |query_builder, query| self.find_many_get_filters(query_builder, query))
+    }
+
+    /// The comment's number of direct replies, counted the same way as `reply_count` is mapped.
+    fn reply_count_expr(include_inactive: bool) -> SimpleExpr {
+        if include_inactive {
+            Expr::cust(r#"(SELECT COUNT(*) FROM "comment" AS "reply" WHERE "reply"."parent_id" = "comment"."id")"#)
+        } else {
+            Expr::cust(r#"(SELECT COUNT(*) FROM "comment" AS "reply" WHERE "reply"."parent_id" = "comment"."id" AND "reply"."is_active")"#)
+        }
+    }
+
+    /// The comment's number of likes.
+    fn like_count_expr() -> SimpleExpr {
+        Expr::cust(r#"(SELECT COUNT(*) FROM "comment_like" WHERE "comment_like"."comment_id" = "comment"."id")"#)
+    }
+
+    /// What the comments are ranked by before `created_at` and `id`, mirroring `CommentSortOrder::rank`.
+    fn rank_expr(sort: CommentSortOrder, include_inactive: bool) -> Option<SimpleExpr> {
+        match sort {
+            CommentSortOrder::Newest | CommentSortOrder::Oldest => None,
+            CommentSortOrder::MostReplied => Some(Self::reply_count_expr(include_inactive)),
+            CommentSortOrder::Top => Some(Self::like_count_expr()),
+        }
+    }
+
+    /// Orders the comments as listed by `sort`, or the other way around if `reversed`.
+    fn order_comments(
+        query_builder: Select<CommentEntity>,
+        sort: CommentSortOrder,
+        include_inactive: bool,
+        reversed: bool,
+    ) -> Select<CommentEntity> {
+        let order = if (sort == CommentSortOrder::Oldest) != reversed { Order::Asc } else { Order::Desc };
+
+        query_builder
+        .apply_if(
+            Self::rank_expr(sort, include_inactive),
+            |query_builder, rank| query_builder.order_by(rank, order.clone())
+        )
+        .order_by(CommentColumn::CreatedAt, order.clone())
+        .order_by(CommentColumn::Id, order)
+    }
+
+    /// Keyset comparison on the sort keys, so that comments sharing the same keys are neither skipped nor repeated.
+    fn keyset_filter(cursor: &PaginationCursor, sort: CommentSortOrder, include_inactive: bool) -> Condition {
+        let walks_up = (cursor.direction == CursorDirection::After) == (sort == CommentSortOrder::Oldest);
+
+        let beyond = |left: Expr, right: sea_orm::Value| if walks_up { left.gt(right) } else { left.lt(right) };
+
+        let time_filter = Condition::any()
+            .add(beyond(Expr::col((CommentEntity, CommentColumn::CreatedAt)), cursor.created_at.into()))
+            .add(Condition::all()
+                .add(CommentColumn::CreatedAt.eq(cursor.created_at))
+                .add(beyond(Expr::col((CommentEntity, CommentColumn::Id)), cursor.id.into())));
+
+        let rank_expr = match Self::rank_expr(sort, include_inactive) {
+            Some(rank_expr) => rank_expr,
+            None => return time_filter,
+        };
+
+        let rank = cursor.rank.unwrap_or_default();
+
+        Condition::any()
+            .add(beyond(Expr::expr(rank_expr.clone()), rank.into()))
+            .add(Condition::all()
+                .add(Expr::expr(rank_expr).eq(rank))
+                .add(time_filter))
+    }
+
+    async fn map_with_counts_and_mentions(
+        &self,
+        comments: Vec<(CommentModel, Option<UserModel>)>,
+        include_inactive: bool,
+    ) -> Result<Vec<CommentWithAuthor>, Box<dyn Error>> {
+        let ids: Vec<Uuid> = comments.iter().map(|(comment, _)| comment.id).collect();
+
+        let mut mentions: HashMap<Uuid, Vec<Mention>> = HashMap::new();
+
+        for mention in SeaMentionRepository::new(self.sea_service.clone()).await.find_many_by_comments(ids.clone()).await? {
+            if let Some(comment_id) = mention.comment_id() {
+                mentions.entry(comment_id).or_default().push(mention);
+            }
+        }
+
+        let reply_counts: HashMap<Uuid, i64> = CommentEntity::find()
+        .select_only()
+        .column(CommentColumn::ParentId)
+        .column_as(CommentColumn::Id.count(), "reply_count")
+        .filter(CommentColumn::ParentId.is_in(ids.clone()))
+        .apply_if(Some(include_inactive), |query_builder, val| {
+            if !val {
+                query_builder.filter(CommentColumn::IsActive.eq(true))
+            } else {
+                query_builder
+            }
+        })
+        .group_by(CommentColumn::ParentId)
+        .into_tuple::<(Uuid, i64)>()
+        .all(&self.sea_service.db)
+        .await?
+        .into_iter()
+        .collect();
+
+        let like_counts: HashMap<Uuid, i64> = CommentLikeEntity::find()
+        .select_only()
+        .column(CommentLikeColumn::CommentId)
+        .column_as(CommentLikeColumn::UserId.count(), "like_count")
+        .filter(CommentLikeColumn::CommentId.is_in(ids))
+        .group_by(CommentLikeColumn::CommentId)
+        .into_tuple::<(Uuid, i64)>()
+        .all(&self.sea_service.db)
+        .await?
+        .into_iter()
+        .collect();
+
         let mut mapped_comments: Vec<CommentWithAuthor> = vec![];
 
         for models in comments {
-            mapped_comments.push(
-                SeaCommentWithAuthorMapper::model_to_comment_with_author(
-                    (models.0, models.1.unwrap())
-                )
-            )
+            let reply_count = reply_counts.get(&models.0.id).copied().unwrap_or(0) as u64;
+            let like_count = like_counts.get(&models.0.id).copied().unwrap_or(0) as u64;
+
+            let comment_mentions = mentions.remove(&models.0.id).unwrap_or_default();
+
+            let mut comment = SeaCommentWithAuthorMapper::model_to_comment_with_author(
+                (models.0, models.1.unwrap()),
+                reply_count,
+            );
+
+            comment.set_mentions(comment_mentions);
+            comment.set_like_count(like_count);
+            mapped_comments.push(comment);
         }
 
-        Ok(FindManyCommentsWithAuthorResponse (mapped_comments, comments_count))
+        Ok(mapped_comments)
     }
-}
 
-impl SeaCommentUserArticleRepository {
     fn find_many_get_filters(&self, query_builder: sea_orm::Select<CommentEntity>, query: CommentWithAuthorQueryType) -> sea_orm::Select<CommentEntity> {
         match query {
             CommentWithAuthorQueryType::Author(content) => {
@@ -1,9 +1,10 @@
 use std::error::Error;
 use async_trait::async_trait;
-use sea_orm::{ActiveModelTrait, EntityTrait, PaginatorTrait,QueryOrder, QuerySelect};
+use sea_orm::{ActiveModelTrait, ColumnTrait, EntityTrait, PaginatorTrait, QueryFilter, QueryOrder, QuerySelect};
 use uuid::Uuid;
 use crate::core::pagination::PaginationParameters;
 use crate::infra::sea::sea_service::SeaService;
+use crate::infra::sea::versioning::map_versioned_update_error;
 use entities::free_badge::Column as FreeBadgeColumn;
 use entities::free_badge::Entity as FreeBadgeEntity;
 use crate::domain::domain_entities::free_badge::FreeBadge;
@@ -34,8 +35,17 @@ impl FreeBadgeRepositoryTrait for SeaFreeBadgeRepository {
     }
 
     async fn save(&self, free_badge: FreeBadge) -> Result<FreeBadge, Box<dyn Error>> {
-        let free_badge = SeaFreeBadgeMapper::free_badge_to_sea_active_model(free_badge);
-        let free_badge = free_badge.update(&self.sea_service.db).await?;
+        let version = free_badge.version();
+
+        let mut free_badge = SeaFreeBadgeMapper::free_badge_to_sea_active_model(free_badge);
+        free_badge.version = sea_orm::ActiveValue::Set(version + 1);
+
+        let free_badge = FreeBadgeEntity::update(free_badge)
+            .filter(FreeBadgeColumn::Version.eq(version))
+            .exec(&self.sea_service.db)
+            .await
+            .map_err(map_versioned_update_error)?;
+
         let free_badge = SeaFreeBadgeMapper::model_to_free_badge(free_badge);
         Ok(free_badge)
     }
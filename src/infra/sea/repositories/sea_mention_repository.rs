@@ -0,0 +1,134 @@
+use std::error::Error;
+
+use async_trait::async_trait;
+use migration::JoinType;
+use sea_orm::{ColumnTrait, EntityTrait, PaginatorTrait, QueryFilter, QueryOrder, QuerySelect, RelationTrait, TransactionTrait};
+use uuid::Uuid;
+
+use crate::core::pagination::{CursorPaginationParameters, PaginationParameters};
+use crate::domain::domain_entities::mention::{Mention, MentionSource};
+use crate::domain::repositories::mention_repository::{FindManyMentionsByCursorResponse, FindManyMentionsResponse, MentionRepositoryTrait};
+use crate::infra::sea::keyset::{into_cursor_page, walk_from_cursor};
+use crate::infra::sea::mappers::sea_mention_mapper::SeaMentionMapper;
+use crate::infra::sea::sea_service::SeaService;
+
+use entities::mention::Entity as MentionEntity;
+use entities::mention::Column as MentionColumn;
+use entities::mention::Relation as MentionRelation;
+use entities::user::Entity as UserEntity;
+
+pub struct SeaMentionRepository {
+    sea_service: SeaService,
+}
+
+impl SeaMentionRepository {
+    // constructor
+    pub async fn new(service: SeaService) -> Self {
+        SeaMentionRepository {
+            sea_service: service,
+        }
+    }
+
+    /// Selects the mentions along with the mentioned users, for their nicknames.
+    fn with_nickname_query() -> sea_orm::SelectTwo<MentionEntity, UserEntity> {
+        MentionEntity::find()
+        .join(JoinType::InnerJoin, MentionRelation::User1.def())
+        .select_also(UserEntity)
+    }
+}
+
+#[async_trait]
+impl MentionRepositoryTrait for SeaMentionRepository {
+    async fn replace_many(&self, source: MentionSource, mentions: Vec<Mention>) -> Result<(), Box<dyn Error>> {
+        let transaction = self.sea_service.db.begin().await?;
+
+        let delete_query = match source {
+            MentionSource::Article(article_id) => MentionEntity::delete_many()
+                .filter(MentionColumn::ArticleId.eq(article_id))
+                .filter(MentionColumn::CommentId.is_null()),
+            MentionSource::Comment { comment_id, .. } => MentionEntity::delete_many()
+                .filter(MentionColumn::CommentId.eq(comment_id)),
+        };
+
+        delete_query.exec(&transaction).await?;
+
+        if !mentions.is_empty() {
+            MentionEntity::insert_many(mentions.into_iter().map(SeaMentionMapper::mention_to_sea_active_model))
+            .exec(&transaction)
+            .await?;
+        }
+
+        transaction.commit().await?;
+
+        Ok(())
+    }
+
+    async fn find_many_by_article(&self, article_id: Uuid) -> Result<Vec<Mention>, Box<dyn Error>> {
+        let mentions = Self::with_nickname_query()
+        .filter(MentionColumn::ArticleId.eq(article_id))
+        .filter(MentionColumn::CommentId.is_null())
+        .all(&self.sea_service.db)
+        .await?;
+
+        Ok(mentions.into_iter().map(|(mention, user)| {
+            SeaMentionMapper::model_to_mention(mention, user.map(|user| user.nickname))
+        }).collect())
+    }
+
+    async fn find_many_by_comments(&self, comment_ids: Vec<Uuid>) -> Result<Vec<Mention>, Box<dyn Error>> {
+        if comment_ids.is_empty() { return Ok(vec![]); }
+
+        let mentions = Self::with_nickname_query()
+        .filter(MentionColumn::CommentId.is_in(comment_ids))
+        .all(&self.sea_service.db)
+        .await?;
+
+        Ok(mentions.into_iter().map(|(mention, user)| {
+            SeaMentionMapper::model_to_mention(mention, user.map(|user| user.nickname))
+        }).collect())
+    }
+
+    async fn find_many_by_user(&self, user_id: Uuid, params: PaginationParameters<()>) -> Result<FindManyMentionsResponse, Box<dyn Error>> {
+        let items_per_page = params.items_per_page as u64;
+        let leap = (params.page as u64 - 1) * items_per_page;
+
+        let total = MentionEntity::find()
+        .filter(MentionColumn::UserId.eq(user_id))
+        .count(&self.sea_service.db)
+        .await?;
+
+        let mentions = Self::with_nickname_query()
+        .filter(MentionColumn::UserId.eq(user_id))
+        .order_by_desc(MentionColumn::CreatedAt)
+        .limit(items_per_page)
+        .offset(leap)
+        .all(&self.sea_service.db)
+        .await?;
+
+        let mentions = mentions.into_iter().map(|(mention, user)| {
+            SeaMentionMapper::model_to_mention(mention, user.map(|user| user.nickname))
+        }).collect();
+
+        Ok(FindManyMentionsResponse (mentions, total))
+    }
+
+    async fn find_many_by_user_by_cursor(&self, user_id: Uuid, params: CursorPaginationParameters<()>) -> Result<FindManyMentionsByCursorResponse, Box<dyn Error>> {
+        let items_per_page = params.items_per_page as u64;
+
+        let query_builder = Self::with_nickname_query()
+        .filter(MentionColumn::UserId.eq(user_id));
+
+        let mentions = walk_from_cursor(query_builder, MentionColumn::CreatedAt, MentionColumn::Id, params.cursor.as_ref())
+        .limit(items_per_page + 1)
+        .all(&self.sea_service.db)
+        .await?;
+
+        let (mentions, has_more) = into_cursor_page(mentions, items_per_page, params.cursor.as_ref());
+
+        let mentions = mentions.into_iter().map(|(mention, user)| {
+            SeaMentionMapper::model_to_mention(mention, user.map(|user| user.nickname))
+        }).collect();
+
+        Ok(FindManyMentionsByCursorResponse (mentions, has_more))
+    }
+}
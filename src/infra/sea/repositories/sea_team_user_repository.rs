@@ -6,6 +6,7 @@ use uuid::Uuid;
 use migration::Expr;
 use migration::Func;
 use sea_orm::{
+    ColumnTrait,
     EntityTrait,
     ActiveModelTrait,
     PaginatorTrait,
@@ -22,6 +23,7 @@ use crate::domain::repositories::team_user_repository::TeamUserQueryType;
 use crate::domain::repositories::team_user_repository::TeamUserRepositoryTrait;
 use crate::infra::sea::mappers::sea_team_user_mapper::SeaTeamUserMapper;
 use crate::infra::sea::sea_service::SeaService;
+use crate::infra::sea::versioning::map_versioned_update_error;
 
 use entities::team_user::Entity as TeamUserEntity;
 use entities::team_user::Column as TeamUserColumn;
@@ -62,9 +64,16 @@ impl TeamUserRepositoryTrait for SeaTeamUserRepository {
     }
 
     async fn save(&self, team_user: TeamUser) -> Result<TeamUser, Box<dyn Error>> {
-        let team_user = SeaTeamUserMapper::team_user_to_sea_active_model(team_user);
+        let version = team_user.version();
+
+        let mut team_user = SeaTeamUserMapper::team_user_to_sea_active_model(team_user);
+        team_user.version = sea_orm::ActiveValue::Set(version + 1);
 
-        let team_user = team_user.update(&self.sea_service.db).await?;
+        let team_user = TeamUserEntity::update(team_user)
+        .filter(TeamUserColumn::Version.eq(version))
+        .exec(&self.sea_service.db)
+        .await
+        .map_err(map_versioned_update_error)?;
 
         Ok(SeaTeamUserMapper::model_to_team_user(team_user))
     }
@@ -4,13 +4,15 @@ use async_trait::async_trait;
 use migration::{Alias, Expr, Func};
 use sea_orm::{ActiveModelTrait, ColumnTrait, EntityTrait, IntoSimpleExpr, PaginatorTrait, QueryFilter, QueryOrder, QuerySelect, QueryTrait};
 use uuid::Uuid;
-use crate::core::pagination::PaginationParameters;
+use crate::core::pagination::{CursorPaginationParameters, PaginationParameters};
+use crate::infra::sea::keyset::{into_cursor_page, walk_from_cursor};
 use crate::infra::sea::mappers::sea_role_mapper::SeaRoleMapper;
 use crate::{domain::repositories::user_repository::UserQueryType, infra::sea::sea_service::SeaService};
 use crate::domain::domain_entities::user::User;
 use crate::infra::sea::mappers::sea_user_mapper::SeaUserMapper;
+use crate::infra::sea::versioning::map_versioned_update_error;
 use entities::user::{Column as UserColumn, Entity as UserEntity};
-use crate::domain::repositories::user_repository::{FindManyUsersResponse, UserRepositoryTrait};
+use crate::domain::repositories::user_repository::{FindManyUsersByCursorResponse, FindManyUsersResponse, UserRepositoryTrait};
 
 pub struct SeaUserRepository {
     pub sea_service: SeaService,
@@ -65,13 +67,17 @@ impl UserRepositoryTrait for SeaUserRepository {
 
     async fn save(&self, user: User) -> Result<User, Box<dyn Error>> {
         let user_id = &user.id().clone();
+        let version = user.version();
 
-        let user = SeaUserMapper::user_to_sea_active_model(user.clone());
+        let mut user = SeaUserMapper::user_to_sea_active_model(user.clone());
+        user.version = sea_orm::ActiveValue::Set(version + 1);
 
         let user = UserEntity::update(user.clone())
         .filter(UserColumn::Id.eq(*user_id))
+        .filter(UserColumn::Version.eq(version))
         .exec(&self.sea_service.db)
-        .await?;
+        .await
+        .map_err(map_versioned_update_error)?;
 
         let user = SeaUserMapper::model_to_user(user);
 
@@ -106,6 +112,23 @@ impl UserRepositoryTrait for SeaUserRepository {
 
         Ok(FindManyUsersResponse(users, users_count))
     }
+
+    async fn find_many_by_cursor(&self, params: CursorPaginationParameters<UserQueryType>) -> Result<FindManyUsersByCursorResponse, Box<dyn Error>> {
+        let items_per_page = params.items_per_page as u64;
+
+        let query_builder = UserEntity::find()
+        .apply_if(params.query, |query_builder, query| self.find_many_get_filters(query_builder, query));
+
+        let users_response = walk_from_cursor(query_builder, UserColumn::CreatedAt, UserColumn::Id, params.cursor.as_ref())
+        .limit(items_per_page + 1)
+        .all(&self.sea_service.db).await?;
+
+        let (users_response, has_more) = into_cursor_page(users_response, items_per_page, params.cursor.as_ref());
+
+        let users = users_response.into_iter().map(SeaUserMapper::model_to_user).collect();
+
+        Ok(FindManyUsersByCursorResponse(users, has_more))
+    }
 }
 
 impl SeaUserRepository {
@@ -0,0 +1,92 @@
+use std::error::Error;
+use async_trait::async_trait;
+use sea_orm::{ActiveModelTrait, ColumnTrait, EntityTrait, PaginatorTrait, QueryFilter, QueryOrder, QuerySelect};
+use uuid::Uuid;
+use crate::core::pagination::PaginationParameters;
+use crate::infra::sea::sea_service::SeaService;
+use crate::infra::sea::versioning::map_versioned_update_error;
+use entities::word_filter::Column as WordFilterColumn;
+use entities::word_filter::Entity as WordFilterEntity;
+use crate::domain::domain_entities::word_filter::WordFilter;
+use crate::domain::repositories::word_filter_repository::{FindManyWordFiltersResponse, WordFilterRepositoryTrait};
+use crate::infra::sea::mappers::sea_word_filter_mapper::SeaWordFilterMapper;
+
+pub struct SeaWordFilterRepository {
+    sea_service: SeaService,
+}
+
+impl SeaWordFilterRepository {
+    // constructor
+    pub async fn new(service: SeaService) -> Self {
+        SeaWordFilterRepository {
+            sea_service: service,
+        }
+    }
+}
+
+#[async_trait]
+impl WordFilterRepositoryTrait for SeaWordFilterRepository {
+    async fn create(&self, word_filter: WordFilter) -> Result<WordFilter, Box<dyn Error>> {
+        let word_filter = SeaWordFilterMapper::word_filter_to_sea_active_model(word_filter);
+        let word_filter = word_filter.insert(&self.sea_service.db).await?;
+
+        Ok(SeaWordFilterMapper::model_to_word_filter(word_filter))
+    }
+
+    async fn save(&self, word_filter: WordFilter) -> Result<WordFilter, Box<dyn Error>> {
+        let version = word_filter.version();
+
+        let mut word_filter = SeaWordFilterMapper::word_filter_to_sea_active_model(word_filter);
+        word_filter.version = sea_orm::ActiveValue::Set(version + 1);
+
+        let word_filter = WordFilterEntity::update(word_filter)
+            .filter(WordFilterColumn::Version.eq(version))
+            .exec(&self.sea_service.db)
+            .await
+            .map_err(map_versioned_update_error)?;
+
+        Ok(SeaWordFilterMapper::model_to_word_filter(word_filter))
+    }
+
+    async fn delete(&self, word_filter: WordFilter) -> Result<(), Box<dyn Error>> {
+        let word_filter = SeaWordFilterMapper::word_filter_to_sea_active_model(word_filter);
+        word_filter.delete(&self.sea_service.db).await?;
+
+        Ok(())
+    }
+
+    async fn find_by_id(&self, id: Uuid) -> Result<Option<WordFilter>, Box<dyn Error>> {
+        let word_filter = WordFilterEntity::find_by_id(id).one(&self.sea_service.db).await?;
+
+        Ok(word_filter.map(SeaWordFilterMapper::model_to_word_filter))
+    }
+
+    async fn find_many(&self, params: PaginationParameters<()>) -> Result<FindManyWordFiltersResponse, Box<dyn Error>> {
+        let items_per_page = params.items_per_page as u64;
+        let leap = (params.page as u64 - 1) * items_per_page;
+
+        let word_filters = WordFilterEntity::find()
+            .order_by_desc(WordFilterColumn::CreatedAt)
+            .limit(items_per_page)
+            .offset(leap)
+            .all(&self.sea_service.db)
+            .await?;
+
+        let word_filters_count = WordFilterEntity::find()
+            .count(&self.sea_service.db)
+            .await?;
+
+        let word_filters = word_filters.into_iter().map(SeaWordFilterMapper::model_to_word_filter).collect();
+
+        Ok(FindManyWordFiltersResponse (word_filters, word_filters_count))
+    }
+
+    async fn find_all(&self) -> Result<Vec<WordFilter>, Box<dyn Error>> {
+        let word_filters = WordFilterEntity::find()
+            .order_by_asc(WordFilterColumn::CreatedAt)
+            .all(&self.sea_service.db)
+            .await?;
+
+        Ok(word_filters.into_iter().map(SeaWordFilterMapper::model_to_word_filter).collect())
+    }
+}
@@ -0,0 +1,18 @@
+use std::error::Error;
+
+use sea_orm::DbErr;
+
+use crate::errors::conflict_error::ConflictError;
+
+/**
+ Maps the error of an update conditioned on the row's version. No row being updated means the row was
+ changed by someone else since it was read, which is reported as a conflict instead of a database failure.
+ */
+pub fn map_versioned_update_error(error: DbErr) -> Box<dyn Error> {
+    match error {
+        DbErr::RecordNotUpdated => Box::new(ConflictError::new_with_message(
+            "The resource was changed by someone else in the meantime, reload it and try again.".into()
+        )),
+        error => Box::new(error)
+    }
+}
@@ -1,7 +1,11 @@
 use std::str::FromStr;
 use validator::ValidationError;
 
+use crate::domain::domain_entities::article_block::{ArticleBlock, MAX_BLOCKS_PER_ARTICLE};
+use crate::domain::domain_entities::article_reaction::ReactionKind;
+use crate::domain::domain_entities::comment_report::ReportReason;
 use crate::domain::domain_entities::role::Role;
+use crate::domain::domain_entities::word_filter::WordFilterAction;
 
 pub fn validate_user_role(role: &str) -> Result<(), ValidationError> {
     let role_is_valid = Role::from_str(role).is_ok();
@@ -11,3 +15,51 @@ pub fn validate_user_role(role: &str) -> Result<(), ValidationError> {
         false => Err(ValidationError::new("Invalid user role."))
     }
 }
+
+pub fn validate_article_reaction(reaction: &str) -> Result<(), ValidationError> {
+    let reaction_is_valid = ReactionKind::from_str(reaction).is_ok();
+
+    match reaction_is_valid {
+        true => Ok(()),
+        false => Err(ValidationError::new("Invalid article reaction."))
+    }
+}
+
+pub fn validate_word_filter_action(action: &str) -> Result<(), ValidationError> {
+    let action_is_valid = WordFilterAction::from_str(action).is_ok();
+
+    match action_is_valid {
+        true => Ok(()),
+        false => Err(ValidationError::new("Invalid word filter action."))
+    }
+}
+
+pub fn validate_report_reason(reason: &str) -> Result<(), ValidationError> {
+    let reason_is_valid = ReportReason::from_str(reason).is_ok();
+
+    match reason_is_valid {
+        true => Ok(()),
+        false => Err(ValidationError::new("Invalid report reason."))
+    }
+}
+
+pub fn validate_article_blocks(blocks: &[ArticleBlock]) -> Result<(), ValidationError> {
+    if blocks.len() > MAX_BLOCKS_PER_ARTICLE {
+        let mut error = ValidationError::new("Invalid article blocks.");
+        error.message = Some(format!("Articles must have at most {} blocks.", MAX_BLOCKS_PER_ARTICLE).into());
+
+        return Err(error);
+    }
+
+    for (index, block) in blocks.iter().enumerate() {
+        if let Err(message) = block.validate() {
+            let mut error = ValidationError::new("Invalid article block.");
+            error.message = Some(format!("Block {}: {}", index + 1, message).into());
+            error.add_param("index".into(), &index);
+
+            return Err(error);
+        }
+    }
+
+    Ok(())
+}
\ No newline at end of file
@@ -3,6 +3,8 @@ use serde_json::json;
 
 use crate::infra::http::routes::route::RouteTrait;
 use crate::infra::http::routes::api::ApiRoutes;
+use crate::infra::http::routes::feed::FeedRoutes;
+use crate::infra::http::routes::sitemap::SitemapRoutes;
 
 pub struct ServerFactory;
 
@@ -17,6 +19,8 @@ impl ServerFactory {
         App::new()
         .wrap(middleware::NormalizePath::new(middleware::TrailingSlash::Trim))
         .configure(ApiRoutes::register)
+        .configure(FeedRoutes::register)
+        .configure(SitemapRoutes::register)
         .app_data(web::JsonConfig::default().error_handler(|err, _req| {
             actix_web::error::InternalError::from_response(
                 "",
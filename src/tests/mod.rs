@@ -1,2 +1,4 @@
 #[cfg(test)]
-pub mod repositories;
\ No newline at end of file
+pub mod repositories;
+#[cfg(test)]
+pub mod pagination;
\ No newline at end of file
@@ -0,0 +1,37 @@
+use chrono::NaiveDateTime as DateTime;
+
+use crate::core::pagination::{CursorDirection, PaginationCursor};
+
+/// Walks the items the way the repositories do on cursor listings, returning the page and whether there are more items past it.
+pub fn walk_by_cursor<T, Id: Ord + Clone>(
+    mut items: Vec<T>,
+    key: impl Fn(&T) -> (DateTime, Id),
+    cursor: Option<PaginationCursor<Id>>,
+    items_per_page: u32,
+) -> (Vec<T>, bool) {
+    items.sort_by_key(|item| std::cmp::Reverse(key(item)));
+
+    let direction = cursor.as_ref().map(|cursor| cursor.direction).unwrap_or(CursorDirection::After);
+
+    if let Some(cursor) = cursor {
+        let cursor_key = (cursor.created_at, cursor.id);
+
+        items.retain(|item| match cursor.direction {
+            CursorDirection::After => key(item) < cursor_key,
+            CursorDirection::Before => key(item) > cursor_key,
+        });
+    }
+
+    if direction == CursorDirection::Before {
+        items.reverse();
+    }
+
+    let has_more = items.len() > items_per_page as usize;
+    items.truncate(items_per_page as usize);
+
+    if direction == CursorDirection::Before {
+        items.reverse();
+    }
+
+    (items, has_more)
+}
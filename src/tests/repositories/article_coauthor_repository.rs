@@ -0,0 +1,42 @@
+use std::sync::{Arc, Mutex};
+use crate::domain::domain_entities::article_coauthor::ArticleCoauthor;
+use crate::domain::repositories::article_coauthor_repository::MockArticleCoauthorRepositoryTrait;
+
+pub fn get_article_coauthor_repository() -> (Arc<Mutex<Vec<ArticleCoauthor>>>, MockArticleCoauthorRepositoryTrait) {
+    let db: Arc<Mutex<Vec<ArticleCoauthor>>> = Arc::new(Mutex::new(Vec::new()));
+
+    let mut repository = MockArticleCoauthorRepositoryTrait::new();
+
+    let db_clone = Arc::clone(&db);
+    repository.expect_find_by_article().returning(move |article_id| {
+        let mut coauthors: Vec<ArticleCoauthor> = db_clone.lock().unwrap().iter()
+            .filter(|item| item.article_id().eq(&article_id))
+            .cloned()
+            .collect();
+
+        coauthors.sort_by_key(|item| item.position());
+
+        Ok(coauthors)
+    });
+
+    let db_clone = Arc::clone(&db);
+    repository.expect_find_by_user().returning(move |article_id, user_id| {
+        let coauthor = db_clone.lock().unwrap().iter()
+            .find(|item| item.article_id().eq(&article_id) && item.user_id().eq(&user_id))
+            .cloned();
+
+        Ok(coauthor)
+    });
+
+    let db_clone = Arc::clone(&db);
+    repository.expect_replace().returning(move |article_id, coauthors| {
+        let mut db = db_clone.lock().unwrap();
+
+        db.retain(|item| !item.article_id().eq(&article_id));
+        db.extend(coauthors.iter().cloned());
+
+        Ok(coauthors)
+    });
+
+    (db, repository)
+}
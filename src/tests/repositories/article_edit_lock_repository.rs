@@ -0,0 +1,37 @@
+use std::sync::{Arc, Mutex};
+use crate::domain::domain_entities::article_edit_lock::ArticleEditLock;
+use crate::domain::repositories::article_edit_lock_repository::MockArticleEditLockRepositoryTrait;
+
+pub fn get_article_edit_lock_repository() -> (Arc<Mutex<Vec<ArticleEditLock>>>, MockArticleEditLockRepositoryTrait) {
+    let db: Arc<Mutex<Vec<ArticleEditLock>>> = Arc::new(Mutex::new(Vec::new()));
+
+    let mut repository = MockArticleEditLockRepositoryTrait::new();
+
+    let db_clone = Arc::clone(&db);
+    repository.expect_find_by_article().returning(move |article_id| {
+        let lock = db_clone.lock().unwrap().iter()
+            .find(|item| item.article_id().eq(&article_id))
+            .cloned();
+
+        Ok(lock)
+    });
+
+    let db_clone = Arc::clone(&db);
+    repository.expect_save().returning(move |lock| {
+        let mut db = db_clone.lock().unwrap();
+
+        db.retain(|item| !item.article_id().eq(&lock.article_id()));
+        db.push(lock.clone());
+
+        Ok(lock)
+    });
+
+    let db_clone = Arc::clone(&db);
+    repository.expect_delete().returning(move |article_id| {
+        db_clone.lock().unwrap().retain(|item| !item.article_id().eq(&article_id));
+
+        Ok(())
+    });
+
+    (db, repository)
+}
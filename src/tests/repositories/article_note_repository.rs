@@ -0,0 +1,43 @@
+use std::sync::{Arc, Mutex};
+use crate::domain::domain_entities::article_note::ArticleNote;
+use crate::domain::repositories::article_note_repository::MockArticleNoteRepositoryTrait;
+
+pub fn get_article_note_repository() -> (Arc<Mutex<Vec<ArticleNote>>>, MockArticleNoteRepositoryTrait) {
+    let db: Arc<Mutex<Vec<ArticleNote>>> = Arc::new(Mutex::new(Vec::new()));
+
+    let mut repository = MockArticleNoteRepositoryTrait::new();
+
+    let db_clone = Arc::clone(&db);
+    repository.expect_create().returning(move |note| {
+        db_clone.lock().unwrap().push(note.clone());
+        Ok(note)
+    });
+
+    let db_clone = Arc::clone(&db);
+    repository.expect_save().returning(move |note| {
+        for item in db_clone.lock().unwrap().iter_mut() {
+            if item.id().eq(&note.id()) {
+                *item = note.clone();
+            }
+        }
+
+        Ok(note)
+    });
+
+    let db_clone = Arc::clone(&db);
+    repository.expect_find_by_id().returning(move |id| {
+        Ok(db_clone.lock().unwrap().iter().find(|item| item.id().eq(&id)).cloned())
+    });
+
+    let db_clone = Arc::clone(&db);
+    repository.expect_find_many_by_article().returning(move |article_id| {
+        let notes = db_clone.lock().unwrap().iter()
+            .filter(|item| item.article_id().eq(&article_id))
+            .cloned()
+            .collect();
+
+        Ok(notes)
+    });
+
+    (db, repository)
+}
@@ -0,0 +1,63 @@
+use std::collections::HashMap;
+use std::sync::{Arc, Mutex};
+use crate::domain::domain_entities::article_reaction::{ArticleReaction, ReactionKind};
+use crate::domain::repositories::article_reaction_repository::MockArticleReactionRepositoryTrait;
+
+pub fn get_article_reaction_repository() -> (Arc<Mutex<Vec<ArticleReaction>>>, MockArticleReactionRepositoryTrait) {
+    let db: Arc<Mutex<Vec<ArticleReaction>>> = Arc::new(Mutex::new(Vec::new()));
+
+    let mut repository = MockArticleReactionRepositoryTrait::new();
+
+    let db_clone = Arc::clone(&db);
+    repository.expect_create().returning(move |reaction| {
+        let mut db = db_clone.lock().unwrap();
+        db.retain(|item| item.article_id() != reaction.article_id() || item.user_id() != reaction.user_id());
+        db.push(reaction.clone());
+
+        Ok(reaction)
+    });
+
+    let db_clone = Arc::clone(&db);
+    repository.expect_save().returning(move |reaction| {
+        for item in db_clone.lock().unwrap().iter_mut() {
+            if item.article_id().eq(&reaction.article_id()) && item.user_id().eq(&reaction.user_id()) {
+                *item = reaction.clone();
+            }
+        }
+
+        Ok(reaction)
+    });
+
+    let db_clone = Arc::clone(&db);
+    repository.expect_delete().returning(move |reaction| {
+        db_clone.lock().unwrap().retain(|item| {
+            !(item.article_id().eq(&reaction.article_id()) && item.user_id().eq(&reaction.user_id()))
+        });
+
+        Ok(())
+    });
+
+    let db_clone = Arc::clone(&db);
+    repository.expect_find_by_user().returning(move |article_id, user_id| {
+        let reaction = db_clone.lock().unwrap().iter()
+            .find(|item| item.article_id().eq(&article_id) && item.user_id().eq(&user_id))
+            .cloned();
+
+        Ok(reaction)
+    });
+
+    let db_clone = Arc::clone(&db);
+    repository.expect_count_by_article().returning(move |article_id| {
+        let mut counts: HashMap<ReactionKind, u64> = HashMap::new();
+
+        for item in db_clone.lock().unwrap().iter() {
+            if item.article_id().eq(&article_id) {
+                *counts.entry(item.kind()).or_insert(0) += 1;
+            }
+        }
+
+        Ok(counts)
+    });
+
+    (db, repository)
+}
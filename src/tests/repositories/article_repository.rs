@@ -1,8 +1,37 @@
 use std::sync::{Arc, Mutex};
-use crate::core::pagination::PaginationParameters;
+use chrono::Datelike;
+use crate::core::pagination::{CursorPaginationParameters, PaginationParameters};
 use crate::domain::domain_entities::article::Article;
-use crate::domain::repositories::article_repository::{ArticleQueryType, FindManyArticlesResponse, MockArticleRepositoryTrait};
+use crate::domain::domain_entities::listed_article::ListedArticle;
+use crate::domain::domain_entities::slug::Slug;
+use crate::domain::repositories::article_repository::{ApprovedArticleSlug, ArticleArchiveMonth, ArticleQueryType, ArticleSortOrder, FindManyArticlesByCursorResponse, FindManyArticlesResponse, MockArticleRepositoryTrait};
+use crate::errors::conflict_error::ConflictError;
 use crate::errors::resource_not_found::ResourceNotFoundError;
+use crate::libs::time::TimeHelper;
+use crate::tests::pagination::walk_by_cursor;
+use uuid::Uuid;
+
+/// An approved article, open for comments.
+pub fn get_article(id: Uuid, author_id: Uuid) -> Article {
+    Article::new_from_existing(
+        id,
+        author_id,
+        "cover_url".into(),
+        "title".into(),
+        "content".into(),
+        None,
+        1,
+        1,
+        true,
+        false,
+        TimeHelper::now(),
+        None,
+        Some(1),
+        Some("Foo".to_string()),
+        Slug::new(id, "title".into()),
+        1
+    )
+}
 
 pub fn get_article_repository() -> (Arc<Mutex<Vec<Article>>>, MockArticleRepositoryTrait) {
     let db: Arc<Mutex<Vec<Article>>> = Arc::new(Mutex::new(vec![]));
@@ -17,44 +46,28 @@ pub fn get_article_repository() -> (Arc<Mutex<Vec<Article>>>, MockArticleReposit
 
     let db_clone = Arc::clone(&db);
     repository.expect_find_many()
-        .returning(move |params, approved_status_filter| {
+        .returning(move |params, approved_status_filter, sort| {
             let PaginationParameters { page, items_per_page, query } = params;
 
-            let mut articles: Vec<Article> = Vec::new();
-
-            if query.is_some() {
-                let query = query.unwrap();
-                match query {
-                    ArticleQueryType::Title(content) => {
-                        for item in db_clone.lock().unwrap().iter() {
-                            if item.title().to_lowercase().contains(&content.clone().to_lowercase()) {
-                                articles.push(item.clone());
-                            }
-                        }
-                    },
-                    ArticleQueryType::Author(content) => {
-                        for item in db_clone.lock().unwrap().iter() {
-                            if item.author_id().eq(&content) {
-                                articles.push(item.clone());
-                            }
-                        }
-                    },
-                    ArticleQueryType::Tag(tag_id) => {
-                        for item in db_clone.lock().unwrap().iter() {
-                            if item.tag_id().unwrap().eq(&tag_id) {}
-                            articles.push(item.clone())
-                        }
-                    }
-                }
-            } else {
-                articles = db_clone.lock().unwrap().clone();
-            }
+            let articles_db = db_clone.lock().unwrap().clone();
+
+            let mut articles: Vec<Article> = match query {
+                Some(filters) => articles_db.into_iter().filter(|article| filters.iter().all(|filter| matches_query(article, filter))).collect(),
+                None => articles_db,
+            };
 
             if approved_status_filter.is_some() {
                 let approved_filter: bool = approved_status_filter.unwrap();
                 articles = articles.into_iter().filter(|article| article.approved().eq(&approved_filter)).collect::<Vec<Article>>();
             }
 
+            match sort {
+                ArticleSortOrder::Newest => (),
+                ArticleSortOrder::Oldest => articles.reverse(),
+                ArticleSortOrder::ShortestReadingTime => articles.sort_by_key(|article| article.word_count()),
+                ArticleSortOrder::LongestReadingTime => articles.sort_by_key(|article| std::cmp::Reverse(article.word_count())),
+            }
+
             let total_of_items_before_paginating = articles.len();
 
             let leap = (page - 1) * items_per_page;
@@ -67,7 +80,40 @@ pub fn get_article_repository() -> (Arc<Mutex<Vec<Article>>>, MockArticleReposit
                 }
             }
 
-            Ok(FindManyArticlesResponse (res_articles, total_of_items_before_paginating as u64))
+            Ok(FindManyArticlesResponse (res_articles.into_iter().map(to_listed_article).collect(), total_of_items_before_paginating as u64))
+        });
+
+    let db_clone = Arc::clone(&db);
+    repository.expect_find_many_by_cursor()
+        .returning(move |params, approved_status_filter| {
+            let CursorPaginationParameters { cursor, items_per_page, query } = params;
+
+            let articles: Vec<Article> = db_clone.lock().unwrap().clone().into_iter().filter(|article| {
+                let matches_query = query.as_ref().map_or(true, |filters| filters.iter().all(|filter| matches_query(article, filter)));
+
+                let matches_approved_state = approved_status_filter.is_none() || article.approved().eq(&approved_status_filter.unwrap());
+
+                matches_query && matches_approved_state
+            }).collect();
+
+            let (articles, has_more) = walk_by_cursor(articles, |article| (article.created_at(), article.id()), cursor, items_per_page);
+
+            Ok(FindManyArticlesByCursorResponse (articles.into_iter().map(to_listed_article).collect(), has_more))
+        });
+
+    let db_clone = Arc::clone(&db);
+    repository.expect_find_all_by_filters()
+        .returning(move |filters, approved_status_filter, limit| {
+            let mut articles: Vec<Article> = db_clone.lock().unwrap().iter()
+                .filter(|article| filters.iter().all(|filter| matches_query(article, filter)))
+                .filter(|article| approved_status_filter.is_none() || article.approved().eq(&approved_status_filter.unwrap()))
+                .cloned()
+                .collect();
+
+            articles.sort_by_key(|article| article.created_at());
+            articles.truncate(limit as usize);
+
+            Ok(articles.into_iter().map(to_listed_article).collect())
         });
 
     let db_clone = Arc::clone(&db);
@@ -95,6 +141,9 @@ pub fn get_article_repository() -> (Arc<Mutex<Vec<Article>>>, MockArticleReposit
 
             return match index {
                 None => Err(Box::new(ResourceNotFoundError::new())),
+                Some(i) if db_clone.lock().unwrap()[i].version() != param_article.version() => {
+                    Err(Box::new(ConflictError::new()))
+                },
                 Some(i) => {
                     db_clone.lock().unwrap()[i] = param_article.clone();
                     Ok(param_article)
@@ -132,5 +181,51 @@ pub fn get_article_repository() -> (Arc<Mutex<Vec<Article>>>, MockArticleReposit
 
         });
 
+    let db_clone = Arc::clone(&db);
+    repository.expect_find_approved_slugs()
+        .returning(move || {
+            let slugs = db_clone.lock().unwrap().iter()
+                .filter(|article| article.approved())
+                .map(|article| ApprovedArticleSlug (article.slug(), article.updated_at().unwrap_or(article.created_at())))
+                .collect();
+
+            Ok(slugs)
+        });
+
+    let db_clone = Arc::clone(&db);
+    repository.expect_count_approved_by_month()
+        .returning(move || {
+            let mut months: Vec<ArticleArchiveMonth> = vec![];
+
+            for article in db_clone.lock().unwrap().iter().filter(|article| article.approved()) {
+                let (year, month) = (article.created_at().year(), article.created_at().month());
+
+                match months.iter_mut().find(|bucket| bucket.0 == year && bucket.1 == month) {
+                    Some(bucket) => bucket.2 += 1,
+                    None => months.push(ArticleArchiveMonth (year, month, 1)),
+                }
+            }
+
+            months.sort_by(|a, b| (b.0, b.1).cmp(&(a.0, a.1)));
+
+            Ok(months)
+        });
+
     (db, repository)
 }
+
+fn matches_query(article: &Article, query: &ArticleQueryType) -> bool {
+    match query {
+        ArticleQueryType::Title(content) => article.title().to_lowercase().contains(&content.to_lowercase()),
+        ArticleQueryType::Author(content) => article.author_id().eq(content),
+        ArticleQueryType::Tag(tag_id) => article.tag_id().eq(&Some(*tag_id)),
+        ArticleQueryType::ReadingTime(min, max) => min.map_or(true, |min| article.reading_time() >= min) && max.map_or(true, |max| article.reading_time() <= max),
+        ArticleQueryType::CreatedAfter(date) => article.created_at() >= *date,
+        ArticleQueryType::CreatedBetween(start, end) => article.created_at() >= *start && article.created_at() < *end,
+    }
+}
+
+/// The mocked database does not hold the authors, the comments nor the edit locks, so the listing summary is left blank.
+fn to_listed_article(article: Article) -> ListedArticle {
+    ListedArticle::new(article, String::new(), None, 0, None)
+}
\ No newline at end of file
@@ -2,6 +2,7 @@ use std::sync::{Arc, Mutex};
 use crate::core::pagination::PaginationParameters;
 use crate::domain::domain_entities::article_tag::ArticleTag;
 use crate::domain::repositories::article_tag_repository::{ArticleTagQueryType, FindManyArticleTagsResponse, MockArticleTagRepositoryTrait};
+use crate::errors::conflict_error::ConflictError;
 use crate::errors::resource_not_found::ResourceNotFoundError;
 
 pub fn get_article_tag_repository() -> (Arc<Mutex<Vec<ArticleTag>>>, MockArticleTagRepositoryTrait) {
@@ -14,7 +15,7 @@ pub fn get_article_tag_repository() -> (Arc<Mutex<Vec<ArticleTag>>>, MockArticle
         .returning(move |draft_tag| {
             let id = db_clone.lock().unwrap().len() + 1;
 
-            let tag = ArticleTag::new_from_existing(id as i32, draft_tag.value().into());
+            let tag = ArticleTag::new_from_existing(id as i32, draft_tag.value().into(), 1);
             db_clone.lock().unwrap().push(tag.clone());
 
             Ok(tag)
@@ -72,6 +73,9 @@ pub fn get_article_tag_repository() -> (Arc<Mutex<Vec<ArticleTag>>>, MockArticle
             }
 
             match index {
+                Some(i) if db_clone.lock().unwrap()[i].version() != tag.version() => {
+                    Err(Box::new(ConflictError::new()))
+                },
                 Some(i) => {
                     db_clone.lock().unwrap()[i] = tag.clone();
                     Ok(tag)
@@ -115,5 +119,9 @@ pub fn get_article_tag_repository() -> (Arc<Mutex<Vec<ArticleTag>>>, MockArticle
             Ok(FindManyArticleTagsResponse (res_tags, total_of_items_before_paginating as u64))
         });
 
+    let db_clone = Arc::clone(&db);
+    repository.expect_find_all()
+        .returning(move || Ok(db_clone.lock().unwrap().clone()));
+
     (db, repository)
 }
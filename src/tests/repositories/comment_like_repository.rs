@@ -0,0 +1,54 @@
+use std::sync::{Arc, Mutex};
+use crate::domain::domain_entities::comment_like::CommentLike;
+use crate::domain::repositories::comment_like_repository::MockCommentLikeRepositoryTrait;
+
+pub fn get_comment_like_repository() -> (Arc<Mutex<Vec<CommentLike>>>, MockCommentLikeRepositoryTrait) {
+    let db: Arc<Mutex<Vec<CommentLike>>> = Arc::new(Mutex::new(Vec::new()));
+
+    let mut repository = MockCommentLikeRepositoryTrait::new();
+
+    let db_clone = Arc::clone(&db);
+    repository.expect_create().returning(move |like| {
+        db_clone.lock().unwrap().push(like.clone());
+        Ok(like)
+    });
+
+    let db_clone = Arc::clone(&db);
+    repository.expect_delete().returning(move |like| {
+        db_clone.lock().unwrap().retain(|item| {
+            !(item.comment_id().eq(&like.comment_id()) && item.user_id().eq(&like.user_id()))
+        });
+
+        Ok(())
+    });
+
+    let db_clone = Arc::clone(&db);
+    repository.expect_find_by_user().returning(move |comment_id, user_id| {
+        let like = db_clone.lock().unwrap().iter()
+            .find(|item| item.comment_id().eq(&comment_id) && item.user_id().eq(&user_id))
+            .cloned();
+
+        Ok(like)
+    });
+
+    let db_clone = Arc::clone(&db);
+    repository.expect_count_by_comment().returning(move |comment_id| {
+        let count = db_clone.lock().unwrap().iter()
+            .filter(|item| item.comment_id().eq(&comment_id))
+            .count();
+
+        Ok(count as u64)
+    });
+
+    let db_clone = Arc::clone(&db);
+    repository.expect_find_liked_comment_ids().returning(move |user_id, comment_ids| {
+        let liked_comment_ids = db_clone.lock().unwrap().iter()
+            .filter(|item| item.user_id().eq(&user_id) && comment_ids.contains(&item.comment_id()))
+            .map(|item| item.comment_id())
+            .collect();
+
+        Ok(liked_comment_ids)
+    });
+
+    (db, repository)
+}
@@ -0,0 +1,113 @@
+use std::cmp::Ordering;
+use std::sync::{Arc, Mutex};
+use chrono::NaiveDateTime;
+use uuid::Uuid;
+use crate::core::pagination::{CursorDirection, CursorPaginationParameters, PaginationParameters};
+use crate::domain::domain_entities::comment_with_author::CommentWithAuthor;
+use crate::domain::repositories::comment_user_article_repository::{CommentSortOrder, CommentWithAuthorQueryType, FindManyCommentsWithAuthorByCursorResponse, FindManyCommentsWithAuthorResponse, MockCommentUserArticleRepositoryTrait};
+
+pub fn get_comment_user_article_repository() -> (Arc<Mutex<Vec<CommentWithAuthor>>>, MockCommentUserArticleRepositoryTrait) {
+    let db: Arc<Mutex<Vec<CommentWithAuthor>>> = Arc::new(Mutex::new(vec![]));
+    let mut repository = MockCommentUserArticleRepositoryTrait::new();
+
+    let db_clone = Arc::clone(&db);
+    repository.expect_find_many_comments()
+        .returning(move |article_id, include_inactive, sort, params| {
+            let PaginationParameters { page, items_per_page, query } = params;
+
+            let comments = listing(&db_clone.lock().unwrap(), article_id, include_inactive, sort, query.as_ref());
+            let total_items = comments.len() as u64;
+
+            let comments = comments.into_iter()
+                .skip(((page - 1) * items_per_page) as usize)
+                .take(items_per_page as usize)
+                .collect();
+
+            Ok(FindManyCommentsWithAuthorResponse (comments, total_items))
+        });
+
+    let db_clone = Arc::clone(&db);
+    repository.expect_find_many_comments_by_cursor()
+        .returning(move |article_id, include_inactive, sort, params| {
+            let CursorPaginationParameters { cursor, items_per_page, query } = params;
+
+            let (mut pinned_comments, mut comments): (Vec<CommentWithAuthor>, Vec<CommentWithAuthor>) =
+                listing(&db_clone.lock().unwrap(), article_id, include_inactive, sort, query.as_ref())
+                .into_iter()
+                .partition(|comment| comment.pinned_at().is_some());
+
+            let is_first_page = cursor.is_none();
+            let direction = cursor.as_ref().map(|cursor| cursor.direction).unwrap_or(CursorDirection::After);
+
+            if let Some(cursor) = cursor {
+                let key = (cursor.rank.unwrap_or_default(), cursor.created_at, cursor.id);
+
+                comments.retain(|comment| match cursor.direction {
+                    CursorDirection::After => compare(sort, &sort_key(sort, comment), &key) == Ordering::Greater,
+                    CursorDirection::Before => compare(sort, &sort_key(sort, comment), &key) == Ordering::Less,
+                });
+            }
+
+            if direction == CursorDirection::Before {
+                comments.reverse();
+            }
+
+            let has_more = comments.len() > items_per_page as usize;
+            comments.truncate(items_per_page as usize);
+
+            if direction == CursorDirection::Before {
+                comments.reverse();
+            }
+
+            if is_first_page || (direction == CursorDirection::Before && !has_more) {
+                pinned_comments.append(&mut comments);
+                comments = pinned_comments;
+            }
+
+            Ok(FindManyCommentsWithAuthorByCursorResponse (comments, has_more))
+        });
+
+    (db, repository)
+}
+
+type SortKey = (i64, NaiveDateTime, Uuid);
+
+fn sort_key(sort: CommentSortOrder, comment: &CommentWithAuthor) -> SortKey {
+    (sort.rank(comment).unwrap_or_default(), comment.created_at(), comment.id())
+}
+
+/// How `a` is placed relative to `b` in the listing.
+fn compare(sort: CommentSortOrder, a: &SortKey, b: &SortKey) -> Ordering {
+    match sort {
+        CommentSortOrder::Oldest => a.cmp(b),
+        CommentSortOrder::Newest | CommentSortOrder::MostReplied | CommentSortOrder::Top => b.cmp(a),
+    }
+}
+
+fn listing(
+    db: &[CommentWithAuthor],
+    article_id: Uuid,
+    include_inactive: bool,
+    sort: CommentSortOrder,
+    query: Option<&CommentWithAuthorQueryType>,
+) -> Vec<CommentWithAuthor> {
+    let mut comments: Vec<CommentWithAuthor> = db.iter()
+        .filter(|comment| comment.article_id() == Some(article_id) && comment.parent_id().is_none())
+        .filter(|comment| include_inactive || comment.is_active())
+        .filter(|comment| match query {
+            None => true,
+            Some(CommentWithAuthorQueryType::Author(author_id)) => comment.author().id().eq(author_id),
+            Some(CommentWithAuthorQueryType::Content(content)) => comment.content().to_lowercase().contains(&content.to_lowercase()),
+        })
+        .cloned()
+        .collect();
+
+    comments.sort_by(|a, b| match (a.pinned_at(), b.pinned_at()) {
+        (Some(a), Some(b)) => a.cmp(&b),
+        (Some(_), None) => Ordering::Less,
+        (None, Some(_)) => Ordering::Greater,
+        (None, None) => compare(sort, &sort_key(sort, a), &sort_key(sort, b)),
+    });
+
+    comments
+}
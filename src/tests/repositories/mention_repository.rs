@@ -0,0 +1,71 @@
+use std::sync::{Arc, Mutex};
+use crate::domain::domain_entities::mention::{Mention, MentionSource};
+use crate::domain::repositories::mention_repository::{FindManyMentionsByCursorResponse, FindManyMentionsResponse, MockMentionRepositoryTrait};
+use crate::tests::pagination::walk_by_cursor;
+
+pub fn get_mention_repository() -> (Arc<Mutex<Vec<Mention>>>, MockMentionRepositoryTrait) {
+    let db: Arc<Mutex<Vec<Mention>>> = Arc::new(Mutex::new(Vec::new()));
+
+    let mut repository = MockMentionRepositoryTrait::new();
+
+    let db_clone = Arc::clone(&db);
+    repository.expect_replace_many().returning(move |source, mentions| {
+        let mut db = db_clone.lock().unwrap();
+
+        db.retain(|item| match source {
+            MentionSource::Article(article_id) => item.article_id() != Some(article_id) || item.comment_id().is_some(),
+            MentionSource::Comment { comment_id, .. } => item.comment_id() != Some(comment_id),
+        });
+        db.extend(mentions);
+
+        Ok(())
+    });
+
+    let db_clone = Arc::clone(&db);
+    repository.expect_find_many_by_article().returning(move |article_id| {
+        let mentions = db_clone.lock().unwrap().iter()
+            .filter(|item| item.article_id() == Some(article_id) && item.comment_id().is_none())
+            .cloned()
+            .collect();
+
+        Ok(mentions)
+    });
+
+    let db_clone = Arc::clone(&db);
+    repository.expect_find_many_by_comments().returning(move |comment_ids| {
+        let mentions = db_clone.lock().unwrap().iter()
+            .filter(|item| item.comment_id().is_some_and(|id| comment_ids.contains(&id)))
+            .cloned()
+            .collect();
+
+        Ok(mentions)
+    });
+
+    let db_clone = Arc::clone(&db);
+    repository.expect_find_many_by_user().returning(move |user_id, params| {
+        let mentions: Vec<Mention> = db_clone.lock().unwrap().iter()
+            .filter(|item| item.user_id() == user_id)
+            .cloned()
+            .collect();
+
+        let total = mentions.len() as u64;
+        let leap = ((params.page - 1) * params.items_per_page) as usize;
+        let page = mentions.into_iter().skip(leap).take(params.items_per_page as usize).collect();
+
+        Ok(FindManyMentionsResponse (page, total))
+    });
+
+    let db_clone = Arc::clone(&db);
+    repository.expect_find_many_by_user_by_cursor().returning(move |user_id, params| {
+        let mentions: Vec<Mention> = db_clone.lock().unwrap().iter()
+            .filter(|item| item.user_id() == user_id)
+            .cloned()
+            .collect();
+
+        let (page, has_more) = walk_by_cursor(mentions, |mention| (mention.created_at(), mention.id()), params.cursor, params.items_per_page);
+
+        Ok(FindManyMentionsByCursorResponse (page, has_more))
+    });
+
+    (db, repository)
+}
@@ -1,3 +1,13 @@
 pub mod article_tag_repository;
 pub mod article_repository;
-pub mod free_badge_repository;
\ No newline at end of file
+pub mod free_badge_repository;
+pub mod article_reaction_repository;
+pub mod comment_like_repository;
+pub mod article_coauthor_repository;
+pub mod article_edit_lock_repository;
+pub mod article_note_repository;
+pub mod mention_repository;
+pub mod user_repository;
+pub mod word_filter_repository;
+
+pub mod comment_user_article_repository;
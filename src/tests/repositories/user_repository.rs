@@ -0,0 +1,22 @@
+use std::sync::{Arc, Mutex};
+use crate::domain::domain_entities::user::User;
+use crate::domain::repositories::user_repository::MockUserRepositoryTrait;
+
+/// Only covers the lookups, the tests writing users mock them on their own.
+pub fn get_user_repository() -> (Arc<Mutex<Vec<User>>>, MockUserRepositoryTrait) {
+    let db: Arc<Mutex<Vec<User>>> = Arc::new(Mutex::new(Vec::new()));
+
+    let mut repository = MockUserRepositoryTrait::new();
+
+    let db_clone = Arc::clone(&db);
+    repository.expect_find_by_id().returning(move |id| {
+        Ok(db_clone.lock().unwrap().iter().find(|item| item.id().eq(id)).cloned())
+    });
+
+    let db_clone = Arc::clone(&db);
+    repository.expect_find_by_nickname().returning(move |nickname| {
+        Ok(db_clone.lock().unwrap().iter().find(|item| item.nickname().eq_ignore_ascii_case(nickname)).cloned())
+    });
+
+    (db, repository)
+}
@@ -0,0 +1,60 @@
+use std::sync::{Arc, Mutex};
+
+use crate::domain::domain_entities::word_filter::WordFilter;
+use crate::domain::repositories::word_filter_repository::{FindManyWordFiltersResponse, MockWordFilterRepositoryTrait};
+
+pub fn get_word_filter_repository() -> (Arc<Mutex<Vec<WordFilter>>>, MockWordFilterRepositoryTrait) {
+    let db: Arc<Mutex<Vec<WordFilter>>> = Arc::new(Mutex::new(Vec::new()));
+
+    let mut repository = MockWordFilterRepositoryTrait::new();
+
+    let db_clone = Arc::clone(&db);
+    repository.expect_create().returning(move |word_filter| {
+        db_clone.lock().unwrap().push(word_filter.clone());
+        Ok(word_filter)
+    });
+
+    let db_clone = Arc::clone(&db);
+    repository.expect_save().returning(move |word_filter| {
+        for item in db_clone.lock().unwrap().iter_mut() {
+            if item.id() == word_filter.id() {
+                *item = word_filter.clone();
+            }
+        }
+
+        Ok(word_filter)
+    });
+
+    let db_clone = Arc::clone(&db);
+    repository.expect_delete().returning(move |word_filter| {
+        db_clone.lock().unwrap().retain(|item| item.id() != word_filter.id());
+        Ok(())
+    });
+
+    let db_clone = Arc::clone(&db);
+    repository.expect_find_by_id().returning(move |id| {
+        Ok(db_clone.lock().unwrap().iter().find(|item| item.id() == id).cloned())
+    });
+
+    let db_clone = Arc::clone(&db);
+    repository.expect_find_many().returning(move |params| {
+        let word_filters = db_clone.lock().unwrap().clone();
+        let total = word_filters.len() as u64;
+
+        let leap = ((params.page - 1) * params.items_per_page) as usize;
+
+        let word_filters = word_filters.into_iter()
+            .skip(leap)
+            .take(params.items_per_page as usize)
+            .collect();
+
+        Ok(FindManyWordFiltersResponse (word_filters, total))
+    });
+
+    let db_clone = Arc::clone(&db);
+    repository.expect_find_all().returning(move || {
+        Ok(db_clone.lock().unwrap().clone())
+    });
+
+    (db, repository)
+}
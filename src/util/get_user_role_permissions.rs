@@ -12,9 +12,11 @@ pub enum RolePermissions {
     SeeUnapprovedArticle,
     ChangeArticleAuthor,
     DeleteArticle,
+    SeeArticleViews,
 
     InactivateComment,
     DeleteComment,
+    LockArticleComments,
 
     SolveReport,
     DeleteReport,
@@ -34,6 +36,8 @@ pub enum RolePermissions {
     CreateFreeBadge,
     UpdateFreeBadge,
     DeleteFreeBadge,
+
+    ManageWordFilters,
 }
 
 impl RolePermissions {
@@ -41,9 +45,9 @@ impl RolePermissions {
         use RolePermissions::*;
     
         let perms_user = vec![];
-        let perms_writer = vec![CreateArticle, CreateFreeBadge, UpdateFreeBadge, DeleteFreeBadge];
+        let perms_writer = vec![CreateArticle, SeeArticleViews, CreateFreeBadge, UpdateFreeBadge, DeleteFreeBadge];
         let perms_editor = [&perms_writer[..], &[UpdateArticle, ApproveArticle, SeeUnapprovedArticle]].concat();
-        let perms_coord = [&perms_editor[..], &[DisapproveArticle, InactivateComment, SolveReport]].concat();
+        let perms_coord = [&perms_editor[..], &[DisapproveArticle, InactivateComment, LockArticleComments, SolveReport, ManageWordFilters]].concat();
         let perms_admin = [&perms_coord[..], &[UpdateUser, DeleteComment, CreateTeamUser, UpdateTeamUser, DeleteTeamUser]].concat();
         let perms_principal = [&perms_admin[..], &[ChangeUserPassword, DeleteArticle, DeleteReport, CreateNewTeamRole, UpdateTeamRole, ChangeArticleAuthor, CreateArticleTag, UpdateArticleTag]].concat();
         let perms_ceo = [&perms_principal[..], &[DeleteTeamRole, DeleteArticleTag]].concat();
@@ -3,9 +3,11 @@ mod get_user_role_permissions;
 mod verify_user_role_has_permission;
 mod service_internal_error_factory;
 mod error_response_factory;
+mod verify_expected_version;
 
 pub use verify_user_role_hierarchy::exec as verify_role_hierarchy_matches;
 pub use get_user_role_permissions::RolePermissions as RolePermissions;
 pub use verify_user_role_has_permission::exec as verify_role_has_permission;
 pub use service_internal_error_factory::generate_service_internal_error as generate_service_internal_error;
-pub use error_response_factory::generate_error_response as generate_error_response;
\ No newline at end of file
+pub use error_response_factory::generate_error_response as generate_error_response;
+pub use verify_expected_version::exec as verify_expected_version;
\ No newline at end of file
@@ -1,8 +1,13 @@
 use log::error;
-use crate::{errors::{error::DomainErrorTrait, internal_error::InternalError}, LOG_SEP, R_EOL};
+use crate::{errors::{conflict_error::ConflictError, error::DomainErrorTrait, internal_error::InternalError}, LOG_SEP, R_EOL};
 use std::error::Error;
 
+/// Logs the repository's error and hides it behind an internal error, except for conflicts, such as a row changed by someone else meanwhile, which are reported as they are.
 pub fn generate_service_internal_error(message: &str, error: &Box<dyn Error>) -> Box<dyn DomainErrorTrait>{
+    if let Some(conflict) = error.downcast_ref::<ConflictError>() {
+        return Box::new(conflict.clone());
+    }
+
     error!(
         "{R_EOL}{LOG_SEP}{R_EOL}{}: {R_EOL}{}{R_EOL}{LOG_SEP}{R_EOL}",
         message, error
@@ -0,0 +1,14 @@
+use crate::errors::error::DomainErrorTrait;
+use crate::errors::precondition_failed_error::PreconditionFailedError;
+
+/**
+ Verifies the resource is still at the version the client last read, which the services receive as their
+ `expected_version` from the `If-Match` header. Writes based on a stale read are refused, and clients sending
+ no version skip the check.
+ */
+pub fn exec(expected_version: Option<i32>, current_version: i32) -> Result<(), Box<dyn DomainErrorTrait>> {
+    match expected_version {
+        Some(expected_version) if expected_version != current_version => Err(Box::new(PreconditionFailedError::new())),
+        _ => Ok(())
+    }
+}